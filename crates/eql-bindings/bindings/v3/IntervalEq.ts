@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Ciphertext } from "./Ciphertext";
+import type { Hmac256 } from "./Hmac256";
+import type { Identifier } from "./Identifier";
+import type { SchemaVersion } from "./SchemaVersion";
+
+/**
+ * `public.eql_v3_interval_eq` — equality domain.
+ *
+ * Operators: `=` `<>`. Required keys: `v` `i` `c` `hm`.
+ */
+export type IntervalEq = { v: SchemaVersion, i: Identifier, c: Ciphertext, hm: Hmac256, };
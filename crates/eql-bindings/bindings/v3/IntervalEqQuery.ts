@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Hmac256 } from "./Hmac256";
+import type { Identifier } from "./Identifier";
+import type { SchemaVersion } from "./SchemaVersion";
+
+/**
+ * `eql_v3.query_interval_eq` — equality domain query operand.
+ *
+ * Operators: `=` `<>`. Required keys: `v` `i` `hm`.
+ */
+export type IntervalEqQuery = { v: SchemaVersion, i: Identifier, hm: Hmac256, };
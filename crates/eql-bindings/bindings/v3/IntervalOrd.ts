@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Ciphertext } from "./Ciphertext";
+import type { Identifier } from "./Identifier";
+import type { OpeCllw } from "./OpeCllw";
+import type { SchemaVersion } from "./SchemaVersion";
+
+/**
+ * `public.eql_v3_interval_ord` — ordering domain.
+ *
+ * Operators: `=` `<>` `<` `<=` `>` `>=`. Required keys: `v` `i` `c` `op`.
+ */
+export type IntervalOrd = { v: SchemaVersion, i: Identifier, c: Ciphertext, op: OpeCllw, };
@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Ciphertext } from "./Ciphertext";
+import type { Identifier } from "./Identifier";
+import type { OreBlock256 } from "./OreBlock256";
+import type { SchemaVersion } from "./SchemaVersion";
+
+/**
+ * `public.eql_v3_interval_ord_ore` — ordering domain.
+ *
+ * Operators: `=` `<>` `<` `<=` `>` `>=`. Required keys: `v` `i` `c` `ob`.
+ */
+export type IntervalOrdOre = { v: SchemaVersion, i: Identifier, c: Ciphertext, ob: OreBlock256, };
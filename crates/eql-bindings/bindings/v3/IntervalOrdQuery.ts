@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Identifier } from "./Identifier";
+import type { OpeCllw } from "./OpeCllw";
+import type { SchemaVersion } from "./SchemaVersion";
+
+/**
+ * `eql_v3.query_interval_ord` — ordering domain query operand.
+ *
+ * Operators: `=` `<>` `<` `<=` `>` `>=`. Required keys: `v` `i` `op`.
+ */
+export type IntervalOrdQuery = { v: SchemaVersion, i: Identifier, op: OpeCllw, };
@@ -11,5 +11,11 @@
  * Extracted by `eql_v3.ord_term` (scalar domains and the
  * `public.eql_v3_json_entry` overload alike); SQL-side constructor:
  * `eql_v3_internal.ope_cllw`.
+ *
+ * `Ord` is that bytea order: the hex-decoded bytes compared as unsigned
+ * bytes, a proper prefix first. Two spellings of the same bytes (`"AB"`,
+ * `"ab"`) — equal to Postgres — are tie-broken by their text so `Ord` stays
+ * consistent with the derived `Eq`; a term that is not valid hex (the
+ * database would reject it) sorts after every valid one.
  */
 export type OpeCllw = string;
@@ -13,7 +13,10 @@
 //! be an over-provisioned `text` storage column. The caller names the target
 //! via [`TargetDomain::parse`], which resolves against the catalog-generated
 //! inventory ([`crate::v3::all`]) so the accepted names and required term
-//! keys can never drift from `eql-domains::CATALOG`.
+//! keys can never drift from `eql-domains::CATALOG`. When the column
+//! configuration is lost, [`candidate_targets`] suggests the domains of a
+//! family the payload's terms can satisfy, richest first — a hint for a
+//! human or a migration tool, never a substitute for naming the target.
 //!
 //! ## Conversion rules
 //!
@@ -27,6 +30,12 @@
 //! [`FromV2Error::BloomOutOfRange`]; already-negative values pass through).
 //! `ob` and `op` pass through verbatim.
 //!
+//! [`from_v2_many`] splits one over-provisioned payload across several
+//! targets (`hm`+`ob`+`bf` into `text_eq`, `text_ord_ore` and `text_match`
+//! columns) under the same rules, lifting the terms once and returning a
+//! result per target, so a target that fails does not drop the ones that
+//! convert.
+//!
 //! Two deliberate points of divergence from the v2.3 schema file:
 //!
 //! - **`k` is required.** The v2.3 schema marks `k` optional on the `ct`
@@ -79,12 +88,15 @@
 //!
 //! Native v3 SteVec documents and queries are intentionally outside this
 //! module: use cipherstash-client's v3 encryption APIs to produce them.
+//!
+//! The scalar conversions are reversible: [`crate::to_v2`] downgrades a v3
+//! payload or query operand back to the v2 wire for a staged rollback.
 
 mod error;
 mod target;
 
 pub use error::FromV2Error;
-pub use target::{ScalarTarget, TargetDomain};
+pub use target::{candidate_targets, ScalarTarget, TargetDomain};
 
 use serde::de::Error as _;
 use serde_json::{json, Map, Value};
@@ -120,7 +132,67 @@ pub fn from_v2(v2: &Value, target: TargetDomain) -> Result<Value, FromV2Error> {
 /// exactly (pinned by `tests/domain_payload.rs`).
 pub fn from_v2_typed(v2: &Value, target: TargetDomain) -> Result<DomainPayload, FromV2Error> {
     let out = convert(v2, target)?;
-    DomainPayload::parse(target.describe(), &out)
+    parse_stored(target, &out)
+}
+
+/// Convert one over-provisioned STORED v2.3 payload into a typed v3 payload
+/// for each of several `targets` — splitting a v2 column that carried
+/// `hm`+`ob`+`bf` into, say, `text_eq` and `text_match` columns.
+///
+/// The v2 envelope is checked and its terms lifted once (`bf` reinterpreted
+/// once); each target then takes the envelope plus exactly its required
+/// terms, through the same strict parse as [`from_v2_typed`]. A fault in the
+/// payload itself — wrong `v`, a `k` other than `"ct"` (a v2 SteVec document
+/// is [`FromV2Error::UnconvertibleSteVecDocument`] whatever the targets), an
+/// out-of-range `bf` some target needs — is the outer error. A fault
+/// confined to one target (a [`FromV2Error::MissingTerm`], or
+/// [`TargetDomain::Json`], which no `ct` payload converts to) does not stop
+/// the others: the inner results are one per target, in `targets` order,
+/// each what [`from_v2_typed`] would return for it. As there, a target
+/// missing a term fails with that even when the payload's `bf` is also bad.
+pub fn from_v2_many(
+    v2: &Value,
+    targets: &[TargetDomain],
+) -> Result<Vec<Result<DomainPayload, FromV2Error>>, FromV2Error> {
+    let obj = require_v2_envelope(v2)?;
+    match obj.get("k").and_then(Value::as_str) {
+        Some("ct") => {}
+        Some("sv") => return Err(FromV2Error::UnconvertibleSteVecDocument),
+        found => {
+            return Err(FromV2Error::UnknownKind {
+                found: found.map(String::from),
+            })
+        }
+    }
+    let checked: Vec<Result<ScalarTarget, FromV2Error>> = targets
+        .iter()
+        .map(|&target| match target {
+            TargetDomain::Scalar(t) => require_terms(obj, t).map(|()| t),
+            TargetDomain::Json => Err(FromV2Error::KindMismatch {
+                kind: "ct".into(),
+                target: target.describe().into(),
+            }),
+        })
+        .collect();
+    let terms = checked
+        .iter()
+        .flatten()
+        .flat_map(ScalarTarget::term_json_keys);
+    let lifted = lift_scalar(obj, terms)?;
+
+    Ok(checked
+        .into_iter()
+        .map(|checked| {
+            let t = checked?;
+            parse_stored(TargetDomain::Scalar(t), &project_scalar(&lifted, t)?)
+        })
+        .collect())
+}
+
+/// The final strict parse behind [`from_v2_typed`] / [`from_v2_many`],
+/// keeping the [`DomainPayload`] variant for `target`.
+fn parse_stored(target: TargetDomain, out: &Value) -> Result<DomainPayload, FromV2Error> {
+    DomainPayload::parse(target.describe(), out)
         .unwrap_or_else(|| {
             // Every conversion target (all scalar domains + "eql_v3_json_search") has a
             // generated DomainPayload variant; TargetDomain::parse resolved
@@ -362,6 +434,40 @@ fn validate_as(domain: &str, value: &Value) -> Result<(), FromV2Error> {
 
 /// v2 `k: "ct"` → flat scalar `{v: 3, i, c, <required terms>}`.
 fn convert_scalar(obj: &Map<String, Value>, target: ScalarTarget) -> Result<Value, FromV2Error> {
+    require_terms(obj, target)?;
+    project_scalar(&lift_scalar(obj, target.term_json_keys())?, target)
+}
+
+/// Fail with [`FromV2Error::MissingTerm`] on the first of `target`'s
+/// required term keys absent from the v2 payload, in wire order. Checked
+/// before [`lift_scalar`] reinterprets `bf`, so a payload missing a term
+/// reports that rather than a bad `bf`.
+fn require_terms(obj: &Map<String, Value>, target: ScalarTarget) -> Result<(), FromV2Error> {
+    match target
+        .term_json_keys()
+        .iter()
+        .find(|&&key| !obj.contains_key(key))
+    {
+        Some(key) => Err(missing_term(target, key)),
+        None => Ok(()),
+    }
+}
+
+fn missing_term(target: ScalarTarget, key: &str) -> FromV2Error {
+    FromV2Error::MissingTerm {
+        domain: target.domain().into(),
+        key: key.into(),
+        entry: None,
+    }
+}
+
+/// The v3 envelope `{v: 3, i, c}` of a v2 `k: "ct"` payload plus each of
+/// `terms` the payload carries, `bf` reinterpreted. An absent term is left
+/// out for [`project_scalar`] to report against the target that needs it.
+fn lift_scalar<'a>(
+    obj: &Map<String, Value>,
+    terms: impl IntoIterator<Item = &'a &'static str>,
+) -> Result<Map<String, Value>, FromV2Error> {
     let mut out = Map::new();
     out.insert("v".into(), json!(crate::EQL_SCHEMA_VERSION));
     for key in ["i", "c"] {
@@ -372,12 +478,11 @@ fn convert_scalar(obj: &Map<String, Value>, target: ScalarTarget) -> Result<Valu
         // (e.g. a v2 QUERY payload, which omits `c`, is rejected there —
         // from_v2 converts stored payloads only).
     }
-    for &key in target.term_json_keys() {
-        let v = obj.get(key).ok_or_else(|| FromV2Error::MissingTerm {
-            domain: target.domain().into(),
-            key: key.into(),
-            entry: None,
-        })?;
+    for &key in terms {
+        let Some(v) = obj.get(key) else { continue };
+        if out.contains_key(key) {
+            continue;
+        }
         let converted = if key == "bf" {
             convert_bloom(v)?
         } else {
@@ -387,6 +492,23 @@ fn convert_scalar(obj: &Map<String, Value>, target: ScalarTarget) -> Result<Valu
         };
         out.insert(key.into(), converted);
     }
+    Ok(out)
+}
+
+/// `target`'s payload out of a [`lift_scalar`]ed map: the envelope plus
+/// exactly the target's required terms, failing closed with
+/// [`FromV2Error::MissingTerm`] on the first one absent.
+fn project_scalar(lifted: &Map<String, Value>, target: ScalarTarget) -> Result<Value, FromV2Error> {
+    let mut out = Map::new();
+    for key in ["v", "i", "c"] {
+        if let Some(v) = lifted.get(key) {
+            out.insert(key.into(), v.clone());
+        }
+    }
+    for &key in target.term_json_keys() {
+        let v = lifted.get(key).ok_or_else(|| missing_term(target, key))?;
+        out.insert(key.into(), v.clone());
+    }
     Ok(Value::Object(out))
 }
 
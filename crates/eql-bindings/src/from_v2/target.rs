@@ -3,8 +3,12 @@
 //! never be inferred from the payload — the caller (protect-ffi's column
 //! config, a bench's per-table intent) must name it.
 
+use std::cmp::Reverse;
+
+use serde_json::Value;
+
 use super::FromV2Error;
-use crate::v3::all;
+use crate::v3::{all, DomainPayload};
 
 /// The v3 domain a v2 payload converts into.
 ///
@@ -41,6 +45,45 @@ impl ScalarTarget {
     pub fn term_json_keys(&self) -> &'static [&'static str] {
         self.term_keys
     }
+
+    /// `eql_domains::Role::rank` of the domain's role, generated from the
+    /// catalog's `Term::role_for_terms`: the richest capability wins — ord
+    /// 3, eq 2, match 1, storage-only 0. Parity is pinned by
+    /// `tests/catalog_parity.rs`.
+    pub fn rank(&self) -> u8 {
+        DomainPayload::role_rank_of(self.domain)
+            .unwrap_or_else(|| unreachable!("scalar target {} has a catalog role", self.domain))
+    }
+}
+
+/// Every scalar domain of `family` (`"text"`, `"integer"`, …) whose required
+/// term keys are all present in `v2`, richest first — for migration tooling
+/// that lost the column configuration and needs a starting guess.
+///
+/// Advisory only: nothing is converted or validated, and the conversion
+/// entry points still take an explicit [`TargetDomain`]. Candidates are
+/// ordered by [`ScalarTarget::rank`], then by how many terms they carry
+/// (`text_search` before `text_ord_ope`), then in catalog order; the family's
+/// storage-only domain, which every payload satisfies, comes last. Empty for
+/// an unknown family or a non-object `v2`.
+pub fn candidate_targets(v2: &Value, family: &str) -> Vec<ScalarTarget> {
+    let Some(obj) = v2.as_object() else {
+        return Vec::new();
+    };
+    let mut candidates: Vec<ScalarTarget> = all()
+        .into_iter()
+        .filter(|d| DomainPayload::family_of(d.domain()) == Some(family))
+        .filter_map(|d| {
+            let term_keys = d.term_json_keys()?;
+            Some(ScalarTarget {
+                domain: d.domain(),
+                term_keys,
+            })
+        })
+        .filter(|t| t.term_keys.iter().all(|key| obj.contains_key(*key)))
+        .collect();
+    candidates.sort_by_key(|t| Reverse((t.rank(), t.term_keys.len())));
+    candidates
 }
 
 impl TargetDomain {
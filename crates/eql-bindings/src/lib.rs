@@ -32,6 +32,7 @@ use ts_rs::TS;
 
 pub mod from_v2;
 pub mod sql;
+pub mod to_v2;
 pub mod v3;
 
 /// EQL wire-format version. Hard-coded to `3` for every payload in the
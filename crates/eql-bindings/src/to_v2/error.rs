@@ -0,0 +1,55 @@
+//! The `to_v2` error enum — hand-rolled `Display`/`Error`, like
+//! [`crate::from_v2::FromV2Error`].
+
+use std::error::Error;
+use std::fmt;
+
+/// Why a v3 → v2 downgrade was refused. The downgrade only runs the
+/// [`from_v2`](crate::from_v2::from_v2) rules backwards, so every refusal is
+/// a payload `from_v2` could never have produced.
+#[derive(Debug)]
+pub enum ToV2Error {
+    /// A v3 SteVec **document** has no v2 representation: its entries share
+    /// one key header (`h`) and decrypt under selector-derived nonces, where
+    /// a v2 document carries a self-describing ciphertext per entry.
+    /// Re-encrypt through a v2-emitting client.
+    UnconvertibleSteVecDocument,
+    /// A v3 SteVec **query** has no v2 representation: its value-inclusive
+    /// selectors cannot be turned back into a legacy path selector plus
+    /// `hm`/`op` term.
+    UnconvertibleSteVecQuery,
+    /// The payload's domain is not a `from_v2` conversion target (the array
+    /// containment domains and their query operands), so there is no v2
+    /// form to restore.
+    NoV2Form {
+        /// The payload's (unqualified) domain name.
+        domain: &'static str,
+    },
+}
+
+impl fmt::Display for ToV2Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnconvertibleSteVecDocument => {
+                write!(
+                    f,
+                    "a v3 SteVec document cannot be downgraded to v2 (shared key header + \
+                     selector-derived entry nonces) — that is re-encryption, not a JSON \
+                     transformation"
+                )
+            }
+            Self::UnconvertibleSteVecQuery => {
+                write!(
+                    f,
+                    "a v3 SteVec query cannot be downgraded to v2: a value-inclusive selector \
+                     does not yield a legacy path selector and term"
+                )
+            }
+            Self::NoV2Form { domain } => {
+                write!(f, "domain `{domain}` has no v2 payload form")
+            }
+        }
+    }
+}
+
+impl Error for ToV2Error {}
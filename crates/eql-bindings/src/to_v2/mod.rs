@@ -0,0 +1,120 @@
+//! # `to_v2` — EQL v3 → v2.3 wire downgrade
+//!
+//! The inverse of [`crate::from_v2`] for scalar payloads, for staged
+//! rollbacks: when a v3 cutover has to be backed out, already-written v3
+//! rows and freshly-built v3 operands can be put back on the v2 wire without
+//! re-encrypting. Nothing is decrypted — the ciphertext and terms are the
+//! same bytes in both versions.
+//!
+//! ## Conversion rules
+//!
+//! **Scalar** (a [`DomainPayload`] whose domain is a `from_v2` scalar
+//! target): `v: 3` becomes `v: 2`, `k: "ct"` is added, and `i`, `c` and
+//! every term key are copied. `bf` is reinterpreted from the signed
+//! `smallint[]` representation back into v2's unsigned bit positions
+//! (negative values wrap into `32768..=65535`). `hm`, `ob` and `op` pass
+//! through verbatim. The term keys the v3 domain dropped on the way in are
+//! gone for good; the downgraded payload carries exactly the v3 domain's
+//! capability.
+//!
+//! **Query operands** ([`to_v2_query`]): the scalar `{v: 3, i, <terms>}`
+//! operand becomes `{v: 2, k: "ct", i, <terms>}`, which
+//! [`from_v2_query`](crate::from_v2::from_v2_query) accepts for the matching
+//! target.
+//!
+//! The round trip is exact: for every scalar payload `x`,
+//! `from_v2(&to_v2(&x)?, target)` returns `x`'s wire `Value` (pinned for
+//! every scalar domain by `tests/from_v2.rs`). SteVec documents and queries
+//! have no v2 form ([`ToV2Error::UnconvertibleSteVecDocument`],
+//! [`ToV2Error::UnconvertibleSteVecQuery`]), and neither do the array
+//! containment domains `from_v2` cannot target ([`ToV2Error::NoV2Form`]).
+
+mod error;
+
+pub use error::ToV2Error;
+
+use serde_json::{json, Map, Value};
+
+use crate::from_v2::TargetDomain;
+use crate::v3::domain_type::PUBLIC_TYPNAME_PREFIX;
+use crate::v3::{DomainPayload, QueryPayload};
+
+/// The v2 wire version this converter emits.
+const V2_WIRE_VERSION: u64 = 2;
+
+/// Downgrade a STORED v3 payload to the EQL v2.3 `k: "ct"` payload
+/// [`from_v2`](crate::from_v2::from_v2) would convert back into it.
+///
+/// See the [module docs](self) for the conversion rules.
+pub fn to_v2(payload: &DomainPayload) -> Result<Value, ToV2Error> {
+    if let DomainPayload::SteVecDocument(_) = payload {
+        return Err(ToV2Error::UnconvertibleSteVecDocument);
+    }
+    require_scalar_target(payload.domain(), payload.domain())?;
+    Ok(downgrade(payload))
+}
+
+/// Downgrade a v3 QUERY operand to the EQL v2.3 query payload
+/// [`from_v2_query`](crate::from_v2::from_v2_query) would convert back into
+/// it.
+pub fn to_v2_query(payload: &QueryPayload) -> Result<Value, ToV2Error> {
+    if let QueryPayload::SteVec(_) = payload {
+        return Err(ToV2Error::UnconvertibleSteVecQuery);
+    }
+    // `query_text_eq` twins the stored `eql_v3_text_eq`.
+    let stored = payload
+        .domain()
+        .strip_prefix("query_")
+        .map(|bare| format!("{PUBLIC_TYPNAME_PREFIX}{bare}"))
+        .unwrap_or_default();
+    require_scalar_target(&stored, payload.domain())?;
+    Ok(downgrade(payload))
+}
+
+/// Refuse anything `from_v2` could not have converted into: `stored` must
+/// resolve to a [`TargetDomain::Scalar`]. `domain` is the payload's own
+/// name, for the error.
+fn require_scalar_target(stored: &str, domain: &'static str) -> Result<(), ToV2Error> {
+    match TargetDomain::parse(stored) {
+        Ok(TargetDomain::Scalar(_)) => Ok(()),
+        _ => Err(ToV2Error::NoV2Form { domain }),
+    }
+}
+
+/// The v3 wire `Value` with the v2 envelope restored: `v: 2`, `k: "ct"`,
+/// `bf` back to unsigned bit positions, everything else verbatim.
+fn downgrade(payload: &impl serde::Serialize) -> Value {
+    let Value::Object(v3) = serde_json::to_value(payload).expect("a v3 payload serializes to JSON")
+    else {
+        unreachable!("a scalar v3 payload serializes to a JSON object")
+    };
+    let mut out = Map::new();
+    out.insert("v".into(), json!(V2_WIRE_VERSION));
+    out.insert("k".into(), json!("ct"));
+    for (key, value) in v3 {
+        match key.as_str() {
+            "v" => {}
+            "bf" => {
+                out.insert(key, unsigned_bloom(&value));
+            }
+            _ => {
+                out.insert(key, value);
+            }
+        }
+    }
+    Value::Object(out)
+}
+
+/// Reinterpret a signed `smallint[]` `bf` into v2's unsigned bit positions:
+/// the inverse of `from_v2`'s reinterpretation, so negative positions wrap
+/// into the upper half (`-1` → `65535`).
+fn unsigned_bloom(bf: &Value) -> Value {
+    bf.as_array()
+        .expect("a parsed `bf` is an array")
+        .iter()
+        .map(|bit| {
+            let signed = bit.as_i64().expect("a parsed `bf` holds integers") as i16;
+            Value::from(signed as u16)
+        })
+        .collect()
+}
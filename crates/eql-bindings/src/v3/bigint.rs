@@ -37,6 +37,56 @@ impl DomainType for Bigint {
         schema_for!(Bigint)
     }
 }
+#[cfg(feature = "sqlx")]
+impl sqlx::Type<sqlx::Postgres> for Bigint {
+    fn type_info() -> sqlx::postgres::PgTypeInfo {
+        crate::v3::sqlx_codec::type_info::<Self>()
+    }
+    fn compatible(ty: &sqlx::postgres::PgTypeInfo) -> bool {
+        crate::v3::sqlx_codec::compatible::<Self>(ty)
+    }
+}
+#[cfg(feature = "sqlx")]
+impl sqlx::Encode<'_, sqlx::Postgres> for Bigint {
+    fn encode_by_ref(
+        &self,
+        buf: &mut sqlx::postgres::PgArgumentBuffer,
+    ) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+        crate::v3::sqlx_codec::encode(self, buf)
+    }
+}
+#[cfg(feature = "sqlx")]
+impl<'r> sqlx::Decode<'r, sqlx::Postgres> for Bigint {
+    fn decode(value: sqlx::postgres::PgValueRef<'r>) -> Result<Self, sqlx::error::BoxDynError> {
+        crate::v3::sqlx_codec::decode(value)
+    }
+}
+#[cfg(feature = "postgres-types")]
+impl postgres_types::ToSql for Bigint {
+    fn to_sql(
+        &self,
+        ty: &postgres_types::Type,
+        out: &mut bytes::BytesMut,
+    ) -> Result<postgres_types::IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        crate::v3::postgres_codec::to_sql(self, ty, out)
+    }
+    fn accepts(ty: &postgres_types::Type) -> bool {
+        crate::v3::postgres_codec::accepts::<Self>(ty)
+    }
+    postgres_types::to_sql_checked!();
+}
+#[cfg(feature = "postgres-types")]
+impl<'a> postgres_types::FromSql<'a> for Bigint {
+    fn from_sql(
+        ty: &postgres_types::Type,
+        raw: &'a [u8],
+    ) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        crate::v3::postgres_codec::from_sql(ty, raw)
+    }
+    fn accepts(ty: &postgres_types::Type) -> bool {
+        crate::v3::postgres_codec::accepts_column::<Self>(ty)
+    }
+}
 /// `public.eql_v3_bigint_eq` — equality domain.
 ///
 /// Operators: `=` `<>`. Required keys: `v` `i` `c` `hm`.
@@ -69,6 +119,56 @@ impl DomainType for BigintEq {
         schema_for!(BigintEq)
     }
 }
+#[cfg(feature = "sqlx")]
+impl sqlx::Type<sqlx::Postgres> for BigintEq {
+    fn type_info() -> sqlx::postgres::PgTypeInfo {
+        crate::v3::sqlx_codec::type_info::<Self>()
+    }
+    fn compatible(ty: &sqlx::postgres::PgTypeInfo) -> bool {
+        crate::v3::sqlx_codec::compatible::<Self>(ty)
+    }
+}
+#[cfg(feature = "sqlx")]
+impl sqlx::Encode<'_, sqlx::Postgres> for BigintEq {
+    fn encode_by_ref(
+        &self,
+        buf: &mut sqlx::postgres::PgArgumentBuffer,
+    ) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+        crate::v3::sqlx_codec::encode(self, buf)
+    }
+}
+#[cfg(feature = "sqlx")]
+impl<'r> sqlx::Decode<'r, sqlx::Postgres> for BigintEq {
+    fn decode(value: sqlx::postgres::PgValueRef<'r>) -> Result<Self, sqlx::error::BoxDynError> {
+        crate::v3::sqlx_codec::decode(value)
+    }
+}
+#[cfg(feature = "postgres-types")]
+impl postgres_types::ToSql for BigintEq {
+    fn to_sql(
+        &self,
+        ty: &postgres_types::Type,
+        out: &mut bytes::BytesMut,
+    ) -> Result<postgres_types::IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        crate::v3::postgres_codec::to_sql(self, ty, out)
+    }
+    fn accepts(ty: &postgres_types::Type) -> bool {
+        crate::v3::postgres_codec::accepts::<Self>(ty)
+    }
+    postgres_types::to_sql_checked!();
+}
+#[cfg(feature = "postgres-types")]
+impl<'a> postgres_types::FromSql<'a> for BigintEq {
+    fn from_sql(
+        ty: &postgres_types::Type,
+        raw: &'a [u8],
+    ) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        crate::v3::postgres_codec::from_sql(ty, raw)
+    }
+    fn accepts(ty: &postgres_types::Type) -> bool {
+        crate::v3::postgres_codec::accepts_column::<Self>(ty)
+    }
+}
 /// `public.eql_v3_bigint_ord_ore` — ordering domain.
 ///
 /// Operators: `=` `<>` `<` `<=` `>` `>=`. Required keys: `v` `i` `c` `ob`.
@@ -101,6 +201,56 @@ impl DomainType for BigintOrdOre {
         schema_for!(BigintOrdOre)
     }
 }
+#[cfg(feature = "sqlx")]
+impl sqlx::Type<sqlx::Postgres> for BigintOrdOre {
+    fn type_info() -> sqlx::postgres::PgTypeInfo {
+        crate::v3::sqlx_codec::type_info::<Self>()
+    }
+    fn compatible(ty: &sqlx::postgres::PgTypeInfo) -> bool {
+        crate::v3::sqlx_codec::compatible::<Self>(ty)
+    }
+}
+#[cfg(feature = "sqlx")]
+impl sqlx::Encode<'_, sqlx::Postgres> for BigintOrdOre {
+    fn encode_by_ref(
+        &self,
+        buf: &mut sqlx::postgres::PgArgumentBuffer,
+    ) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+        crate::v3::sqlx_codec::encode(self, buf)
+    }
+}
+#[cfg(feature = "sqlx")]
+impl<'r> sqlx::Decode<'r, sqlx::Postgres> for BigintOrdOre {
+    fn decode(value: sqlx::postgres::PgValueRef<'r>) -> Result<Self, sqlx::error::BoxDynError> {
+        crate::v3::sqlx_codec::decode(value)
+    }
+}
+#[cfg(feature = "postgres-types")]
+impl postgres_types::ToSql for BigintOrdOre {
+    fn to_sql(
+        &self,
+        ty: &postgres_types::Type,
+        out: &mut bytes::BytesMut,
+    ) -> Result<postgres_types::IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        crate::v3::postgres_codec::to_sql(self, ty, out)
+    }
+    fn accepts(ty: &postgres_types::Type) -> bool {
+        crate::v3::postgres_codec::accepts::<Self>(ty)
+    }
+    postgres_types::to_sql_checked!();
+}
+#[cfg(feature = "postgres-types")]
+impl<'a> postgres_types::FromSql<'a> for BigintOrdOre {
+    fn from_sql(
+        ty: &postgres_types::Type,
+        raw: &'a [u8],
+    ) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        crate::v3::postgres_codec::from_sql(ty, raw)
+    }
+    fn accepts(ty: &postgres_types::Type) -> bool {
+        crate::v3::postgres_codec::accepts_column::<Self>(ty)
+    }
+}
 /// `public.eql_v3_bigint_ord` — ordering domain.
 ///
 /// Operators: `=` `<>` `<` `<=` `>` `>=`. Required keys: `v` `i` `c` `op`.
@@ -133,6 +283,56 @@ impl DomainType for BigintOrd {
         schema_for!(BigintOrd)
     }
 }
+#[cfg(feature = "sqlx")]
+impl sqlx::Type<sqlx::Postgres> for BigintOrd {
+    fn type_info() -> sqlx::postgres::PgTypeInfo {
+        crate::v3::sqlx_codec::type_info::<Self>()
+    }
+    fn compatible(ty: &sqlx::postgres::PgTypeInfo) -> bool {
+        crate::v3::sqlx_codec::compatible::<Self>(ty)
+    }
+}
+#[cfg(feature = "sqlx")]
+impl sqlx::Encode<'_, sqlx::Postgres> for BigintOrd {
+    fn encode_by_ref(
+        &self,
+        buf: &mut sqlx::postgres::PgArgumentBuffer,
+    ) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+        crate::v3::sqlx_codec::encode(self, buf)
+    }
+}
+#[cfg(feature = "sqlx")]
+impl<'r> sqlx::Decode<'r, sqlx::Postgres> for BigintOrd {
+    fn decode(value: sqlx::postgres::PgValueRef<'r>) -> Result<Self, sqlx::error::BoxDynError> {
+        crate::v3::sqlx_codec::decode(value)
+    }
+}
+#[cfg(feature = "postgres-types")]
+impl postgres_types::ToSql for BigintOrd {
+    fn to_sql(
+        &self,
+        ty: &postgres_types::Type,
+        out: &mut bytes::BytesMut,
+    ) -> Result<postgres_types::IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        crate::v3::postgres_codec::to_sql(self, ty, out)
+    }
+    fn accepts(ty: &postgres_types::Type) -> bool {
+        crate::v3::postgres_codec::accepts::<Self>(ty)
+    }
+    postgres_types::to_sql_checked!();
+}
+#[cfg(feature = "postgres-types")]
+impl<'a> postgres_types::FromSql<'a> for BigintOrd {
+    fn from_sql(
+        ty: &postgres_types::Type,
+        raw: &'a [u8],
+    ) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        crate::v3::postgres_codec::from_sql(ty, raw)
+    }
+    fn accepts(ty: &postgres_types::Type) -> bool {
+        crate::v3::postgres_codec::accepts_column::<Self>(ty)
+    }
+}
 /// `public.eql_v3_bigint_ord_ope` — ordering domain.
 ///
 /// Operators: `=` `<>` `<` `<=` `>` `>=`. Required keys: `v` `i` `c` `op`.
@@ -165,6 +365,66 @@ impl DomainType for BigintOrdOpe {
         schema_for!(BigintOrdOpe)
     }
 }
+#[cfg(feature = "sqlx")]
+impl sqlx::Type<sqlx::Postgres> for BigintOrdOpe {
+    fn type_info() -> sqlx::postgres::PgTypeInfo {
+        crate::v3::sqlx_codec::type_info::<Self>()
+    }
+    fn compatible(ty: &sqlx::postgres::PgTypeInfo) -> bool {
+        crate::v3::sqlx_codec::compatible::<Self>(ty)
+    }
+}
+#[cfg(feature = "sqlx")]
+impl sqlx::Encode<'_, sqlx::Postgres> for BigintOrdOpe {
+    fn encode_by_ref(
+        &self,
+        buf: &mut sqlx::postgres::PgArgumentBuffer,
+    ) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+        crate::v3::sqlx_codec::encode(self, buf)
+    }
+}
+#[cfg(feature = "sqlx")]
+impl<'r> sqlx::Decode<'r, sqlx::Postgres> for BigintOrdOpe {
+    fn decode(value: sqlx::postgres::PgValueRef<'r>) -> Result<Self, sqlx::error::BoxDynError> {
+        crate::v3::sqlx_codec::decode(value)
+    }
+}
+#[cfg(feature = "postgres-types")]
+impl postgres_types::ToSql for BigintOrdOpe {
+    fn to_sql(
+        &self,
+        ty: &postgres_types::Type,
+        out: &mut bytes::BytesMut,
+    ) -> Result<postgres_types::IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        crate::v3::postgres_codec::to_sql(self, ty, out)
+    }
+    fn accepts(ty: &postgres_types::Type) -> bool {
+        crate::v3::postgres_codec::accepts::<Self>(ty)
+    }
+    postgres_types::to_sql_checked!();
+}
+#[cfg(feature = "postgres-types")]
+impl<'a> postgres_types::FromSql<'a> for BigintOrdOpe {
+    fn from_sql(
+        ty: &postgres_types::Type,
+        raw: &'a [u8],
+    ) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        crate::v3::postgres_codec::from_sql(ty, raw)
+    }
+    fn accepts(ty: &postgres_types::Type) -> bool {
+        crate::v3::postgres_codec::accepts_column::<Self>(ty)
+    }
+}
+impl BigintEq {
+    /// The `eql_v3.query_bigint_eq` operand: this payload minus `c`.
+    pub fn to_query(&self) -> BigintEqQuery {
+        BigintEqQuery {
+            v: self.v,
+            i: self.i.clone(),
+            hm: self.hm.clone(),
+        }
+    }
+}
 /// `eql_v3.query_bigint_eq` — equality domain query operand.
 ///
 /// Operators: `=` `<>`. Required keys: `v` `i` `hm`.
@@ -196,6 +456,66 @@ impl DomainType for BigintEqQuery {
         schema_for!(BigintEqQuery)
     }
 }
+#[cfg(feature = "sqlx")]
+impl sqlx::Type<sqlx::Postgres> for BigintEqQuery {
+    fn type_info() -> sqlx::postgres::PgTypeInfo {
+        crate::v3::sqlx_codec::type_info::<Self>()
+    }
+    fn compatible(ty: &sqlx::postgres::PgTypeInfo) -> bool {
+        crate::v3::sqlx_codec::compatible::<Self>(ty)
+    }
+}
+#[cfg(feature = "sqlx")]
+impl sqlx::Encode<'_, sqlx::Postgres> for BigintEqQuery {
+    fn encode_by_ref(
+        &self,
+        buf: &mut sqlx::postgres::PgArgumentBuffer,
+    ) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+        crate::v3::sqlx_codec::encode(self, buf)
+    }
+}
+#[cfg(feature = "sqlx")]
+impl<'r> sqlx::Decode<'r, sqlx::Postgres> for BigintEqQuery {
+    fn decode(value: sqlx::postgres::PgValueRef<'r>) -> Result<Self, sqlx::error::BoxDynError> {
+        crate::v3::sqlx_codec::decode(value)
+    }
+}
+#[cfg(feature = "postgres-types")]
+impl postgres_types::ToSql for BigintEqQuery {
+    fn to_sql(
+        &self,
+        ty: &postgres_types::Type,
+        out: &mut bytes::BytesMut,
+    ) -> Result<postgres_types::IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        crate::v3::postgres_codec::to_sql(self, ty, out)
+    }
+    fn accepts(ty: &postgres_types::Type) -> bool {
+        crate::v3::postgres_codec::accepts::<Self>(ty)
+    }
+    postgres_types::to_sql_checked!();
+}
+#[cfg(feature = "postgres-types")]
+impl<'a> postgres_types::FromSql<'a> for BigintEqQuery {
+    fn from_sql(
+        ty: &postgres_types::Type,
+        raw: &'a [u8],
+    ) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        crate::v3::postgres_codec::from_sql(ty, raw)
+    }
+    fn accepts(ty: &postgres_types::Type) -> bool {
+        crate::v3::postgres_codec::accepts_column::<Self>(ty)
+    }
+}
+impl BigintOrdOre {
+    /// The `eql_v3.query_bigint_ord_ore` operand: this payload minus `c`.
+    pub fn to_query(&self) -> BigintOrdOreQuery {
+        BigintOrdOreQuery {
+            v: self.v,
+            i: self.i.clone(),
+            ob: self.ob.clone(),
+        }
+    }
+}
 /// `eql_v3.query_bigint_ord_ore` — ordering domain query operand.
 ///
 /// Operators: `=` `<>` `<` `<=` `>` `>=`. Required keys: `v` `i` `ob`.
@@ -227,6 +547,66 @@ impl DomainType for BigintOrdOreQuery {
         schema_for!(BigintOrdOreQuery)
     }
 }
+#[cfg(feature = "sqlx")]
+impl sqlx::Type<sqlx::Postgres> for BigintOrdOreQuery {
+    fn type_info() -> sqlx::postgres::PgTypeInfo {
+        crate::v3::sqlx_codec::type_info::<Self>()
+    }
+    fn compatible(ty: &sqlx::postgres::PgTypeInfo) -> bool {
+        crate::v3::sqlx_codec::compatible::<Self>(ty)
+    }
+}
+#[cfg(feature = "sqlx")]
+impl sqlx::Encode<'_, sqlx::Postgres> for BigintOrdOreQuery {
+    fn encode_by_ref(
+        &self,
+        buf: &mut sqlx::postgres::PgArgumentBuffer,
+    ) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+        crate::v3::sqlx_codec::encode(self, buf)
+    }
+}
+#[cfg(feature = "sqlx")]
+impl<'r> sqlx::Decode<'r, sqlx::Postgres> for BigintOrdOreQuery {
+    fn decode(value: sqlx::postgres::PgValueRef<'r>) -> Result<Self, sqlx::error::BoxDynError> {
+        crate::v3::sqlx_codec::decode(value)
+    }
+}
+#[cfg(feature = "postgres-types")]
+impl postgres_types::ToSql for BigintOrdOreQuery {
+    fn to_sql(
+        &self,
+        ty: &postgres_types::Type,
+        out: &mut bytes::BytesMut,
+    ) -> Result<postgres_types::IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        crate::v3::postgres_codec::to_sql(self, ty, out)
+    }
+    fn accepts(ty: &postgres_types::Type) -> bool {
+        crate::v3::postgres_codec::accepts::<Self>(ty)
+    }
+    postgres_types::to_sql_checked!();
+}
+#[cfg(feature = "postgres-types")]
+impl<'a> postgres_types::FromSql<'a> for BigintOrdOreQuery {
+    fn from_sql(
+        ty: &postgres_types::Type,
+        raw: &'a [u8],
+    ) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        crate::v3::postgres_codec::from_sql(ty, raw)
+    }
+    fn accepts(ty: &postgres_types::Type) -> bool {
+        crate::v3::postgres_codec::accepts_column::<Self>(ty)
+    }
+}
+impl BigintOrd {
+    /// The `eql_v3.query_bigint_ord` operand: this payload minus `c`.
+    pub fn to_query(&self) -> BigintOrdQuery {
+        BigintOrdQuery {
+            v: self.v,
+            i: self.i.clone(),
+            op: self.op.clone(),
+        }
+    }
+}
 /// `eql_v3.query_bigint_ord` — ordering domain query operand.
 ///
 /// Operators: `=` `<>` `<` `<=` `>` `>=`. Required keys: `v` `i` `op`.
@@ -258,6 +638,66 @@ impl DomainType for BigintOrdQuery {
         schema_for!(BigintOrdQuery)
     }
 }
+#[cfg(feature = "sqlx")]
+impl sqlx::Type<sqlx::Postgres> for BigintOrdQuery {
+    fn type_info() -> sqlx::postgres::PgTypeInfo {
+        crate::v3::sqlx_codec::type_info::<Self>()
+    }
+    fn compatible(ty: &sqlx::postgres::PgTypeInfo) -> bool {
+        crate::v3::sqlx_codec::compatible::<Self>(ty)
+    }
+}
+#[cfg(feature = "sqlx")]
+impl sqlx::Encode<'_, sqlx::Postgres> for BigintOrdQuery {
+    fn encode_by_ref(
+        &self,
+        buf: &mut sqlx::postgres::PgArgumentBuffer,
+    ) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+        crate::v3::sqlx_codec::encode(self, buf)
+    }
+}
+#[cfg(feature = "sqlx")]
+impl<'r> sqlx::Decode<'r, sqlx::Postgres> for BigintOrdQuery {
+    fn decode(value: sqlx::postgres::PgValueRef<'r>) -> Result<Self, sqlx::error::BoxDynError> {
+        crate::v3::sqlx_codec::decode(value)
+    }
+}
+#[cfg(feature = "postgres-types")]
+impl postgres_types::ToSql for BigintOrdQuery {
+    fn to_sql(
+        &self,
+        ty: &postgres_types::Type,
+        out: &mut bytes::BytesMut,
+    ) -> Result<postgres_types::IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        crate::v3::postgres_codec::to_sql(self, ty, out)
+    }
+    fn accepts(ty: &postgres_types::Type) -> bool {
+        crate::v3::postgres_codec::accepts::<Self>(ty)
+    }
+    postgres_types::to_sql_checked!();
+}
+#[cfg(feature = "postgres-types")]
+impl<'a> postgres_types::FromSql<'a> for BigintOrdQuery {
+    fn from_sql(
+        ty: &postgres_types::Type,
+        raw: &'a [u8],
+    ) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        crate::v3::postgres_codec::from_sql(ty, raw)
+    }
+    fn accepts(ty: &postgres_types::Type) -> bool {
+        crate::v3::postgres_codec::accepts_column::<Self>(ty)
+    }
+}
+impl BigintOrdOpe {
+    /// The `eql_v3.query_bigint_ord_ope` operand: this payload minus `c`.
+    pub fn to_query(&self) -> BigintOrdOpeQuery {
+        BigintOrdOpeQuery {
+            v: self.v,
+            i: self.i.clone(),
+            op: self.op.clone(),
+        }
+    }
+}
 /// `eql_v3.query_bigint_ord_ope` — ordering domain query operand.
 ///
 /// Operators: `=` `<>` `<` `<=` `>` `>=`. Required keys: `v` `i` `op`.
@@ -289,3 +729,53 @@ impl DomainType for BigintOrdOpeQuery {
         schema_for!(BigintOrdOpeQuery)
     }
 }
+#[cfg(feature = "sqlx")]
+impl sqlx::Type<sqlx::Postgres> for BigintOrdOpeQuery {
+    fn type_info() -> sqlx::postgres::PgTypeInfo {
+        crate::v3::sqlx_codec::type_info::<Self>()
+    }
+    fn compatible(ty: &sqlx::postgres::PgTypeInfo) -> bool {
+        crate::v3::sqlx_codec::compatible::<Self>(ty)
+    }
+}
+#[cfg(feature = "sqlx")]
+impl sqlx::Encode<'_, sqlx::Postgres> for BigintOrdOpeQuery {
+    fn encode_by_ref(
+        &self,
+        buf: &mut sqlx::postgres::PgArgumentBuffer,
+    ) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+        crate::v3::sqlx_codec::encode(self, buf)
+    }
+}
+#[cfg(feature = "sqlx")]
+impl<'r> sqlx::Decode<'r, sqlx::Postgres> for BigintOrdOpeQuery {
+    fn decode(value: sqlx::postgres::PgValueRef<'r>) -> Result<Self, sqlx::error::BoxDynError> {
+        crate::v3::sqlx_codec::decode(value)
+    }
+}
+#[cfg(feature = "postgres-types")]
+impl postgres_types::ToSql for BigintOrdOpeQuery {
+    fn to_sql(
+        &self,
+        ty: &postgres_types::Type,
+        out: &mut bytes::BytesMut,
+    ) -> Result<postgres_types::IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        crate::v3::postgres_codec::to_sql(self, ty, out)
+    }
+    fn accepts(ty: &postgres_types::Type) -> bool {
+        crate::v3::postgres_codec::accepts::<Self>(ty)
+    }
+    postgres_types::to_sql_checked!();
+}
+#[cfg(feature = "postgres-types")]
+impl<'a> postgres_types::FromSql<'a> for BigintOrdOpeQuery {
+    fn from_sql(
+        ty: &postgres_types::Type,
+        raw: &'a [u8],
+    ) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        crate::v3::postgres_codec::from_sql(ty, raw)
+    }
+    fn accepts(ty: &postgres_types::Type) -> bool {
+        crate::v3::postgres_codec::accepts_column::<Self>(ty)
+    }
+}
@@ -37,3 +37,53 @@ impl DomainType for Boolean {
         schema_for!(Boolean)
     }
 }
+#[cfg(feature = "sqlx")]
+impl sqlx::Type<sqlx::Postgres> for Boolean {
+    fn type_info() -> sqlx::postgres::PgTypeInfo {
+        crate::v3::sqlx_codec::type_info::<Self>()
+    }
+    fn compatible(ty: &sqlx::postgres::PgTypeInfo) -> bool {
+        crate::v3::sqlx_codec::compatible::<Self>(ty)
+    }
+}
+#[cfg(feature = "sqlx")]
+impl sqlx::Encode<'_, sqlx::Postgres> for Boolean {
+    fn encode_by_ref(
+        &self,
+        buf: &mut sqlx::postgres::PgArgumentBuffer,
+    ) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+        crate::v3::sqlx_codec::encode(self, buf)
+    }
+}
+#[cfg(feature = "sqlx")]
+impl<'r> sqlx::Decode<'r, sqlx::Postgres> for Boolean {
+    fn decode(value: sqlx::postgres::PgValueRef<'r>) -> Result<Self, sqlx::error::BoxDynError> {
+        crate::v3::sqlx_codec::decode(value)
+    }
+}
+#[cfg(feature = "postgres-types")]
+impl postgres_types::ToSql for Boolean {
+    fn to_sql(
+        &self,
+        ty: &postgres_types::Type,
+        out: &mut bytes::BytesMut,
+    ) -> Result<postgres_types::IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        crate::v3::postgres_codec::to_sql(self, ty, out)
+    }
+    fn accepts(ty: &postgres_types::Type) -> bool {
+        crate::v3::postgres_codec::accepts::<Self>(ty)
+    }
+    postgres_types::to_sql_checked!();
+}
+#[cfg(feature = "postgres-types")]
+impl<'a> postgres_types::FromSql<'a> for Boolean {
+    fn from_sql(
+        ty: &postgres_types::Type,
+        raw: &'a [u8],
+    ) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        crate::v3::postgres_codec::from_sql(ty, raw)
+    }
+    fn accepts(ty: &postgres_types::Type) -> bool {
+        crate::v3::postgres_codec::accepts_column::<Self>(ty)
+    }
+}
@@ -0,0 +1,262 @@
+// @generated by eql-codegen from the eql-domains catalog — do not edit
+//! The `bytea` encrypted-domain family — generated from the eql-domains catalog.
+use crate::v3::terms::{Ciphertext, Hmac256};
+use crate::v3::DomainType;
+use crate::{Identifier, SchemaVersion};
+use schemars::{schema_for, JsonSchema, Schema};
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+/// `public.eql_v3_bytea` — storage-only domain.
+///
+/// Operators: none. Required keys: `v` `i` `c`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, TS, JsonSchema)]
+#[ts(export, export_to = "v3/")]
+#[serde(deny_unknown_fields)]
+pub struct Bytea {
+    pub v: SchemaVersion,
+    pub i: Identifier,
+    pub c: Ciphertext,
+}
+impl DomainType for Bytea {
+    fn sql_domain_static() -> &'static str {
+        "public.eql_v3_bytea"
+    }
+    fn sql_domain(&self) -> &'static str {
+        Self::sql_domain_static()
+    }
+    fn term_json_keys_static() -> Option<&'static [&'static str]> {
+        Some(&[])
+    }
+    fn term_json_keys(&self) -> Option<&'static [&'static str]> {
+        Self::term_json_keys_static()
+    }
+    fn parse_value(&self, value: &serde_json::Value) -> Result<(), serde_json::Error> {
+        Bytea::deserialize(value).map(|_| ())
+    }
+    fn schema(&self) -> Schema {
+        schema_for!(Bytea)
+    }
+}
+#[cfg(feature = "sqlx")]
+impl sqlx::Type<sqlx::Postgres> for Bytea {
+    fn type_info() -> sqlx::postgres::PgTypeInfo {
+        crate::v3::sqlx_codec::type_info::<Self>()
+    }
+    fn compatible(ty: &sqlx::postgres::PgTypeInfo) -> bool {
+        crate::v3::sqlx_codec::compatible::<Self>(ty)
+    }
+}
+#[cfg(feature = "sqlx")]
+impl sqlx::Encode<'_, sqlx::Postgres> for Bytea {
+    fn encode_by_ref(
+        &self,
+        buf: &mut sqlx::postgres::PgArgumentBuffer,
+    ) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+        crate::v3::sqlx_codec::encode(self, buf)
+    }
+}
+#[cfg(feature = "sqlx")]
+impl<'r> sqlx::Decode<'r, sqlx::Postgres> for Bytea {
+    fn decode(value: sqlx::postgres::PgValueRef<'r>) -> Result<Self, sqlx::error::BoxDynError> {
+        crate::v3::sqlx_codec::decode(value)
+    }
+}
+#[cfg(feature = "postgres-types")]
+impl postgres_types::ToSql for Bytea {
+    fn to_sql(
+        &self,
+        ty: &postgres_types::Type,
+        out: &mut bytes::BytesMut,
+    ) -> Result<postgres_types::IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        crate::v3::postgres_codec::to_sql(self, ty, out)
+    }
+    fn accepts(ty: &postgres_types::Type) -> bool {
+        crate::v3::postgres_codec::accepts::<Self>(ty)
+    }
+    postgres_types::to_sql_checked!();
+}
+#[cfg(feature = "postgres-types")]
+impl<'a> postgres_types::FromSql<'a> for Bytea {
+    fn from_sql(
+        ty: &postgres_types::Type,
+        raw: &'a [u8],
+    ) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        crate::v3::postgres_codec::from_sql(ty, raw)
+    }
+    fn accepts(ty: &postgres_types::Type) -> bool {
+        crate::v3::postgres_codec::accepts_column::<Self>(ty)
+    }
+}
+/// `public.eql_v3_bytea_eq` — equality domain.
+///
+/// Operators: `=` `<>`. Required keys: `v` `i` `c` `hm`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, TS, JsonSchema)]
+#[ts(export, export_to = "v3/")]
+#[serde(deny_unknown_fields)]
+pub struct ByteaEq {
+    pub v: SchemaVersion,
+    pub i: Identifier,
+    pub c: Ciphertext,
+    pub hm: Hmac256,
+}
+impl DomainType for ByteaEq {
+    fn sql_domain_static() -> &'static str {
+        "public.eql_v3_bytea_eq"
+    }
+    fn sql_domain(&self) -> &'static str {
+        Self::sql_domain_static()
+    }
+    fn term_json_keys_static() -> Option<&'static [&'static str]> {
+        Some(&["hm"])
+    }
+    fn term_json_keys(&self) -> Option<&'static [&'static str]> {
+        Self::term_json_keys_static()
+    }
+    fn parse_value(&self, value: &serde_json::Value) -> Result<(), serde_json::Error> {
+        ByteaEq::deserialize(value).map(|_| ())
+    }
+    fn schema(&self) -> Schema {
+        schema_for!(ByteaEq)
+    }
+}
+#[cfg(feature = "sqlx")]
+impl sqlx::Type<sqlx::Postgres> for ByteaEq {
+    fn type_info() -> sqlx::postgres::PgTypeInfo {
+        crate::v3::sqlx_codec::type_info::<Self>()
+    }
+    fn compatible(ty: &sqlx::postgres::PgTypeInfo) -> bool {
+        crate::v3::sqlx_codec::compatible::<Self>(ty)
+    }
+}
+#[cfg(feature = "sqlx")]
+impl sqlx::Encode<'_, sqlx::Postgres> for ByteaEq {
+    fn encode_by_ref(
+        &self,
+        buf: &mut sqlx::postgres::PgArgumentBuffer,
+    ) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+        crate::v3::sqlx_codec::encode(self, buf)
+    }
+}
+#[cfg(feature = "sqlx")]
+impl<'r> sqlx::Decode<'r, sqlx::Postgres> for ByteaEq {
+    fn decode(value: sqlx::postgres::PgValueRef<'r>) -> Result<Self, sqlx::error::BoxDynError> {
+        crate::v3::sqlx_codec::decode(value)
+    }
+}
+#[cfg(feature = "postgres-types")]
+impl postgres_types::ToSql for ByteaEq {
+    fn to_sql(
+        &self,
+        ty: &postgres_types::Type,
+        out: &mut bytes::BytesMut,
+    ) -> Result<postgres_types::IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        crate::v3::postgres_codec::to_sql(self, ty, out)
+    }
+    fn accepts(ty: &postgres_types::Type) -> bool {
+        crate::v3::postgres_codec::accepts::<Self>(ty)
+    }
+    postgres_types::to_sql_checked!();
+}
+#[cfg(feature = "postgres-types")]
+impl<'a> postgres_types::FromSql<'a> for ByteaEq {
+    fn from_sql(
+        ty: &postgres_types::Type,
+        raw: &'a [u8],
+    ) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        crate::v3::postgres_codec::from_sql(ty, raw)
+    }
+    fn accepts(ty: &postgres_types::Type) -> bool {
+        crate::v3::postgres_codec::accepts_column::<Self>(ty)
+    }
+}
+impl ByteaEq {
+    /// The `eql_v3.query_bytea_eq` operand: this payload minus `c`.
+    pub fn to_query(&self) -> ByteaEqQuery {
+        ByteaEqQuery {
+            v: self.v,
+            i: self.i.clone(),
+            hm: self.hm.clone(),
+        }
+    }
+}
+/// `eql_v3.query_bytea_eq` — equality domain query operand.
+///
+/// Operators: `=` `<>`. Required keys: `v` `i` `hm`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, TS, JsonSchema)]
+#[ts(export, export_to = "v3/")]
+#[serde(deny_unknown_fields)]
+pub struct ByteaEqQuery {
+    pub v: SchemaVersion,
+    pub i: Identifier,
+    pub hm: Hmac256,
+}
+impl DomainType for ByteaEqQuery {
+    fn sql_domain_static() -> &'static str {
+        "eql_v3.query_bytea_eq"
+    }
+    fn sql_domain(&self) -> &'static str {
+        Self::sql_domain_static()
+    }
+    fn term_json_keys_static() -> Option<&'static [&'static str]> {
+        Some(&["hm"])
+    }
+    fn term_json_keys(&self) -> Option<&'static [&'static str]> {
+        Self::term_json_keys_static()
+    }
+    fn parse_value(&self, value: &serde_json::Value) -> Result<(), serde_json::Error> {
+        ByteaEqQuery::deserialize(value).map(|_| ())
+    }
+    fn schema(&self) -> Schema {
+        schema_for!(ByteaEqQuery)
+    }
+}
+#[cfg(feature = "sqlx")]
+impl sqlx::Type<sqlx::Postgres> for ByteaEqQuery {
+    fn type_info() -> sqlx::postgres::PgTypeInfo {
+        crate::v3::sqlx_codec::type_info::<Self>()
+    }
+    fn compatible(ty: &sqlx::postgres::PgTypeInfo) -> bool {
+        crate::v3::sqlx_codec::compatible::<Self>(ty)
+    }
+}
+#[cfg(feature = "sqlx")]
+impl sqlx::Encode<'_, sqlx::Postgres> for ByteaEqQuery {
+    fn encode_by_ref(
+        &self,
+        buf: &mut sqlx::postgres::PgArgumentBuffer,
+    ) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+        crate::v3::sqlx_codec::encode(self, buf)
+    }
+}
+#[cfg(feature = "sqlx")]
+impl<'r> sqlx::Decode<'r, sqlx::Postgres> for ByteaEqQuery {
+    fn decode(value: sqlx::postgres::PgValueRef<'r>) -> Result<Self, sqlx::error::BoxDynError> {
+        crate::v3::sqlx_codec::decode(value)
+    }
+}
+#[cfg(feature = "postgres-types")]
+impl postgres_types::ToSql for ByteaEqQuery {
+    fn to_sql(
+        &self,
+        ty: &postgres_types::Type,
+        out: &mut bytes::BytesMut,
+    ) -> Result<postgres_types::IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        crate::v3::postgres_codec::to_sql(self, ty, out)
+    }
+    fn accepts(ty: &postgres_types::Type) -> bool {
+        crate::v3::postgres_codec::accepts::<Self>(ty)
+    }
+    postgres_types::to_sql_checked!();
+}
+#[cfg(feature = "postgres-types")]
+impl<'a> postgres_types::FromSql<'a> for ByteaEqQuery {
+    fn from_sql(
+        ty: &postgres_types::Type,
+        raw: &'a [u8],
+    ) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        crate::v3::postgres_codec::from_sql(ty, raw)
+    }
+    fn accepts(ty: &postgres_types::Type) -> bool {
+        crate::v3::postgres_codec::accepts_column::<Self>(ty)
+    }
+}
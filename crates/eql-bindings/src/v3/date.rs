@@ -37,6 +37,56 @@ impl DomainType for Date {
         schema_for!(Date)
     }
 }
+#[cfg(feature = "sqlx")]
+impl sqlx::Type<sqlx::Postgres> for Date {
+    fn type_info() -> sqlx::postgres::PgTypeInfo {
+        crate::v3::sqlx_codec::type_info::<Self>()
+    }
+    fn compatible(ty: &sqlx::postgres::PgTypeInfo) -> bool {
+        crate::v3::sqlx_codec::compatible::<Self>(ty)
+    }
+}
+#[cfg(feature = "sqlx")]
+impl sqlx::Encode<'_, sqlx::Postgres> for Date {
+    fn encode_by_ref(
+        &self,
+        buf: &mut sqlx::postgres::PgArgumentBuffer,
+    ) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+        crate::v3::sqlx_codec::encode(self, buf)
+    }
+}
+#[cfg(feature = "sqlx")]
+impl<'r> sqlx::Decode<'r, sqlx::Postgres> for Date {
+    fn decode(value: sqlx::postgres::PgValueRef<'r>) -> Result<Self, sqlx::error::BoxDynError> {
+        crate::v3::sqlx_codec::decode(value)
+    }
+}
+#[cfg(feature = "postgres-types")]
+impl postgres_types::ToSql for Date {
+    fn to_sql(
+        &self,
+        ty: &postgres_types::Type,
+        out: &mut bytes::BytesMut,
+    ) -> Result<postgres_types::IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        crate::v3::postgres_codec::to_sql(self, ty, out)
+    }
+    fn accepts(ty: &postgres_types::Type) -> bool {
+        crate::v3::postgres_codec::accepts::<Self>(ty)
+    }
+    postgres_types::to_sql_checked!();
+}
+#[cfg(feature = "postgres-types")]
+impl<'a> postgres_types::FromSql<'a> for Date {
+    fn from_sql(
+        ty: &postgres_types::Type,
+        raw: &'a [u8],
+    ) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        crate::v3::postgres_codec::from_sql(ty, raw)
+    }
+    fn accepts(ty: &postgres_types::Type) -> bool {
+        crate::v3::postgres_codec::accepts_column::<Self>(ty)
+    }
+}
 /// `public.eql_v3_date_eq` — equality domain.
 ///
 /// Operators: `=` `<>`. Required keys: `v` `i` `c` `hm`.
@@ -69,6 +119,56 @@ impl DomainType for DateEq {
         schema_for!(DateEq)
     }
 }
+#[cfg(feature = "sqlx")]
+impl sqlx::Type<sqlx::Postgres> for DateEq {
+    fn type_info() -> sqlx::postgres::PgTypeInfo {
+        crate::v3::sqlx_codec::type_info::<Self>()
+    }
+    fn compatible(ty: &sqlx::postgres::PgTypeInfo) -> bool {
+        crate::v3::sqlx_codec::compatible::<Self>(ty)
+    }
+}
+#[cfg(feature = "sqlx")]
+impl sqlx::Encode<'_, sqlx::Postgres> for DateEq {
+    fn encode_by_ref(
+        &self,
+        buf: &mut sqlx::postgres::PgArgumentBuffer,
+    ) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+        crate::v3::sqlx_codec::encode(self, buf)
+    }
+}
+#[cfg(feature = "sqlx")]
+impl<'r> sqlx::Decode<'r, sqlx::Postgres> for DateEq {
+    fn decode(value: sqlx::postgres::PgValueRef<'r>) -> Result<Self, sqlx::error::BoxDynError> {
+        crate::v3::sqlx_codec::decode(value)
+    }
+}
+#[cfg(feature = "postgres-types")]
+impl postgres_types::ToSql for DateEq {
+    fn to_sql(
+        &self,
+        ty: &postgres_types::Type,
+        out: &mut bytes::BytesMut,
+    ) -> Result<postgres_types::IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        crate::v3::postgres_codec::to_sql(self, ty, out)
+    }
+    fn accepts(ty: &postgres_types::Type) -> bool {
+        crate::v3::postgres_codec::accepts::<Self>(ty)
+    }
+    postgres_types::to_sql_checked!();
+}
+#[cfg(feature = "postgres-types")]
+impl<'a> postgres_types::FromSql<'a> for DateEq {
+    fn from_sql(
+        ty: &postgres_types::Type,
+        raw: &'a [u8],
+    ) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        crate::v3::postgres_codec::from_sql(ty, raw)
+    }
+    fn accepts(ty: &postgres_types::Type) -> bool {
+        crate::v3::postgres_codec::accepts_column::<Self>(ty)
+    }
+}
 /// `public.eql_v3_date_ord_ore` — ordering domain.
 ///
 /// Operators: `=` `<>` `<` `<=` `>` `>=`. Required keys: `v` `i` `c` `ob`.
@@ -101,6 +201,56 @@ impl DomainType for DateOrdOre {
         schema_for!(DateOrdOre)
     }
 }
+#[cfg(feature = "sqlx")]
+impl sqlx::Type<sqlx::Postgres> for DateOrdOre {
+    fn type_info() -> sqlx::postgres::PgTypeInfo {
+        crate::v3::sqlx_codec::type_info::<Self>()
+    }
+    fn compatible(ty: &sqlx::postgres::PgTypeInfo) -> bool {
+        crate::v3::sqlx_codec::compatible::<Self>(ty)
+    }
+}
+#[cfg(feature = "sqlx")]
+impl sqlx::Encode<'_, sqlx::Postgres> for DateOrdOre {
+    fn encode_by_ref(
+        &self,
+        buf: &mut sqlx::postgres::PgArgumentBuffer,
+    ) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+        crate::v3::sqlx_codec::encode(self, buf)
+    }
+}
+#[cfg(feature = "sqlx")]
+impl<'r> sqlx::Decode<'r, sqlx::Postgres> for DateOrdOre {
+    fn decode(value: sqlx::postgres::PgValueRef<'r>) -> Result<Self, sqlx::error::BoxDynError> {
+        crate::v3::sqlx_codec::decode(value)
+    }
+}
+#[cfg(feature = "postgres-types")]
+impl postgres_types::ToSql for DateOrdOre {
+    fn to_sql(
+        &self,
+        ty: &postgres_types::Type,
+        out: &mut bytes::BytesMut,
+    ) -> Result<postgres_types::IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        crate::v3::postgres_codec::to_sql(self, ty, out)
+    }
+    fn accepts(ty: &postgres_types::Type) -> bool {
+        crate::v3::postgres_codec::accepts::<Self>(ty)
+    }
+    postgres_types::to_sql_checked!();
+}
+#[cfg(feature = "postgres-types")]
+impl<'a> postgres_types::FromSql<'a> for DateOrdOre {
+    fn from_sql(
+        ty: &postgres_types::Type,
+        raw: &'a [u8],
+    ) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        crate::v3::postgres_codec::from_sql(ty, raw)
+    }
+    fn accepts(ty: &postgres_types::Type) -> bool {
+        crate::v3::postgres_codec::accepts_column::<Self>(ty)
+    }
+}
 /// `public.eql_v3_date_ord` — ordering domain.
 ///
 /// Operators: `=` `<>` `<` `<=` `>` `>=`. Required keys: `v` `i` `c` `op`.
@@ -133,6 +283,56 @@ impl DomainType for DateOrd {
         schema_for!(DateOrd)
     }
 }
+#[cfg(feature = "sqlx")]
+impl sqlx::Type<sqlx::Postgres> for DateOrd {
+    fn type_info() -> sqlx::postgres::PgTypeInfo {
+        crate::v3::sqlx_codec::type_info::<Self>()
+    }
+    fn compatible(ty: &sqlx::postgres::PgTypeInfo) -> bool {
+        crate::v3::sqlx_codec::compatible::<Self>(ty)
+    }
+}
+#[cfg(feature = "sqlx")]
+impl sqlx::Encode<'_, sqlx::Postgres> for DateOrd {
+    fn encode_by_ref(
+        &self,
+        buf: &mut sqlx::postgres::PgArgumentBuffer,
+    ) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+        crate::v3::sqlx_codec::encode(self, buf)
+    }
+}
+#[cfg(feature = "sqlx")]
+impl<'r> sqlx::Decode<'r, sqlx::Postgres> for DateOrd {
+    fn decode(value: sqlx::postgres::PgValueRef<'r>) -> Result<Self, sqlx::error::BoxDynError> {
+        crate::v3::sqlx_codec::decode(value)
+    }
+}
+#[cfg(feature = "postgres-types")]
+impl postgres_types::ToSql for DateOrd {
+    fn to_sql(
+        &self,
+        ty: &postgres_types::Type,
+        out: &mut bytes::BytesMut,
+    ) -> Result<postgres_types::IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        crate::v3::postgres_codec::to_sql(self, ty, out)
+    }
+    fn accepts(ty: &postgres_types::Type) -> bool {
+        crate::v3::postgres_codec::accepts::<Self>(ty)
+    }
+    postgres_types::to_sql_checked!();
+}
+#[cfg(feature = "postgres-types")]
+impl<'a> postgres_types::FromSql<'a> for DateOrd {
+    fn from_sql(
+        ty: &postgres_types::Type,
+        raw: &'a [u8],
+    ) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        crate::v3::postgres_codec::from_sql(ty, raw)
+    }
+    fn accepts(ty: &postgres_types::Type) -> bool {
+        crate::v3::postgres_codec::accepts_column::<Self>(ty)
+    }
+}
 /// `public.eql_v3_date_ord_ope` — ordering domain.
 ///
 /// Operators: `=` `<>` `<` `<=` `>` `>=`. Required keys: `v` `i` `c` `op`.
@@ -165,6 +365,66 @@ impl DomainType for DateOrdOpe {
         schema_for!(DateOrdOpe)
     }
 }
+#[cfg(feature = "sqlx")]
+impl sqlx::Type<sqlx::Postgres> for DateOrdOpe {
+    fn type_info() -> sqlx::postgres::PgTypeInfo {
+        crate::v3::sqlx_codec::type_info::<Self>()
+    }
+    fn compatible(ty: &sqlx::postgres::PgTypeInfo) -> bool {
+        crate::v3::sqlx_codec::compatible::<Self>(ty)
+    }
+}
+#[cfg(feature = "sqlx")]
+impl sqlx::Encode<'_, sqlx::Postgres> for DateOrdOpe {
+    fn encode_by_ref(
+        &self,
+        buf: &mut sqlx::postgres::PgArgumentBuffer,
+    ) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+        crate::v3::sqlx_codec::encode(self, buf)
+    }
+}
+#[cfg(feature = "sqlx")]
+impl<'r> sqlx::Decode<'r, sqlx::Postgres> for DateOrdOpe {
+    fn decode(value: sqlx::postgres::PgValueRef<'r>) -> Result<Self, sqlx::error::BoxDynError> {
+        crate::v3::sqlx_codec::decode(value)
+    }
+}
+#[cfg(feature = "postgres-types")]
+impl postgres_types::ToSql for DateOrdOpe {
+    fn to_sql(
+        &self,
+        ty: &postgres_types::Type,
+        out: &mut bytes::BytesMut,
+    ) -> Result<postgres_types::IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        crate::v3::postgres_codec::to_sql(self, ty, out)
+    }
+    fn accepts(ty: &postgres_types::Type) -> bool {
+        crate::v3::postgres_codec::accepts::<Self>(ty)
+    }
+    postgres_types::to_sql_checked!();
+}
+#[cfg(feature = "postgres-types")]
+impl<'a> postgres_types::FromSql<'a> for DateOrdOpe {
+    fn from_sql(
+        ty: &postgres_types::Type,
+        raw: &'a [u8],
+    ) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        crate::v3::postgres_codec::from_sql(ty, raw)
+    }
+    fn accepts(ty: &postgres_types::Type) -> bool {
+        crate::v3::postgres_codec::accepts_column::<Self>(ty)
+    }
+}
+impl DateEq {
+    /// The `eql_v3.query_date_eq` operand: this payload minus `c`.
+    pub fn to_query(&self) -> DateEqQuery {
+        DateEqQuery {
+            v: self.v,
+            i: self.i.clone(),
+            hm: self.hm.clone(),
+        }
+    }
+}
 /// `eql_v3.query_date_eq` — equality domain query operand.
 ///
 /// Operators: `=` `<>`. Required keys: `v` `i` `hm`.
@@ -196,6 +456,66 @@ impl DomainType for DateEqQuery {
         schema_for!(DateEqQuery)
     }
 }
+#[cfg(feature = "sqlx")]
+impl sqlx::Type<sqlx::Postgres> for DateEqQuery {
+    fn type_info() -> sqlx::postgres::PgTypeInfo {
+        crate::v3::sqlx_codec::type_info::<Self>()
+    }
+    fn compatible(ty: &sqlx::postgres::PgTypeInfo) -> bool {
+        crate::v3::sqlx_codec::compatible::<Self>(ty)
+    }
+}
+#[cfg(feature = "sqlx")]
+impl sqlx::Encode<'_, sqlx::Postgres> for DateEqQuery {
+    fn encode_by_ref(
+        &self,
+        buf: &mut sqlx::postgres::PgArgumentBuffer,
+    ) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+        crate::v3::sqlx_codec::encode(self, buf)
+    }
+}
+#[cfg(feature = "sqlx")]
+impl<'r> sqlx::Decode<'r, sqlx::Postgres> for DateEqQuery {
+    fn decode(value: sqlx::postgres::PgValueRef<'r>) -> Result<Self, sqlx::error::BoxDynError> {
+        crate::v3::sqlx_codec::decode(value)
+    }
+}
+#[cfg(feature = "postgres-types")]
+impl postgres_types::ToSql for DateEqQuery {
+    fn to_sql(
+        &self,
+        ty: &postgres_types::Type,
+        out: &mut bytes::BytesMut,
+    ) -> Result<postgres_types::IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        crate::v3::postgres_codec::to_sql(self, ty, out)
+    }
+    fn accepts(ty: &postgres_types::Type) -> bool {
+        crate::v3::postgres_codec::accepts::<Self>(ty)
+    }
+    postgres_types::to_sql_checked!();
+}
+#[cfg(feature = "postgres-types")]
+impl<'a> postgres_types::FromSql<'a> for DateEqQuery {
+    fn from_sql(
+        ty: &postgres_types::Type,
+        raw: &'a [u8],
+    ) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        crate::v3::postgres_codec::from_sql(ty, raw)
+    }
+    fn accepts(ty: &postgres_types::Type) -> bool {
+        crate::v3::postgres_codec::accepts_column::<Self>(ty)
+    }
+}
+impl DateOrdOre {
+    /// The `eql_v3.query_date_ord_ore` operand: this payload minus `c`.
+    pub fn to_query(&self) -> DateOrdOreQuery {
+        DateOrdOreQuery {
+            v: self.v,
+            i: self.i.clone(),
+            ob: self.ob.clone(),
+        }
+    }
+}
 /// `eql_v3.query_date_ord_ore` — ordering domain query operand.
 ///
 /// Operators: `=` `<>` `<` `<=` `>` `>=`. Required keys: `v` `i` `ob`.
@@ -227,6 +547,66 @@ impl DomainType for DateOrdOreQuery {
         schema_for!(DateOrdOreQuery)
     }
 }
+#[cfg(feature = "sqlx")]
+impl sqlx::Type<sqlx::Postgres> for DateOrdOreQuery {
+    fn type_info() -> sqlx::postgres::PgTypeInfo {
+        crate::v3::sqlx_codec::type_info::<Self>()
+    }
+    fn compatible(ty: &sqlx::postgres::PgTypeInfo) -> bool {
+        crate::v3::sqlx_codec::compatible::<Self>(ty)
+    }
+}
+#[cfg(feature = "sqlx")]
+impl sqlx::Encode<'_, sqlx::Postgres> for DateOrdOreQuery {
+    fn encode_by_ref(
+        &self,
+        buf: &mut sqlx::postgres::PgArgumentBuffer,
+    ) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+        crate::v3::sqlx_codec::encode(self, buf)
+    }
+}
+#[cfg(feature = "sqlx")]
+impl<'r> sqlx::Decode<'r, sqlx::Postgres> for DateOrdOreQuery {
+    fn decode(value: sqlx::postgres::PgValueRef<'r>) -> Result<Self, sqlx::error::BoxDynError> {
+        crate::v3::sqlx_codec::decode(value)
+    }
+}
+#[cfg(feature = "postgres-types")]
+impl postgres_types::ToSql for DateOrdOreQuery {
+    fn to_sql(
+        &self,
+        ty: &postgres_types::Type,
+        out: &mut bytes::BytesMut,
+    ) -> Result<postgres_types::IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        crate::v3::postgres_codec::to_sql(self, ty, out)
+    }
+    fn accepts(ty: &postgres_types::Type) -> bool {
+        crate::v3::postgres_codec::accepts::<Self>(ty)
+    }
+    postgres_types::to_sql_checked!();
+}
+#[cfg(feature = "postgres-types")]
+impl<'a> postgres_types::FromSql<'a> for DateOrdOreQuery {
+    fn from_sql(
+        ty: &postgres_types::Type,
+        raw: &'a [u8],
+    ) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        crate::v3::postgres_codec::from_sql(ty, raw)
+    }
+    fn accepts(ty: &postgres_types::Type) -> bool {
+        crate::v3::postgres_codec::accepts_column::<Self>(ty)
+    }
+}
+impl DateOrd {
+    /// The `eql_v3.query_date_ord` operand: this payload minus `c`.
+    pub fn to_query(&self) -> DateOrdQuery {
+        DateOrdQuery {
+            v: self.v,
+            i: self.i.clone(),
+            op: self.op.clone(),
+        }
+    }
+}
 /// `eql_v3.query_date_ord` — ordering domain query operand.
 ///
 /// Operators: `=` `<>` `<` `<=` `>` `>=`. Required keys: `v` `i` `op`.
@@ -258,6 +638,66 @@ impl DomainType for DateOrdQuery {
         schema_for!(DateOrdQuery)
     }
 }
+#[cfg(feature = "sqlx")]
+impl sqlx::Type<sqlx::Postgres> for DateOrdQuery {
+    fn type_info() -> sqlx::postgres::PgTypeInfo {
+        crate::v3::sqlx_codec::type_info::<Self>()
+    }
+    fn compatible(ty: &sqlx::postgres::PgTypeInfo) -> bool {
+        crate::v3::sqlx_codec::compatible::<Self>(ty)
+    }
+}
+#[cfg(feature = "sqlx")]
+impl sqlx::Encode<'_, sqlx::Postgres> for DateOrdQuery {
+    fn encode_by_ref(
+        &self,
+        buf: &mut sqlx::postgres::PgArgumentBuffer,
+    ) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+        crate::v3::sqlx_codec::encode(self, buf)
+    }
+}
+#[cfg(feature = "sqlx")]
+impl<'r> sqlx::Decode<'r, sqlx::Postgres> for DateOrdQuery {
+    fn decode(value: sqlx::postgres::PgValueRef<'r>) -> Result<Self, sqlx::error::BoxDynError> {
+        crate::v3::sqlx_codec::decode(value)
+    }
+}
+#[cfg(feature = "postgres-types")]
+impl postgres_types::ToSql for DateOrdQuery {
+    fn to_sql(
+        &self,
+        ty: &postgres_types::Type,
+        out: &mut bytes::BytesMut,
+    ) -> Result<postgres_types::IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        crate::v3::postgres_codec::to_sql(self, ty, out)
+    }
+    fn accepts(ty: &postgres_types::Type) -> bool {
+        crate::v3::postgres_codec::accepts::<Self>(ty)
+    }
+    postgres_types::to_sql_checked!();
+}
+#[cfg(feature = "postgres-types")]
+impl<'a> postgres_types::FromSql<'a> for DateOrdQuery {
+    fn from_sql(
+        ty: &postgres_types::Type,
+        raw: &'a [u8],
+    ) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        crate::v3::postgres_codec::from_sql(ty, raw)
+    }
+    fn accepts(ty: &postgres_types::Type) -> bool {
+        crate::v3::postgres_codec::accepts_column::<Self>(ty)
+    }
+}
+impl DateOrdOpe {
+    /// The `eql_v3.query_date_ord_ope` operand: this payload minus `c`.
+    pub fn to_query(&self) -> DateOrdOpeQuery {
+        DateOrdOpeQuery {
+            v: self.v,
+            i: self.i.clone(),
+            op: self.op.clone(),
+        }
+    }
+}
 /// `eql_v3.query_date_ord_ope` — ordering domain query operand.
 ///
 /// Operators: `=` `<>` `<` `<=` `>` `>=`. Required keys: `v` `i` `op`.
@@ -289,3 +729,53 @@ impl DomainType for DateOrdOpeQuery {
         schema_for!(DateOrdOpeQuery)
     }
 }
+#[cfg(feature = "sqlx")]
+impl sqlx::Type<sqlx::Postgres> for DateOrdOpeQuery {
+    fn type_info() -> sqlx::postgres::PgTypeInfo {
+        crate::v3::sqlx_codec::type_info::<Self>()
+    }
+    fn compatible(ty: &sqlx::postgres::PgTypeInfo) -> bool {
+        crate::v3::sqlx_codec::compatible::<Self>(ty)
+    }
+}
+#[cfg(feature = "sqlx")]
+impl sqlx::Encode<'_, sqlx::Postgres> for DateOrdOpeQuery {
+    fn encode_by_ref(
+        &self,
+        buf: &mut sqlx::postgres::PgArgumentBuffer,
+    ) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+        crate::v3::sqlx_codec::encode(self, buf)
+    }
+}
+#[cfg(feature = "sqlx")]
+impl<'r> sqlx::Decode<'r, sqlx::Postgres> for DateOrdOpeQuery {
+    fn decode(value: sqlx::postgres::PgValueRef<'r>) -> Result<Self, sqlx::error::BoxDynError> {
+        crate::v3::sqlx_codec::decode(value)
+    }
+}
+#[cfg(feature = "postgres-types")]
+impl postgres_types::ToSql for DateOrdOpeQuery {
+    fn to_sql(
+        &self,
+        ty: &postgres_types::Type,
+        out: &mut bytes::BytesMut,
+    ) -> Result<postgres_types::IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        crate::v3::postgres_codec::to_sql(self, ty, out)
+    }
+    fn accepts(ty: &postgres_types::Type) -> bool {
+        crate::v3::postgres_codec::accepts::<Self>(ty)
+    }
+    postgres_types::to_sql_checked!();
+}
+#[cfg(feature = "postgres-types")]
+impl<'a> postgres_types::FromSql<'a> for DateOrdOpeQuery {
+    fn from_sql(
+        ty: &postgres_types::Type,
+        raw: &'a [u8],
+    ) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        crate::v3::postgres_codec::from_sql(ty, raw)
+    }
+    fn accepts(ty: &postgres_types::Type) -> bool {
+        crate::v3::postgres_codec::accepts_column::<Self>(ty)
+    }
+}
@@ -9,6 +9,8 @@ use std::marker::PhantomData;
 use schemars::{schema_for, JsonSchema, Schema};
 use serde::Deserialize;
 
+use super::EncodingError;
+
 /// The PostgreSQL schema every user-COLUMN domain in this module inhabits.
 /// The `query_<name>` operand twins are NOT column types and live in `eql_v3`
 /// instead — dropping the EQL-owned schema can never drop an
@@ -102,6 +104,18 @@ pub trait DomainType {
     /// validation check, not a constructor.
     fn parse_value(&self, value: &serde_json::Value) -> Result<(), serde_json::Error>;
 
+    /// [`parse_value`](Self::parse_value), then the wire encodings the parse
+    /// leaves opaque: `c`/`h` decode as mp_base85, `hm` is a 32-byte HMAC,
+    /// `op` carries the right CLLW domain tag, every `ob` block term has one
+    /// width, and selectors are long enough for the entry nonce (see
+    /// [`super::encoding`]). Opt-in — decoding every ciphertext costs more
+    /// than the shape check, and catches what would otherwise only fail at
+    /// decryption.
+    fn parse_strict_encoding(&self, value: &serde_json::Value) -> Result<(), EncodingError> {
+        self.parse_value(value).map_err(EncodingError::Parse)?;
+        super::encoding::check(value)
+    }
+
     /// The type's JSON Schema.
     fn schema(&self) -> Schema;
 }
@@ -37,6 +37,56 @@ impl DomainType for Double {
         schema_for!(Double)
     }
 }
+#[cfg(feature = "sqlx")]
+impl sqlx::Type<sqlx::Postgres> for Double {
+    fn type_info() -> sqlx::postgres::PgTypeInfo {
+        crate::v3::sqlx_codec::type_info::<Self>()
+    }
+    fn compatible(ty: &sqlx::postgres::PgTypeInfo) -> bool {
+        crate::v3::sqlx_codec::compatible::<Self>(ty)
+    }
+}
+#[cfg(feature = "sqlx")]
+impl sqlx::Encode<'_, sqlx::Postgres> for Double {
+    fn encode_by_ref(
+        &self,
+        buf: &mut sqlx::postgres::PgArgumentBuffer,
+    ) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+        crate::v3::sqlx_codec::encode(self, buf)
+    }
+}
+#[cfg(feature = "sqlx")]
+impl<'r> sqlx::Decode<'r, sqlx::Postgres> for Double {
+    fn decode(value: sqlx::postgres::PgValueRef<'r>) -> Result<Self, sqlx::error::BoxDynError> {
+        crate::v3::sqlx_codec::decode(value)
+    }
+}
+#[cfg(feature = "postgres-types")]
+impl postgres_types::ToSql for Double {
+    fn to_sql(
+        &self,
+        ty: &postgres_types::Type,
+        out: &mut bytes::BytesMut,
+    ) -> Result<postgres_types::IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        crate::v3::postgres_codec::to_sql(self, ty, out)
+    }
+    fn accepts(ty: &postgres_types::Type) -> bool {
+        crate::v3::postgres_codec::accepts::<Self>(ty)
+    }
+    postgres_types::to_sql_checked!();
+}
+#[cfg(feature = "postgres-types")]
+impl<'a> postgres_types::FromSql<'a> for Double {
+    fn from_sql(
+        ty: &postgres_types::Type,
+        raw: &'a [u8],
+    ) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        crate::v3::postgres_codec::from_sql(ty, raw)
+    }
+    fn accepts(ty: &postgres_types::Type) -> bool {
+        crate::v3::postgres_codec::accepts_column::<Self>(ty)
+    }
+}
 /// `public.eql_v3_double_eq` — equality domain.
 ///
 /// Operators: `=` `<>`. Required keys: `v` `i` `c` `hm`.
@@ -69,6 +119,56 @@ impl DomainType for DoubleEq {
         schema_for!(DoubleEq)
     }
 }
+#[cfg(feature = "sqlx")]
+impl sqlx::Type<sqlx::Postgres> for DoubleEq {
+    fn type_info() -> sqlx::postgres::PgTypeInfo {
+        crate::v3::sqlx_codec::type_info::<Self>()
+    }
+    fn compatible(ty: &sqlx::postgres::PgTypeInfo) -> bool {
+        crate::v3::sqlx_codec::compatible::<Self>(ty)
+    }
+}
+#[cfg(feature = "sqlx")]
+impl sqlx::Encode<'_, sqlx::Postgres> for DoubleEq {
+    fn encode_by_ref(
+        &self,
+        buf: &mut sqlx::postgres::PgArgumentBuffer,
+    ) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+        crate::v3::sqlx_codec::encode(self, buf)
+    }
+}
+#[cfg(feature = "sqlx")]
+impl<'r> sqlx::Decode<'r, sqlx::Postgres> for DoubleEq {
+    fn decode(value: sqlx::postgres::PgValueRef<'r>) -> Result<Self, sqlx::error::BoxDynError> {
+        crate::v3::sqlx_codec::decode(value)
+    }
+}
+#[cfg(feature = "postgres-types")]
+impl postgres_types::ToSql for DoubleEq {
+    fn to_sql(
+        &self,
+        ty: &postgres_types::Type,
+        out: &mut bytes::BytesMut,
+    ) -> Result<postgres_types::IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        crate::v3::postgres_codec::to_sql(self, ty, out)
+    }
+    fn accepts(ty: &postgres_types::Type) -> bool {
+        crate::v3::postgres_codec::accepts::<Self>(ty)
+    }
+    postgres_types::to_sql_checked!();
+}
+#[cfg(feature = "postgres-types")]
+impl<'a> postgres_types::FromSql<'a> for DoubleEq {
+    fn from_sql(
+        ty: &postgres_types::Type,
+        raw: &'a [u8],
+    ) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        crate::v3::postgres_codec::from_sql(ty, raw)
+    }
+    fn accepts(ty: &postgres_types::Type) -> bool {
+        crate::v3::postgres_codec::accepts_column::<Self>(ty)
+    }
+}
 /// `public.eql_v3_double_ord_ore` — ordering domain.
 ///
 /// Operators: `=` `<>` `<` `<=` `>` `>=`. Required keys: `v` `i` `c` `ob`.
@@ -101,6 +201,56 @@ impl DomainType for DoubleOrdOre {
         schema_for!(DoubleOrdOre)
     }
 }
+#[cfg(feature = "sqlx")]
+impl sqlx::Type<sqlx::Postgres> for DoubleOrdOre {
+    fn type_info() -> sqlx::postgres::PgTypeInfo {
+        crate::v3::sqlx_codec::type_info::<Self>()
+    }
+    fn compatible(ty: &sqlx::postgres::PgTypeInfo) -> bool {
+        crate::v3::sqlx_codec::compatible::<Self>(ty)
+    }
+}
+#[cfg(feature = "sqlx")]
+impl sqlx::Encode<'_, sqlx::Postgres> for DoubleOrdOre {
+    fn encode_by_ref(
+        &self,
+        buf: &mut sqlx::postgres::PgArgumentBuffer,
+    ) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+        crate::v3::sqlx_codec::encode(self, buf)
+    }
+}
+#[cfg(feature = "sqlx")]
+impl<'r> sqlx::Decode<'r, sqlx::Postgres> for DoubleOrdOre {
+    fn decode(value: sqlx::postgres::PgValueRef<'r>) -> Result<Self, sqlx::error::BoxDynError> {
+        crate::v3::sqlx_codec::decode(value)
+    }
+}
+#[cfg(feature = "postgres-types")]
+impl postgres_types::ToSql for DoubleOrdOre {
+    fn to_sql(
+        &self,
+        ty: &postgres_types::Type,
+        out: &mut bytes::BytesMut,
+    ) -> Result<postgres_types::IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        crate::v3::postgres_codec::to_sql(self, ty, out)
+    }
+    fn accepts(ty: &postgres_types::Type) -> bool {
+        crate::v3::postgres_codec::accepts::<Self>(ty)
+    }
+    postgres_types::to_sql_checked!();
+}
+#[cfg(feature = "postgres-types")]
+impl<'a> postgres_types::FromSql<'a> for DoubleOrdOre {
+    fn from_sql(
+        ty: &postgres_types::Type,
+        raw: &'a [u8],
+    ) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        crate::v3::postgres_codec::from_sql(ty, raw)
+    }
+    fn accepts(ty: &postgres_types::Type) -> bool {
+        crate::v3::postgres_codec::accepts_column::<Self>(ty)
+    }
+}
 /// `public.eql_v3_double_ord` — ordering domain.
 ///
 /// Operators: `=` `<>` `<` `<=` `>` `>=`. Required keys: `v` `i` `c` `op`.
@@ -133,6 +283,56 @@ impl DomainType for DoubleOrd {
         schema_for!(DoubleOrd)
     }
 }
+#[cfg(feature = "sqlx")]
+impl sqlx::Type<sqlx::Postgres> for DoubleOrd {
+    fn type_info() -> sqlx::postgres::PgTypeInfo {
+        crate::v3::sqlx_codec::type_info::<Self>()
+    }
+    fn compatible(ty: &sqlx::postgres::PgTypeInfo) -> bool {
+        crate::v3::sqlx_codec::compatible::<Self>(ty)
+    }
+}
+#[cfg(feature = "sqlx")]
+impl sqlx::Encode<'_, sqlx::Postgres> for DoubleOrd {
+    fn encode_by_ref(
+        &self,
+        buf: &mut sqlx::postgres::PgArgumentBuffer,
+    ) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+        crate::v3::sqlx_codec::encode(self, buf)
+    }
+}
+#[cfg(feature = "sqlx")]
+impl<'r> sqlx::Decode<'r, sqlx::Postgres> for DoubleOrd {
+    fn decode(value: sqlx::postgres::PgValueRef<'r>) -> Result<Self, sqlx::error::BoxDynError> {
+        crate::v3::sqlx_codec::decode(value)
+    }
+}
+#[cfg(feature = "postgres-types")]
+impl postgres_types::ToSql for DoubleOrd {
+    fn to_sql(
+        &self,
+        ty: &postgres_types::Type,
+        out: &mut bytes::BytesMut,
+    ) -> Result<postgres_types::IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        crate::v3::postgres_codec::to_sql(self, ty, out)
+    }
+    fn accepts(ty: &postgres_types::Type) -> bool {
+        crate::v3::postgres_codec::accepts::<Self>(ty)
+    }
+    postgres_types::to_sql_checked!();
+}
+#[cfg(feature = "postgres-types")]
+impl<'a> postgres_types::FromSql<'a> for DoubleOrd {
+    fn from_sql(
+        ty: &postgres_types::Type,
+        raw: &'a [u8],
+    ) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        crate::v3::postgres_codec::from_sql(ty, raw)
+    }
+    fn accepts(ty: &postgres_types::Type) -> bool {
+        crate::v3::postgres_codec::accepts_column::<Self>(ty)
+    }
+}
 /// `public.eql_v3_double_ord_ope` — ordering domain.
 ///
 /// Operators: `=` `<>` `<` `<=` `>` `>=`. Required keys: `v` `i` `c` `op`.
@@ -165,6 +365,66 @@ impl DomainType for DoubleOrdOpe {
         schema_for!(DoubleOrdOpe)
     }
 }
+#[cfg(feature = "sqlx")]
+impl sqlx::Type<sqlx::Postgres> for DoubleOrdOpe {
+    fn type_info() -> sqlx::postgres::PgTypeInfo {
+        crate::v3::sqlx_codec::type_info::<Self>()
+    }
+    fn compatible(ty: &sqlx::postgres::PgTypeInfo) -> bool {
+        crate::v3::sqlx_codec::compatible::<Self>(ty)
+    }
+}
+#[cfg(feature = "sqlx")]
+impl sqlx::Encode<'_, sqlx::Postgres> for DoubleOrdOpe {
+    fn encode_by_ref(
+        &self,
+        buf: &mut sqlx::postgres::PgArgumentBuffer,
+    ) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+        crate::v3::sqlx_codec::encode(self, buf)
+    }
+}
+#[cfg(feature = "sqlx")]
+impl<'r> sqlx::Decode<'r, sqlx::Postgres> for DoubleOrdOpe {
+    fn decode(value: sqlx::postgres::PgValueRef<'r>) -> Result<Self, sqlx::error::BoxDynError> {
+        crate::v3::sqlx_codec::decode(value)
+    }
+}
+#[cfg(feature = "postgres-types")]
+impl postgres_types::ToSql for DoubleOrdOpe {
+    fn to_sql(
+        &self,
+        ty: &postgres_types::Type,
+        out: &mut bytes::BytesMut,
+    ) -> Result<postgres_types::IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        crate::v3::postgres_codec::to_sql(self, ty, out)
+    }
+    fn accepts(ty: &postgres_types::Type) -> bool {
+        crate::v3::postgres_codec::accepts::<Self>(ty)
+    }
+    postgres_types::to_sql_checked!();
+}
+#[cfg(feature = "postgres-types")]
+impl<'a> postgres_types::FromSql<'a> for DoubleOrdOpe {
+    fn from_sql(
+        ty: &postgres_types::Type,
+        raw: &'a [u8],
+    ) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        crate::v3::postgres_codec::from_sql(ty, raw)
+    }
+    fn accepts(ty: &postgres_types::Type) -> bool {
+        crate::v3::postgres_codec::accepts_column::<Self>(ty)
+    }
+}
+impl DoubleEq {
+    /// The `eql_v3.query_double_eq` operand: this payload minus `c`.
+    pub fn to_query(&self) -> DoubleEqQuery {
+        DoubleEqQuery {
+            v: self.v,
+            i: self.i.clone(),
+            hm: self.hm.clone(),
+        }
+    }
+}
 /// `eql_v3.query_double_eq` — equality domain query operand.
 ///
 /// Operators: `=` `<>`. Required keys: `v` `i` `hm`.
@@ -196,6 +456,66 @@ impl DomainType for DoubleEqQuery {
         schema_for!(DoubleEqQuery)
     }
 }
+#[cfg(feature = "sqlx")]
+impl sqlx::Type<sqlx::Postgres> for DoubleEqQuery {
+    fn type_info() -> sqlx::postgres::PgTypeInfo {
+        crate::v3::sqlx_codec::type_info::<Self>()
+    }
+    fn compatible(ty: &sqlx::postgres::PgTypeInfo) -> bool {
+        crate::v3::sqlx_codec::compatible::<Self>(ty)
+    }
+}
+#[cfg(feature = "sqlx")]
+impl sqlx::Encode<'_, sqlx::Postgres> for DoubleEqQuery {
+    fn encode_by_ref(
+        &self,
+        buf: &mut sqlx::postgres::PgArgumentBuffer,
+    ) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+        crate::v3::sqlx_codec::encode(self, buf)
+    }
+}
+#[cfg(feature = "sqlx")]
+impl<'r> sqlx::Decode<'r, sqlx::Postgres> for DoubleEqQuery {
+    fn decode(value: sqlx::postgres::PgValueRef<'r>) -> Result<Self, sqlx::error::BoxDynError> {
+        crate::v3::sqlx_codec::decode(value)
+    }
+}
+#[cfg(feature = "postgres-types")]
+impl postgres_types::ToSql for DoubleEqQuery {
+    fn to_sql(
+        &self,
+        ty: &postgres_types::Type,
+        out: &mut bytes::BytesMut,
+    ) -> Result<postgres_types::IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        crate::v3::postgres_codec::to_sql(self, ty, out)
+    }
+    fn accepts(ty: &postgres_types::Type) -> bool {
+        crate::v3::postgres_codec::accepts::<Self>(ty)
+    }
+    postgres_types::to_sql_checked!();
+}
+#[cfg(feature = "postgres-types")]
+impl<'a> postgres_types::FromSql<'a> for DoubleEqQuery {
+    fn from_sql(
+        ty: &postgres_types::Type,
+        raw: &'a [u8],
+    ) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        crate::v3::postgres_codec::from_sql(ty, raw)
+    }
+    fn accepts(ty: &postgres_types::Type) -> bool {
+        crate::v3::postgres_codec::accepts_column::<Self>(ty)
+    }
+}
+impl DoubleOrdOre {
+    /// The `eql_v3.query_double_ord_ore` operand: this payload minus `c`.
+    pub fn to_query(&self) -> DoubleOrdOreQuery {
+        DoubleOrdOreQuery {
+            v: self.v,
+            i: self.i.clone(),
+            ob: self.ob.clone(),
+        }
+    }
+}
 /// `eql_v3.query_double_ord_ore` — ordering domain query operand.
 ///
 /// Operators: `=` `<>` `<` `<=` `>` `>=`. Required keys: `v` `i` `ob`.
@@ -227,6 +547,66 @@ impl DomainType for DoubleOrdOreQuery {
         schema_for!(DoubleOrdOreQuery)
     }
 }
+#[cfg(feature = "sqlx")]
+impl sqlx::Type<sqlx::Postgres> for DoubleOrdOreQuery {
+    fn type_info() -> sqlx::postgres::PgTypeInfo {
+        crate::v3::sqlx_codec::type_info::<Self>()
+    }
+    fn compatible(ty: &sqlx::postgres::PgTypeInfo) -> bool {
+        crate::v3::sqlx_codec::compatible::<Self>(ty)
+    }
+}
+#[cfg(feature = "sqlx")]
+impl sqlx::Encode<'_, sqlx::Postgres> for DoubleOrdOreQuery {
+    fn encode_by_ref(
+        &self,
+        buf: &mut sqlx::postgres::PgArgumentBuffer,
+    ) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+        crate::v3::sqlx_codec::encode(self, buf)
+    }
+}
+#[cfg(feature = "sqlx")]
+impl<'r> sqlx::Decode<'r, sqlx::Postgres> for DoubleOrdOreQuery {
+    fn decode(value: sqlx::postgres::PgValueRef<'r>) -> Result<Self, sqlx::error::BoxDynError> {
+        crate::v3::sqlx_codec::decode(value)
+    }
+}
+#[cfg(feature = "postgres-types")]
+impl postgres_types::ToSql for DoubleOrdOreQuery {
+    fn to_sql(
+        &self,
+        ty: &postgres_types::Type,
+        out: &mut bytes::BytesMut,
+    ) -> Result<postgres_types::IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        crate::v3::postgres_codec::to_sql(self, ty, out)
+    }
+    fn accepts(ty: &postgres_types::Type) -> bool {
+        crate::v3::postgres_codec::accepts::<Self>(ty)
+    }
+    postgres_types::to_sql_checked!();
+}
+#[cfg(feature = "postgres-types")]
+impl<'a> postgres_types::FromSql<'a> for DoubleOrdOreQuery {
+    fn from_sql(
+        ty: &postgres_types::Type,
+        raw: &'a [u8],
+    ) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        crate::v3::postgres_codec::from_sql(ty, raw)
+    }
+    fn accepts(ty: &postgres_types::Type) -> bool {
+        crate::v3::postgres_codec::accepts_column::<Self>(ty)
+    }
+}
+impl DoubleOrd {
+    /// The `eql_v3.query_double_ord` operand: this payload minus `c`.
+    pub fn to_query(&self) -> DoubleOrdQuery {
+        DoubleOrdQuery {
+            v: self.v,
+            i: self.i.clone(),
+            op: self.op.clone(),
+        }
+    }
+}
 /// `eql_v3.query_double_ord` — ordering domain query operand.
 ///
 /// Operators: `=` `<>` `<` `<=` `>` `>=`. Required keys: `v` `i` `op`.
@@ -258,6 +638,66 @@ impl DomainType for DoubleOrdQuery {
         schema_for!(DoubleOrdQuery)
     }
 }
+#[cfg(feature = "sqlx")]
+impl sqlx::Type<sqlx::Postgres> for DoubleOrdQuery {
+    fn type_info() -> sqlx::postgres::PgTypeInfo {
+        crate::v3::sqlx_codec::type_info::<Self>()
+    }
+    fn compatible(ty: &sqlx::postgres::PgTypeInfo) -> bool {
+        crate::v3::sqlx_codec::compatible::<Self>(ty)
+    }
+}
+#[cfg(feature = "sqlx")]
+impl sqlx::Encode<'_, sqlx::Postgres> for DoubleOrdQuery {
+    fn encode_by_ref(
+        &self,
+        buf: &mut sqlx::postgres::PgArgumentBuffer,
+    ) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+        crate::v3::sqlx_codec::encode(self, buf)
+    }
+}
+#[cfg(feature = "sqlx")]
+impl<'r> sqlx::Decode<'r, sqlx::Postgres> for DoubleOrdQuery {
+    fn decode(value: sqlx::postgres::PgValueRef<'r>) -> Result<Self, sqlx::error::BoxDynError> {
+        crate::v3::sqlx_codec::decode(value)
+    }
+}
+#[cfg(feature = "postgres-types")]
+impl postgres_types::ToSql for DoubleOrdQuery {
+    fn to_sql(
+        &self,
+        ty: &postgres_types::Type,
+        out: &mut bytes::BytesMut,
+    ) -> Result<postgres_types::IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        crate::v3::postgres_codec::to_sql(self, ty, out)
+    }
+    fn accepts(ty: &postgres_types::Type) -> bool {
+        crate::v3::postgres_codec::accepts::<Self>(ty)
+    }
+    postgres_types::to_sql_checked!();
+}
+#[cfg(feature = "postgres-types")]
+impl<'a> postgres_types::FromSql<'a> for DoubleOrdQuery {
+    fn from_sql(
+        ty: &postgres_types::Type,
+        raw: &'a [u8],
+    ) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        crate::v3::postgres_codec::from_sql(ty, raw)
+    }
+    fn accepts(ty: &postgres_types::Type) -> bool {
+        crate::v3::postgres_codec::accepts_column::<Self>(ty)
+    }
+}
+impl DoubleOrdOpe {
+    /// The `eql_v3.query_double_ord_ope` operand: this payload minus `c`.
+    pub fn to_query(&self) -> DoubleOrdOpeQuery {
+        DoubleOrdOpeQuery {
+            v: self.v,
+            i: self.i.clone(),
+            op: self.op.clone(),
+        }
+    }
+}
 /// `eql_v3.query_double_ord_ope` — ordering domain query operand.
 ///
 /// Operators: `=` `<>` `<` `<=` `>` `>=`. Required keys: `v` `i` `op`.
@@ -289,3 +729,53 @@ impl DomainType for DoubleOrdOpeQuery {
         schema_for!(DoubleOrdOpeQuery)
     }
 }
+#[cfg(feature = "sqlx")]
+impl sqlx::Type<sqlx::Postgres> for DoubleOrdOpeQuery {
+    fn type_info() -> sqlx::postgres::PgTypeInfo {
+        crate::v3::sqlx_codec::type_info::<Self>()
+    }
+    fn compatible(ty: &sqlx::postgres::PgTypeInfo) -> bool {
+        crate::v3::sqlx_codec::compatible::<Self>(ty)
+    }
+}
+#[cfg(feature = "sqlx")]
+impl sqlx::Encode<'_, sqlx::Postgres> for DoubleOrdOpeQuery {
+    fn encode_by_ref(
+        &self,
+        buf: &mut sqlx::postgres::PgArgumentBuffer,
+    ) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+        crate::v3::sqlx_codec::encode(self, buf)
+    }
+}
+#[cfg(feature = "sqlx")]
+impl<'r> sqlx::Decode<'r, sqlx::Postgres> for DoubleOrdOpeQuery {
+    fn decode(value: sqlx::postgres::PgValueRef<'r>) -> Result<Self, sqlx::error::BoxDynError> {
+        crate::v3::sqlx_codec::decode(value)
+    }
+}
+#[cfg(feature = "postgres-types")]
+impl postgres_types::ToSql for DoubleOrdOpeQuery {
+    fn to_sql(
+        &self,
+        ty: &postgres_types::Type,
+        out: &mut bytes::BytesMut,
+    ) -> Result<postgres_types::IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        crate::v3::postgres_codec::to_sql(self, ty, out)
+    }
+    fn accepts(ty: &postgres_types::Type) -> bool {
+        crate::v3::postgres_codec::accepts::<Self>(ty)
+    }
+    postgres_types::to_sql_checked!();
+}
+#[cfg(feature = "postgres-types")]
+impl<'a> postgres_types::FromSql<'a> for DoubleOrdOpeQuery {
+    fn from_sql(
+        ty: &postgres_types::Type,
+        raw: &'a [u8],
+    ) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        crate::v3::postgres_codec::from_sql(ty, raw)
+    }
+    fn accepts(ty: &postgres_types::Type) -> bool {
+        crate::v3::postgres_codec::accepts_column::<Self>(ty)
+    }
+}
@@ -0,0 +1,379 @@
+//! Wire-level encoding checks for ciphertexts and terms — the hand-written
+//! half of [`DomainType::parse_strict_encoding`].
+//!
+//! The term newtypes in [`super::terms`] hold any `String`, so the strict
+//! parse ([`DomainType::parse_value`]) accepts a truncated or re-encoded
+//! ciphertext and the damage only shows at decryption (or, for a term, as a
+//! database error mid-query). These checks decode what the parse leaves
+//! opaque:
+//!
+//! - `c` / `h`: RFC 1924 base85 of one complete MessagePack value
+//!   (mp_base85); a SteVec entry's `c` is raw AEAD output, so base85 only.
+//! - `hm`: hex of exactly 32 bytes (HMAC-SHA-256).
+//! - `op`: hex whose first byte is the CLLW domain tag — `0x00` on a scalar
+//!   payload or operand, `0x01` (number) / `0x02` (string) on a SteVec entry.
+//! - `ob`: hex block terms of `49 * N + 16` bytes, one `N` across the array
+//!   (the SQL comparator raises on mixed widths).
+//! - `s`: hex of at least the 12 bytes the entry nonce is taken from.
+//!
+//! The `*_PATTERN` constants express the same shapes as JSON Schema
+//! `pattern`s, which the published schemas carry. A regex cannot decode
+//! MessagePack or tell a scalar `op` from an entry's, so the schemas are the
+//! looser of the two.
+//!
+//! [`DomainType::parse_strict_encoding`]: super::DomainType::parse_strict_encoding
+//! [`DomainType::parse_value`]: super::DomainType::parse_value
+
+use std::error::Error;
+use std::fmt;
+
+use serde_json::{Map, Value};
+
+use super::terms::decode_hex;
+
+/// `pattern` for base85 text ([`Ciphertext`](super::terms::Ciphertext),
+/// [`KeyHeader`](super::terms::KeyHeader),
+/// [`EntryCiphertext`](super::terms::EntryCiphertext)): RFC 1924 alphabet,
+/// a length that is not 1 modulo 5, never empty.
+pub const BASE85_PATTERN: &str = "^(?:[0-9A-Za-z!#$%&()*+\\-;<=>?@^_`{|}~]{5})*\
+     (?:[0-9A-Za-z!#$%&()*+\\-;<=>?@^_`{|}~]{5}|[0-9A-Za-z!#$%&()*+\\-;<=>?@^_`{|}~]{2,4})$";
+
+/// `pattern` for [`Hmac256`](super::terms::Hmac256): 32 hex-encoded bytes.
+pub const HMAC_256_PATTERN: &str = "^[0-9a-fA-F]{64}$";
+
+/// `pattern` for [`OpeCllw`](super::terms::OpeCllw): hex led by a domain
+/// tag byte `0x00`–`0x02`.
+pub const OPE_CLLW_PATTERN: &str = "^0[0-2](?:[0-9a-fA-F]{2})*$";
+
+/// `pattern` for each [`OreBlock256`](super::terms::OreBlock256) block term:
+/// hex of `49 * N + 16` bytes, `N >= 1`.
+pub const ORE_BLOCK_256_PATTERN: &str = "^(?:[0-9a-fA-F]{98})+[0-9a-fA-F]{32}$";
+
+/// `pattern` for [`Selector`](super::terms::Selector): at least 12
+/// hex-encoded bytes.
+pub const SELECTOR_PATTERN: &str = "^(?:[0-9a-fA-F]{2}){12,}$";
+
+/// Bytes of an HMAC-SHA-256 `hm` term.
+const HMAC_256_BYTES: usize = 32;
+
+/// Selector bytes the SteVec entry nonce is taken from.
+const NONCE_BYTES: usize = 12;
+
+/// CLLW domain tag of a scalar `op`.
+const OPE_TAG_SCALAR: &[u8] = &[0x00];
+
+/// CLLW domain tags of a SteVec entry's `op`: number, string.
+const OPE_TAG_ENTRY: &[u8] = &[0x01, 0x02];
+
+/// Why [`DomainType::parse_strict_encoding`] rejected a payload. `path` names
+/// the offending field as it sits in the payload (`"c"`, `"hm[1]"`,
+/// `"sv[2].op"`).
+///
+/// [`DomainType::parse_strict_encoding`]: super::DomainType::parse_strict_encoding
+#[derive(Debug)]
+pub enum EncodingError {
+    /// The payload failed the strict parse; no encoding was checked.
+    Parse(serde_json::Error),
+    /// Not RFC 1924 base85: a character outside the alphabet, a length of 1
+    /// modulo 5, or a group that overflows 32 bits.
+    Base85 {
+        /// The offending field.
+        path: String,
+    },
+    /// Decodes as base85, but not to exactly one complete MessagePack value.
+    MessagePack {
+        /// The offending field.
+        path: String,
+    },
+    /// Not pairs of hex digits.
+    Hex {
+        /// The offending field.
+        path: String,
+    },
+    /// An `hm` term that is not 32 bytes.
+    HmacLength {
+        /// The offending field.
+        path: String,
+        /// The decoded length.
+        bytes: usize,
+    },
+    /// An `op` term that is empty (`tag: None`) or led by the wrong domain
+    /// tag for where it sits.
+    OpeTag {
+        /// The offending field.
+        path: String,
+        /// The leading byte, if any.
+        tag: Option<u8>,
+    },
+    /// An `ob` block term that is not `49 * N + 16` bytes for some `N >= 1`.
+    OreBlock {
+        /// The offending field.
+        path: String,
+        /// The decoded length.
+        bytes: usize,
+    },
+    /// An `ob` block term whose block count differs from the first term's.
+    OreWidth {
+        /// The offending field.
+        path: String,
+        /// Blocks in `ob[0]`.
+        expected: usize,
+        /// Blocks in this term.
+        found: usize,
+    },
+    /// A selector shorter than the 12-byte entry nonce.
+    SelectorLength {
+        /// The offending field.
+        path: String,
+        /// The decoded length.
+        bytes: usize,
+    },
+}
+
+impl fmt::Display for EncodingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parse(e) => write!(f, "{e}"),
+            Self::Base85 { path } => write!(f, "`{path}` is not RFC 1924 base85"),
+            Self::MessagePack { path } => {
+                write!(f, "`{path}` does not decode to one MessagePack value")
+            }
+            Self::Hex { path } => write!(f, "`{path}` is not hex"),
+            Self::HmacLength { path, bytes } => {
+                write!(
+                    f,
+                    "`{path}` is {bytes} bytes, expected a {HMAC_256_BYTES}-byte HMAC-SHA-256"
+                )
+            }
+            Self::OpeTag {
+                path,
+                tag: Some(tag),
+            } => write!(f, "`{path}` has CLLW domain tag {tag:#04x}"),
+            Self::OpeTag { path, tag: None } => write!(f, "`{path}` is empty"),
+            Self::OreBlock { path, bytes } => {
+                write!(f, "`{path}` is {bytes} bytes, not 49 * N + 16")
+            }
+            Self::OreWidth {
+                path,
+                expected,
+                found,
+            } => write!(f, "`{path}` has {found} blocks, expected {expected}"),
+            Self::SelectorLength { path, bytes } => {
+                write!(
+                    f,
+                    "`{path}` is {bytes} bytes, shorter than the {NONCE_BYTES}-byte nonce"
+                )
+            }
+        }
+    }
+}
+
+impl Error for EncodingError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Parse(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// Check every ciphertext and term in a payload that has already passed the
+/// strict parse. A top-level `s` marks a standalone SteVec entry
+/// (`public.eql_v3_json_entry`); `sv` elements are entries too.
+pub(super) fn check(value: &Value) -> Result<(), EncodingError> {
+    let Some(object) = value.as_object() else {
+        return Ok(());
+    };
+    check_fields(object, "", object.contains_key("s"))?;
+    if let Some(entries) = object.get("sv").and_then(Value::as_array) {
+        for (i, entry) in entries.iter().enumerate() {
+            if let Some(entry) = entry.as_object() {
+                check_fields(entry, &format!("sv[{i}]."), true)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn check_fields(
+    object: &Map<String, Value>,
+    prefix: &str,
+    entry: bool,
+) -> Result<(), EncodingError> {
+    let path = |key: &str| format!("{prefix}{key}");
+    if let Some(Value::String(c)) = object.get("c") {
+        let bytes = base85(c).ok_or_else(|| EncodingError::Base85 { path: path("c") })?;
+        if !entry && !is_one_msgpack_value(&bytes) {
+            return Err(EncodingError::MessagePack { path: path("c") });
+        }
+    }
+    if let Some(Value::String(h)) = object.get("h") {
+        let bytes = base85(h).ok_or_else(|| EncodingError::Base85 { path: path("h") })?;
+        if !is_one_msgpack_value(&bytes) {
+            return Err(EncodingError::MessagePack { path: path("h") });
+        }
+    }
+    match object.get("hm") {
+        Some(Value::String(hm)) => check_hmac(hm, path("hm"))?,
+        Some(Value::Array(terms)) => {
+            for (i, hm) in terms.iter().enumerate() {
+                if let Value::String(hm) = hm {
+                    check_hmac(hm, path(&format!("hm[{i}]")))?;
+                }
+            }
+        }
+        _ => {}
+    }
+    if let Some(Value::String(op)) = object.get("op") {
+        let tags = if entry { OPE_TAG_ENTRY } else { OPE_TAG_SCALAR };
+        let path = path("op");
+        let bytes = decode_hex(op).ok_or_else(|| EncodingError::Hex { path: path.clone() })?;
+        match bytes.first() {
+            Some(tag) if tags.contains(tag) => {}
+            tag => {
+                return Err(EncodingError::OpeTag {
+                    path,
+                    tag: tag.copied(),
+                })
+            }
+        }
+    }
+    if let Some(Value::Array(terms)) = object.get("ob") {
+        let mut expected = None;
+        for (i, term) in terms.iter().enumerate() {
+            let Value::String(term) = term else { continue };
+            let path = path(&format!("ob[{i}]"));
+            let bytes =
+                decode_hex(term).ok_or_else(|| EncodingError::Hex { path: path.clone() })?;
+            let bytes = bytes.len();
+            if bytes <= 16 || !(bytes - 16).is_multiple_of(49) {
+                return Err(EncodingError::OreBlock { path, bytes });
+            }
+            let found = (bytes - 16) / 49;
+            match expected {
+                None => expected = Some(found),
+                Some(expected) if expected != found => {
+                    return Err(EncodingError::OreWidth {
+                        path,
+                        expected,
+                        found,
+                    })
+                }
+                Some(_) => {}
+            }
+        }
+    }
+    if let Some(Value::String(s)) = object.get("s") {
+        let path = path("s");
+        let bytes = decode_hex(s).ok_or_else(|| EncodingError::Hex { path: path.clone() })?;
+        if bytes.len() < NONCE_BYTES {
+            return Err(EncodingError::SelectorLength {
+                path,
+                bytes: bytes.len(),
+            });
+        }
+    }
+    Ok(())
+}
+
+fn check_hmac(hm: &str, path: String) -> Result<(), EncodingError> {
+    match decode_hex(hm) {
+        None => Err(EncodingError::Hex { path }),
+        Some(bytes) if bytes.len() != HMAC_256_BYTES => Err(EncodingError::HmacLength {
+            path,
+            bytes: bytes.len(),
+        }),
+        Some(_) => Ok(()),
+    }
+}
+
+/// The RFC 1924 alphabet, in digit order.
+const BASE85_ALPHABET: &[u8; 85] =
+    b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz!#$%&()*+-;<=>?@^_`{|}~";
+
+/// Decode RFC 1924 base85. Each 5-character group is a big-endian 32-bit
+/// word; a final group of `k` characters (2–4) carries `k - 1` bytes and is
+/// decoded padded with the highest digit. `None` for a character outside the
+/// alphabet, a stray single trailing character, or a group above `u32::MAX`.
+fn base85(text: &str) -> Option<Vec<u8>> {
+    let digit = |c: u8| BASE85_ALPHABET.iter().position(|&d| d == c);
+    let text = text.as_bytes();
+    if text.len() % 5 == 1 {
+        return None;
+    }
+    let mut bytes = Vec::with_capacity(text.len() / 5 * 4 + 3);
+    for group in text.chunks(5) {
+        let mut word: u64 = 0;
+        for i in 0..5 {
+            let d = match group.get(i) {
+                Some(&c) => digit(c)?,
+                None => 84,
+            };
+            word = word * 85 + d as u64;
+        }
+        let word = u32::try_from(word).ok()?;
+        bytes.extend_from_slice(&word.to_be_bytes()[..group.len() - 1]);
+    }
+    Some(bytes)
+}
+
+/// Whether `bytes` is exactly one well-formed MessagePack value: every
+/// marker known, every length in bounds, nothing left over. Walks the
+/// encoding iteratively (a count of values still owed), so a deeply nested
+/// or hostile input cannot overflow the stack.
+fn is_one_msgpack_value(bytes: &[u8]) -> bool {
+    let mut pos = 0;
+    let mut owed: usize = 1;
+    let length = |pos: &mut usize, width: usize| -> Option<usize> {
+        let field = bytes.get(*pos..*pos + width)?;
+        *pos += width;
+        Some(field.iter().fold(0usize, |n, &b| n << 8 | b as usize))
+    };
+    while owed > 0 {
+        owed -= 1;
+        let Some(&marker) = bytes.get(pos) else {
+            return false;
+        };
+        pos += 1;
+        // (payload bytes to skip, nested values now owed)
+        let step = match marker {
+            0x00..=0x7f | 0xc0 | 0xc2 | 0xc3 | 0xe0..=0xff => Some((0, 0)),
+            0x80..=0x8f => Some((0, 2 * (marker & 0x0f) as usize)),
+            0x90..=0x9f => Some((0, (marker & 0x0f) as usize)),
+            0xa0..=0xbf => Some(((marker & 0x1f) as usize, 0)),
+            0xc4 | 0xd9 => length(&mut pos, 1).map(|n| (n, 0)),
+            0xc5 | 0xda => length(&mut pos, 2).map(|n| (n, 0)),
+            0xc6 | 0xdb => length(&mut pos, 4).map(|n| (n, 0)),
+            // ext 8/16/32: length, then a type byte
+            0xc7 => length(&mut pos, 1).map(|n| (n + 1, 0)),
+            0xc8 => length(&mut pos, 2).map(|n| (n + 1, 0)),
+            0xc9 => length(&mut pos, 4).map(|n| (n + 1, 0)),
+            0xcc | 0xd0 => Some((1, 0)),
+            0xcd | 0xd1 => Some((2, 0)),
+            0xca | 0xce | 0xd2 => Some((4, 0)),
+            0xcb | 0xcf | 0xd3 => Some((8, 0)),
+            // fixext 1/2/4/8/16, plus the type byte
+            0xd4 => Some((2, 0)),
+            0xd5 => Some((3, 0)),
+            0xd6 => Some((5, 0)),
+            0xd7 => Some((9, 0)),
+            0xd8 => Some((17, 0)),
+            0xdc => length(&mut pos, 2).map(|n| (0, n)),
+            0xdd => length(&mut pos, 4).map(|n| (0, n)),
+            0xde => length(&mut pos, 2).map(|n| (0, 2 * n)),
+            0xdf => length(&mut pos, 4).map(|n| (0, 2 * n)),
+            // 0xc1: never used
+            _ => None,
+        };
+        let Some((skip, nested)) = step else {
+            return false;
+        };
+        pos += skip;
+        owed += nested;
+        // Every owed value takes at least one byte.
+        if pos > bytes.len() || owed > bytes.len() - pos {
+            return false;
+        }
+    }
+    pos == bytes.len()
+}
@@ -61,6 +61,29 @@ pub fn all() -> Vec<Box<dyn DomainType>> {
         Box::new(PhantomData::<super::json::SteVecEntry>),
         Box::new(PhantomData::<super::json::SteVecQuery>),
         Box::new(PhantomData::<super::json::Json>),
+        Box::new(PhantomData::<super::uuid::Uuid>),
+        Box::new(PhantomData::<super::uuid::UuidEq>),
+        Box::new(PhantomData::<super::timestamp_naive::TimestampNaive>),
+        Box::new(PhantomData::<super::timestamp_naive::TimestampNaiveEq>),
+        Box::new(PhantomData::<super::timestamp_naive::TimestampNaiveOrdOre>),
+        Box::new(PhantomData::<super::timestamp_naive::TimestampNaiveOrd>),
+        Box::new(PhantomData::<super::timestamp_naive::TimestampNaiveOrdOpe>),
+        Box::new(PhantomData::<super::time::Time>),
+        Box::new(PhantomData::<super::time::TimeEq>),
+        Box::new(PhantomData::<super::time::TimeOrdOre>),
+        Box::new(PhantomData::<super::time::TimeOrd>),
+        Box::new(PhantomData::<super::time::TimeOrdOpe>),
+        Box::new(PhantomData::<super::interval::Interval>),
+        Box::new(PhantomData::<super::interval::IntervalEq>),
+        Box::new(PhantomData::<super::interval::IntervalOrdOre>),
+        Box::new(PhantomData::<super::interval::IntervalOrd>),
+        Box::new(PhantomData::<super::interval::IntervalOrdOpe>),
+        Box::new(PhantomData::<super::bytea::Bytea>),
+        Box::new(PhantomData::<super::bytea::ByteaEq>),
+        Box::new(PhantomData::<super::integer_array::IntegerArray>),
+        Box::new(PhantomData::<super::integer_array::IntegerArrayContains>),
+        Box::new(PhantomData::<super::text_array::TextArray>),
+        Box::new(PhantomData::<super::text_array::TextArrayContains>),
     ]
 }
 /// Every v3 QUERY-operand twin (`eql_v3.query_<name>`, the enveloped
@@ -109,5 +132,21 @@ pub fn all_query() -> Vec<Box<dyn DomainType>> {
         Box::new(PhantomData::<super::double::DoubleOrdOreQuery>),
         Box::new(PhantomData::<super::double::DoubleOrdQuery>),
         Box::new(PhantomData::<super::double::DoubleOrdOpeQuery>),
+        Box::new(PhantomData::<super::uuid::UuidEqQuery>),
+        Box::new(PhantomData::<super::timestamp_naive::TimestampNaiveEqQuery>),
+        Box::new(PhantomData::<super::timestamp_naive::TimestampNaiveOrdOreQuery>),
+        Box::new(PhantomData::<super::timestamp_naive::TimestampNaiveOrdQuery>),
+        Box::new(PhantomData::<super::timestamp_naive::TimestampNaiveOrdOpeQuery>),
+        Box::new(PhantomData::<super::time::TimeEqQuery>),
+        Box::new(PhantomData::<super::time::TimeOrdOreQuery>),
+        Box::new(PhantomData::<super::time::TimeOrdQuery>),
+        Box::new(PhantomData::<super::time::TimeOrdOpeQuery>),
+        Box::new(PhantomData::<super::interval::IntervalEqQuery>),
+        Box::new(PhantomData::<super::interval::IntervalOrdOreQuery>),
+        Box::new(PhantomData::<super::interval::IntervalOrdQuery>),
+        Box::new(PhantomData::<super::interval::IntervalOrdOpeQuery>),
+        Box::new(PhantomData::<super::bytea::ByteaEqQuery>),
+        Box::new(PhantomData::<super::integer_array::IntegerArrayContainsQuery>),
+        Box::new(PhantomData::<super::text_array::TextArrayContainsQuery>),
     ]
 }
@@ -158,6 +158,74 @@ pub struct SteVecQueryEntry {
     pub op: Option<OpeCllw>,
 }
 
+/// Client-side mirrors of the SQL entry accessors in `src/v3/json/`, so a
+/// stored document can be read or turned into a needle without a round trip.
+/// Lookups compare selectors byte for byte (`elem ->> 's' = selector`) and
+/// keep `sv` order; the borrowing accessors return entries as stored, and
+/// [`Self::extract_entry`] / [`Self::graft_metadata`] return them as SQL
+/// does, with the document metadata grafted on.
+impl SteVecDocument {
+    /// The first entry whose selector is `selector` — the entry `->` and
+    /// `eql_v3.jsonb_path_query_first` select — or `None`.
+    pub fn entry(&self, selector: &Selector) -> Option<&SteVecEntry> {
+        self.sv.iter().find(|entry| entry.s == *selector)
+    }
+
+    /// Every entry whose selector is `selector`, in `sv` order: the rows of
+    /// `eql_v3.jsonb_path_query`, before grafting.
+    pub fn entries_matching<'a>(
+        &'a self,
+        selector: &'a Selector,
+    ) -> impl Iterator<Item = &'a SteVecEntry> + 'a {
+        self.sv.iter().filter(move |entry| entry.s == *selector)
+    }
+
+    /// The entries flagged as array elements (`a: true`), in `sv` order — the
+    /// entries `eql_v3_internal.is_ste_vec_array` accepts, so each one is an
+    /// operand `eql_v3.jsonb_array_elements` expands rather than rejects. An
+    /// absent `a` and `a: false` are both "not an array element".
+    pub fn array_entries(&self) -> impl Iterator<Item = &SteVecEntry> {
+        self.sv.iter().filter(|entry| entry.a == Some(true))
+    }
+
+    /// The first entry for `selector` with the document's `i`/`v`/`h`
+    /// grafted on — `doc -> selector` in SQL, a self-contained decryption
+    /// unit. `None` where SQL returns NULL.
+    pub fn extract_entry(&self, selector: &Selector) -> Option<SteVecEntry> {
+        self.entry(selector).map(|entry| self.graft_metadata(entry))
+    }
+
+    /// `entry` with the document's `i`/`v`/`h` grafted on, as
+    /// `eql_v3.meta_data(doc) || entry` does for `->`,
+    /// `eql_v3.jsonb_path_query` and `eql_v3.jsonb_array_elements`. The
+    /// concat is right-biased: metadata the entry already carries wins.
+    pub fn graft_metadata(&self, entry: &SteVecEntry) -> SteVecEntry {
+        SteVecEntry {
+            i: entry.i.clone().or_else(|| Some(self.i.clone())),
+            v: entry.v.or(Some(self.v)),
+            h: entry.h.clone().or_else(|| Some(self.h.clone())),
+            ..entry.clone()
+        }
+    }
+
+    /// The containment needle matching every document that contains this one:
+    /// each entry's selector, in order — what the `eql_v3.to_ste_vec_query`
+    /// cast builds in SQL. `op` is dropped, as containment only compares
+    /// selectors.
+    pub fn to_query(&self) -> SteVecQuery {
+        SteVecQuery {
+            sv: self
+                .sv
+                .iter()
+                .map(|entry| SteVecQueryEntry {
+                    s: entry.s.clone(),
+                    op: None,
+                })
+                .collect(),
+        }
+    }
+}
+
 macro_rules! ste_vec_domain_type {
     ($ty:ident, $sql:literal) => {
         impl DomainType for $ty {
@@ -176,6 +244,35 @@ macro_rules! ste_vec_domain_type {
                 schema_for!($ty)
             }
         }
+
+        // Same delegation as the generated scalar impls.
+        #[cfg(feature = "postgres-types")]
+        impl postgres_types::ToSql for $ty {
+            fn to_sql(
+                &self,
+                ty: &postgres_types::Type,
+                out: &mut bytes::BytesMut,
+            ) -> Result<postgres_types::IsNull, Box<dyn std::error::Error + Sync + Send>> {
+                crate::v3::postgres_codec::to_sql(self, ty, out)
+            }
+            fn accepts(ty: &postgres_types::Type) -> bool {
+                crate::v3::postgres_codec::accepts::<Self>(ty)
+            }
+            postgres_types::to_sql_checked!();
+        }
+
+        #[cfg(feature = "postgres-types")]
+        impl<'a> postgres_types::FromSql<'a> for $ty {
+            fn from_sql(
+                ty: &postgres_types::Type,
+                raw: &'a [u8],
+            ) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+                crate::v3::postgres_codec::from_sql(ty, raw)
+            }
+            fn accepts(ty: &postgres_types::Type) -> bool {
+                crate::v3::postgres_codec::accepts_column::<Self>(ty)
+            }
+        }
     };
 }
 
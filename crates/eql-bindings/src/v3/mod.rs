@@ -107,6 +107,41 @@
 //! searchable index (even HMAC equality) would trivially leak the plaintext
 //! distribution. The payload is `{v,i,c}` only and every operator is blocked.
 //!
+//! **`uuid` is equality-only.** `uuid_eq` carries `hm` and nothing else: UUID
+//! byte order is not a useful range key, so there is no `_ord` domain and
+//! `<`/`<=`/`>`/`>=` are blocked. The `hm` is computed over the canonical
+//! lowercase-hyphenated rendering, so producers must not encrypt other
+//! spellings (braced, uppercase, URN) if they expect them to compare equal.
+//!
+//! **`bytea` is equality-only too.** `bytea_eq` carries `hm` over the value's
+//! lowercase hex rendering, so two payloads are equal exactly when their bytes
+//! are — digests can be joined on `=`. There is no `_ord` domain: byte order
+//! over a digest or token is never a meaningful range.
+//!
+//! **`timestamp_naive` is a wall-clock reading, not an instant.** Its payloads
+//! are shaped exactly like `timestamp`'s (12-block `ob`, `op`), but the
+//! producer encrypts the reading's fields unshifted, so `=` and ordering compare
+//! wall-clock fields. Never convert a naive value through a zone before
+//! encrypting it, and never mix the two families in one comparison.
+//!
+//! **`time` and `interval` encrypt a canonical integer.** A `time` is encrypted
+//! as microseconds since midnight (no `24:00:00`, no zone). An `interval` is
+//! encrypted as its canonical span (months as 30 days, days as 24 hours, in
+//! microseconds), the same key Postgres compares intervals by — so
+//! `'1 mon'` and `'30 days'` payloads are equal on `=` and tie on ordering,
+//! exactly as the plaintext would.
+//!
+//! **`integer_array` / `text_array` hold one `hm` per element.** The
+//! `_contains` payload is the flat envelope with `hm` as an array: `c`
+//! encrypts the whole array, and each element is HMACed under the same key as
+//! the element family's `_eq`. Only set semantics survive — `@>`, `<@` and
+//! `&&` compare the term sets, so element order and duplicates are not
+//! observable and there is no `=`. An empty array is `hm: []`. The
+//! `_contains` domains and their query operands are not `from_v2` targets: a
+//! v2 payload never carries per-element terms. The storage-only
+//! `integer_array` / `text_array` domains need no terms, so they convert like
+//! any storage-only scalar.
+//!
 //! **`json` (SteVec) uses explicit optional fields.** `a` and `op` are absent
 //! when they do not apply; `i`/`v`/`h` are present only on entries extracted by
 //! SQL. Naming those fields explicitly lets `SteVecEntry` and
@@ -126,10 +161,14 @@
 
 pub mod bigint;
 pub mod boolean;
+pub mod bytea;
 pub mod date;
 pub mod domain_type;
 pub mod double;
+pub mod encoding;
 pub mod integer;
+pub mod integer_array;
+pub mod interval;
 pub mod inventory;
 pub mod json;
 /// Generated bindings for the `json` family's bare scalar storage domain
@@ -139,14 +178,25 @@ pub mod json;
 pub mod json_storage;
 pub mod numeric;
 pub mod payload;
+#[cfg(feature = "postgres-types")]
+mod postgres_codec;
+pub mod project;
 pub mod query_payload;
 pub mod real;
 pub mod smallint;
+#[cfg(feature = "sqlx")]
+mod sqlx_codec;
 pub mod terms;
 pub mod text;
+pub mod text_array;
+pub mod time;
 pub mod timestamp;
+pub mod timestamp_naive;
+pub mod uuid;
 
 pub use domain_type::{DomainType, SCHEMA_ID_BASE, SQL_SCHEMA};
+pub use encoding::EncodingError;
 pub use inventory::{all, all_query};
-pub use payload::DomainPayload;
-pub use query_payload::QueryPayload;
+pub use payload::{DomainPayload, DomainSeed};
+pub use project::ProjectError;
+pub use query_payload::{QueryPayload, QuerySeed};
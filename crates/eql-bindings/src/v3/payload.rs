@@ -1,6 +1,9 @@
 // @generated by eql-codegen from the eql-domains catalog — do not edit
 //! The generated `DomainPayload` enum — every stored-payload v3 domain in one Rust type. Generated from the catalog; the DomainType trait, the shared newtypes, and the architectural module doc stay hand-written (domain_type.rs / terms.rs / mod.rs).
 use super::domain_type::DomainType;
+use super::project::ProjectError;
+use super::query_payload::QueryPayload;
+use serde::de::{self, DeserializeSeed, Deserializer};
 use serde::{Deserialize, Serialize};
 /// Every stored-payload v3 domain in one type: one variant per flat
 /// scalar domain in `eql-domains::CATALOG` plus the SteVec document
@@ -12,7 +15,7 @@ use serde::{Deserialize, Serialize};
 /// Deliberately NO `Deserialize`: cross-token payloads are
 /// byte-identical on the wire (see "Why there is no discriminated
 /// enum" in the v3 module docs), so a variant is only constructible
-/// from a KNOWN domain — [`DomainPayload::parse`] or
+/// from a KNOWN domain — [`DomainPayload::parse`], [`DomainSeed`] or
 /// [`crate::from_v2::from_v2_typed`] — never inferred from bytes.
 #[derive(Clone, Debug, PartialEq, Serialize)]
 #[serde(untagged)]
@@ -119,6 +122,52 @@ pub enum DomainPayload {
     SteVecDocument(super::json::SteVecDocument),
     /// The `public.eql_v3_json` payload.
     Json(super::json::Json),
+    /// The `public.eql_v3_uuid` payload.
+    Uuid(super::uuid::Uuid),
+    /// The `public.eql_v3_uuid_eq` payload.
+    UuidEq(super::uuid::UuidEq),
+    /// The `public.eql_v3_timestamp_naive` payload.
+    TimestampNaive(super::timestamp_naive::TimestampNaive),
+    /// The `public.eql_v3_timestamp_naive_eq` payload.
+    TimestampNaiveEq(super::timestamp_naive::TimestampNaiveEq),
+    /// The `public.eql_v3_timestamp_naive_ord_ore` payload.
+    TimestampNaiveOrdOre(super::timestamp_naive::TimestampNaiveOrdOre),
+    /// The `public.eql_v3_timestamp_naive_ord` payload.
+    TimestampNaiveOrd(super::timestamp_naive::TimestampNaiveOrd),
+    /// The `public.eql_v3_timestamp_naive_ord_ope` payload.
+    TimestampNaiveOrdOpe(super::timestamp_naive::TimestampNaiveOrdOpe),
+    /// The `public.eql_v3_time` payload.
+    Time(super::time::Time),
+    /// The `public.eql_v3_time_eq` payload.
+    TimeEq(super::time::TimeEq),
+    /// The `public.eql_v3_time_ord_ore` payload.
+    TimeOrdOre(super::time::TimeOrdOre),
+    /// The `public.eql_v3_time_ord` payload.
+    TimeOrd(super::time::TimeOrd),
+    /// The `public.eql_v3_time_ord_ope` payload.
+    TimeOrdOpe(super::time::TimeOrdOpe),
+    /// The `public.eql_v3_interval` payload.
+    Interval(super::interval::Interval),
+    /// The `public.eql_v3_interval_eq` payload.
+    IntervalEq(super::interval::IntervalEq),
+    /// The `public.eql_v3_interval_ord_ore` payload.
+    IntervalOrdOre(super::interval::IntervalOrdOre),
+    /// The `public.eql_v3_interval_ord` payload.
+    IntervalOrd(super::interval::IntervalOrd),
+    /// The `public.eql_v3_interval_ord_ope` payload.
+    IntervalOrdOpe(super::interval::IntervalOrdOpe),
+    /// The `public.eql_v3_bytea` payload.
+    Bytea(super::bytea::Bytea),
+    /// The `public.eql_v3_bytea_eq` payload.
+    ByteaEq(super::bytea::ByteaEq),
+    /// The `public.eql_v3_integer_array` payload.
+    IntegerArray(super::integer_array::IntegerArray),
+    /// The `public.eql_v3_integer_array_contains` payload.
+    IntegerArrayContains(super::integer_array::IntegerArrayContains),
+    /// The `public.eql_v3_text_array` payload.
+    TextArray(super::text_array::TextArray),
+    /// The `public.eql_v3_text_array_contains` payload.
+    TextArrayContains(super::text_array::TextArrayContains),
 }
 impl DomainPayload {
     /// Strictly parse `value` as `domain`'s payload, KEEPING the
@@ -263,6 +312,68 @@ impl DomainPayload {
                 Some(super::json::SteVecDocument::deserialize(value).map(Self::SteVecDocument))
             }
             "eql_v3_json" => Some(super::json::Json::deserialize(value).map(Self::Json)),
+            "eql_v3_uuid" => Some(super::uuid::Uuid::deserialize(value).map(Self::Uuid)),
+            "eql_v3_uuid_eq" => Some(super::uuid::UuidEq::deserialize(value).map(Self::UuidEq)),
+            "eql_v3_timestamp_naive" => Some(
+                super::timestamp_naive::TimestampNaive::deserialize(value)
+                    .map(Self::TimestampNaive),
+            ),
+            "eql_v3_timestamp_naive_eq" => Some(
+                super::timestamp_naive::TimestampNaiveEq::deserialize(value)
+                    .map(Self::TimestampNaiveEq),
+            ),
+            "eql_v3_timestamp_naive_ord_ore" => Some(
+                super::timestamp_naive::TimestampNaiveOrdOre::deserialize(value)
+                    .map(Self::TimestampNaiveOrdOre),
+            ),
+            "eql_v3_timestamp_naive_ord" => Some(
+                super::timestamp_naive::TimestampNaiveOrd::deserialize(value)
+                    .map(Self::TimestampNaiveOrd),
+            ),
+            "eql_v3_timestamp_naive_ord_ope" => Some(
+                super::timestamp_naive::TimestampNaiveOrdOpe::deserialize(value)
+                    .map(Self::TimestampNaiveOrdOpe),
+            ),
+            "eql_v3_time" => Some(super::time::Time::deserialize(value).map(Self::Time)),
+            "eql_v3_time_eq" => Some(super::time::TimeEq::deserialize(value).map(Self::TimeEq)),
+            "eql_v3_time_ord_ore" => {
+                Some(super::time::TimeOrdOre::deserialize(value).map(Self::TimeOrdOre))
+            }
+            "eql_v3_time_ord" => Some(super::time::TimeOrd::deserialize(value).map(Self::TimeOrd)),
+            "eql_v3_time_ord_ope" => {
+                Some(super::time::TimeOrdOpe::deserialize(value).map(Self::TimeOrdOpe))
+            }
+            "eql_v3_interval" => {
+                Some(super::interval::Interval::deserialize(value).map(Self::Interval))
+            }
+            "eql_v3_interval_eq" => {
+                Some(super::interval::IntervalEq::deserialize(value).map(Self::IntervalEq))
+            }
+            "eql_v3_interval_ord_ore" => {
+                Some(super::interval::IntervalOrdOre::deserialize(value).map(Self::IntervalOrdOre))
+            }
+            "eql_v3_interval_ord" => {
+                Some(super::interval::IntervalOrd::deserialize(value).map(Self::IntervalOrd))
+            }
+            "eql_v3_interval_ord_ope" => {
+                Some(super::interval::IntervalOrdOpe::deserialize(value).map(Self::IntervalOrdOpe))
+            }
+            "eql_v3_bytea" => Some(super::bytea::Bytea::deserialize(value).map(Self::Bytea)),
+            "eql_v3_bytea_eq" => Some(super::bytea::ByteaEq::deserialize(value).map(Self::ByteaEq)),
+            "eql_v3_integer_array" => {
+                Some(super::integer_array::IntegerArray::deserialize(value).map(Self::IntegerArray))
+            }
+            "eql_v3_integer_array_contains" => Some(
+                super::integer_array::IntegerArrayContains::deserialize(value)
+                    .map(Self::IntegerArrayContains),
+            ),
+            "eql_v3_text_array" => {
+                Some(super::text_array::TextArray::deserialize(value).map(Self::TextArray))
+            }
+            "eql_v3_text_array_contains" => Some(
+                super::text_array::TextArrayContains::deserialize(value)
+                    .map(Self::TextArrayContains),
+            ),
             _ => None,
         }
     }
@@ -320,6 +431,29 @@ impl DomainPayload {
             Self::DoubleOrdOpe(payload) => payload,
             Self::SteVecDocument(payload) => payload,
             Self::Json(payload) => payload,
+            Self::Uuid(payload) => payload,
+            Self::UuidEq(payload) => payload,
+            Self::TimestampNaive(payload) => payload,
+            Self::TimestampNaiveEq(payload) => payload,
+            Self::TimestampNaiveOrdOre(payload) => payload,
+            Self::TimestampNaiveOrd(payload) => payload,
+            Self::TimestampNaiveOrdOpe(payload) => payload,
+            Self::Time(payload) => payload,
+            Self::TimeEq(payload) => payload,
+            Self::TimeOrdOre(payload) => payload,
+            Self::TimeOrd(payload) => payload,
+            Self::TimeOrdOpe(payload) => payload,
+            Self::Interval(payload) => payload,
+            Self::IntervalEq(payload) => payload,
+            Self::IntervalOrdOre(payload) => payload,
+            Self::IntervalOrd(payload) => payload,
+            Self::IntervalOrdOpe(payload) => payload,
+            Self::Bytea(payload) => payload,
+            Self::ByteaEq(payload) => payload,
+            Self::IntegerArray(payload) => payload,
+            Self::IntegerArrayContains(payload) => payload,
+            Self::TextArray(payload) => payload,
+            Self::TextArrayContains(payload) => payload,
         }
     }
     /// Fully-qualified SQL domain name, e.g. `"public.eql_v3_integer_eq"`.
@@ -331,4 +465,523 @@ impl DomainPayload {
     pub fn domain(&self) -> &'static str {
         self.as_domain_type().domain()
     }
+    /// The query operand that searches for this payload: `c` stripped,
+    /// exactly the query twin's terms kept. `None` for a storage-only
+    /// domain, which has no query operand (as
+    /// [`crate::from_v2::from_v2_query`] refuses one). The SteVec
+    /// document yields its selector-only containment needle, the
+    /// client-side `eql_v3.to_ste_vec_query`.
+    pub fn to_query(&self) -> Option<QueryPayload> {
+        match self {
+            Self::Integer(_) => None,
+            Self::IntegerEq(payload) => Some(QueryPayload::IntegerEqQuery(payload.to_query())),
+            Self::IntegerOrdOre(payload) => {
+                Some(QueryPayload::IntegerOrdOreQuery(payload.to_query()))
+            }
+            Self::IntegerOrd(payload) => Some(QueryPayload::IntegerOrdQuery(payload.to_query())),
+            Self::IntegerOrdOpe(payload) => {
+                Some(QueryPayload::IntegerOrdOpeQuery(payload.to_query()))
+            }
+            Self::Smallint(_) => None,
+            Self::SmallintEq(payload) => Some(QueryPayload::SmallintEqQuery(payload.to_query())),
+            Self::SmallintOrdOre(payload) => {
+                Some(QueryPayload::SmallintOrdOreQuery(payload.to_query()))
+            }
+            Self::SmallintOrd(payload) => Some(QueryPayload::SmallintOrdQuery(payload.to_query())),
+            Self::SmallintOrdOpe(payload) => {
+                Some(QueryPayload::SmallintOrdOpeQuery(payload.to_query()))
+            }
+            Self::Bigint(_) => None,
+            Self::BigintEq(payload) => Some(QueryPayload::BigintEqQuery(payload.to_query())),
+            Self::BigintOrdOre(payload) => {
+                Some(QueryPayload::BigintOrdOreQuery(payload.to_query()))
+            }
+            Self::BigintOrd(payload) => Some(QueryPayload::BigintOrdQuery(payload.to_query())),
+            Self::BigintOrdOpe(payload) => {
+                Some(QueryPayload::BigintOrdOpeQuery(payload.to_query()))
+            }
+            Self::Date(_) => None,
+            Self::DateEq(payload) => Some(QueryPayload::DateEqQuery(payload.to_query())),
+            Self::DateOrdOre(payload) => Some(QueryPayload::DateOrdOreQuery(payload.to_query())),
+            Self::DateOrd(payload) => Some(QueryPayload::DateOrdQuery(payload.to_query())),
+            Self::DateOrdOpe(payload) => Some(QueryPayload::DateOrdOpeQuery(payload.to_query())),
+            Self::Timestamp(_) => None,
+            Self::TimestampEq(payload) => Some(QueryPayload::TimestampEqQuery(payload.to_query())),
+            Self::TimestampOrdOre(payload) => {
+                Some(QueryPayload::TimestampOrdOreQuery(payload.to_query()))
+            }
+            Self::TimestampOrd(payload) => {
+                Some(QueryPayload::TimestampOrdQuery(payload.to_query()))
+            }
+            Self::TimestampOrdOpe(payload) => {
+                Some(QueryPayload::TimestampOrdOpeQuery(payload.to_query()))
+            }
+            Self::Numeric(_) => None,
+            Self::NumericEq(payload) => Some(QueryPayload::NumericEqQuery(payload.to_query())),
+            Self::NumericOrdOre(payload) => {
+                Some(QueryPayload::NumericOrdOreQuery(payload.to_query()))
+            }
+            Self::NumericOrd(payload) => Some(QueryPayload::NumericOrdQuery(payload.to_query())),
+            Self::NumericOrdOpe(payload) => {
+                Some(QueryPayload::NumericOrdOpeQuery(payload.to_query()))
+            }
+            Self::Text(_) => None,
+            Self::TextEq(payload) => Some(QueryPayload::TextEqQuery(payload.to_query())),
+            Self::TextMatch(payload) => Some(QueryPayload::TextMatchQuery(payload.to_query())),
+            Self::TextOrdOre(payload) => Some(QueryPayload::TextOrdOreQuery(payload.to_query())),
+            Self::TextOrd(payload) => Some(QueryPayload::TextOrdQuery(payload.to_query())),
+            Self::TextOrdOpe(payload) => Some(QueryPayload::TextOrdOpeQuery(payload.to_query())),
+            Self::TextSearchOre(payload) => {
+                Some(QueryPayload::TextSearchOreQuery(payload.to_query()))
+            }
+            Self::TextSearch(payload) => Some(QueryPayload::TextSearchQuery(payload.to_query())),
+            Self::Boolean(_) => None,
+            Self::Real(_) => None,
+            Self::RealEq(payload) => Some(QueryPayload::RealEqQuery(payload.to_query())),
+            Self::RealOrdOre(payload) => Some(QueryPayload::RealOrdOreQuery(payload.to_query())),
+            Self::RealOrd(payload) => Some(QueryPayload::RealOrdQuery(payload.to_query())),
+            Self::RealOrdOpe(payload) => Some(QueryPayload::RealOrdOpeQuery(payload.to_query())),
+            Self::Double(_) => None,
+            Self::DoubleEq(payload) => Some(QueryPayload::DoubleEqQuery(payload.to_query())),
+            Self::DoubleOrdOre(payload) => {
+                Some(QueryPayload::DoubleOrdOreQuery(payload.to_query()))
+            }
+            Self::DoubleOrd(payload) => Some(QueryPayload::DoubleOrdQuery(payload.to_query())),
+            Self::DoubleOrdOpe(payload) => {
+                Some(QueryPayload::DoubleOrdOpeQuery(payload.to_query()))
+            }
+            Self::SteVecDocument(payload) => Some(QueryPayload::SteVec(payload.to_query())),
+            Self::Json(_) => None,
+            Self::Uuid(_) => None,
+            Self::UuidEq(payload) => Some(QueryPayload::UuidEqQuery(payload.to_query())),
+            Self::TimestampNaive(_) => None,
+            Self::TimestampNaiveEq(payload) => {
+                Some(QueryPayload::TimestampNaiveEqQuery(payload.to_query()))
+            }
+            Self::TimestampNaiveOrdOre(payload) => {
+                Some(QueryPayload::TimestampNaiveOrdOreQuery(payload.to_query()))
+            }
+            Self::TimestampNaiveOrd(payload) => {
+                Some(QueryPayload::TimestampNaiveOrdQuery(payload.to_query()))
+            }
+            Self::TimestampNaiveOrdOpe(payload) => {
+                Some(QueryPayload::TimestampNaiveOrdOpeQuery(payload.to_query()))
+            }
+            Self::Time(_) => None,
+            Self::TimeEq(payload) => Some(QueryPayload::TimeEqQuery(payload.to_query())),
+            Self::TimeOrdOre(payload) => Some(QueryPayload::TimeOrdOreQuery(payload.to_query())),
+            Self::TimeOrd(payload) => Some(QueryPayload::TimeOrdQuery(payload.to_query())),
+            Self::TimeOrdOpe(payload) => Some(QueryPayload::TimeOrdOpeQuery(payload.to_query())),
+            Self::Interval(_) => None,
+            Self::IntervalEq(payload) => Some(QueryPayload::IntervalEqQuery(payload.to_query())),
+            Self::IntervalOrdOre(payload) => {
+                Some(QueryPayload::IntervalOrdOreQuery(payload.to_query()))
+            }
+            Self::IntervalOrd(payload) => Some(QueryPayload::IntervalOrdQuery(payload.to_query())),
+            Self::IntervalOrdOpe(payload) => {
+                Some(QueryPayload::IntervalOrdOpeQuery(payload.to_query()))
+            }
+            Self::Bytea(_) => None,
+            Self::ByteaEq(payload) => Some(QueryPayload::ByteaEqQuery(payload.to_query())),
+            Self::IntegerArray(_) => None,
+            Self::IntegerArrayContains(payload) => {
+                Some(QueryPayload::IntegerArrayContainsQuery(payload.to_query()))
+            }
+            Self::TextArray(_) => None,
+            Self::TextArrayContains(payload) => {
+                Some(QueryPayload::TextArrayContainsQuery(payload.to_query()))
+            }
+        }
+    }
+    /// Narrow this payload to `target`, a domain of the same family
+    /// (`"eql_v3_text_eq"` from a `text_search` payload): `c` and the
+    /// envelope kept, term keys `target` does not require dropped,
+    /// the result strictly re-parsed as `target`. Fails closed when
+    /// `target` needs a term this payload lacks, is in another
+    /// family, or is an array or SteVec domain. See [`super::project`].
+    pub fn project_to(&self, target: &str) -> Result<DomainPayload, ProjectError> {
+        let family = Self::family_of(self.domain())
+            .expect("every DomainPayload variant is a stored-payload domain");
+        super::project::project(self, family, target, Self::family_of(target))
+    }
+    /// The catalog family owning stored-payload domain `domain`.
+    pub(crate) fn family_of(domain: &str) -> Option<&'static str> {
+        match domain {
+            "eql_v3_integer" => Some("integer"),
+            "eql_v3_integer_eq" => Some("integer"),
+            "eql_v3_integer_ord_ore" => Some("integer"),
+            "eql_v3_integer_ord" => Some("integer"),
+            "eql_v3_integer_ord_ope" => Some("integer"),
+            "eql_v3_smallint" => Some("smallint"),
+            "eql_v3_smallint_eq" => Some("smallint"),
+            "eql_v3_smallint_ord_ore" => Some("smallint"),
+            "eql_v3_smallint_ord" => Some("smallint"),
+            "eql_v3_smallint_ord_ope" => Some("smallint"),
+            "eql_v3_bigint" => Some("bigint"),
+            "eql_v3_bigint_eq" => Some("bigint"),
+            "eql_v3_bigint_ord_ore" => Some("bigint"),
+            "eql_v3_bigint_ord" => Some("bigint"),
+            "eql_v3_bigint_ord_ope" => Some("bigint"),
+            "eql_v3_date" => Some("date"),
+            "eql_v3_date_eq" => Some("date"),
+            "eql_v3_date_ord_ore" => Some("date"),
+            "eql_v3_date_ord" => Some("date"),
+            "eql_v3_date_ord_ope" => Some("date"),
+            "eql_v3_timestamp" => Some("timestamp"),
+            "eql_v3_timestamp_eq" => Some("timestamp"),
+            "eql_v3_timestamp_ord_ore" => Some("timestamp"),
+            "eql_v3_timestamp_ord" => Some("timestamp"),
+            "eql_v3_timestamp_ord_ope" => Some("timestamp"),
+            "eql_v3_numeric" => Some("numeric"),
+            "eql_v3_numeric_eq" => Some("numeric"),
+            "eql_v3_numeric_ord_ore" => Some("numeric"),
+            "eql_v3_numeric_ord" => Some("numeric"),
+            "eql_v3_numeric_ord_ope" => Some("numeric"),
+            "eql_v3_text" => Some("text"),
+            "eql_v3_text_eq" => Some("text"),
+            "eql_v3_text_match" => Some("text"),
+            "eql_v3_text_ord_ore" => Some("text"),
+            "eql_v3_text_ord" => Some("text"),
+            "eql_v3_text_ord_ope" => Some("text"),
+            "eql_v3_text_search_ore" => Some("text"),
+            "eql_v3_text_search" => Some("text"),
+            "eql_v3_boolean" => Some("boolean"),
+            "eql_v3_real" => Some("real"),
+            "eql_v3_real_eq" => Some("real"),
+            "eql_v3_real_ord_ore" => Some("real"),
+            "eql_v3_real_ord" => Some("real"),
+            "eql_v3_real_ord_ope" => Some("real"),
+            "eql_v3_double" => Some("double"),
+            "eql_v3_double_eq" => Some("double"),
+            "eql_v3_double_ord_ore" => Some("double"),
+            "eql_v3_double_ord" => Some("double"),
+            "eql_v3_double_ord_ope" => Some("double"),
+            "eql_v3_json_search" => Some("json"),
+            "eql_v3_json" => Some("json"),
+            "eql_v3_uuid" => Some("uuid"),
+            "eql_v3_uuid_eq" => Some("uuid"),
+            "eql_v3_timestamp_naive" => Some("timestamp_naive"),
+            "eql_v3_timestamp_naive_eq" => Some("timestamp_naive"),
+            "eql_v3_timestamp_naive_ord_ore" => Some("timestamp_naive"),
+            "eql_v3_timestamp_naive_ord" => Some("timestamp_naive"),
+            "eql_v3_timestamp_naive_ord_ope" => Some("timestamp_naive"),
+            "eql_v3_time" => Some("time"),
+            "eql_v3_time_eq" => Some("time"),
+            "eql_v3_time_ord_ore" => Some("time"),
+            "eql_v3_time_ord" => Some("time"),
+            "eql_v3_time_ord_ope" => Some("time"),
+            "eql_v3_interval" => Some("interval"),
+            "eql_v3_interval_eq" => Some("interval"),
+            "eql_v3_interval_ord_ore" => Some("interval"),
+            "eql_v3_interval_ord" => Some("interval"),
+            "eql_v3_interval_ord_ope" => Some("interval"),
+            "eql_v3_bytea" => Some("bytea"),
+            "eql_v3_bytea_eq" => Some("bytea"),
+            "eql_v3_integer_array" => Some("integer_array"),
+            "eql_v3_integer_array_contains" => Some("integer_array"),
+            "eql_v3_text_array" => Some("text_array"),
+            "eql_v3_text_array_contains" => Some("text_array"),
+            _ => None,
+        }
+    }
+    /// `eql_domains::Role::rank` of the role `Term::role_for_terms`
+    /// resolves for stored-payload domain `domain`'s terms. `None`
+    /// for the SteVec document and unknown names.
+    pub(crate) fn role_rank_of(domain: &str) -> Option<u8> {
+        match domain {
+            "eql_v3_integer" => Some(0),
+            "eql_v3_integer_eq" => Some(2),
+            "eql_v3_integer_ord_ore" => Some(3),
+            "eql_v3_integer_ord" => Some(3),
+            "eql_v3_integer_ord_ope" => Some(3),
+            "eql_v3_smallint" => Some(0),
+            "eql_v3_smallint_eq" => Some(2),
+            "eql_v3_smallint_ord_ore" => Some(3),
+            "eql_v3_smallint_ord" => Some(3),
+            "eql_v3_smallint_ord_ope" => Some(3),
+            "eql_v3_bigint" => Some(0),
+            "eql_v3_bigint_eq" => Some(2),
+            "eql_v3_bigint_ord_ore" => Some(3),
+            "eql_v3_bigint_ord" => Some(3),
+            "eql_v3_bigint_ord_ope" => Some(3),
+            "eql_v3_date" => Some(0),
+            "eql_v3_date_eq" => Some(2),
+            "eql_v3_date_ord_ore" => Some(3),
+            "eql_v3_date_ord" => Some(3),
+            "eql_v3_date_ord_ope" => Some(3),
+            "eql_v3_timestamp" => Some(0),
+            "eql_v3_timestamp_eq" => Some(2),
+            "eql_v3_timestamp_ord_ore" => Some(3),
+            "eql_v3_timestamp_ord" => Some(3),
+            "eql_v3_timestamp_ord_ope" => Some(3),
+            "eql_v3_numeric" => Some(0),
+            "eql_v3_numeric_eq" => Some(2),
+            "eql_v3_numeric_ord_ore" => Some(3),
+            "eql_v3_numeric_ord" => Some(3),
+            "eql_v3_numeric_ord_ope" => Some(3),
+            "eql_v3_text" => Some(0),
+            "eql_v3_text_eq" => Some(2),
+            "eql_v3_text_match" => Some(1),
+            "eql_v3_text_ord_ore" => Some(3),
+            "eql_v3_text_ord" => Some(3),
+            "eql_v3_text_ord_ope" => Some(3),
+            "eql_v3_text_search_ore" => Some(3),
+            "eql_v3_text_search" => Some(3),
+            "eql_v3_boolean" => Some(0),
+            "eql_v3_real" => Some(0),
+            "eql_v3_real_eq" => Some(2),
+            "eql_v3_real_ord_ore" => Some(3),
+            "eql_v3_real_ord" => Some(3),
+            "eql_v3_real_ord_ope" => Some(3),
+            "eql_v3_double" => Some(0),
+            "eql_v3_double_eq" => Some(2),
+            "eql_v3_double_ord_ore" => Some(3),
+            "eql_v3_double_ord" => Some(3),
+            "eql_v3_double_ord_ope" => Some(3),
+            "eql_v3_json" => Some(0),
+            "eql_v3_uuid" => Some(0),
+            "eql_v3_uuid_eq" => Some(2),
+            "eql_v3_timestamp_naive" => Some(0),
+            "eql_v3_timestamp_naive_eq" => Some(2),
+            "eql_v3_timestamp_naive_ord_ore" => Some(3),
+            "eql_v3_timestamp_naive_ord" => Some(3),
+            "eql_v3_timestamp_naive_ord_ope" => Some(3),
+            "eql_v3_time" => Some(0),
+            "eql_v3_time_eq" => Some(2),
+            "eql_v3_time_ord_ore" => Some(3),
+            "eql_v3_time_ord" => Some(3),
+            "eql_v3_time_ord_ope" => Some(3),
+            "eql_v3_interval" => Some(0),
+            "eql_v3_interval_eq" => Some(2),
+            "eql_v3_interval_ord_ore" => Some(3),
+            "eql_v3_interval_ord" => Some(3),
+            "eql_v3_interval_ord_ope" => Some(3),
+            "eql_v3_bytea" => Some(0),
+            "eql_v3_bytea_eq" => Some(2),
+            "eql_v3_integer_array" => Some(0),
+            "eql_v3_integer_array_contains" => Some(2),
+            "eql_v3_text_array" => Some(0),
+            "eql_v3_text_array_contains" => Some(2),
+            _ => None,
+        }
+    }
+}
+/// Streams one payload of a KNOWN domain from any serde
+/// [`Deserializer`] straight into its [`DomainPayload`] variant — the
+/// [`DomainPayload::parse`] constructor without the intermediate
+/// `serde_json::Value`. The domain name (`"eql_v3_integer_eq"`, …)
+/// comes first, so the variant is never inferred from bytes; an
+/// unknown name is a deserialization error, raised before any input
+/// is read.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DomainSeed<'a>(pub &'a str);
+impl<'de> DeserializeSeed<'de> for DomainSeed<'_> {
+    type Value = DomainPayload;
+    fn deserialize<D>(self, deserializer: D) -> Result<DomainPayload, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match self.0 {
+            "eql_v3_integer" => {
+                super::integer::Integer::deserialize(deserializer).map(DomainPayload::Integer)
+            }
+            "eql_v3_integer_eq" => {
+                super::integer::IntegerEq::deserialize(deserializer).map(DomainPayload::IntegerEq)
+            }
+            "eql_v3_integer_ord_ore" => super::integer::IntegerOrdOre::deserialize(deserializer)
+                .map(DomainPayload::IntegerOrdOre),
+            "eql_v3_integer_ord" => {
+                super::integer::IntegerOrd::deserialize(deserializer).map(DomainPayload::IntegerOrd)
+            }
+            "eql_v3_integer_ord_ope" => super::integer::IntegerOrdOpe::deserialize(deserializer)
+                .map(DomainPayload::IntegerOrdOpe),
+            "eql_v3_smallint" => {
+                super::smallint::Smallint::deserialize(deserializer).map(DomainPayload::Smallint)
+            }
+            "eql_v3_smallint_eq" => super::smallint::SmallintEq::deserialize(deserializer)
+                .map(DomainPayload::SmallintEq),
+            "eql_v3_smallint_ord_ore" => super::smallint::SmallintOrdOre::deserialize(deserializer)
+                .map(DomainPayload::SmallintOrdOre),
+            "eql_v3_smallint_ord" => super::smallint::SmallintOrd::deserialize(deserializer)
+                .map(DomainPayload::SmallintOrd),
+            "eql_v3_smallint_ord_ope" => super::smallint::SmallintOrdOpe::deserialize(deserializer)
+                .map(DomainPayload::SmallintOrdOpe),
+            "eql_v3_bigint" => {
+                super::bigint::Bigint::deserialize(deserializer).map(DomainPayload::Bigint)
+            }
+            "eql_v3_bigint_eq" => {
+                super::bigint::BigintEq::deserialize(deserializer).map(DomainPayload::BigintEq)
+            }
+            "eql_v3_bigint_ord_ore" => super::bigint::BigintOrdOre::deserialize(deserializer)
+                .map(DomainPayload::BigintOrdOre),
+            "eql_v3_bigint_ord" => {
+                super::bigint::BigintOrd::deserialize(deserializer).map(DomainPayload::BigintOrd)
+            }
+            "eql_v3_bigint_ord_ope" => super::bigint::BigintOrdOpe::deserialize(deserializer)
+                .map(DomainPayload::BigintOrdOpe),
+            "eql_v3_date" => super::date::Date::deserialize(deserializer).map(DomainPayload::Date),
+            "eql_v3_date_eq" => {
+                super::date::DateEq::deserialize(deserializer).map(DomainPayload::DateEq)
+            }
+            "eql_v3_date_ord_ore" => {
+                super::date::DateOrdOre::deserialize(deserializer).map(DomainPayload::DateOrdOre)
+            }
+            "eql_v3_date_ord" => {
+                super::date::DateOrd::deserialize(deserializer).map(DomainPayload::DateOrd)
+            }
+            "eql_v3_date_ord_ope" => {
+                super::date::DateOrdOpe::deserialize(deserializer).map(DomainPayload::DateOrdOpe)
+            }
+            "eql_v3_timestamp" => {
+                super::timestamp::Timestamp::deserialize(deserializer).map(DomainPayload::Timestamp)
+            }
+            "eql_v3_timestamp_eq" => super::timestamp::TimestampEq::deserialize(deserializer)
+                .map(DomainPayload::TimestampEq),
+            "eql_v3_timestamp_ord_ore" => {
+                super::timestamp::TimestampOrdOre::deserialize(deserializer)
+                    .map(DomainPayload::TimestampOrdOre)
+            }
+            "eql_v3_timestamp_ord" => super::timestamp::TimestampOrd::deserialize(deserializer)
+                .map(DomainPayload::TimestampOrd),
+            "eql_v3_timestamp_ord_ope" => {
+                super::timestamp::TimestampOrdOpe::deserialize(deserializer)
+                    .map(DomainPayload::TimestampOrdOpe)
+            }
+            "eql_v3_numeric" => {
+                super::numeric::Numeric::deserialize(deserializer).map(DomainPayload::Numeric)
+            }
+            "eql_v3_numeric_eq" => {
+                super::numeric::NumericEq::deserialize(deserializer).map(DomainPayload::NumericEq)
+            }
+            "eql_v3_numeric_ord_ore" => super::numeric::NumericOrdOre::deserialize(deserializer)
+                .map(DomainPayload::NumericOrdOre),
+            "eql_v3_numeric_ord" => {
+                super::numeric::NumericOrd::deserialize(deserializer).map(DomainPayload::NumericOrd)
+            }
+            "eql_v3_numeric_ord_ope" => super::numeric::NumericOrdOpe::deserialize(deserializer)
+                .map(DomainPayload::NumericOrdOpe),
+            "eql_v3_text" => super::text::Text::deserialize(deserializer).map(DomainPayload::Text),
+            "eql_v3_text_eq" => {
+                super::text::TextEq::deserialize(deserializer).map(DomainPayload::TextEq)
+            }
+            "eql_v3_text_match" => {
+                super::text::TextMatch::deserialize(deserializer).map(DomainPayload::TextMatch)
+            }
+            "eql_v3_text_ord_ore" => {
+                super::text::TextOrdOre::deserialize(deserializer).map(DomainPayload::TextOrdOre)
+            }
+            "eql_v3_text_ord" => {
+                super::text::TextOrd::deserialize(deserializer).map(DomainPayload::TextOrd)
+            }
+            "eql_v3_text_ord_ope" => {
+                super::text::TextOrdOpe::deserialize(deserializer).map(DomainPayload::TextOrdOpe)
+            }
+            "eql_v3_text_search_ore" => super::text::TextSearchOre::deserialize(deserializer)
+                .map(DomainPayload::TextSearchOre),
+            "eql_v3_text_search" => {
+                super::text::TextSearch::deserialize(deserializer).map(DomainPayload::TextSearch)
+            }
+            "eql_v3_boolean" => {
+                super::boolean::Boolean::deserialize(deserializer).map(DomainPayload::Boolean)
+            }
+            "eql_v3_real" => super::real::Real::deserialize(deserializer).map(DomainPayload::Real),
+            "eql_v3_real_eq" => {
+                super::real::RealEq::deserialize(deserializer).map(DomainPayload::RealEq)
+            }
+            "eql_v3_real_ord_ore" => {
+                super::real::RealOrdOre::deserialize(deserializer).map(DomainPayload::RealOrdOre)
+            }
+            "eql_v3_real_ord" => {
+                super::real::RealOrd::deserialize(deserializer).map(DomainPayload::RealOrd)
+            }
+            "eql_v3_real_ord_ope" => {
+                super::real::RealOrdOpe::deserialize(deserializer).map(DomainPayload::RealOrdOpe)
+            }
+            "eql_v3_double" => {
+                super::double::Double::deserialize(deserializer).map(DomainPayload::Double)
+            }
+            "eql_v3_double_eq" => {
+                super::double::DoubleEq::deserialize(deserializer).map(DomainPayload::DoubleEq)
+            }
+            "eql_v3_double_ord_ore" => super::double::DoubleOrdOre::deserialize(deserializer)
+                .map(DomainPayload::DoubleOrdOre),
+            "eql_v3_double_ord" => {
+                super::double::DoubleOrd::deserialize(deserializer).map(DomainPayload::DoubleOrd)
+            }
+            "eql_v3_double_ord_ope" => super::double::DoubleOrdOpe::deserialize(deserializer)
+                .map(DomainPayload::DoubleOrdOpe),
+            "eql_v3_json_search" => super::json::SteVecDocument::deserialize(deserializer)
+                .map(DomainPayload::SteVecDocument),
+            "eql_v3_json" => super::json::Json::deserialize(deserializer).map(DomainPayload::Json),
+            "eql_v3_uuid" => super::uuid::Uuid::deserialize(deserializer).map(DomainPayload::Uuid),
+            "eql_v3_uuid_eq" => {
+                super::uuid::UuidEq::deserialize(deserializer).map(DomainPayload::UuidEq)
+            }
+            "eql_v3_timestamp_naive" => {
+                super::timestamp_naive::TimestampNaive::deserialize(deserializer)
+                    .map(DomainPayload::TimestampNaive)
+            }
+            "eql_v3_timestamp_naive_eq" => {
+                super::timestamp_naive::TimestampNaiveEq::deserialize(deserializer)
+                    .map(DomainPayload::TimestampNaiveEq)
+            }
+            "eql_v3_timestamp_naive_ord_ore" => {
+                super::timestamp_naive::TimestampNaiveOrdOre::deserialize(deserializer)
+                    .map(DomainPayload::TimestampNaiveOrdOre)
+            }
+            "eql_v3_timestamp_naive_ord" => {
+                super::timestamp_naive::TimestampNaiveOrd::deserialize(deserializer)
+                    .map(DomainPayload::TimestampNaiveOrd)
+            }
+            "eql_v3_timestamp_naive_ord_ope" => {
+                super::timestamp_naive::TimestampNaiveOrdOpe::deserialize(deserializer)
+                    .map(DomainPayload::TimestampNaiveOrdOpe)
+            }
+            "eql_v3_time" => super::time::Time::deserialize(deserializer).map(DomainPayload::Time),
+            "eql_v3_time_eq" => {
+                super::time::TimeEq::deserialize(deserializer).map(DomainPayload::TimeEq)
+            }
+            "eql_v3_time_ord_ore" => {
+                super::time::TimeOrdOre::deserialize(deserializer).map(DomainPayload::TimeOrdOre)
+            }
+            "eql_v3_time_ord" => {
+                super::time::TimeOrd::deserialize(deserializer).map(DomainPayload::TimeOrd)
+            }
+            "eql_v3_time_ord_ope" => {
+                super::time::TimeOrdOpe::deserialize(deserializer).map(DomainPayload::TimeOrdOpe)
+            }
+            "eql_v3_interval" => {
+                super::interval::Interval::deserialize(deserializer).map(DomainPayload::Interval)
+            }
+            "eql_v3_interval_eq" => super::interval::IntervalEq::deserialize(deserializer)
+                .map(DomainPayload::IntervalEq),
+            "eql_v3_interval_ord_ore" => super::interval::IntervalOrdOre::deserialize(deserializer)
+                .map(DomainPayload::IntervalOrdOre),
+            "eql_v3_interval_ord" => super::interval::IntervalOrd::deserialize(deserializer)
+                .map(DomainPayload::IntervalOrd),
+            "eql_v3_interval_ord_ope" => super::interval::IntervalOrdOpe::deserialize(deserializer)
+                .map(DomainPayload::IntervalOrdOpe),
+            "eql_v3_bytea" => {
+                super::bytea::Bytea::deserialize(deserializer).map(DomainPayload::Bytea)
+            }
+            "eql_v3_bytea_eq" => {
+                super::bytea::ByteaEq::deserialize(deserializer).map(DomainPayload::ByteaEq)
+            }
+            "eql_v3_integer_array" => super::integer_array::IntegerArray::deserialize(deserializer)
+                .map(DomainPayload::IntegerArray),
+            "eql_v3_integer_array_contains" => {
+                super::integer_array::IntegerArrayContains::deserialize(deserializer)
+                    .map(DomainPayload::IntegerArrayContains)
+            }
+            "eql_v3_text_array" => super::text_array::TextArray::deserialize(deserializer)
+                .map(DomainPayload::TextArray),
+            "eql_v3_text_array_contains" => {
+                super::text_array::TextArrayContains::deserialize(deserializer)
+                    .map(DomainPayload::TextArrayContains)
+            }
+            domain => Err(de::Error::custom(format_args!(
+                "unknown stored-payload domain {domain:?}"
+            ))),
+        }
+    }
 }
@@ -0,0 +1,57 @@
+//! Shared bodies of the `postgres-types` impls (`postgres-types` feature):
+//! `ToSql`/`FromSql` on the generated payload structs (emitted by
+//! `eql-codegen`'s bindings renderer) and on the hand-written SteVec shapes.
+//!
+//! `ToSql::accepts` matches the parameter's schema-qualified typname against
+//! `DomainType::sql_domain_static()` exactly, so binding an `IntegerEq` where
+//! Postgres expects `public.eql_v3_integer_ord` fails on the client rather
+//! than at the domain CHECK — or not at all, when the two shapes coincide.
+//! `FromSql::accepts` also takes plain `jsonb`/`json`: Postgres describes a
+//! domain-typed result column by its base type, so a strict domain match would
+//! reject every fetched row. Either way the bytes go through the struct's
+//! strict serde parse.
+
+use std::error::Error;
+use std::fmt::Debug;
+
+use bytes::BytesMut;
+use postgres_types::{FromSql, IsNull, Json, Kind, ToSql, Type};
+use serde::{Deserialize, Serialize};
+
+use super::DomainType;
+
+type BoxError = Box<dyn Error + Sync + Send>;
+
+/// `ty` is exactly `T`'s SQL domain.
+pub(crate) fn accepts<T: DomainType>(ty: &Type) -> bool {
+    let (schema, name) = T::sql_domain_static()
+        .split_once('.')
+        .expect("sql_domain must be schema-qualified");
+    ty.schema() == schema && ty.name() == name
+}
+
+/// `ty` is `T`'s SQL domain or a JSON base type a domain column reports as.
+pub(crate) fn accepts_column<T: DomainType>(ty: &Type) -> bool {
+    accepts::<T>(ty) || *ty == Type::JSONB || *ty == Type::JSON
+}
+
+pub(crate) fn to_sql<T: Serialize + Debug>(
+    payload: &T,
+    ty: &Type,
+    out: &mut BytesMut,
+) -> Result<IsNull, BoxError> {
+    Json(payload).to_sql(base_type(ty), out)
+}
+
+pub(crate) fn from_sql<'a, T: Deserialize<'a>>(ty: &Type, raw: &'a [u8]) -> Result<T, BoxError> {
+    Ok(Json::<T>::from_sql(base_type(ty), raw)?.0)
+}
+
+/// The wire format of a domain is its base type's, so `Json` must see the base
+/// type to emit (or strip) the `jsonb` version byte.
+fn base_type(ty: &Type) -> &Type {
+    match ty.kind() {
+        Kind::Domain(base) => base,
+        _ => ty,
+    }
+}
@@ -0,0 +1,152 @@
+//! Capability-narrowing projection between domains of one family — the
+//! hand-written half of the generated [`DomainPayload::project_to`].
+//!
+//! A stored payload keeps its ciphertext and envelope; projecting only drops
+//! the term keys the target domain does not require (`text_search` →
+//! `text_eq` keeps `hm`, drops `op`/`bf`). Nothing is re-encrypted, so a
+//! projection can only narrow: a target needing a term the source lacks is
+//! refused, as is any target in another family (whose `c` decrypts to a
+//! different plaintext type). Required keys come from
+//! [`DomainType::term_json_keys`](super::DomainType::term_json_keys), the same
+//! catalog-derived lists `from_v2` converts with — so, as there, only the flat
+//! scalar payloads project; array and SteVec domains are refused.
+
+use std::error::Error;
+use std::fmt;
+
+use super::{all, DomainPayload};
+
+/// Why [`DomainPayload::project_to`] refused a projection. Fail-closed: a
+/// projected payload is always re-parsed through the target binding struct.
+#[derive(Debug)]
+pub enum ProjectError {
+    /// The target is not a stored-payload domain name
+    /// (`"eql_v3_text_eq"`, …) — the names [`DomainPayload::parse`] accepts.
+    UnknownDomain {
+        /// The name that failed to resolve.
+        name: String,
+    },
+    /// The target belongs to another family than the source.
+    CrossFamily {
+        /// The source payload's domain.
+        source: &'static str,
+        /// The requested target domain.
+        target: String,
+    },
+    /// The source or target has no flat term keys to narrow (an array or
+    /// SteVec domain).
+    Unprojectable {
+        /// The array or SteVec domain involved.
+        domain: String,
+    },
+    /// The target requires a term key the source payload does not carry.
+    MissingTerm {
+        /// The source payload's domain.
+        source: &'static str,
+        /// The requested target domain.
+        target: String,
+        /// The missing wire key (`hm`/`ob`/`bf`/`op`).
+        key: &'static str,
+    },
+    /// The projected payload failed the strict parse through the target
+    /// binding struct.
+    Invalid(serde_json::Error),
+}
+
+impl fmt::Display for ProjectError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownDomain { name } => {
+                write!(f, "unknown projection target {name:?}")
+            }
+            Self::CrossFamily { source, target } => {
+                write!(
+                    f,
+                    "cannot project `{source}` onto `{target}`: different families"
+                )
+            }
+            Self::Unprojectable { domain } => {
+                write!(
+                    f,
+                    "`{domain}` has no flat term keys to project (array or SteVec domain)"
+                )
+            }
+            Self::MissingTerm {
+                source,
+                target,
+                key,
+            } => {
+                write!(
+                    f,
+                    "target domain `{target}` requires term key `{key}`, absent from the `{source}` payload"
+                )
+            }
+            Self::Invalid(e) => {
+                write!(f, "projected payload failed v3 validation: {e}")
+            }
+        }
+    }
+}
+
+impl Error for ProjectError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Invalid(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// Project `payload` (of family `family`) onto `target` (of `target_family`,
+/// `None` when `target` is not a stored-payload domain).
+pub(super) fn project(
+    payload: &DomainPayload,
+    family: &'static str,
+    target: &str,
+    target_family: Option<&'static str>,
+) -> Result<DomainPayload, ProjectError> {
+    let source = payload.domain();
+    let target_family = target_family.ok_or_else(|| ProjectError::UnknownDomain {
+        name: target.into(),
+    })?;
+    if target_family != family {
+        return Err(ProjectError::CrossFamily {
+            source,
+            target: target.into(),
+        });
+    }
+
+    let source_keys =
+        payload
+            .as_domain_type()
+            .term_json_keys()
+            .ok_or_else(|| ProjectError::Unprojectable {
+                domain: source.into(),
+            })?;
+    let target_keys = all()
+        .into_iter()
+        .find(|d| d.domain() == target)
+        .and_then(|d| d.term_json_keys())
+        .ok_or_else(|| ProjectError::Unprojectable {
+            domain: target.into(),
+        })?;
+    if let Some(key) = target_keys.iter().find(|k| !source_keys.contains(k)) {
+        return Err(ProjectError::MissingTerm {
+            source,
+            target: target.into(),
+            key,
+        });
+    }
+
+    let mut value = serde_json::to_value(payload).map_err(ProjectError::Invalid)?;
+    if let Some(obj) = value.as_object_mut() {
+        obj.retain(|key, _| {
+            !source_keys.contains(&key.as_str()) || target_keys.contains(&key.as_str())
+        });
+    }
+    DomainPayload::parse(target, &value)
+        .ok_or_else(|| ProjectError::UnknownDomain {
+            name: target.into(),
+        })?
+        .map_err(ProjectError::Invalid)
+}
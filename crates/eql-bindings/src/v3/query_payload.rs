@@ -1,6 +1,7 @@
 // @generated by eql-codegen from the eql-domains catalog — do not edit
 //! The generated `QueryPayload` enum — every v3 QUERY-operand domain in one Rust type. Generated from the catalog; the DomainType trait, the shared newtypes, and the architectural module doc stay hand-written (domain_type.rs / terms.rs / mod.rs).
 use super::domain_type::DomainType;
+use serde::de::{self, DeserializeSeed, Deserializer};
 use serde::{Deserialize, Serialize};
 /// Every v3 QUERY-operand shape in one type: one variant per term-bearing
 /// scalar query twin (`eql_v3.query_<name>`, the enveloped term-only
@@ -12,8 +13,8 @@ use serde::{Deserialize, Serialize};
 /// adds no tagging), so typing a query operand never changes the wire.
 /// Deliberately NO `Deserialize`: cross-token operands are byte-identical
 /// on the wire, so a variant is only constructible from a KNOWN domain
-/// — [`QueryPayload::parse`] or [`crate::from_v2::from_v2_query_typed`] —
-/// never inferred from bytes. No ts-rs/schemars: it adds no wire shape.
+/// — [`QueryPayload::parse`], [`QuerySeed`] or
+/// [`crate::from_v2::from_v2_query_typed`] — never inferred from bytes. No ts-rs/schemars: it adds no wire shape.
 #[derive(Clone, Debug, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum QueryPayload {
@@ -95,6 +96,38 @@ pub enum QueryPayload {
     DoubleOrdQuery(super::double::DoubleOrdQuery),
     /// The `eql_v3.query_double_ord_ope` query operand.
     DoubleOrdOpeQuery(super::double::DoubleOrdOpeQuery),
+    /// The `eql_v3.query_uuid_eq` query operand.
+    UuidEqQuery(super::uuid::UuidEqQuery),
+    /// The `eql_v3.query_timestamp_naive_eq` query operand.
+    TimestampNaiveEqQuery(super::timestamp_naive::TimestampNaiveEqQuery),
+    /// The `eql_v3.query_timestamp_naive_ord_ore` query operand.
+    TimestampNaiveOrdOreQuery(super::timestamp_naive::TimestampNaiveOrdOreQuery),
+    /// The `eql_v3.query_timestamp_naive_ord` query operand.
+    TimestampNaiveOrdQuery(super::timestamp_naive::TimestampNaiveOrdQuery),
+    /// The `eql_v3.query_timestamp_naive_ord_ope` query operand.
+    TimestampNaiveOrdOpeQuery(super::timestamp_naive::TimestampNaiveOrdOpeQuery),
+    /// The `eql_v3.query_time_eq` query operand.
+    TimeEqQuery(super::time::TimeEqQuery),
+    /// The `eql_v3.query_time_ord_ore` query operand.
+    TimeOrdOreQuery(super::time::TimeOrdOreQuery),
+    /// The `eql_v3.query_time_ord` query operand.
+    TimeOrdQuery(super::time::TimeOrdQuery),
+    /// The `eql_v3.query_time_ord_ope` query operand.
+    TimeOrdOpeQuery(super::time::TimeOrdOpeQuery),
+    /// The `eql_v3.query_interval_eq` query operand.
+    IntervalEqQuery(super::interval::IntervalEqQuery),
+    /// The `eql_v3.query_interval_ord_ore` query operand.
+    IntervalOrdOreQuery(super::interval::IntervalOrdOreQuery),
+    /// The `eql_v3.query_interval_ord` query operand.
+    IntervalOrdQuery(super::interval::IntervalOrdQuery),
+    /// The `eql_v3.query_interval_ord_ope` query operand.
+    IntervalOrdOpeQuery(super::interval::IntervalOrdOpeQuery),
+    /// The `eql_v3.query_bytea_eq` query operand.
+    ByteaEqQuery(super::bytea::ByteaEqQuery),
+    /// The `eql_v3.query_integer_array_contains` query operand.
+    IntegerArrayContainsQuery(super::integer_array::IntegerArrayContainsQuery),
+    /// The `eql_v3.query_text_array_contains` query operand.
+    TextArrayContainsQuery(super::text_array::TextArrayContainsQuery),
     /// The `eql_v3.query_json` query operand.
     SteVec(super::json::SteVecQuery),
 }
@@ -236,6 +269,62 @@ impl QueryPayload {
             "query_double_ord_ope" => Some(
                 super::double::DoubleOrdOpeQuery::deserialize(value).map(Self::DoubleOrdOpeQuery),
             ),
+            "query_uuid_eq" => {
+                Some(super::uuid::UuidEqQuery::deserialize(value).map(Self::UuidEqQuery))
+            }
+            "query_timestamp_naive_eq" => Some(
+                super::timestamp_naive::TimestampNaiveEqQuery::deserialize(value)
+                    .map(Self::TimestampNaiveEqQuery),
+            ),
+            "query_timestamp_naive_ord_ore" => Some(
+                super::timestamp_naive::TimestampNaiveOrdOreQuery::deserialize(value)
+                    .map(Self::TimestampNaiveOrdOreQuery),
+            ),
+            "query_timestamp_naive_ord" => Some(
+                super::timestamp_naive::TimestampNaiveOrdQuery::deserialize(value)
+                    .map(Self::TimestampNaiveOrdQuery),
+            ),
+            "query_timestamp_naive_ord_ope" => Some(
+                super::timestamp_naive::TimestampNaiveOrdOpeQuery::deserialize(value)
+                    .map(Self::TimestampNaiveOrdOpeQuery),
+            ),
+            "query_time_eq" => {
+                Some(super::time::TimeEqQuery::deserialize(value).map(Self::TimeEqQuery))
+            }
+            "query_time_ord_ore" => {
+                Some(super::time::TimeOrdOreQuery::deserialize(value).map(Self::TimeOrdOreQuery))
+            }
+            "query_time_ord" => {
+                Some(super::time::TimeOrdQuery::deserialize(value).map(Self::TimeOrdQuery))
+            }
+            "query_time_ord_ope" => {
+                Some(super::time::TimeOrdOpeQuery::deserialize(value).map(Self::TimeOrdOpeQuery))
+            }
+            "query_interval_eq" => Some(
+                super::interval::IntervalEqQuery::deserialize(value).map(Self::IntervalEqQuery),
+            ),
+            "query_interval_ord_ore" => Some(
+                super::interval::IntervalOrdOreQuery::deserialize(value)
+                    .map(Self::IntervalOrdOreQuery),
+            ),
+            "query_interval_ord" => Some(
+                super::interval::IntervalOrdQuery::deserialize(value).map(Self::IntervalOrdQuery),
+            ),
+            "query_interval_ord_ope" => Some(
+                super::interval::IntervalOrdOpeQuery::deserialize(value)
+                    .map(Self::IntervalOrdOpeQuery),
+            ),
+            "query_bytea_eq" => {
+                Some(super::bytea::ByteaEqQuery::deserialize(value).map(Self::ByteaEqQuery))
+            }
+            "query_integer_array_contains" => Some(
+                super::integer_array::IntegerArrayContainsQuery::deserialize(value)
+                    .map(Self::IntegerArrayContainsQuery),
+            ),
+            "query_text_array_contains" => Some(
+                super::text_array::TextArrayContainsQuery::deserialize(value)
+                    .map(Self::TextArrayContainsQuery),
+            ),
             "query_json" => Some(super::json::SteVecQuery::deserialize(value).map(Self::SteVec)),
             _ => None,
         }
@@ -282,6 +371,22 @@ impl QueryPayload {
             Self::DoubleOrdOreQuery(payload) => payload,
             Self::DoubleOrdQuery(payload) => payload,
             Self::DoubleOrdOpeQuery(payload) => payload,
+            Self::UuidEqQuery(payload) => payload,
+            Self::TimestampNaiveEqQuery(payload) => payload,
+            Self::TimestampNaiveOrdOreQuery(payload) => payload,
+            Self::TimestampNaiveOrdQuery(payload) => payload,
+            Self::TimestampNaiveOrdOpeQuery(payload) => payload,
+            Self::TimeEqQuery(payload) => payload,
+            Self::TimeOrdOreQuery(payload) => payload,
+            Self::TimeOrdQuery(payload) => payload,
+            Self::TimeOrdOpeQuery(payload) => payload,
+            Self::IntervalEqQuery(payload) => payload,
+            Self::IntervalOrdOreQuery(payload) => payload,
+            Self::IntervalOrdQuery(payload) => payload,
+            Self::IntervalOrdOpeQuery(payload) => payload,
+            Self::ByteaEqQuery(payload) => payload,
+            Self::IntegerArrayContainsQuery(payload) => payload,
+            Self::TextArrayContainsQuery(payload) => payload,
             Self::SteVec(payload) => payload,
         }
     }
@@ -295,3 +400,176 @@ impl QueryPayload {
         self.as_domain_type().domain()
     }
 }
+/// The query-side [`super::DomainSeed`]: streams one operand of a
+/// KNOWN query domain (`"query_integer_eq"`, `"query_json"`, …) from
+/// any serde [`Deserializer`] into its [`QueryPayload`] variant,
+/// without the `serde_json::Value` [`QueryPayload::parse`] takes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct QuerySeed<'a>(pub &'a str);
+impl<'de> DeserializeSeed<'de> for QuerySeed<'_> {
+    type Value = QueryPayload;
+    fn deserialize<D>(self, deserializer: D) -> Result<QueryPayload, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match self.0 {
+            "query_integer_eq" => super::integer::IntegerEqQuery::deserialize(deserializer)
+                .map(QueryPayload::IntegerEqQuery),
+            "query_integer_ord_ore" => {
+                super::integer::IntegerOrdOreQuery::deserialize(deserializer)
+                    .map(QueryPayload::IntegerOrdOreQuery)
+            }
+            "query_integer_ord" => super::integer::IntegerOrdQuery::deserialize(deserializer)
+                .map(QueryPayload::IntegerOrdQuery),
+            "query_integer_ord_ope" => {
+                super::integer::IntegerOrdOpeQuery::deserialize(deserializer)
+                    .map(QueryPayload::IntegerOrdOpeQuery)
+            }
+            "query_smallint_eq" => super::smallint::SmallintEqQuery::deserialize(deserializer)
+                .map(QueryPayload::SmallintEqQuery),
+            "query_smallint_ord_ore" => {
+                super::smallint::SmallintOrdOreQuery::deserialize(deserializer)
+                    .map(QueryPayload::SmallintOrdOreQuery)
+            }
+            "query_smallint_ord" => super::smallint::SmallintOrdQuery::deserialize(deserializer)
+                .map(QueryPayload::SmallintOrdQuery),
+            "query_smallint_ord_ope" => {
+                super::smallint::SmallintOrdOpeQuery::deserialize(deserializer)
+                    .map(QueryPayload::SmallintOrdOpeQuery)
+            }
+            "query_bigint_eq" => super::bigint::BigintEqQuery::deserialize(deserializer)
+                .map(QueryPayload::BigintEqQuery),
+            "query_bigint_ord_ore" => super::bigint::BigintOrdOreQuery::deserialize(deserializer)
+                .map(QueryPayload::BigintOrdOreQuery),
+            "query_bigint_ord" => super::bigint::BigintOrdQuery::deserialize(deserializer)
+                .map(QueryPayload::BigintOrdQuery),
+            "query_bigint_ord_ope" => super::bigint::BigintOrdOpeQuery::deserialize(deserializer)
+                .map(QueryPayload::BigintOrdOpeQuery),
+            "query_date_eq" => {
+                super::date::DateEqQuery::deserialize(deserializer).map(QueryPayload::DateEqQuery)
+            }
+            "query_date_ord_ore" => super::date::DateOrdOreQuery::deserialize(deserializer)
+                .map(QueryPayload::DateOrdOreQuery),
+            "query_date_ord" => {
+                super::date::DateOrdQuery::deserialize(deserializer).map(QueryPayload::DateOrdQuery)
+            }
+            "query_date_ord_ope" => super::date::DateOrdOpeQuery::deserialize(deserializer)
+                .map(QueryPayload::DateOrdOpeQuery),
+            "query_timestamp_eq" => super::timestamp::TimestampEqQuery::deserialize(deserializer)
+                .map(QueryPayload::TimestampEqQuery),
+            "query_timestamp_ord_ore" => {
+                super::timestamp::TimestampOrdOreQuery::deserialize(deserializer)
+                    .map(QueryPayload::TimestampOrdOreQuery)
+            }
+            "query_timestamp_ord" => super::timestamp::TimestampOrdQuery::deserialize(deserializer)
+                .map(QueryPayload::TimestampOrdQuery),
+            "query_timestamp_ord_ope" => {
+                super::timestamp::TimestampOrdOpeQuery::deserialize(deserializer)
+                    .map(QueryPayload::TimestampOrdOpeQuery)
+            }
+            "query_numeric_eq" => super::numeric::NumericEqQuery::deserialize(deserializer)
+                .map(QueryPayload::NumericEqQuery),
+            "query_numeric_ord_ore" => {
+                super::numeric::NumericOrdOreQuery::deserialize(deserializer)
+                    .map(QueryPayload::NumericOrdOreQuery)
+            }
+            "query_numeric_ord" => super::numeric::NumericOrdQuery::deserialize(deserializer)
+                .map(QueryPayload::NumericOrdQuery),
+            "query_numeric_ord_ope" => {
+                super::numeric::NumericOrdOpeQuery::deserialize(deserializer)
+                    .map(QueryPayload::NumericOrdOpeQuery)
+            }
+            "query_text_eq" => {
+                super::text::TextEqQuery::deserialize(deserializer).map(QueryPayload::TextEqQuery)
+            }
+            "query_text_match" => super::text::TextMatchQuery::deserialize(deserializer)
+                .map(QueryPayload::TextMatchQuery),
+            "query_text_ord_ore" => super::text::TextOrdOreQuery::deserialize(deserializer)
+                .map(QueryPayload::TextOrdOreQuery),
+            "query_text_ord" => {
+                super::text::TextOrdQuery::deserialize(deserializer).map(QueryPayload::TextOrdQuery)
+            }
+            "query_text_ord_ope" => super::text::TextOrdOpeQuery::deserialize(deserializer)
+                .map(QueryPayload::TextOrdOpeQuery),
+            "query_text_search_ore" => super::text::TextSearchOreQuery::deserialize(deserializer)
+                .map(QueryPayload::TextSearchOreQuery),
+            "query_text_search" => super::text::TextSearchQuery::deserialize(deserializer)
+                .map(QueryPayload::TextSearchQuery),
+            "query_real_eq" => {
+                super::real::RealEqQuery::deserialize(deserializer).map(QueryPayload::RealEqQuery)
+            }
+            "query_real_ord_ore" => super::real::RealOrdOreQuery::deserialize(deserializer)
+                .map(QueryPayload::RealOrdOreQuery),
+            "query_real_ord" => {
+                super::real::RealOrdQuery::deserialize(deserializer).map(QueryPayload::RealOrdQuery)
+            }
+            "query_real_ord_ope" => super::real::RealOrdOpeQuery::deserialize(deserializer)
+                .map(QueryPayload::RealOrdOpeQuery),
+            "query_double_eq" => super::double::DoubleEqQuery::deserialize(deserializer)
+                .map(QueryPayload::DoubleEqQuery),
+            "query_double_ord_ore" => super::double::DoubleOrdOreQuery::deserialize(deserializer)
+                .map(QueryPayload::DoubleOrdOreQuery),
+            "query_double_ord" => super::double::DoubleOrdQuery::deserialize(deserializer)
+                .map(QueryPayload::DoubleOrdQuery),
+            "query_double_ord_ope" => super::double::DoubleOrdOpeQuery::deserialize(deserializer)
+                .map(QueryPayload::DoubleOrdOpeQuery),
+            "query_uuid_eq" => {
+                super::uuid::UuidEqQuery::deserialize(deserializer).map(QueryPayload::UuidEqQuery)
+            }
+            "query_timestamp_naive_eq" => {
+                super::timestamp_naive::TimestampNaiveEqQuery::deserialize(deserializer)
+                    .map(QueryPayload::TimestampNaiveEqQuery)
+            }
+            "query_timestamp_naive_ord_ore" => {
+                super::timestamp_naive::TimestampNaiveOrdOreQuery::deserialize(deserializer)
+                    .map(QueryPayload::TimestampNaiveOrdOreQuery)
+            }
+            "query_timestamp_naive_ord" => {
+                super::timestamp_naive::TimestampNaiveOrdQuery::deserialize(deserializer)
+                    .map(QueryPayload::TimestampNaiveOrdQuery)
+            }
+            "query_timestamp_naive_ord_ope" => {
+                super::timestamp_naive::TimestampNaiveOrdOpeQuery::deserialize(deserializer)
+                    .map(QueryPayload::TimestampNaiveOrdOpeQuery)
+            }
+            "query_time_eq" => {
+                super::time::TimeEqQuery::deserialize(deserializer).map(QueryPayload::TimeEqQuery)
+            }
+            "query_time_ord_ore" => super::time::TimeOrdOreQuery::deserialize(deserializer)
+                .map(QueryPayload::TimeOrdOreQuery),
+            "query_time_ord" => {
+                super::time::TimeOrdQuery::deserialize(deserializer).map(QueryPayload::TimeOrdQuery)
+            }
+            "query_time_ord_ope" => super::time::TimeOrdOpeQuery::deserialize(deserializer)
+                .map(QueryPayload::TimeOrdOpeQuery),
+            "query_interval_eq" => super::interval::IntervalEqQuery::deserialize(deserializer)
+                .map(QueryPayload::IntervalEqQuery),
+            "query_interval_ord_ore" => {
+                super::interval::IntervalOrdOreQuery::deserialize(deserializer)
+                    .map(QueryPayload::IntervalOrdOreQuery)
+            }
+            "query_interval_ord" => super::interval::IntervalOrdQuery::deserialize(deserializer)
+                .map(QueryPayload::IntervalOrdQuery),
+            "query_interval_ord_ope" => {
+                super::interval::IntervalOrdOpeQuery::deserialize(deserializer)
+                    .map(QueryPayload::IntervalOrdOpeQuery)
+            }
+            "query_bytea_eq" => super::bytea::ByteaEqQuery::deserialize(deserializer)
+                .map(QueryPayload::ByteaEqQuery),
+            "query_integer_array_contains" => {
+                super::integer_array::IntegerArrayContainsQuery::deserialize(deserializer)
+                    .map(QueryPayload::IntegerArrayContainsQuery)
+            }
+            "query_text_array_contains" => {
+                super::text_array::TextArrayContainsQuery::deserialize(deserializer)
+                    .map(QueryPayload::TextArrayContainsQuery)
+            }
+            "query_json" => {
+                super::json::SteVecQuery::deserialize(deserializer).map(QueryPayload::SteVec)
+            }
+            domain => Err(de::Error::custom(format_args!(
+                "unknown query-operand domain {domain:?}"
+            ))),
+        }
+    }
+}
@@ -0,0 +1,52 @@
+//! Shared bodies of the `sqlx` impls the generated payload structs carry under
+//! the `sqlx` feature (emitted by `eql-codegen`'s bindings renderer, one
+//! one-line delegation per method).
+//!
+//! A payload binds as its own domain — `PgTypeInfo::with_name` resolves the
+//! schema-qualified name through `regtype`, so both `public.eql_v3_*` columns
+//! and `eql_v3.query_*` operands work — and is written in the `jsonb` binary
+//! format the domain inherits. On read, Postgres describes a domain-typed
+//! column by its base type, so `jsonb` (and `json`) are accepted as well; the
+//! value is then deserialized straight into the struct, with the same
+//! `deny_unknown_fields` and `v: 3` checks as any other serde parse.
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use sqlx::encode::IsNull;
+use sqlx::error::BoxDynError;
+use sqlx::postgres::{PgArgumentBuffer, PgTypeInfo, PgValueRef};
+use sqlx::types::{Json, JsonValue};
+use sqlx::{Decode, Encode, Postgres, Type};
+
+use super::DomainType;
+
+pub(crate) fn type_info<T: DomainType>() -> PgTypeInfo {
+    PgTypeInfo::with_name(T::sql_domain_static())
+}
+
+/// `ty` is `T`'s SQL domain, or the `jsonb` / `json` base type a domain
+/// column reports on read.
+///
+/// Postgres describes a domain-typed result column by its base type, so the
+/// column's domain is not visible here: every `jsonb` column passes, and
+/// telling domains apart falls to the strict parse in [`decode`]. A row of
+/// another domain fails it when the keys differ — an `eql_v3_integer_ord` row
+/// read as `TextEq` carries `op` and lacks `hm` — but decodes when the shapes
+/// coincide (`IntegerEq` and `TextEq` are both `{v, i, c, hm}`). A type
+/// naming any other domain is refused.
+pub(crate) fn compatible<T: DomainType>(ty: &PgTypeInfo) -> bool {
+    *ty == type_info::<T>() || <JsonValue as Type<Postgres>>::compatible(ty)
+}
+
+pub(crate) fn encode<T: Serialize>(
+    payload: &T,
+    buf: &mut PgArgumentBuffer,
+) -> Result<IsNull, BoxDynError> {
+    Json(payload).encode_by_ref(buf)
+}
+
+pub(crate) fn decode<'r, T: DeserializeOwned + 'r>(
+    value: PgValueRef<'r>,
+) -> Result<T, BoxDynError> {
+    Ok(<Json<T> as Decode<'r, Postgres>>::decode(value)?.0)
+}
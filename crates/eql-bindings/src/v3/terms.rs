@@ -10,17 +10,40 @@
 //! Names follow the SEM constructor names in `eql-domains` (`Term::ctor()`):
 //! a future scheme change (e.g. a 12-block wide ORE term for timestamp
 //! ordering) is a new newtype, not a hunt through `Vec<String>` fields.
+//!
+//! The two order terms also order client-side, exactly as the database
+//! would: [`OpeCllw`] like the hex-decoded `bytea` `eql_v3.ord_term` returns,
+//! and [`OreBlock256`] through a port of
+//! `eql_v3_internal.compare_ore_block_256_terms`, so results fetched from
+//! several databases can be merged without another round trip. The ORE
+//! comparator needs AES, so it sits behind the default-on `ore-compare`
+//! feature.
+//! [`BloomFilter::matches`] likewise answers `@@` without the database.
+
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::hash::Hash;
+#[cfg(feature = "ore-compare")]
+use std::hash::Hasher;
 
+#[cfg(feature = "ore-compare")]
+use aes::cipher::{BlockEncrypt, KeyInit};
+#[cfg(feature = "ore-compare")]
+use aes::{Aes128, Block};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
 
+use super::encoding::{
+    BASE85_PATTERN, HMAC_256_PATTERN, OPE_CLLW_PATTERN, ORE_BLOCK_256_PATTERN, SELECTOR_PATTERN,
+};
+
 /// mp_base85 source ciphertext — the `c` envelope key.
 ///
 /// Required by every v3 domain CHECK; present on every payload.
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, TS, JsonSchema)]
 #[ts(export, export_to = "v3/")]
-pub struct Ciphertext(pub String);
+pub struct Ciphertext(#[schemars(pattern(BASE85_PATTERN))] pub String);
 
 /// The document key header — the `h` envelope key of a SteVec document.
 ///
@@ -32,7 +55,7 @@ pub struct Ciphertext(pub String);
 /// `eql_v3.meta_data`), never parsed database-side.
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, TS, JsonSchema)]
 #[ts(export, export_to = "v3/")]
-pub struct KeyHeader(pub String);
+pub struct KeyHeader(#[schemars(pattern(BASE85_PATTERN))] pub String);
 
 /// A SteVec entry's ciphertext — the sv-element `c` key: base85 of the raw
 /// AEAD output only.
@@ -43,19 +66,19 @@ pub struct KeyHeader(pub String);
 /// decryption unit is the entry — `h` + `s` + `c`.
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, TS, JsonSchema)]
 #[ts(export, export_to = "v3/")]
-pub struct EntryCiphertext(pub String);
+pub struct EntryCiphertext(#[schemars(pattern(BASE85_PATTERN))] pub String);
 
 /// HMAC-SHA-256 equality term — the `hm` wire key. Backs the `_eq` domains
 /// (`=`, `<>`). SQL-side constructor: `eql_v3_internal.hmac_256`.
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, TS, JsonSchema)]
 #[ts(export, export_to = "v3/")]
-pub struct Hmac256(pub String);
+pub struct Hmac256(#[schemars(pattern(HMAC_256_PATTERN))] pub String);
 
 /// A SteVec selector — the `s` wire key. Addresses a JSON path leaf within an
 /// encrypted document (`public.eql_v3_json_search`); present on every entry and query element.
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, TS, JsonSchema)]
 #[ts(export, export_to = "v3/")]
-pub struct Selector(pub String);
+pub struct Selector(#[schemars(pattern(SELECTOR_PATTERN))] pub String);
 
 /// CLLW-OPE order term — the `op` wire key. Backs the scalar `_ord` (the
 /// default ordering domain), `_ord_ope`, and `text_search` domains, their
@@ -67,9 +90,15 @@ pub struct Selector(pub String);
 /// Extracted by `eql_v3.ord_term` (scalar domains and the
 /// `public.eql_v3_json_entry` overload alike); SQL-side constructor:
 /// `eql_v3_internal.ope_cllw`.
+///
+/// `Ord` is that bytea order: the hex-decoded bytes compared as unsigned
+/// bytes, a proper prefix first. Two spellings of the same bytes (`"AB"`,
+/// `"ab"`) — equal to Postgres — are tie-broken by their text so `Ord` stays
+/// consistent with the derived `Eq`; a term that is not valid hex (the
+/// database would reject it) sorts after every valid one.
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, TS, JsonSchema)]
 #[ts(export, export_to = "v3/")]
-pub struct OpeCllw(pub String);
+pub struct OpeCllw(#[schemars(pattern(OPE_CLLW_PATTERN))] pub String);
 
 /// Block-ORE order term — the `ob` wire key. Backs the `_ord_ore` domains and
 /// `text_search_ore` (`=` `<>` `<` `<=` `>` `>=`); ORE is lossless over the scalar's
@@ -77,9 +106,21 @@ pub struct OpeCllw(pub String);
 /// wire (8 for the int scalars, 12 for timestamp, 14 for numeric) — the
 /// array just carries more block strings. Extracted by `eql_v3.ord_term_ore`;
 /// SQL-side constructor: `eql_v3_internal.ore_block_256`.
-#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, TS, JsonSchema)]
+///
+/// `Ord` and `Eq` are the SQL comparator's, not the strings': two encryptions
+/// of one plaintext carry different right halves yet compare equal, and hash
+/// alike. Terms compare block-term by block-term, an empty array first. Where
+/// the database would raise instead — a block term that is not valid hex or
+/// not `49 * N + 16` bytes, or two terms of different widths — the order
+/// stays total: malformed terms sort after well-formed ones (by their text),
+/// and a narrower term before a wider one.
+///
+/// That comparator is the `ore-compare` feature (on by default). Without it
+/// `OreBlock256` has no `Ord`, and `Eq`/`Hash` fall back to the raw strings.
+#[derive(Clone, Debug, Serialize, Deserialize, TS, JsonSchema)]
+#[cfg_attr(not(feature = "ore-compare"), derive(PartialEq, Eq, Hash))]
 #[ts(export, export_to = "v3/")]
-pub struct OreBlock256(pub Vec<String>);
+pub struct OreBlock256(#[schemars(inner(pattern(ORE_BLOCK_256_PATTERN)))] pub Vec<String>);
 
 /// Bloom-filter match term — the `bf` wire key. Backs the fuzzy-match `@@`
 /// (`eql_v3.matches`) surface on the `_match` domains; the containment operators
@@ -160,6 +201,24 @@ impl From<Vec<i16>> for BloomFilter {
     }
 }
 
+impl BloomFilter {
+    /// Whether `needle` matches this filter, exactly as `self @@ needle`
+    /// (`eql_v3.matches`) decides it: every bit position set in `needle` is
+    /// set here, and an empty `needle` (a search string too short to yield an
+    /// n-gram) matches only an empty filter.
+    ///
+    /// Positions compare as the signed `smallint` values the database stores,
+    /// so an upper-half position matches only its own negative encoding.
+    /// Order and repeats are irrelevant, as for Postgres array containment.
+    pub fn matches(&self, needle: &BloomFilter) -> bool {
+        if needle.0.is_empty() {
+            return self.0.is_empty();
+        }
+        let haystack: HashSet<i16> = self.0.iter().copied().collect();
+        needle.0.iter().all(|bit| haystack.contains(bit))
+    }
+}
+
 impl From<String> for Selector {
     fn from(value: String) -> Self {
         Self(value)
@@ -171,3 +230,160 @@ impl From<String> for OpeCllw {
         Self(value)
     }
 }
+
+impl PartialOrd for OpeCllw {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OpeCllw {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (decode_hex(&self.0), decode_hex(&other.0)) {
+            (Some(a), Some(b)) => a.cmp(&b),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        }
+        .then_with(|| self.0.cmp(&other.0))
+    }
+}
+
+#[cfg(feature = "ore-compare")]
+impl PartialEq for OreBlock256 {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+#[cfg(feature = "ore-compare")]
+impl Eq for OreBlock256 {}
+
+/// Hashes only what [`OreBlock256`]'s `Eq` compares: the width, PRP bytes and
+/// left blocks of each block term (the first `17 * N` bytes), or the text of a
+/// malformed one.
+#[cfg(feature = "ore-compare")]
+impl Hash for OreBlock256 {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.0.len());
+        for term in &self.0 {
+            match OreTerm::parse(term) {
+                OreTerm::WellFormed { bytes, blocks } => {
+                    state.write_u8(0);
+                    bytes.len().hash(state);
+                    bytes[..17 * blocks].hash(state);
+                }
+                OreTerm::Malformed(text) => {
+                    state.write_u8(1);
+                    text.hash(state);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "ore-compare")]
+impl PartialOrd for OreBlock256 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Port of `eql_v3_internal.compare_ore_block_256_terms`: lexicographic over
+/// the block terms, so `[]` sorts before every non-empty term.
+#[cfg(feature = "ore-compare")]
+impl Ord for OreBlock256 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let mut ours = self.0.iter().map(|term| OreTerm::parse(term));
+        let mut theirs = other.0.iter().map(|term| OreTerm::parse(term));
+        loop {
+            match (ours.next(), theirs.next()) {
+                (None, None) => return Ordering::Equal,
+                (None, Some(_)) => return Ordering::Less,
+                (Some(_), None) => return Ordering::Greater,
+                (Some(a), Some(b)) => match a.compare(&b) {
+                    Ordering::Equal => continue,
+                    decided => return decided,
+                },
+            }
+        }
+    }
+}
+
+/// One `ob` block term, decoded once per comparison.
+#[cfg(feature = "ore-compare")]
+enum OreTerm<'a> {
+    /// Valid hex of `49 * blocks + 16` bytes, `blocks >= 1`.
+    WellFormed { bytes: Vec<u8>, blocks: usize },
+    /// Anything the SQL comparator would raise on.
+    Malformed(&'a str),
+}
+
+#[cfg(feature = "ore-compare")]
+impl<'a> OreTerm<'a> {
+    fn parse(text: &'a str) -> Self {
+        match decode_hex(text) {
+            Some(bytes) if bytes.len() > 16 && (bytes.len() - 16).is_multiple_of(49) => {
+                let blocks = (bytes.len() - 16) / 49;
+                OreTerm::WellFormed { bytes, blocks }
+            }
+            _ => OreTerm::Malformed(text),
+        }
+    }
+
+    /// Port of `eql_v3_internal.compare_ore_block_256_term`. Equal when every
+    /// PRP byte and left block matches; otherwise the first differing block
+    /// `k` decides, via the indicator bit
+    /// `AES_ECB(b.nonce, a.left[k])[bit 0] XOR b.right[k][bit a.prp[k]]`.
+    fn compare(&self, other: &Self) -> Ordering {
+        let (a, b, n) = match (self, other) {
+            (OreTerm::Malformed(a), OreTerm::Malformed(b)) => return a.cmp(b),
+            (OreTerm::WellFormed { .. }, OreTerm::Malformed(_)) => return Ordering::Less,
+            (OreTerm::Malformed(_), OreTerm::WellFormed { .. }) => return Ordering::Greater,
+            (OreTerm::WellFormed { bytes: a, blocks }, OreTerm::WellFormed { bytes: b, .. }) => {
+                if a.len() != b.len() {
+                    return a.len().cmp(&b.len());
+                }
+                (a, b, *blocks)
+            }
+        };
+
+        // Left block `i` sits after the `n` PRP bytes.
+        fn left(bytes: &[u8], n: usize, block: usize) -> &[u8] {
+            &bytes[n + 16 * block..n + 16 * (block + 1)]
+        }
+        let Some(k) =
+            (0..n).find(|&block| a[block] != b[block] || left(a, n, block) != left(b, n, block))
+        else {
+            return Ordering::Equal;
+        };
+
+        let nonce = &b[17 * n..17 * n + 16];
+        let mut encrypted =
+            Block::from(<[u8; 16]>::try_from(left(a, n, k)).expect("16-byte block"));
+        Aes128::new_from_slice(nonce)
+            .expect("16-byte AES-128 key")
+            .encrypt_block(&mut encrypted);
+
+        let right = &b[17 * n + 16 + 32 * k..17 * n + 16 + 32 * (k + 1)];
+        let bit = usize::from(a[k]);
+        if (encrypted[0] & 1) ^ ((right[bit / 8] >> (bit % 8)) & 1) == 1 {
+            Ordering::Greater
+        } else {
+            Ordering::Less
+        }
+    }
+}
+
+/// Decode a hex string as Postgres `decode(_, 'hex')` does for well-formed
+/// input: pairs of case-insensitive hex digits. `None` for anything else.
+pub(super) fn decode_hex(text: &str) -> Option<Vec<u8>> {
+    let digit = |c: u8| (c as char).to_digit(16).map(|d| d as u8);
+    let text = text.as_bytes();
+    if !text.len().is_multiple_of(2) {
+        return None;
+    }
+    text.chunks(2)
+        .map(|pair| Some(digit(pair[0])? << 4 | digit(pair[1])?))
+        .collect()
+}
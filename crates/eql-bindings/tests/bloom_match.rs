@@ -0,0 +1,44 @@
+//! [`BloomFilter::matches`] against the truth table of `eql_v3.matches`
+//! (`@@`): `smallint[]` containment plus the empty-needle guard. The
+//! cross-check against the SQL operator over real fixture filters is the
+//! `bloom_match_parity` module of the SQLx property suite.
+
+use eql_bindings::v3::terms::BloomFilter;
+
+fn bf(bits: &[i16]) -> BloomFilter {
+    BloomFilter(bits.to_vec())
+}
+
+#[test]
+fn needle_bits_must_all_be_set() {
+    let haystack = bf(&[3, 17, 200, 4_095]);
+    assert!(haystack.matches(&bf(&[17])));
+    assert!(haystack.matches(&bf(&[4_095, 3])), "order is irrelevant");
+    assert!(haystack.matches(&haystack.clone()));
+    assert!(!haystack.matches(&bf(&[17, 18])));
+    assert!(!bf(&[17]).matches(&haystack), "containment is one-way");
+}
+
+#[test]
+fn repeats_are_irrelevant() {
+    assert!(bf(&[5, 9]).matches(&bf(&[9, 9, 5, 9])));
+    assert!(bf(&[5, 5, 9]).matches(&bf(&[5, 9])));
+}
+
+#[test]
+fn empty_needle_matches_only_an_empty_filter() {
+    assert!(bf(&[]).matches(&bf(&[])));
+    assert!(!bf(&[1, 2, 3]).matches(&bf(&[])));
+    assert!(!bf(&[]).matches(&bf(&[1])));
+}
+
+#[test]
+fn upper_half_positions_compare_as_signed_values() {
+    // Bit 40_000 of a 65_536-bit filter is stored as 40_000 - 65_536.
+    let upper = (40_000u16 as i16, -25_536i16);
+    assert_eq!(upper.0, upper.1);
+    let haystack = bf(&[i16::MIN, -25_536, -1, 0, i16::MAX]);
+    assert!(haystack.matches(&bf(&[upper.0, i16::MIN, i16::MAX])));
+    assert!(!haystack.matches(&bf(&[25_536])), "the sign is significant");
+    assert!(!haystack.matches(&bf(&[-2])));
+}
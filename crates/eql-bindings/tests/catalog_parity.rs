@@ -15,6 +15,7 @@
 
 use std::collections::BTreeSet;
 
+use eql_bindings::from_v2::TargetDomain;
 use eql_bindings::{v3, EQL_SCHEMA_VERSION};
 use eql_domains::{Term, CATALOG, ENVELOPE_KEYS};
 use serde_json::{json, Value};
@@ -349,3 +350,22 @@ fn jsonb_schema_required_keys_match_the_sql_check_contract() {
         .pointer("/$defs/SteVecQueryEntry/properties/hm")
         .is_none());
 }
+
+/// `ScalarTarget::rank` reads the generated `Role::rank` table instead of a
+/// runtime eql-domains dependency, so `from_v2::candidate_targets` ranks
+/// exactly as the catalog resolves each domain's role.
+#[test]
+fn scalar_target_rank_matches_catalog_role() {
+    for spec in CATALOG {
+        for domain in spec.domains {
+            let name = spec.domain_name(domain);
+            if let Ok(TargetDomain::Scalar(target)) = TargetDomain::parse(&name) {
+                assert_eq!(
+                    target.rank(),
+                    Term::role_for_terms(domain.terms).rank(),
+                    "{name}: rank must be the catalog role's"
+                );
+            }
+        }
+    }
+}
@@ -10,8 +10,11 @@
 //! discriminated enum" note in the v3 module docs (cross-token payloads are
 //! byte-identical on the wire).
 
-use eql_bindings::from_v2::{from_v2, from_v2_typed, is_v3_payload, FromV2Error, TargetDomain};
-use eql_bindings::v3::{DomainPayload, DomainType};
+use eql_bindings::from_v2::{
+    from_v2, from_v2_query, from_v2_typed, is_v3_payload, FromV2Error, TargetDomain,
+};
+use eql_bindings::v3::{DomainPayload, DomainSeed, DomainType, ProjectError};
+use serde::de::DeserializeSeed;
 use serde_json::{json, Value};
 
 const CIPHERTEXT: &str = "mBbL@V^%dN?0W$;g)1-JP*cmqX%JhW0ZKZ^G?lNn$CfXJH";
@@ -227,12 +230,12 @@ fn typed_rejects_the_same_inputs_as_from_v2() {
 
 #[test]
 fn parse_constructs_every_stored_payload_domain() {
-    // Every scalar domain plus the SteVec document is parseable by name; the
-    // constructed variant reports the same domain back.
+    // Every scalar and array domain plus the SteVec document is parseable by
+    // name; the constructed variant reports the same domain back.
     for family in eql_domains::CATALOG {
         for domain in family.domains {
             let name = family.domain_name(domain);
-            let stored = domain.is_scalar() || name == "eql_v3_json_search";
+            let stored = domain.is_generated() || name == "eql_v3_json_search";
             let value = if name == "eql_v3_json_search" {
                 // A v3 document cannot come from from_v2 (the envelope wire
                 // format is unconvertible) — construct the wire shape
@@ -247,6 +250,10 @@ fn parse_constructs_every_stored_payload_domain() {
                         { "s": SELECTOR, "c": CIPHERTEXT, "a": true, "op": HEX_LONG }
                     ]
                 })
+            } else if domain.is_array() {
+                // Not a from_v2 target (no per-element terms in v2): build
+                // the wire shape directly, one `hm` per element.
+                json!({ "v": 3, "i": ident(), "c": CIPHERTEXT, "hm": [HEX, HEX] })
             } else if stored {
                 from_v2(&v2_ct_full(), target(&name)).unwrap()
             } else {
@@ -317,3 +324,253 @@ fn as_domain_type_exposes_the_inner_trait_object() {
     assert_eq!(dt.sql_domain(), "public.eql_v3_bigint_ord_ope");
     assert_eq!(dt.domain(), "eql_v3_bigint_ord_ope");
 }
+
+// ---------------------------------------------------------------------------
+// DomainSeed — parse without the intermediate Value
+// ---------------------------------------------------------------------------
+
+/// `seed` over `value`'s text, through both a borrowing (`from_str`) and an
+/// owning (`from_reader`) deserializer.
+fn stream(seed: DomainSeed<'_>, value: &Value) -> serde_json::Result<DomainPayload> {
+    let text = value.to_string();
+    let borrowed = seed.deserialize(&mut serde_json::Deserializer::from_str(&text))?;
+    let owned = seed.deserialize(&mut serde_json::Deserializer::from_reader(text.as_bytes()))?;
+    assert_eq!(borrowed, owned);
+    Ok(borrowed)
+}
+
+#[test]
+fn seed_streams_the_same_variant_parse_builds() {
+    let mut payloads: Vec<(String, Value)> = eql_domains::scalar_families()
+        .flat_map(|family| {
+            family.domains.iter().map(move |domain| {
+                let name = family.domain_name(domain);
+                let value = from_v2(&v2_ct_full(), target(&name)).unwrap();
+                (name, value)
+            })
+        })
+        .collect();
+    payloads.push((
+        "eql_v3_text_array_contains".into(),
+        json!({ "v": 3, "i": ident(), "c": CIPHERTEXT, "hm": [HEX, HEX] }),
+    ));
+    payloads.push((
+        "eql_v3_json_search".into(),
+        json!({
+            "v": 3,
+            "k": "sv",
+            "i": ident(),
+            "h": "mp_base85_key_header",
+            "sv": [{ "s": SELECTOR, "c": CIPHERTEXT, "a": true, "op": HEX_LONG }]
+        }),
+    ));
+    for (name, value) in payloads {
+        let streamed =
+            stream(DomainSeed(&name), &value).unwrap_or_else(|e| panic!("{name} must stream: {e}"));
+        assert_eq!(
+            streamed,
+            DomainPayload::parse(&name, &value).unwrap().unwrap(),
+            "{name}"
+        );
+    }
+}
+
+#[test]
+fn seed_is_strict_and_rejects_unknown_domains() {
+    let mut value = from_v2(&v2_ct_full(), target("eql_v3_integer_eq")).unwrap();
+    value["extra"] = json!(1);
+    assert!(stream(DomainSeed("eql_v3_integer_eq"), &value).is_err());
+
+    for name in [
+        "public.eql_v3_integer_eq",
+        "query_integer_eq",
+        "eql_v3_json_entry",
+    ] {
+        let err = stream(DomainSeed(name), &json!({})).unwrap_err();
+        assert!(
+            err.to_string().contains("unknown stored-payload domain"),
+            "{name}: {err}"
+        );
+    }
+}
+
+// ---------------------------------------------------------------------------
+// DomainPayload::to_query — the stored payload's query operand
+// ---------------------------------------------------------------------------
+
+#[test]
+fn to_query_hoists_the_same_operand_as_from_v2_query() {
+    // Exhaustive over the scalar targets: stripping `c` from the stored
+    // payload gives exactly what from_v2_query builds from the same v2 input,
+    // and a storage-only domain has no operand on either path.
+    for family in eql_domains::scalar_families() {
+        for domain in family.domains {
+            let name = family.domain_name(domain);
+            let stored = from_v2_typed(&v2_ct_full(), target(&name)).unwrap();
+            match (
+                stored.to_query(),
+                from_v2_query(&v2_ct_full(), target(&name)),
+            ) {
+                (Some(query), Ok(expected)) => {
+                    assert_eq!(serde_json::to_value(&query).unwrap(), expected, "{name}");
+                    assert_eq!(query.sql_domain(), format!("eql_v3.{}", query.domain()));
+                }
+                (None, Err(FromV2Error::UnsupportedQueryTarget { .. })) => {}
+                (query, expected) => {
+                    panic!("{name}: to_query {query:?} vs from_v2_query {expected:?}")
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn to_query_keeps_every_element_term_of_an_array_payload() {
+    let stored = json!({ "v": 3, "i": ident(), "c": CIPHERTEXT, "hm": [HEX, HEX_LONG] });
+    let query = DomainPayload::parse("eql_v3_text_array_contains", &stored)
+        .unwrap()
+        .unwrap()
+        .to_query()
+        .expect("a term-bearing array domain has a query twin");
+    assert_eq!(query.domain(), "query_text_array_contains");
+    assert_eq!(
+        serde_json::to_value(&query).unwrap(),
+        json!({ "v": 3, "i": ident(), "hm": [HEX, HEX_LONG] })
+    );
+}
+
+#[test]
+fn to_query_turns_a_ste_vec_document_into_its_selector_needle() {
+    let document = json!({
+        "v": 3,
+        "k": "sv",
+        "i": ident(),
+        "h": "mp_base85_key_header",
+        "sv": [
+            { "s": SELECTOR, "c": CIPHERTEXT },
+            { "s": HEX, "c": CIPHERTEXT, "a": true, "op": HEX_LONG }
+        ]
+    });
+    let query = DomainPayload::parse("eql_v3_json_search", &document)
+        .unwrap()
+        .unwrap()
+        .to_query()
+        .expect("a document has a containment needle");
+    assert_eq!(query.domain(), "query_json");
+    // Selector-only, like the `eql_v3.to_ste_vec_query` cast.
+    assert_eq!(
+        serde_json::to_value(&query).unwrap(),
+        json!({ "sv": [{ "s": SELECTOR }, { "s": HEX }] })
+    );
+}
+
+// ---------------------------------------------------------------------------
+// DomainPayload::project_to — narrowing within one family
+// ---------------------------------------------------------------------------
+
+fn text_search() -> DomainPayload {
+    from_v2_typed(&v2_ct_full(), target("eql_v3_text_search")).unwrap()
+}
+
+#[test]
+fn project_to_drops_the_terms_the_target_does_not_require() {
+    let eq = text_search().project_to("eql_v3_text_eq").unwrap();
+    assert_eq!(eq.domain(), "eql_v3_text_eq");
+    assert_eq!(
+        serde_json::to_value(&eq).unwrap(),
+        json!({ "v": 3, "i": ident(), "c": CIPHERTEXT, "hm": HEX })
+    );
+    // Down to the bare ciphertext, and onto itself.
+    assert_eq!(
+        serde_json::to_value(text_search().project_to("eql_v3_text").unwrap()).unwrap(),
+        json!({ "v": 3, "i": ident(), "c": CIPHERTEXT })
+    );
+    assert_eq!(
+        text_search().project_to("eql_v3_text_search").unwrap(),
+        text_search()
+    );
+}
+
+#[test]
+fn project_to_agrees_with_converting_straight_to_the_target() {
+    // Exhaustive over every same-family scalar pair: a projection succeeds
+    // exactly when the target's term keys are a subset of the source's, and
+    // then equals what from_v2_typed builds for the target directly.
+    for family in eql_domains::scalar_families() {
+        for source in family.domains {
+            let source = family.domain_name(source);
+            let stored = from_v2_typed(&v2_ct_full(), target(&source)).unwrap();
+            let source_keys = stored.as_domain_type().term_json_keys().unwrap();
+            for domain in family.domains {
+                let name = family.domain_name(domain);
+                let expected = from_v2_typed(&v2_ct_full(), target(&name)).unwrap();
+                let target_keys = expected.as_domain_type().term_json_keys().unwrap();
+                match stored.project_to(&name) {
+                    Ok(projected) => assert_eq!(projected, expected, "{source} -> {name}"),
+                    Err(ProjectError::MissingTerm { key, .. }) => {
+                        assert!(target_keys.contains(&key), "{source} -> {name}");
+                        assert!(!source_keys.contains(&key), "{source} -> {name}");
+                    }
+                    Err(e) => panic!("{source} -> {name}: {e}"),
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn project_to_fails_closed_on_a_missing_term() {
+    let err = text_search().project_to("eql_v3_text_ord_ore").unwrap_err();
+    assert!(
+        matches!(
+            err,
+            ProjectError::MissingTerm { source: "eql_v3_text_search", ref target, key: "ob" }
+                if target == "eql_v3_text_ord_ore"
+        ),
+        "{err:?}"
+    );
+}
+
+#[test]
+fn project_to_refuses_other_families_and_unknown_targets() {
+    assert!(matches!(
+        text_search().project_to("eql_v3_integer_eq"),
+        Err(ProjectError::CrossFamily {
+            source: "eql_v3_text_search",
+            ..
+        })
+    ));
+    for unknown in ["eql_v3_nope", "query_text_eq", "public.eql_v3_text_eq"] {
+        assert!(
+            matches!(
+                text_search().project_to(unknown),
+                Err(ProjectError::UnknownDomain { ref name }) if name == unknown
+            ),
+            "{unknown}"
+        );
+    }
+}
+
+#[test]
+fn project_to_refuses_array_and_ste_vec_domains() {
+    let array = DomainPayload::parse(
+        "eql_v3_text_array_contains",
+        &json!({ "v": 3, "i": ident(), "c": CIPHERTEXT, "hm": [HEX] }),
+    )
+    .unwrap()
+    .unwrap();
+    assert!(matches!(
+        array.project_to("eql_v3_text_array"),
+        Err(ProjectError::Unprojectable { .. })
+    ));
+    let document = DomainPayload::parse(
+        "eql_v3_json_search",
+        &json!({ "v": 3, "k": "sv", "i": ident(), "h": "mp_base85_key_header", "sv": [] }),
+    )
+    .unwrap()
+    .unwrap();
+    assert!(matches!(
+        document.project_to("eql_v3_json"),
+        Err(ProjectError::Unprojectable { .. })
+    ));
+}
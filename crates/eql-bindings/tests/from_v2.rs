@@ -5,12 +5,17 @@
 //! modeled on the payload-schema test constants, and every converted output
 //! is checked against the exact expected v3 JSON (the binding structs are the
 //! contract; schema-file validation lives in
-//! `tests/sqlx/tests/payload_schema_tests.rs`).
+//! `tests/sqlx/tests/payload_schema_tests.rs`). The `to_v2` round trips are
+//! proptest properties over arbitrary terms and identifiers.
 
 use eql_bindings::from_v2::{
-    from_v2, from_v2_query, from_v2_query_typed, from_v2_typed, is_v3_payload, FromV2Error,
-    TargetDomain,
+    candidate_targets, from_v2, from_v2_many, from_v2_query, from_v2_query_typed, from_v2_typed,
+    is_v3_payload, FromV2Error, TargetDomain,
 };
+use eql_bindings::to_v2::{to_v2, to_v2_query, ToV2Error};
+use eql_bindings::v3::{DomainPayload, QueryPayload};
+use proptest::collection::vec;
+use proptest::prelude::*;
 use serde_json::{json, Value};
 
 const CIPHERTEXT: &str = "mBbL@V^%dN?0W$;g)1-JP*cmqX%JhW0ZKZ^G?lNn$CfXJH";
@@ -492,3 +497,368 @@ fn errors_display_and_implement_std_error() {
         "Invalid must expose its serde_json source"
     );
 }
+
+// ---------------------------------------------------------------------------
+// candidate_targets: advisory target suggestions
+// ---------------------------------------------------------------------------
+
+fn candidate_names(v2: &Value, family: &str) -> Vec<&'static str> {
+    candidate_targets(v2, family)
+        .iter()
+        .map(|t| t.domain())
+        .collect()
+}
+
+#[test]
+fn candidate_targets_ranks_every_satisfiable_domain_of_the_family() {
+    // hm + ob + bf: the ore-ordered search domain first, storage last, and
+    // nothing needing the absent `op`.
+    let mut v2 = v2_ct_full();
+    v2.as_object_mut().unwrap().remove("op");
+    assert_eq!(
+        candidate_names(&v2, "text"),
+        [
+            "eql_v3_text_search_ore",
+            "eql_v3_text_ord_ore",
+            "eql_v3_text_eq",
+            "eql_v3_text_match",
+            "eql_v3_text",
+        ]
+    );
+    // Ranked by the catalog role first, then term count.
+    let ranks: Vec<(u8, usize)> = candidate_targets(&v2, "text")
+        .iter()
+        .map(|t| (t.rank(), t.term_json_keys().len()))
+        .collect();
+    assert!(ranks.windows(2).all(|w| w[0] >= w[1]), "{ranks:?}");
+
+    // Every candidate really converts; the family boundary holds.
+    for t in candidate_targets(&v2, "text") {
+        from_v2(&v2, TargetDomain::Scalar(t)).unwrap();
+    }
+    assert!(!candidate_names(&v2, "timestamp")
+        .iter()
+        .any(|d| d.starts_with("eql_v3_timestamp_naive")));
+}
+
+#[test]
+fn candidate_targets_is_empty_or_storage_only_when_nothing_else_fits() {
+    assert_eq!(
+        candidate_names(&v2_ct_minimal(), "integer"),
+        ["eql_v3_integer"]
+    );
+    assert!(candidate_names(&v2_ct_full(), "no_such_family").is_empty());
+    assert!(candidate_names(&json!("not an object"), "text").is_empty());
+}
+
+// ---------------------------------------------------------------------------
+// from_v2_many: one v2 payload, several v3 columns
+// ---------------------------------------------------------------------------
+
+#[test]
+fn from_v2_many_splits_one_payload_per_target() {
+    // The over-provisioned `hm`+`ob`+`bf` column split into eq, match and
+    // ord columns; each output matches the single-target conversion.
+    let mut v2 = v2_ct_full();
+    v2["bf"] = json!([12, 40000]);
+    let targets = [
+        target("eql_v3_text_eq"),
+        target("eql_v3_text_match"),
+        target("eql_v3_text_ord_ore"),
+    ];
+    let out: Vec<_> = from_v2_many(&v2, &targets)
+        .unwrap()
+        .into_iter()
+        .map(Result::unwrap)
+        .collect();
+    assert_eq!(out.len(), 3);
+    for (payload, t) in out.iter().zip(targets) {
+        assert_eq!(*payload, from_v2_typed(&v2, t).unwrap());
+    }
+    assert_eq!(
+        serde_json::to_value(&out[1]).unwrap(),
+        json!({ "v": 3, "i": ident(), "c": CIPHERTEXT, "bf": [12, -25536] })
+    );
+    assert!(from_v2_many(&v2, &[]).unwrap().is_empty());
+}
+
+#[test]
+fn from_v2_many_reports_failed_targets_without_aborting_the_rest() {
+    let v2 = json!({ "v": 2, "k": "ct", "c": CIPHERTEXT, "i": ident(), "hm": HEX, "bf": [1] });
+    let targets = [
+        target("eql_v3_text_eq"),
+        target("eql_v3_text_ord_ore"),
+        target("eql_v3_text_match"),
+        TargetDomain::Json,
+    ];
+    let out = from_v2_many(&v2, &targets).unwrap();
+    assert_eq!(out.len(), 4);
+    assert_eq!(out[0].as_ref().unwrap().domain(), "eql_v3_text_eq");
+    assert!(matches!(
+        &out[1],
+        Err(FromV2Error::MissingTerm { key, .. }) if key == "ob"
+    ));
+    assert_eq!(out[2].as_ref().unwrap().domain(), "eql_v3_text_match");
+    assert!(matches!(&out[3], Err(FromV2Error::KindMismatch { .. })));
+}
+
+#[test]
+fn a_missing_term_is_reported_before_a_bad_bf() {
+    // `bf` is always last in wire order, so a target missing any other term
+    // reports that, not the `bf` it would have reinterpreted after it —
+    // single target or many.
+    let v2 = json!({ "v": 2, "k": "ct", "c": CIPHERTEXT, "i": ident(), "bf": [70000] });
+    let search = target("eql_v3_text_search");
+    assert!(matches!(
+        from_v2(&v2, search).unwrap_err(),
+        FromV2Error::MissingTerm { key, .. } if key == "hm"
+    ));
+    let out = from_v2_many(&v2, &[search]).unwrap();
+    assert!(matches!(
+        &out[..],
+        [Err(FromV2Error::MissingTerm { key, .. })] if key == "hm"
+    ));
+    assert!(matches!(
+        from_v2(&v2, target("eql_v3_text_match")).unwrap_err(),
+        FromV2Error::BloomOutOfRange {
+            index: 0,
+            value: 70000
+        }
+    ));
+}
+
+#[test]
+fn from_v2_many_fails_whole_on_a_faulty_payload() {
+    let targets = [target("eql_v3_text_eq"), target("eql_v3_text_match")];
+    let mut v3 = v2_ct_full();
+    v3["v"] = json!(3);
+    assert!(matches!(
+        from_v2_many(&v3, &targets).unwrap_err(),
+        FromV2Error::UnsupportedVersion { found: Some(3) }
+    ));
+    assert!(matches!(
+        from_v2_many(&v2_sv(), &targets).unwrap_err(),
+        FromV2Error::UnconvertibleSteVecDocument
+    ));
+    // An out-of-range `bf` fails only when some target needs it.
+    let mut v2 = v2_ct_full();
+    v2["bf"] = json!([70000]);
+    assert!(matches!(
+        from_v2_many(&v2, &targets).unwrap_err(),
+        FromV2Error::BloomOutOfRange {
+            index: 0,
+            value: 70000
+        }
+    ));
+    assert_eq!(
+        from_v2_many(&v2, &targets[..1]).unwrap().len(),
+        1,
+        "text_eq alone never reads bf"
+    );
+}
+
+// ---------------------------------------------------------------------------
+// to_v2 / to_v2_query: the downgrade round trip
+// ---------------------------------------------------------------------------
+
+/// Every catalog scalar domain, as a conversion target.
+fn scalar_targets() -> Vec<TargetDomain> {
+    eql_domains::CATALOG
+        .iter()
+        .flat_map(|family| {
+            family
+                .domains
+                .iter()
+                .filter(|domain| domain.is_scalar())
+                .map(|domain| target(&family.domain_name(domain)))
+        })
+        .collect()
+}
+
+/// An arbitrary v2.3 `k: "ct"` payload carrying every term: hex `hm`/`op`
+/// and `ob` blocks of any length, `bf` over the whole unsigned `u16` range
+/// v2 emits, and any table/column identifier.
+fn arb_v2_ct() -> impl Strategy<Value = Value> {
+    let hex = || "([0-9a-f]{2}){1,48}";
+    (
+        (any::<String>(), any::<String>()),
+        hex(),
+        vec(hex(), 1..4),
+        hex(),
+        vec(any::<u16>(), 0..64),
+    )
+        .prop_map(|((table, column), hm, ob, op, bf)| {
+            json!({
+                "v": 2,
+                "k": "ct",
+                "c": CIPHERTEXT,
+                "i": { "t": table, "c": column },
+                "hm": hm,
+                "bf": bf,
+                "ob": ob,
+                "op": op
+            })
+        })
+}
+
+proptest! {
+    #[test]
+    fn to_v2_round_trips_every_scalar_domain(v2 in arb_v2_ct()) {
+        // from_v2(to_v2(x)) == x for every scalar domain, and the downgrade
+        // carries exactly the target's terms as the v2 payload had them.
+        for t in scalar_targets() {
+            let TargetDomain::Scalar(scalar) = t else {
+                unreachable!()
+            };
+            let x = from_v2_typed(&v2, t).unwrap();
+            let down = to_v2(&x).unwrap();
+            prop_assert_eq!(&down["v"], &json!(2), "{:?}", t);
+            prop_assert_eq!(&down["k"], &json!("ct"), "{:?}", t);
+            prop_assert_eq!(&down["i"], &v2["i"], "{:?}", t);
+            for &key in scalar.term_json_keys() {
+                prop_assert_eq!(&down[key], &v2[key], "{:?} `{}`", t, key);
+            }
+            prop_assert_eq!(
+                from_v2(&down, t).unwrap(),
+                serde_json::to_value(&x).unwrap(),
+                "{:?}",
+                t
+            );
+            prop_assert_eq!(from_v2_typed(&down, t).unwrap(), x, "{:?}", t);
+        }
+    }
+
+    #[test]
+    fn to_v2_query_round_trips_every_term_bearing_scalar_domain(v2 in arb_v2_ct()) {
+        let mut query = v2;
+        query.as_object_mut().unwrap().remove("c");
+        for t in scalar_targets() {
+            let TargetDomain::Scalar(scalar) = t else {
+                unreachable!()
+            };
+            if scalar.term_json_keys().is_empty() {
+                continue;
+            }
+            let x = from_v2_query_typed(&query, t).unwrap();
+            let down = to_v2_query(&x).unwrap();
+            prop_assert!(down.get("c").is_none(), "{:?}", t);
+            for &key in scalar.term_json_keys() {
+                prop_assert_eq!(&down[key], &query[key], "{:?} `{}`", t, key);
+            }
+            prop_assert_eq!(
+                from_v2_query(&down, t).unwrap(),
+                serde_json::to_value(&x).unwrap(),
+                "{:?}",
+                t
+            );
+        }
+    }
+}
+
+#[test]
+fn to_v2_restores_unsigned_bloom_positions_exhaustively() {
+    let signed: Vec<i64> = (i64::from(i16::MIN)..=i64::from(i16::MAX)).collect();
+    let x = DomainPayload::parse(
+        "eql_v3_text_match",
+        &json!({ "v": 3, "i": ident(), "c": CIPHERTEXT, "bf": signed }),
+    )
+    .unwrap()
+    .unwrap();
+    let down = to_v2(&x).unwrap();
+    for (s, u) in signed.iter().zip(down["bf"].as_array().unwrap()) {
+        let u = u.as_i64().unwrap();
+        assert!((0..=i64::from(u16::MAX)).contains(&u));
+        assert_eq!(u as u16, *s as u16, "bit pattern must be preserved for {s}");
+    }
+    assert_eq!(
+        from_v2(&down, target("eql_v3_text_match")).unwrap(),
+        serde_json::to_value(&x).unwrap()
+    );
+}
+
+#[test]
+fn storage_arrays_are_targets_and_contains_arrays_are_not() {
+    // The storage-only array domains need no terms, so a v2 payload converts
+    // (and downgrades) as for any storage-only scalar; the `_contains`
+    // domains need per-element `hm`, which no v2 payload carries.
+    for family in ["integer_array", "text_array"] {
+        let storage = format!("eql_v3_{family}");
+        let t = target(&storage);
+        assert!(
+            matches!(t, TargetDomain::Scalar(s) if s.term_json_keys().is_empty()),
+            "{t:?}"
+        );
+        let x = from_v2_typed(&v2_ct_full(), t).unwrap();
+        assert_eq!(
+            serde_json::to_value(&x).unwrap(),
+            json!({ "v": 3, "i": ident(), "c": CIPHERTEXT })
+        );
+        assert_eq!(from_v2_typed(&to_v2(&x).unwrap(), t).unwrap(), x);
+        assert_eq!(candidate_names(&v2_ct_full(), family), [storage.as_str()]);
+
+        let contains = format!("eql_v3_{family}_contains");
+        assert!(
+            matches!(
+                TargetDomain::parse(&contains),
+                Err(FromV2Error::UnknownDomain { .. })
+            ),
+            "{contains}"
+        );
+    }
+}
+
+#[test]
+fn to_v2_refuses_payloads_with_no_v2_form() {
+    let document = DomainPayload::parse(
+        "eql_v3_json_search",
+        &json!({
+            "v": 3, "k": "sv", "i": ident(), "h": "mp_base85_key_header",
+            "sv": [{ "s": SELECTOR, "c": CIPHERTEXT }]
+        }),
+    )
+    .unwrap()
+    .unwrap();
+    assert!(matches!(
+        to_v2(&document).unwrap_err(),
+        ToV2Error::UnconvertibleSteVecDocument
+    ));
+
+    let needle = QueryPayload::parse("query_json", &json!({ "sv": [{ "s": SELECTOR }] }))
+        .unwrap()
+        .unwrap();
+    assert!(matches!(
+        to_v2_query(&needle).unwrap_err(),
+        ToV2Error::UnconvertibleSteVecQuery
+    ));
+
+    let contains = DomainPayload::parse(
+        "eql_v3_text_array_contains",
+        &json!({ "v": 3, "i": ident(), "c": CIPHERTEXT, "hm": [HEX] }),
+    )
+    .unwrap()
+    .unwrap();
+    let err = to_v2(&contains).unwrap_err();
+    assert!(matches!(
+        err,
+        ToV2Error::NoV2Form {
+            domain: "eql_v3_text_array_contains"
+        }
+    ));
+    assert_eq!(
+        err.to_string(),
+        "domain `eql_v3_text_array_contains` has no v2 payload form"
+    );
+
+    let contains_query = QueryPayload::parse(
+        "query_text_array_contains",
+        &json!({ "v": 3, "i": ident(), "hm": [HEX] }),
+    )
+    .unwrap()
+    .unwrap();
+    assert!(matches!(
+        to_v2_query(&contains_query).unwrap_err(),
+        ToV2Error::NoV2Form {
+            domain: "query_text_array_contains"
+        }
+    ));
+}
@@ -0,0 +1,109 @@
+//! The `postgres-types` feature: `ToSql` binds a payload only to its own SQL
+//! domain, `FromSql` also reads the `jsonb` a domain column reports, and both
+//! speak the `jsonb` binary format through the strict serde parse.
+//!
+//! Run with `cargo test -p eql-bindings --features postgres-types`.
+
+#![cfg(feature = "postgres-types")]
+
+use bytes::BytesMut;
+use eql_bindings::v3::integer::{IntegerEq, IntegerEqQuery};
+use eql_bindings::v3::json::SteVecQuery;
+use postgres_types::{FromSql, Kind, ToSql, Type};
+use serde_json::json;
+
+/// A `jsonb`-based domain type as tokio-postgres describes it.
+fn domain(schema: &str, name: &str) -> Type {
+    Type::new(
+        name.to_owned(),
+        0,
+        Kind::Domain(Type::JSONB),
+        schema.to_owned(),
+    )
+}
+
+fn integer_eq() -> IntegerEq {
+    serde_json::from_value(json!({
+        "v": 3,
+        "i": { "t": "users", "c": "age" },
+        "c": "mp_base85_ciphertext",
+        "hm": "deadbeef"
+    }))
+    .unwrap()
+}
+
+#[test]
+fn to_sql_accepts_only_the_exact_domain() {
+    assert!(<IntegerEq as ToSql>::accepts(&domain(
+        "public",
+        "eql_v3_integer_eq"
+    )));
+    for other in [
+        domain("public", "eql_v3_integer_ord"),
+        domain("public", "eql_v3_bigint_eq"),
+        domain("eql_v3", "eql_v3_integer_eq"),
+        Type::JSONB,
+    ] {
+        assert!(!<IntegerEq as ToSql>::accepts(&other), "{other:?}");
+    }
+    assert!(<IntegerEqQuery as ToSql>::accepts(&domain(
+        "eql_v3",
+        "query_integer_eq"
+    )));
+    assert!(<SteVecQuery as ToSql>::accepts(&domain(
+        "eql_v3",
+        "query_json"
+    )));
+}
+
+#[test]
+fn binding_to_another_domain_fails_on_the_client() {
+    let mut buf = BytesMut::new();
+    let Err(err) = integer_eq().to_sql_checked(&domain("public", "eql_v3_integer_ord"), &mut buf)
+    else {
+        panic!("IntegerEq bound to public.eql_v3_integer_ord");
+    };
+    assert!(err.to_string().contains("IntegerEq"), "{err}");
+    assert!(buf.is_empty());
+}
+
+#[test]
+fn from_sql_also_accepts_the_base_type() {
+    assert!(<IntegerEq as FromSql>::accepts(&domain(
+        "public",
+        "eql_v3_integer_eq"
+    )));
+    assert!(<IntegerEq as FromSql>::accepts(&Type::JSONB));
+    assert!(<IntegerEq as FromSql>::accepts(&Type::JSON));
+    assert!(!<IntegerEq as FromSql>::accepts(&domain(
+        "public",
+        "eql_v3_integer_ord"
+    )));
+}
+
+#[test]
+fn round_trips_the_jsonb_binary_format() {
+    let ty = domain("public", "eql_v3_integer_eq");
+    let mut buf = BytesMut::new();
+    assert!(integer_eq().to_sql_checked(&ty, &mut buf).is_ok());
+    assert_eq!(buf[0], 1, "jsonb version byte");
+
+    assert_eq!(IntegerEq::from_sql(&ty, &buf).unwrap(), integer_eq());
+    assert_eq!(
+        IntegerEq::from_sql(&Type::JSONB, &buf).unwrap(),
+        integer_eq()
+    );
+    assert_eq!(
+        IntegerEq::from_sql(&Type::JSON, &buf[1..]).unwrap(),
+        integer_eq()
+    );
+}
+
+#[test]
+fn from_sql_is_strict() {
+    let mut wire = serde_json::to_value(integer_eq()).unwrap();
+    wire["ob"] = json!(["00"]);
+    let mut raw = vec![1];
+    raw.extend(serde_json::to_vec(&wire).unwrap());
+    assert!(IntegerEq::from_sql(&Type::JSONB, &raw).is_err());
+}
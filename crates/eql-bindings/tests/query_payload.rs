@@ -11,7 +11,8 @@
 //! `query_<name>` operand).
 
 use eql_bindings::from_v2::{from_v2_query, from_v2_query_typed, FromV2Error, TargetDomain};
-use eql_bindings::v3::QueryPayload;
+use eql_bindings::v3::{QueryPayload, QuerySeed};
+use serde::de::DeserializeSeed;
 use serde_json::{json, Value};
 
 const CIPHERTEXT: &str = "mBbL@V^%dN?0W$;g)1-JP*cmqX%JhW0ZKZ^G?lNn$CfXJH";
@@ -153,6 +154,49 @@ fn parse_is_strict_exactly_like_the_binding_struct() {
     );
 }
 
+#[test]
+fn seed_streams_the_same_variant_parse_builds() {
+    let mut operands = vec![(
+        "query_json".to_string(),
+        json!({ "sv": [ { "s": SELECTOR }, { "s": SELECTOR, "op": HEX_LONG } ] }),
+    )];
+    for family in eql_domains::scalar_families() {
+        for domain in family.domains.iter().filter(|d| !d.terms.is_empty()) {
+            let keys = eql_domains::Term::term_json_keys(domain.terms);
+            let operand =
+                from_v2_query(&v2_scalar_query(&keys), target(&family.domain_name(domain)))
+                    .unwrap();
+            operands.push((domain.query_name(family.name), operand));
+        }
+    }
+    for (name, operand) in operands {
+        let text = operand.to_string();
+        let streamed = QuerySeed(&name)
+            .deserialize(&mut serde_json::Deserializer::from_reader(text.as_bytes()))
+            .unwrap_or_else(|e| panic!("{name} must stream: {e}"));
+        assert_eq!(
+            streamed,
+            QueryPayload::parse(&name, &operand).unwrap().unwrap(),
+            "{name}"
+        );
+    }
+}
+
+#[test]
+fn seed_is_strict_and_rejects_non_query_domains() {
+    let stray = r#"{ "sv": [], "extra": 1 }"#;
+    assert!(QuerySeed("query_json")
+        .deserialize(&mut serde_json::Deserializer::from_str(stray))
+        .is_err());
+    let err = QuerySeed("eql_v3_integer_eq")
+        .deserialize(&mut serde_json::Deserializer::from_str("{}"))
+        .unwrap_err();
+    assert!(
+        err.to_string().contains("unknown query-operand domain"),
+        "{err}"
+    );
+}
+
 #[test]
 fn parse_returns_none_for_non_query_domains() {
     // Stored-payload domains (DomainPayload territory), the entry shape, and
@@ -0,0 +1,74 @@
+//! The `sqlx` feature: a payload binds as its own SQL domain, decoding also
+//! accepts the `jsonb` / `json` a domain column reports, and values are
+//! written in the `jsonb` binary format. Decoding needs a `PgValueRef`, which
+//! only a live connection produces, so the strict decode and the round trip
+//! through real domain parameters are in `tests/sqlx/tests/v3_sqlx_codec_tests.rs`.
+//!
+//! Run with `cargo test -p eql-bindings --features sqlx`.
+
+#![cfg(feature = "sqlx")]
+
+use eql_bindings::v3::integer::{IntegerEq, IntegerEqQuery, IntegerOrd};
+use eql_bindings::v3::text::TextEq;
+use serde_json::{json, Value};
+use sqlx::postgres::{PgArgumentBuffer, PgTypeInfo};
+use sqlx::types::JsonValue;
+use sqlx::{Encode, Postgres, Type, TypeInfo};
+
+fn integer_eq() -> IntegerEq {
+    serde_json::from_value(json!({
+        "v": 3,
+        "i": { "t": "users", "c": "age" },
+        "c": "mp_base85_ciphertext",
+        "hm": "deadbeef"
+    }))
+    .unwrap()
+}
+
+fn jsonb() -> PgTypeInfo {
+    <JsonValue as Type<Postgres>>::type_info()
+}
+
+#[test]
+fn type_info_names_the_schema_qualified_domain() {
+    assert_eq!(
+        <IntegerEq as Type<Postgres>>::type_info().name(),
+        "public.eql_v3_integer_eq"
+    );
+    assert_eq!(
+        <IntegerEqQuery as Type<Postgres>>::type_info().name(),
+        "eql_v3.query_integer_eq"
+    );
+}
+
+#[test]
+fn compatible_takes_the_own_domain_and_the_json_base_types() {
+    assert!(<IntegerEq as Type<Postgres>>::compatible(
+        &<IntegerEq as Type<Postgres>>::type_info()
+    ));
+    assert!(<IntegerEq as Type<Postgres>>::compatible(&jsonb()));
+    assert!(<IntegerEq as Type<Postgres>>::compatible(
+        &PgTypeInfo::with_name("json")
+    ));
+    for other in [
+        <IntegerOrd as Type<Postgres>>::type_info(),
+        <TextEq as Type<Postgres>>::type_info(),
+        <IntegerEqQuery as Type<Postgres>>::type_info(),
+        PgTypeInfo::with_name("text"),
+    ] {
+        assert!(
+            !<IntegerEq as Type<Postgres>>::compatible(&other),
+            "{}",
+            other.name()
+        );
+    }
+}
+
+#[test]
+fn encodes_the_jsonb_binary_format() {
+    let mut buf = PgArgumentBuffer::default();
+    assert!(!integer_eq().encode_by_ref(&mut buf).unwrap().is_null());
+    assert_eq!(buf[0], 1, "jsonb version byte");
+    let wire: Value = serde_json::from_slice(&buf[1..]).unwrap();
+    assert_eq!(wire, serde_json::to_value(integer_eq()).unwrap());
+}
@@ -0,0 +1,109 @@
+//! The [`SteVecDocument`] entry accessors against the SQL they mirror:
+//! `->` / `eql_v3.jsonb_path_query_first` ([`SteVecDocument::entry`],
+//! [`SteVecDocument::extract_entry`]), `eql_v3.jsonb_path_query`
+//! ([`SteVecDocument::entries_matching`]), the `a` flag
+//! `eql_v3.jsonb_array_elements` honours ([`SteVecDocument::array_entries`])
+//! and `eql_v3.to_ste_vec_query` ([`SteVecDocument::to_query`]). The parity
+//! tests over real fixture documents are in
+//! `tests/sqlx/tests/v3_jsonb_bindings_tests.rs`.
+
+use eql_bindings::v3::json::{SteVecDocument, SteVecEntry};
+use eql_bindings::v3::terms::{KeyHeader, Selector};
+use serde_json::{json, Value};
+
+const ROOT: &str = "bca213de9ccce676fa849ff9c4807963";
+const HELLO: &str = "a7cea93975ed8c01f861ccb6bd082784";
+const ITEMS: &str = "f510853730e1c3dbd31b86963f029dd5";
+const MISSING: &str = "00000000000000000000000000000000";
+
+fn document() -> SteVecDocument {
+    serde_json::from_value(json!({
+        "v": 3, "k": "sv", "i": { "t": "users", "c": "profile" }, "h": "kh",
+        "sv": [
+            { "s": ROOT, "c": "root" },
+            { "s": HELLO, "c": "hello", "op": "0298a35aba5ea0bd", "a": false },
+            { "s": ITEMS, "c": "first", "a": true },
+            { "s": ITEMS, "c": "second", "a": true }
+        ]
+    }))
+    .unwrap()
+}
+
+fn sel(s: &str) -> Selector {
+    Selector(s.into())
+}
+
+fn ciphertexts<'a>(entries: impl Iterator<Item = &'a SteVecEntry>) -> Vec<&'a str> {
+    entries.map(|entry| entry.c.0.as_str()).collect()
+}
+
+#[test]
+fn entry_is_the_first_match_and_none_on_a_miss() {
+    let doc = document();
+    assert_eq!(doc.entry(&sel(HELLO)).unwrap().c.0, "hello");
+    assert_eq!(doc.entry(&sel(ITEMS)).unwrap().c.0, "first");
+    assert!(doc.entry(&sel(MISSING)).is_none());
+    // Selectors match byte for byte, as `elem ->> 's' = selector` does.
+    assert!(doc.entry(&sel(&HELLO.to_uppercase())).is_none());
+}
+
+#[test]
+fn entries_matching_yields_every_match_in_sv_order() {
+    let doc = document();
+    assert_eq!(
+        ciphertexts(doc.entries_matching(&sel(ITEMS))),
+        ["first", "second"]
+    );
+    assert_eq!(doc.entries_matching(&sel(MISSING)).count(), 0);
+}
+
+#[test]
+fn array_entries_honour_only_a_true() {
+    let doc = document();
+    // `a: false` (HELLO) and an absent `a` (ROOT) are not array elements.
+    assert_eq!(ciphertexts(doc.array_entries()), ["first", "second"]);
+}
+
+#[test]
+fn extract_entry_grafts_the_document_metadata() {
+    let doc = document();
+    let entry = doc.extract_entry(&sel(HELLO)).unwrap();
+    assert_eq!(
+        serde_json::to_value(&entry).unwrap(),
+        json!({
+            "s": HELLO, "c": "hello", "op": "0298a35aba5ea0bd", "a": false,
+            "i": { "t": "users", "c": "profile" }, "v": 3, "h": "kh"
+        })
+    );
+    // The grafted entry is a valid `public.eql_v3_json_entry`.
+    let _: SteVecEntry = serde_json::from_value(serde_json::to_value(&entry).unwrap()).unwrap();
+    assert!(doc.extract_entry(&sel(MISSING)).is_none());
+}
+
+#[test]
+fn grafting_keeps_metadata_the_entry_already_carries() {
+    // `meta_data(doc) || entry`: the right-hand entry wins on a shared key.
+    let doc = document();
+    let mut entry = doc.sv[0].clone();
+    entry.h = Some(KeyHeader("entry-kh".into()));
+    let grafted = doc.graft_metadata(&entry);
+    assert_eq!(grafted.h.unwrap().0, "entry-kh");
+    assert_eq!(grafted.i, Some(doc.i.clone()));
+}
+
+#[test]
+fn to_query_is_selector_only_in_sv_order() {
+    let doc = document();
+    let needle: Value = serde_json::to_value(doc.to_query()).unwrap();
+    assert_eq!(
+        needle,
+        json!({ "sv": [{ "s": ROOT }, { "s": HELLO }, { "s": ITEMS }, { "s": ITEMS }] })
+    );
+
+    let mut empty = doc;
+    empty.sv.clear();
+    assert_eq!(
+        serde_json::to_value(empty.to_query()).unwrap(),
+        json!({ "sv": [] })
+    );
+}
@@ -0,0 +1,232 @@
+//! [`DomainType::parse_strict_encoding`]: payloads that pass the strict
+//! parse but carry a ciphertext or term the database (or decryption) would
+//! reject are refused, with the offending field named.
+//!
+//! The fixtures are well-formed on the wire: `c`/`h` are RFC 1924 base85 of
+//! a MessagePack value, `hm` is 32 bytes, `op` leads with its CLLW domain
+//! tag, and both `ob` block terms are one block wide.
+
+use eql_bindings::v3::{all, all_query, DomainType, EncodingError};
+use serde_json::{json, Value};
+
+/// base85 of a MessagePack `bin 8` of 32 bytes.
+const CIPHERTEXT: &str = "#2|S>z49Rj@Pv0_@bk+}c1_0zAW;>X4~g=|+REcZyD<";
+/// base85 of a MessagePack three-element array.
+const KEY_HEADER: &str = "lf(=XMg4Dt-<sFOv(R>^Yh`(JWpphu%>e)";
+/// base85 of 30 raw AEAD bytes.
+const ENTRY_CIPHERTEXT: &str = "tPYP;r!{Pdkv|~z_bsp@(oG7M+SEK27s08HcoF";
+const HMAC: &str = "9e0327371065d095864f15ada0b846c1c0ebc5348adc799adf849bad05d4a10a";
+const OPE_SCALAR: &str = "00c0441eaaeeb4b48efa0b1f0abd80e9";
+const OPE_STRING: &str = "0298a35aba5ea0bd8799c1350d439e71";
+const ORE_A: &str = "897aa75fde3134a4aa72e05628ac6fe68a733d1161a15d8eae2bb042d7958aedb1d594d6d112d34f6602f4de7110e993ae7422923d7d171165dc1906f63d57997a";
+const ORE_B: &str = "0ad31b3aae4081f41fb471653e3d577a8c4103f9cc198a7f89d81af2a5001c40173f1923f7102cfaa150a124b3c5c79bb88761a8db3f4101c2285b15bfebc216dc";
+const SELECTOR: &str = "9493d6010fe7845d52149b697729c745";
+
+fn domain(name: &str) -> Box<dyn DomainType> {
+    all()
+        .into_iter()
+        .chain(all_query())
+        .find(|d| d.domain() == name)
+        .unwrap_or_else(|| panic!("no domain {name}"))
+}
+
+fn ident() -> Value {
+    json!({ "t": "users", "c": "email" })
+}
+
+fn text_search() -> Value {
+    json!({
+        "v": 3, "i": ident(), "c": CIPHERTEXT,
+        "hm": HMAC, "op": OPE_SCALAR, "bf": [12, 47]
+    })
+}
+
+fn document() -> Value {
+    json!({
+        "v": 3, "k": "sv", "i": ident(), "h": KEY_HEADER,
+        "sv": [
+            { "s": SELECTOR, "c": ENTRY_CIPHERTEXT },
+            { "s": SELECTOR, "c": ENTRY_CIPHERTEXT, "op": OPE_STRING }
+        ]
+    })
+}
+
+#[track_caller]
+fn reject(domain_name: &str, payload: &Value) -> EncodingError {
+    let d = domain(domain_name);
+    d.parse_value(payload)
+        .unwrap_or_else(|e| panic!("{domain_name} must still parse: {e}"));
+    d.parse_strict_encoding(payload).unwrap_err()
+}
+
+#[test]
+fn well_formed_payloads_pass() {
+    domain("eql_v3_text_search")
+        .parse_strict_encoding(&text_search())
+        .unwrap();
+    domain("eql_v3_json_search")
+        .parse_strict_encoding(&document())
+        .unwrap();
+    domain("eql_v3_integer_ord_ore")
+        .parse_strict_encoding(&json!({
+            "v": 3, "i": ident(), "c": CIPHERTEXT, "ob": [ORE_A, ORE_B]
+        }))
+        .unwrap();
+    domain("query_text_eq")
+        .parse_strict_encoding(&json!({ "v": 3, "i": ident(), "hm": HMAC }))
+        .unwrap();
+}
+
+#[test]
+fn a_parse_failure_is_reported_as_such() {
+    let err = domain("eql_v3_text_eq")
+        .parse_strict_encoding(&text_search())
+        .unwrap_err();
+    assert!(matches!(err, EncodingError::Parse(_)), "{err:?}");
+}
+
+#[test]
+fn truncated_or_re_encoded_ciphertexts_are_rejected() {
+    // One character short: a trailing group of one character carries no byte.
+    let mut payload = text_search();
+    payload["c"] = json!(&CIPHERTEXT[..41]);
+    let err = reject("eql_v3_text_search", &payload);
+    assert!(
+        matches!(&err, EncodingError::Base85 { path } if path == "c"),
+        "{err:?}"
+    );
+
+    // Two short: still base85, but the MessagePack bin is cut off.
+    payload["c"] = json!(&CIPHERTEXT[..40]);
+    let err = reject("eql_v3_text_search", &payload);
+    assert!(
+        matches!(&err, EncodingError::MessagePack { path } if path == "c"),
+        "{err:?}"
+    );
+
+    // Re-encoded as hex: valid base85 characters, not a MessagePack value.
+    payload["c"] = json!(HMAC);
+    let err = reject("eql_v3_text_search", &payload);
+    assert!(
+        matches!(&err, EncodingError::MessagePack { path } if path == "c"),
+        "{err:?}"
+    );
+    assert_eq!(
+        err.to_string(),
+        "`c` does not decode to one MessagePack value"
+    );
+
+    let mut doc = document();
+    doc["h"] = json!("not base85: spaces");
+    let err = reject("eql_v3_json_search", &doc);
+    assert!(
+        matches!(&err, EncodingError::Base85 { path } if path == "h"),
+        "{err:?}"
+    );
+}
+
+#[test]
+fn hmac_terms_must_be_32_bytes() {
+    let mut payload = text_search();
+    payload["hm"] = json!(&HMAC[..32]);
+    let err = reject("eql_v3_text_search", &payload);
+    assert!(
+        matches!(&err, EncodingError::HmacLength { path, bytes: 16 } if path == "hm"),
+        "{err:?}"
+    );
+
+    payload["hm"] = json!(format!("{}zz", &HMAC[..62]));
+    let err = reject("eql_v3_text_search", &payload);
+    assert!(
+        matches!(&err, EncodingError::Hex { path } if path == "hm"),
+        "{err:?}"
+    );
+
+    let err = reject(
+        "eql_v3_text_array_contains",
+        &json!({ "v": 3, "i": ident(), "c": CIPHERTEXT, "hm": [HMAC, "abcd"] }),
+    );
+    assert!(
+        matches!(&err, EncodingError::HmacLength { path, bytes: 2 } if path == "hm[1]"),
+        "{err:?}"
+    );
+}
+
+#[test]
+fn ope_terms_carry_the_domain_tag_for_where_they_sit() {
+    // An entry's string tag on a scalar payload.
+    let mut payload = text_search();
+    payload["op"] = json!(OPE_STRING);
+    let err = reject("eql_v3_text_search", &payload);
+    assert!(
+        matches!(&err, EncodingError::OpeTag { path, tag: Some(0x02) } if path == "op"),
+        "{err:?}"
+    );
+    assert_eq!(err.to_string(), "`op` has CLLW domain tag 0x02");
+
+    payload["op"] = json!("");
+    let err = reject("eql_v3_text_search", &payload);
+    assert!(
+        matches!(&err, EncodingError::OpeTag { tag: None, .. }),
+        "{err:?}"
+    );
+
+    // And the scalar tag on a SteVec entry.
+    let mut doc = document();
+    doc["sv"][1]["op"] = json!(OPE_SCALAR);
+    let err = reject("eql_v3_json_search", &doc);
+    assert!(
+        matches!(&err, EncodingError::OpeTag { path, tag: Some(0x00) } if path == "sv[1].op"),
+        "{err:?}"
+    );
+
+    let err = reject(
+        "query_json",
+        &json!({ "sv": [{ "s": SELECTOR, "op": OPE_SCALAR }] }),
+    );
+    assert!(
+        matches!(&err, EncodingError::OpeTag { path, .. } if path == "sv[0].op"),
+        "{err:?}"
+    );
+}
+
+#[test]
+fn ore_block_terms_share_one_width() {
+    let ore = |ob: Value| json!({ "v": 3, "i": ident(), "c": CIPHERTEXT, "ob": ob });
+    let wide = format!("{}{}", &ORE_A[..98], ORE_B);
+    let err = reject("eql_v3_integer_ord_ore", &ore(json!([ORE_A, wide])));
+    assert!(
+        matches!(
+            &err,
+            EncodingError::OreWidth { path, expected: 1, found: 2 } if path == "ob[1]"
+        ),
+        "{err:?}"
+    );
+
+    let err = reject("eql_v3_integer_ord_ore", &ore(json!([&ORE_A[..128]])));
+    assert!(
+        matches!(&err, EncodingError::OreBlock { path, bytes: 64 } if path == "ob[0]"),
+        "{err:?}"
+    );
+}
+
+#[test]
+fn selectors_cover_the_entry_nonce() {
+    let mut doc = document();
+    doc["sv"][0]["s"] = json!(&SELECTOR[..22]);
+    let err = reject("eql_v3_json_search", &doc);
+    assert!(
+        matches!(&err, EncodingError::SelectorLength { path, bytes: 11 } if path == "sv[0].s"),
+        "{err:?}"
+    );
+
+    // An entry's own ciphertext is raw AEAD output, never MessagePack-checked;
+    // only its base85 is.
+    let mut doc = document();
+    doc["sv"][0]["c"] = json!(&ENTRY_CIPHERTEXT[..36]);
+    let err = reject("eql_v3_json_search", &doc);
+    assert!(
+        matches!(&err, EncodingError::Base85 { path } if path == "sv[0].c"),
+        "{err:?}"
+    );
+}
@@ -0,0 +1,144 @@
+//! Client-side ordering of the two order terms: [`OpeCllw`] must order like
+//! the hex-decoded `bytea` Postgres compares, and [`OreBlock256`] like
+//! `eql_v3_internal.compare_ore_block_256_terms`.
+//!
+//! The ORE terms here are built by [`ore_term`] with the same wire layout and
+//! indicator bit the SQL comparator reads, under a toy PRF — enough to pin the
+//! port's block offsets and bit numbering without real key material. The
+//! cross-check against the SQL comparator itself, over real fixture
+//! ciphertexts, is `tests/sqlx/tests/term_order_parity_tests.rs`.
+
+use eql_bindings::v3::terms::OpeCllw;
+
+fn ope(hex: &str) -> OpeCllw {
+    OpeCllw(hex.to_owned())
+}
+
+#[test]
+fn ope_orders_like_bytea() {
+    let ascending = ["", "00", "0000", "0001", "01", "7f", "80", "ff", "ff00"];
+    for (i, a) in ascending.iter().enumerate() {
+        for (j, b) in ascending.iter().enumerate() {
+            assert_eq!(ope(a).cmp(&ope(b)), i.cmp(&j), "{a:?} vs {b:?}");
+        }
+    }
+}
+
+#[test]
+fn ope_tie_breaks_case_and_sorts_malformed_last() {
+    // Same bytes, different spelling: adjacent, and ordered by text so `Ord`
+    // agrees with the derived `Eq`.
+    assert!(ope("AB") < ope("ab"));
+    assert!(ope("aa") < ope("AB") && ope("ab") < ope("ac"));
+    // Not hex: after every valid term.
+    assert!(ope("ffff") < ope("0"));
+    assert!(ope("ffff") < ope("zz"));
+    assert!(ope("0") < ope("zz"));
+}
+
+/// The ORE comparator needs AES, so it only exists under `ore-compare`.
+#[cfg(feature = "ore-compare")]
+mod ore {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    use aes::cipher::{BlockEncrypt, KeyInit};
+    use aes::{Aes128, Block};
+    use eql_bindings::v3::terms::OreBlock256;
+
+    /// A toy left block for `byte` after `prefix`: injective for prefixes up to 14
+    /// bytes, which is all these tests need.
+    fn left_block(prefix: &[u8], byte: u8) -> [u8; 16] {
+        let mut block = [0u8; 16];
+        block[0] = prefix.len() as u8;
+        block[1..=prefix.len()].copy_from_slice(prefix);
+        block[prefix.len() + 1] = byte;
+        block
+    }
+
+    /// One `49 * N + 16`-byte ORE term over `key` (one block per byte, identity
+    /// PRP) with right-half nonce `nonce`, hex-encoded.
+    fn ore_term(key: &[u8], nonce: [u8; 16]) -> String {
+        let cipher = Aes128::new(&Block::from(nonce));
+        let mut term = key.to_vec();
+        for i in 0..key.len() {
+            term.extend(left_block(&key[..i], key[i]));
+        }
+        term.extend(nonce);
+        for i in 0..key.len() {
+            let mut right = [0u8; 32];
+            for j in 0..=255u8 {
+                let mut block = Block::from(left_block(&key[..i], j));
+                cipher.encrypt_block(&mut block);
+                let bit = u8::from(j > key[i]) ^ (block[0] & 1);
+                right[usize::from(j / 8)] |= bit << (j % 8);
+            }
+            term.extend(right);
+        }
+        term.iter().map(|b| format!("{b:02x}")).collect()
+    }
+
+    fn ore(key: u64, nonce: u8) -> OreBlock256 {
+        OreBlock256(vec![ore_term(&key.to_be_bytes(), [nonce; 16])])
+    }
+
+    fn hash_of<T: Hash>(value: &T) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn ore_orders_like_the_plaintexts() {
+        let values = [0u64, 1, 255, 256, 65_535, 1 << 32, u64::MAX - 1, u64::MAX];
+        for (i, a) in values.iter().enumerate() {
+            assert_eq!(ore(*a, 1).0[0].len(), 2 * (49 * 8 + 16));
+            for (j, b) in values.iter().enumerate() {
+                // Different right-half nonces on each side: equality must not
+                // depend on them.
+                let (ta, tb) = (ore(*a, 1), ore(*b, 2));
+                assert_eq!(ta.cmp(&tb), i.cmp(&j), "{a} vs {b}");
+                assert_eq!(ta == tb, a == b, "{a} == {b}");
+            }
+        }
+    }
+
+    #[test]
+    fn ore_equal_terms_hash_alike() {
+        let (a, b) = (ore(42, 1), ore(42, 2));
+        assert_ne!(a.0, b.0, "the right halves differ");
+        assert_eq!(a, b);
+        assert_eq!(hash_of(&a), hash_of(&b));
+    }
+
+    #[test]
+    fn ore_arrays_compare_block_term_by_block_term() {
+        let nonce = [7u8; 16];
+        let term = |s: &str| ore_term(s.as_bytes(), nonce);
+        let array = |terms: &[&str]| OreBlock256(terms.iter().map(|s| term(s)).collect());
+
+        let empty = OreBlock256(vec![]);
+        assert!(empty < array(&["aaaa"]), "`[]` sorts before every term");
+        assert_eq!(empty, OreBlock256(vec![]));
+        assert!(array(&["aaaa"]) < array(&["aaaa", "aaaa"]));
+        assert!(array(&["aaaa", "zzzz"]) < array(&["aaab"]));
+        assert!(array(&["aaab", "aaaa"]) > array(&["aaaa", "zzzz"]));
+    }
+
+    #[test]
+    fn ore_malformed_and_mixed_width_terms_still_order_totally() {
+        let narrow = OreBlock256(vec![ore_term(&[9], [1; 16])]);
+        let wide = OreBlock256(vec![ore_term(&[0, 0], [1; 16])]);
+        let short = OreBlock256(vec!["00010203".to_owned()]);
+        let not_hex = OreBlock256(vec!["zz".to_owned()]);
+
+        assert!(narrow < wide, "a narrower term sorts before a wider one");
+        assert!(
+            wide < short,
+            "a malformed term sorts after well-formed ones"
+        );
+        assert!(short < not_hex, "malformed terms order by text");
+        assert_eq!(short, OreBlock256(vec!["00010203".to_owned()]));
+        assert_ne!(short, OreBlock256(vec!["04050607".to_owned()]));
+    }
+}
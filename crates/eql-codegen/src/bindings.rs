@@ -76,6 +76,7 @@ fn capability_label(domain_name: &str) -> &'static str {
         "ord" | "ord_ore" | "ord_ope" => "ordering domain",
         "match" => "match domain",
         "search" | "search_ore" => "search domain",
+        "contains" => "array containment domain",
         other => panic!(
             "unmapped bare domain name {other:?} — add it to capability_label \
              in crates/eql-codegen/src/bindings.rs"
@@ -87,7 +88,7 @@ fn capability_label(domain_name: &str) -> &'static str {
 /// (`` `public.<name>` — <label>. ``) and a detail line listing the supported
 /// SQL operators and the required payload keys. Every part is derived from data
 /// the catalog already carries — the capability label, the operator union
-/// (`Term::operators_for_terms`), and the key list (`ENVELOPE_KEYS` ++
+/// (`Domain::operators`, per-element for an array domain), and the key list (`ENVELOPE_KEYS` ++
 /// `Term::term_json_keys`) — so it stays deterministic and cannot drift from the
 /// payload shape. No free-form prose and no field docs: per-field semantics live
 /// on the shared term newtypes (`terms.rs`) and per-family caveats in `mod.rs`.
@@ -102,7 +103,7 @@ fn struct_doc_lines(full: &str, domain: &Domain) -> [String; 3] {
         capability_label(domain.name)
     );
 
-    let ops = Term::operators_for_terms(domain.terms);
+    let ops = domain.operators();
     let ops_str = if ops.is_empty() {
         "none".to_string()
     } else {
@@ -126,6 +127,108 @@ fn struct_doc_lines(full: &str, domain: &Domain) -> [String; 3] {
     [summary, String::new(), detail]
 }
 
+/// The `pub <key>: <Newtype>` term fields of a domain's payload struct, in
+/// `Term::payload_terms` order. An array domain holds one term per element, so
+/// each field is a `Vec` of the newtype (`hm: Vec<Hmac256>`).
+fn term_fields(domain: &Domain) -> TokenStream {
+    let mut fields = TokenStream::new();
+    for term in Term::payload_terms(domain.terms) {
+        let fid = format_ident!("{}", term.json_key());
+        let tid = format_ident!("{}", term.binding_newtype());
+        if domain.is_array() {
+            fields.extend(quote! { pub #fid: Vec<#tid>, });
+        } else {
+            fields.extend(quote! { pub #fid: #tid, });
+        }
+    }
+    fields
+}
+
+/// The `term_json_keys_static` body: the domain's flat term keys, or `None`
+/// for an array domain. `from_v2` converts only flat scalar payloads — a v2
+/// payload carries one `hm`, never one per element — so `None` makes an array
+/// domain an unknown conversion target, exactly like the SteVec shapes.
+fn term_keys_expr(domain: &Domain) -> TokenStream {
+    if domain.is_array() {
+        return quote! { None };
+    }
+    let term_keys = Term::term_json_keys(domain.terms);
+    quote! { Some(&[#(#term_keys),*]) }
+}
+
+/// The `sqlx` feature's `Type`/`Encode`/`Decode` impls for one payload struct,
+/// each a one-line call into the hand-written `crate::v3::sqlx_codec` module:
+/// the type is named by `sql_domain_static()`, and decoding goes through the
+/// struct's strict serde parse. Paths are fully qualified so the generated
+/// `use` header needs no feature gate.
+fn render_sqlx_impls(ident: &proc_macro2::Ident) -> TokenStream {
+    quote! {
+        #[cfg(feature = "sqlx")]
+        impl sqlx::Type<sqlx::Postgres> for #ident {
+            fn type_info() -> sqlx::postgres::PgTypeInfo {
+                crate::v3::sqlx_codec::type_info::<Self>()
+            }
+            fn compatible(ty: &sqlx::postgres::PgTypeInfo) -> bool {
+                crate::v3::sqlx_codec::compatible::<Self>(ty)
+            }
+        }
+
+        #[cfg(feature = "sqlx")]
+        impl sqlx::Encode<'_, sqlx::Postgres> for #ident {
+            fn encode_by_ref(
+                &self,
+                buf: &mut sqlx::postgres::PgArgumentBuffer,
+            ) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+                crate::v3::sqlx_codec::encode(self, buf)
+            }
+        }
+
+        #[cfg(feature = "sqlx")]
+        impl<'r> sqlx::Decode<'r, sqlx::Postgres> for #ident {
+            fn decode(value: sqlx::postgres::PgValueRef<'r>) -> Result<Self, sqlx::error::BoxDynError> {
+                crate::v3::sqlx_codec::decode(value)
+            }
+        }
+    }
+}
+
+/// The `postgres-types` feature's `ToSql`/`FromSql` impls for one payload
+/// struct, delegating to the hand-written `crate::v3::postgres_codec` module
+/// (which also serves the hand-written SteVec shapes). `ToSql::accepts` is the
+/// exact domain; `FromSql::accepts` adds the `jsonb` base type result columns
+/// report.
+fn render_postgres_types_impls(ident: &proc_macro2::Ident) -> TokenStream {
+    quote! {
+        #[cfg(feature = "postgres-types")]
+        impl postgres_types::ToSql for #ident {
+            fn to_sql(
+                &self,
+                ty: &postgres_types::Type,
+                out: &mut bytes::BytesMut,
+            ) -> Result<postgres_types::IsNull, Box<dyn std::error::Error + Sync + Send>> {
+                crate::v3::postgres_codec::to_sql(self, ty, out)
+            }
+            fn accepts(ty: &postgres_types::Type) -> bool {
+                crate::v3::postgres_codec::accepts::<Self>(ty)
+            }
+            postgres_types::to_sql_checked!();
+        }
+
+        #[cfg(feature = "postgres-types")]
+        impl<'a> postgres_types::FromSql<'a> for #ident {
+            fn from_sql(
+                ty: &postgres_types::Type,
+                raw: &'a [u8],
+            ) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+                crate::v3::postgres_codec::from_sql(ty, raw)
+            }
+            fn accepts(ty: &postgres_types::Type) -> bool {
+                crate::v3::postgres_codec::accepts_column::<Self>(ty)
+            }
+        }
+    }
+}
+
 /// One payload struct + its three-method `DomainType` impl. A catalog-derived
 /// struct doc (summary + operators + required keys — see [`struct_doc_lines`]),
 /// no field docs. Term fields come from `Term::payload_terms`, matching on the
@@ -146,18 +249,16 @@ fn render_struct(family: &DomainFamily, domain: &Domain) -> TokenStream {
     fields.extend(quote! { pub v: SchemaVersion, });
     fields.extend(quote! { pub i: Identifier, });
     fields.extend(quote! { pub c: Ciphertext, });
-    for term in Term::payload_terms(domain.terms) {
-        let fid = format_ident!("{}", term.json_key());
-        let tid = format_ident!("{}", term.binding_newtype());
-        fields.extend(quote! { pub #fid: #tid, });
-    }
+    fields.extend(term_fields(domain));
 
     // The domain's required term keys, threaded through the trait so
     // `from_v2::TargetDomain::parse` resolves them from the inventory alone —
-    // Some(&[]) for a storage-only scalar (None is reserved for the
+    // Some(&[]) for a storage-only scalar (None for the array domains and the
     // hand-written SteVec shapes). Parity with the catalog is pinned by
     // eql-bindings `tests/catalog_parity.rs`.
-    let term_keys = Term::term_json_keys(domain.terms);
+    let term_keys = term_keys_expr(domain);
+    let sqlx_impls = render_sqlx_impls(&ident);
+    let postgres_types_impls = render_postgres_types_impls(&ident);
 
     quote! {
         #[doc = #doc_summary]
@@ -178,7 +279,7 @@ fn render_struct(family: &DomainFamily, domain: &Domain) -> TokenStream {
                 Self::sql_domain_static()
             }
             fn term_json_keys_static() -> Option<&'static [&'static str]> {
-                Some(&[#(#term_keys),*])
+                #term_keys
             }
             fn term_json_keys(&self) -> Option<&'static [&'static str]> {
                 Self::term_json_keys_static()
@@ -190,6 +291,10 @@ fn render_struct(family: &DomainFamily, domain: &Domain) -> TokenStream {
                 schema_for!(#ident)
             }
         }
+
+        #sqlx_impls
+
+        #postgres_types_impls
     }
 }
 
@@ -213,7 +318,7 @@ fn render_query_struct(family: &DomainFamily, domain: &Domain) -> TokenStream {
         " `eql_v3.{query_name}` — {} query operand.",
         capability_label(domain.name)
     );
-    let ops = Term::operators_for_terms(domain.terms);
+    let ops = domain.operators();
     let ops_str = ops
         .iter()
         .map(|o| format!("`{o}`"))
@@ -232,14 +337,31 @@ fn render_query_struct(family: &DomainFamily, domain: &Domain) -> TokenStream {
     let mut fields = TokenStream::new();
     fields.extend(quote! { pub v: SchemaVersion, });
     fields.extend(quote! { pub i: Identifier, });
-    for term in Term::payload_terms(domain.terms) {
-        let fid = format_ident!("{}", term.json_key());
-        let tid = format_ident!("{}", term.binding_newtype());
-        fields.extend(quote! { pub #fid: #tid, });
-    }
-    let term_keys = Term::term_json_keys(domain.terms);
+    fields.extend(term_fields(domain));
+    let term_keys = term_keys_expr(domain);
+    let sqlx_impls = render_sqlx_impls(&ident);
+    let postgres_types_impls = render_postgres_types_impls(&ident);
+
+    // `<Storage>::to_query`: the same envelope and terms, minus `c`.
+    let storage = format_ident!("{}", domain.struct_ident(family.name));
+    let to_query_doc = format!(" The `eql_v3.{query_name}` operand: this payload minus `c`.");
+    let term_idents: Vec<_> = Term::payload_terms(domain.terms)
+        .into_iter()
+        .map(|t| format_ident!("{}", t.json_key()))
+        .collect();
 
     quote! {
+        impl #storage {
+            #[doc = #to_query_doc]
+            pub fn to_query(&self) -> #ident {
+                #ident {
+                    v: self.v,
+                    i: self.i.clone(),
+                    #(#term_idents: self.#term_idents.clone(),)*
+                }
+            }
+        }
+
         #[doc = #summary]
         #[doc = ""]
         #[doc = #detail]
@@ -258,7 +380,7 @@ fn render_query_struct(family: &DomainFamily, domain: &Domain) -> TokenStream {
                 Self::sql_domain_static()
             }
             fn term_json_keys_static() -> Option<&'static [&'static str]> {
-                Some(&[#(#term_keys),*])
+                #term_keys
             }
             fn term_json_keys(&self) -> Option<&'static [&'static str]> {
                 Self::term_json_keys_static()
@@ -270,10 +392,14 @@ fn render_query_struct(family: &DomainFamily, domain: &Domain) -> TokenStream {
                 schema_for!(#ident)
             }
         }
+
+        #sqlx_impls
+
+        #postgres_types_impls
     }
 }
 
-/// Render a whole family module (`integer.rs`, `text.rs`, …): the import header
+/// Render a whole family module (`integer.rs`, `text_array.rs`, …): the import header
 /// (exactly the term newtypes the family uses) followed by every domain's
 /// storage struct + impl, then a query twin for each term-bearing domain.
 pub fn render_family_bindings(family: &DomainFamily) -> String {
@@ -406,13 +532,15 @@ pub fn render_inventory_rs() -> String {
         })
         .collect();
 
-    // The QUERY-operand inventory: one twin per term-bearing scalar domain,
-    // in CATALOG order. Kept SEPARATE from `all()` — `all()` is the stored +
+    // The QUERY-operand inventory: one twin per term-bearing generated
+    // (scalar or array) domain, in CATALOG order. Kept SEPARATE from `all()` — `all()` is the stored +
     // SteVec inventory that `from_v2::TargetDomain` and `catalog_parity`
     // resolve against, and query twins must not appear there as conversion
     // targets. `all_query()` gives the schema export (and query validation) a
     // handle on the twins without polluting `all()`.
-    let query_entries: TokenStream = eql_domains::scalar_families()
+    let query_entries: TokenStream = CATALOG
+        .iter()
+        .filter(|f| f.is_generated())
         .flat_map(|f| {
             let m = format_ident!("{}", f.name);
             f.domains
@@ -465,16 +593,16 @@ pub fn render_inventory_rs() -> String {
 }
 
 /// The stored-payload domains of the catalog, in CATALOG order: every flat
-/// scalar domain (including the bare `public.eql_v3_json` storage domain) plus
-/// the SteVec document (`public.eql_v3_json_search`). The SteVec entry/query
+/// scalar domain (including the bare `public.eql_v3_json` storage domain), every
+/// array domain, plus the SteVec document (`public.eql_v3_json_search`). The SteVec entry/query
 /// shapes are inventory members but not stored column payloads, so they are
-/// excluded — exactly the set `eql_bindings::from_v2` accepts as conversion
-/// targets ([`render_payload_rs`]'s `DomainPayload` variants).
+/// excluded. Every non-array member is a conversion target `eql_bindings::from_v2`
+/// accepts ([`render_payload_rs`]'s `DomainPayload` variants).
 fn stored_payload_domains() -> impl Iterator<Item = (&'static DomainFamily, &'static Domain)> {
     CATALOG
         .iter()
         .flat_map(|f| f.domains.iter().map(move |d| (f, d)))
-        .filter(|(f, d)| d.is_scalar() || d.full_name(f.name) == "json_search")
+        .filter(|(f, d)| d.is_generated() || d.full_name(f.name) == "json_search")
 }
 
 /// Render the generated `crates/eql-bindings/src/v3/payload.rs`: the
@@ -495,6 +623,10 @@ pub fn render_payload_rs() -> String {
     let mut variants = TokenStream::new();
     let mut parse_arms = TokenStream::new();
     let mut inner_arms = TokenStream::new();
+    let mut to_query_arms = TokenStream::new();
+    let mut family_arms = TokenStream::new();
+    let mut rank_arms = TokenStream::new();
+    let mut seed_arms = TokenStream::new();
     for (f, d) in stored_payload_domains() {
         let module = format_ident!("{}", f.name);
         let strukt = format_ident!("{}", d.rust_struct_name(f.name));
@@ -510,9 +642,42 @@ pub fn render_payload_rs() -> String {
         parse_arms.extend(quote! {
             #typname => Some(super::#module::#strukt::deserialize(value).map(Self::#strukt)),
         });
+        seed_arms.extend(quote! {
+            #typname => super::#module::#strukt::deserialize(deserializer)
+                .map(DomainPayload::#strukt),
+        });
         inner_arms.extend(quote! {
             Self::#strukt(payload) => payload,
         });
+        let family = f.name;
+        family_arms.extend(quote! {
+            #typname => Some(#family),
+        });
+        // The SteVec document has no flat terms, hence no role to rank.
+        if d.is_generated() {
+            let rank = proc_macro2::Literal::u8_unsuffixed(Term::role_for_terms(d.terms).rank());
+            rank_arms.extend(quote! {
+                #typname => Some(#rank),
+            });
+        }
+        // Storage-only domains have no query operand; the SteVec document
+        // goes through its hand-written `to_query` (`eql_v3.to_ste_vec_query`).
+        let query_arm = if !d.is_generated() {
+            quote! { Some(QueryPayload::SteVec(payload.to_query())) }
+        } else if d.terms.is_empty() {
+            quote! { None }
+        } else {
+            let q = format_ident!("{}Query", d.struct_ident(f.name));
+            quote! { Some(QueryPayload::#q(payload.to_query())) }
+        };
+        let binding = if d.is_generated() && d.terms.is_empty() {
+            quote! { _ }
+        } else {
+            quote! { payload }
+        };
+        to_query_arms.extend(quote! {
+            Self::#strukt(#binding) => #query_arm,
+        });
     }
 
     let mod_doc = " The generated `DomainPayload` enum — every stored-payload v3 \
@@ -523,9 +688,12 @@ pub fn render_payload_rs() -> String {
     let file = quote! {
         #![doc = #mod_doc]
 
+        use serde::de::{self, DeserializeSeed, Deserializer};
         use serde::{Deserialize, Serialize};
 
         use super::domain_type::DomainType;
+        use super::project::ProjectError;
+        use super::query_payload::QueryPayload;
 
         /// Every stored-payload v3 domain in one type: one variant per flat
         /// scalar domain in `eql-domains::CATALOG` plus the SteVec document
@@ -537,7 +705,7 @@ pub fn render_payload_rs() -> String {
         /// Deliberately NO `Deserialize`: cross-token payloads are
         /// byte-identical on the wire (see "Why there is no discriminated
         /// enum" in the v3 module docs), so a variant is only constructible
-        /// from a KNOWN domain — [`DomainPayload::parse`] or
+        /// from a KNOWN domain — [`DomainPayload::parse`], [`DomainSeed`] or
         /// [`crate::from_v2::from_v2_typed`] — never inferred from bytes.
         #[derive(Clone, Debug, PartialEq, Serialize)]
         #[serde(untagged)]
@@ -581,6 +749,74 @@ pub fn render_payload_rs() -> String {
             pub fn domain(&self) -> &'static str {
                 self.as_domain_type().domain()
             }
+
+            /// The query operand that searches for this payload: `c` stripped,
+            /// exactly the query twin's terms kept. `None` for a storage-only
+            /// domain, which has no query operand (as
+            /// [`crate::from_v2::from_v2_query`] refuses one). The SteVec
+            /// document yields its selector-only containment needle, the
+            /// client-side `eql_v3.to_ste_vec_query`.
+            pub fn to_query(&self) -> Option<QueryPayload> {
+                match self {
+                    #to_query_arms
+                }
+            }
+
+            /// Narrow this payload to `target`, a domain of the same family
+            /// (`"eql_v3_text_eq"` from a `text_search` payload): `c` and the
+            /// envelope kept, term keys `target` does not require dropped,
+            /// the result strictly re-parsed as `target`. Fails closed when
+            /// `target` needs a term this payload lacks, is in another
+            /// family, or is an array or SteVec domain. See [`super::project`].
+            pub fn project_to(&self, target: &str) -> Result<DomainPayload, ProjectError> {
+                let family = Self::family_of(self.domain())
+                    .expect("every DomainPayload variant is a stored-payload domain");
+                super::project::project(self, family, target, Self::family_of(target))
+            }
+
+            /// The catalog family owning stored-payload domain `domain`.
+            pub(crate) fn family_of(domain: &str) -> Option<&'static str> {
+                match domain {
+                    #family_arms
+                    _ => None,
+                }
+            }
+
+            /// `eql_domains::Role::rank` of the role `Term::role_for_terms`
+            /// resolves for stored-payload domain `domain`'s terms. `None`
+            /// for the SteVec document and unknown names.
+            pub(crate) fn role_rank_of(domain: &str) -> Option<u8> {
+                match domain {
+                    #rank_arms
+                    _ => None,
+                }
+            }
+        }
+
+        /// Streams one payload of a KNOWN domain from any serde
+        /// [`Deserializer`] straight into its [`DomainPayload`] variant — the
+        /// [`DomainPayload::parse`] constructor without the intermediate
+        /// `serde_json::Value`. The domain name (`"eql_v3_integer_eq"`, …)
+        /// comes first, so the variant is never inferred from bytes; an
+        /// unknown name is a deserialization error, raised before any input
+        /// is read.
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        pub struct DomainSeed<'a>(pub &'a str);
+
+        impl<'de> DeserializeSeed<'de> for DomainSeed<'_> {
+            type Value = DomainPayload;
+
+            fn deserialize<D>(self, deserializer: D) -> Result<DomainPayload, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                match self.0 {
+                    #seed_arms
+                    domain => Err(de::Error::custom(format_args!(
+                        "unknown stored-payload domain {domain:?}"
+                    ))),
+                }
+            }
         }
     };
 
@@ -588,14 +824,16 @@ pub fn render_payload_rs() -> String {
 }
 
 /// The catalog's QUERY-operand domains, in a stable order: a query twin for
-/// every term-bearing scalar domain (`eql_v3.query_<name>`), then the SteVec
+/// every term-bearing scalar or array domain (`eql_v3.query_<name>`), then the SteVec
 /// containment needle (`eql_v3.query_json`). Exactly the shapes the generated
 /// `QueryPayload` spans and `from_v2_query` can target. Returned as
 /// `(module, variant ident, struct ident, unqualified query-domain name)`; the
 /// SteVec needle keeps the `SteVec` variant name the `from_v2` query path
 /// already uses (its struct is the hand-written `SteVecQuery`).
 fn query_payload_domains() -> Vec<(String, String, String, String)> {
-    let mut out: Vec<(String, String, String, String)> = eql_domains::scalar_families()
+    let mut out: Vec<(String, String, String, String)> = CATALOG
+        .iter()
+        .filter(|f| f.is_generated())
         .flat_map(|f| {
             f.domains
                 .iter()
@@ -631,6 +869,7 @@ pub fn render_query_payload_rs() -> String {
     let mut variants = TokenStream::new();
     let mut parse_arms = TokenStream::new();
     let mut inner_arms = TokenStream::new();
+    let mut seed_arms = TokenStream::new();
     for (module, variant, strukt, key) in query_payload_domains() {
         let m = format_ident!("{module}");
         let v = format_ident!("{variant}");
@@ -644,6 +883,9 @@ pub fn render_query_payload_rs() -> String {
         parse_arms.extend(quote! {
             #key => Some(super::#m::#s::deserialize(value).map(Self::#v)),
         });
+        seed_arms.extend(quote! {
+            #key => super::#m::#s::deserialize(deserializer).map(QueryPayload::#v),
+        });
         inner_arms.extend(quote! {
             Self::#v(payload) => payload,
         });
@@ -657,6 +899,7 @@ pub fn render_query_payload_rs() -> String {
     let file = quote! {
         #![doc = #mod_doc]
 
+        use serde::de::{self, DeserializeSeed, Deserializer};
         use serde::{Deserialize, Serialize};
 
         use super::domain_type::DomainType;
@@ -671,8 +914,8 @@ pub fn render_query_payload_rs() -> String {
         /// adds no tagging), so typing a query operand never changes the wire.
         /// Deliberately NO `Deserialize`: cross-token operands are byte-identical
         /// on the wire, so a variant is only constructible from a KNOWN domain
-        /// — [`QueryPayload::parse`] or [`crate::from_v2::from_v2_query_typed`] —
-        /// never inferred from bytes. No ts-rs/schemars: it adds no wire shape.
+        /// — [`QueryPayload::parse`], [`QuerySeed`] or
+        /// [`crate::from_v2::from_v2_query_typed`] — never inferred from bytes. No ts-rs/schemars: it adds no wire shape.
         #[derive(Clone, Debug, PartialEq, Serialize)]
         #[serde(untagged)]
         pub enum QueryPayload {
@@ -714,6 +957,29 @@ pub fn render_query_payload_rs() -> String {
                 self.as_domain_type().domain()
             }
         }
+
+        /// The query-side [`super::DomainSeed`]: streams one operand of a
+        /// KNOWN query domain (`"query_integer_eq"`, `"query_json"`, …) from
+        /// any serde [`Deserializer`] into its [`QueryPayload`] variant,
+        /// without the `serde_json::Value` [`QueryPayload::parse`] takes.
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        pub struct QuerySeed<'a>(pub &'a str);
+
+        impl<'de> DeserializeSeed<'de> for QuerySeed<'_> {
+            type Value = QueryPayload;
+
+            fn deserialize<D>(self, deserializer: D) -> Result<QueryPayload, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                match self.0 {
+                    #seed_arms
+                    domain => Err(de::Error::custom(format_args!(
+                        "unknown query-operand domain {domain:?}"
+                    ))),
+                }
+            }
+        }
     };
 
     format_rs(file)
@@ -723,14 +989,16 @@ pub fn render_query_payload_rs() -> String {
 const V3_BINDINGS_DIR: &str = "crates/eql-bindings/src/v3";
 
 /// Render every binding file to memory (NO filesystem writes): one
-/// `(<dir>/<family>.rs, body)` per catalog family in CATALOG order, then
+/// `(<dir>/<family>.rs, body)` per generated (scalar or array) family in
+/// CATALOG order, then
 /// `payload.rs` (the `DomainPayload` enum) and `inventory.rs`. Kept separate
 /// from the write orchestration so a render panic
 /// — an unmapped bare-domain name in [`capability_label`], or a missing/failing
 /// `rustfmt` in [`format_rs`] — aborts BEFORE [`generate_bindings`] deletes any
 /// committed source.
 fn render_bindings(dir: &Path) -> Vec<(PathBuf, String)> {
-    let mut rendered: Vec<(PathBuf, String)> = eql_domains::scalar_families()
+    let mut rendered: Vec<(PathBuf, String)> = eql_domains::families_with_scalar_domains()
+        .filter(|f| f.is_generated())
         .map(|f| {
             (
                 dir.join(format!("{}.rs", f.name)),
@@ -738,13 +1006,14 @@ fn render_bindings(dir: &Path) -> Vec<(PathBuf, String)> {
             )
         })
         .collect();
-    // Mixed families (a family with ≥1 scalar domain that is not wholly scalar —
-    // `json` today) are excluded from `scalar_families()`, so their scalar
-    // storage domain(s) render into a separate `<family>_storage.rs` module while
-    // the SteVec `<family>.rs` stays hand-written. Derived from the same generic
+    // Mixed families with a hand-written shape (a family with ≥1 scalar domain
+    // that is not wholly generated — `json` today) render their scalar storage
+    // domain(s) into a separate `<family>_storage.rs` module while the SteVec
+    // `<family>.rs` stays hand-written. The array families are mixed too, but
+    // every domain is generated, so they get a whole `<family>.rs` above. Derived from the same generic
     // `families_with_scalar_domains()` seam the SQL materializer iterates rather
     // than naming a family, so a second mixed family is picked up automatically.
-    for f in eql_domains::families_with_scalar_domains().filter(|f| !f.is_scalar()) {
+    for f in eql_domains::families_with_scalar_domains().filter(|f| !f.is_generated()) {
         rendered.push((
             dir.join(format!("{}_storage.rs", f.name)),
             render_scalar_only_bindings(f),
@@ -793,6 +1062,11 @@ mod tests {
     use eql_domains::CATALOG;
     use quote::quote;
 
+    /// Families rendered to a whole `<family>.rs`: the scalar and array families.
+    fn generated_family_count() -> usize {
+        CATALOG.iter().filter(|f| f.is_generated()).count()
+    }
+
     fn family(name: &str) -> &'static eql_domains::DomainFamily {
         CATALOG.iter().find(|f| f.name == name).expect("family")
     }
@@ -1005,10 +1279,11 @@ mod tests {
         let tmp = crate::writer::test_support::tempdir();
         let written = generate_bindings(tmp.path()).unwrap();
         let dir = tmp.path().join("crates/eql-bindings/src/v3");
-        // scalar families + jsonb_storage + payload + query_payload + inventory.
-        assert_eq!(written.len(), eql_domains::scalar_families().count() + 4);
+        // generated families + jsonb_storage + payload + query_payload + inventory.
+        assert_eq!(written.len(), generated_family_count() + 4);
         assert!(dir.join("integer.rs").is_file());
         assert!(dir.join("text.rs").is_file());
+        assert!(dir.join("text_array.rs").is_file());
         assert!(dir.join("json_storage.rs").is_file());
         assert!(dir.join("payload.rs").is_file());
         assert!(dir.join("inventory.rs").is_file());
@@ -1040,7 +1315,7 @@ mod tests {
 
         let rendered = render_bindings(&dir);
 
-        assert_eq!(rendered.len(), eql_domains::scalar_families().count() + 4);
+        assert_eq!(rendered.len(), generated_family_count() + 4);
         assert_eq!(
             std::fs::read_to_string(&sentinel).unwrap(),
             "SENTINEL",
@@ -1074,15 +1349,15 @@ mod tests {
         assert!(out.starts_with(crate::consts::RUST_GENERATED_MARKER));
 
         // One variant per catalog (family, domain) pair that is a stored
-        // payload: every scalar domain (incl. the bare `eql_v3_json` storage
-        // domain) plus the SteVec document (`eql_v3_json_search`). The SteVec
+        // payload: every scalar or array domain (incl. the bare `eql_v3_json`
+        // storage domain) plus the SteVec document (`eql_v3_json_search`). The SteVec
         // entry/query shapes are inventory members but not stored payloads.
         let expected: Vec<String> = CATALOG
             .iter()
             .flat_map(|f| {
                 f.domains
                     .iter()
-                    .filter(|d| d.is_scalar() || d.full_name(f.name) == "json_search")
+                    .filter(|d| d.is_generated() || d.full_name(f.name) == "json_search")
                     .map(|d| d.rust_struct_name(f.name))
             })
             .collect();
@@ -1108,6 +1383,33 @@ mod tests {
         assert!(out.contains("pub fn as_domain_type(&self) -> &dyn DomainType"));
         assert!(out.contains("pub fn sql_domain(&self) -> &'static str"));
         assert!(out.contains("pub fn domain(&self) -> &'static str"));
+        // Storage-only domains have no query operand; the rest delegate to
+        // the struct's generated (or, for SteVec, hand-written) `to_query`.
+        assert!(out.contains("pub fn to_query(&self) -> Option<QueryPayload>"));
+        assert!(out.contains("Self::Integer(_) => None,"));
+        assert!(out.contains(
+            "Self::IntegerEq(payload) => Some(QueryPayload::IntegerEqQuery(payload.to_query())),"
+        ));
+        assert!(out.contains(
+            "Self::SteVecDocument(payload) => Some(QueryPayload::SteVec(payload.to_query())),"
+        ));
+        // Projection resolves both sides' family from the installed typname.
+        assert!(out.contains(
+            "pub fn project_to(&self, target: &str) -> Result<DomainPayload, ProjectError>"
+        ));
+        assert!(out.contains(r#""eql_v3_text_search" => Some("text"),"#));
+        assert!(out.contains(r#""eql_v3_json_search" => Some("json"),"#));
+        // Role ranks come from the catalog's `Role::rank`; the SteVec
+        // document has none.
+        assert!(out.contains("pub(crate) fn role_rank_of(domain: &str) -> Option<u8>"));
+        assert!(out.contains(r#""eql_v3_text_search" => Some(3),"#));
+        assert!(out.contains(r#""eql_v3_text_match" => Some(1),"#));
+        assert!(out.contains(r#""eql_v3_text" => Some(0),"#));
+        assert!(!out.contains(r#""eql_v3_json_search" => Some(0),"#));
+        // The streaming seed matches on the same typnames as `parse`.
+        assert!(out.contains("pub struct DomainSeed<'a>(pub &'a str);"));
+        assert!(out.contains("impl<'de> DeserializeSeed<'de> for DomainSeed<'_>"));
+        assert!(out.contains("Integer::deserialize(deserializer).map(DomainPayload::Integer)"));
     }
 
     #[test]
@@ -1128,8 +1430,10 @@ mod tests {
         // No storage-only domain twin (they have no operators).
         assert!(!variants.contains(&"IntegerQuery".to_string()));
         assert!(!variants.contains(&"BooleanQuery".to_string()));
-        // One scalar variant per term-bearing scalar domain, + 1 for SteVec.
-        let term_bearing: usize = eql_domains::scalar_families()
+        // One variant per term-bearing scalar or array domain, + 1 for SteVec.
+        let term_bearing: usize = CATALOG
+            .iter()
+            .filter(|f| f.is_generated())
             .flat_map(|f| f.domains.iter())
             .filter(|d| !d.terms.is_empty())
             .count();
@@ -1141,6 +1445,8 @@ mod tests {
         assert!(out.contains(r#""query_json" =>"#));
         assert!(out.contains("SteVecQuery::deserialize(value).map(Self::SteVec)"));
         assert!(out.contains("_ => None,"));
+        assert!(out.contains("pub struct QuerySeed<'a>(pub &'a str);"));
+        assert!(out.contains("SteVecQuery::deserialize(deserializer).map(QueryPayload::SteVec)"));
 
         // Serialize-only, untagged, no export derives (mirrors DomainPayload).
         assert!(out.contains("#[derive(Clone, Debug, PartialEq, Serialize)]"));
@@ -1201,17 +1507,20 @@ mod tests {
             );
         }
         // Both inventories: all() (every CATALOG domain) + all_query() (a twin
-        // per term-bearing scalar domain).
+        // per term-bearing scalar or array domain).
         assert!(out.contains("pub fn all() -> Vec<Box<dyn DomainType>>"));
         assert!(out.contains("pub fn all_query() -> Vec<Box<dyn DomainType>>"));
         let entries = out.matches("Box::new(PhantomData::<").count();
         let domains: usize = eql_domains::CATALOG.iter().map(|f| f.domains.len()).sum();
-        let query_twins: usize = eql_domains::scalar_families()
+        let query_twins: usize = CATALOG
+            .iter()
+            .filter(|f| f.is_generated())
             .flat_map(|f| f.domains.iter())
             .filter(|d| !d.terms.is_empty())
             .count();
         assert_eq!(entries, domains + query_twins);
         assert!(out.contains("PhantomData::<super::integer::IntegerEqQuery>"));
+        assert!(out.contains("PhantomData::<super::integer_array::IntegerArrayContainsQuery>"));
     }
 
     #[test]
@@ -1246,6 +1555,73 @@ mod tests {
         assert!(text.contains(r#"&["bf"]"#), "text_match keys");
     }
 
+    #[test]
+    fn every_generated_struct_gets_feature_gated_sqlx_impls() {
+        // Storage structs and query twins alike: three gated impls apiece
+        // (Type/Encode/Decode), so the default build never names `sqlx`.
+        let integer = render_family_bindings(family("integer"));
+        let structs = integer.matches("\npub struct ").count();
+        assert_eq!(
+            integer.matches("#[cfg(feature = \"sqlx\")]").count(),
+            3 * structs
+        );
+        for (impl_header, ident) in [
+            ("impl sqlx::Type<sqlx::Postgres> for ", "IntegerOrd"),
+            (
+                "impl sqlx::Encode<'_, sqlx::Postgres> for ",
+                "IntegerEqQuery",
+            ),
+            ("impl<'r> sqlx::Decode<'r, sqlx::Postgres> for ", "Integer"),
+        ] {
+            assert!(
+                integer.contains(&format!("{impl_header}{ident} {{")),
+                "{impl_header}{ident}"
+            );
+        }
+        assert!(integer.contains("crate::v3::sqlx_codec::decode(value)"));
+    }
+
+    #[test]
+    fn every_generated_struct_gets_feature_gated_postgres_types_impls() {
+        let text = render_family_bindings(family("text"));
+        let structs = text.matches("\npub struct ").count();
+        assert_eq!(
+            text.matches("#[cfg(feature = \"postgres-types\")]").count(),
+            2 * structs
+        );
+        assert!(text.contains("impl postgres_types::ToSql for TextMatchQuery {"));
+        assert!(text.contains("impl<'a> postgres_types::FromSql<'a> for TextSearch {"));
+        // Parameters bind to the exact domain; columns also read as `jsonb`.
+        assert!(text.contains("crate::v3::postgres_codec::accepts::<Self>(ty)"));
+        assert!(text.contains("crate::v3::postgres_codec::accepts_column::<Self>(ty)"));
+    }
+
+    #[test]
+    fn array_family_holds_one_term_per_element_and_is_no_conversion_target() {
+        let out = render_family_bindings(family("integer_array"));
+        assert_eq!(
+            field_idents(&out, "IntegerArrayContains"),
+            ["v", "i", "c", "hm"]
+        );
+        assert!(out.contains("pub hm: Vec<Hmac256>,"));
+        assert_eq!(
+            field_idents(&out, "IntegerArrayContainsQuery"),
+            ["v", "i", "hm"]
+        );
+        // The storage domain is an ordinary storage-only scalar: Some(&[]).
+        // Both `_contains` impls report None, so `from_v2` never resolves them.
+        assert_eq!(out.matches("Some(&[])").count(), 1);
+        assert_eq!(
+            out.matches(
+                "fn term_json_keys_static() -> Option<&'static [&'static str]> {\n        None\n"
+            )
+            .count(),
+            2
+        );
+        assert!(out.contains("`public.eql_v3_integer_array_contains` — array containment domain."));
+        assert!(out.contains("Operators: `@>` `<@` `&&`. Required keys: `v` `i` `c` `hm`."));
+    }
+
     #[test]
     fn envelope_fields_match_catalog_keys() {
         // `render_struct` hardcodes the `v`/`i`/`c` envelope triple (each maps to
@@ -1278,8 +1654,9 @@ mod tests {
             "ordering domain",
             "match domain",
             "search domain",
+            "array containment domain",
         ];
-        for f in eql_domains::scalar_families() {
+        for f in CATALOG.iter().filter(|f| f.is_generated()) {
             for d in f.domains {
                 let label = capability_label(d.name);
                 assert!(
@@ -1308,9 +1685,9 @@ mod tests {
             rendered.iter().any(|(p, _)| p.ends_with("json_storage.rs")),
             "the json family's scalar storage domain must generate json_storage.rs"
         );
-        // One file per scalar family + jsonb_storage + payload + query_payload +
+        // One file per generated family + jsonb_storage + payload + query_payload +
         // inventory.
-        assert_eq!(rendered.len(), eql_domains::scalar_families().count() + 4);
+        assert_eq!(rendered.len(), generated_family_count() + 4);
     }
 
     #[test]
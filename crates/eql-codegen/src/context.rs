@@ -59,6 +59,11 @@ pub fn environment() -> minijinja::Environment<'static> {
         include_str!("../templates/ore_fallback.sql.j2"),
     )
     .expect("ore_fallback.sql template");
+    env.add_template(
+        "index_snapshot.sql",
+        include_str!("../templates/index_snapshot.sql.j2"),
+    )
+    .expect("index_snapshot.sql template");
     env.add_global("schema", SCHEMA);
     env.add_global("internal_schema", INTERNAL_SCHEMA);
     env
@@ -75,6 +80,10 @@ pub struct DomainBlock {
     // stays term-agnostic — it renders a non-empty-array CHECK per key without
     // hardcoding `ob`. Empty for non-ORE domains. See issue #262.
     pub nonempty_array_keys: Vec<String>,
+    // sql_str-escaped keys whose payload must be a json array, possibly empty:
+    // the per-element term keys of a `Shape::Array` domain (`hm: [..]` — an
+    // empty encrypted array has no element terms). Empty for every other shape.
+    pub array_keys: Vec<String>,
     // sql_str-escaped keys the payload must NOT carry. Empty for storage domains;
     // `['c']` for a query-operand twin, whose CHECK forbids the ciphertext (a
     // query operand is index-terms-only). The template renders a
@@ -89,11 +98,12 @@ pub struct DomainBlock {
 }
 
 /// Concise capability phrase from a domain's operator set — `equality`,
-/// `ordering`, `containment` (joined), or `storage only` when term-less. Derived
-/// from `operators_for_terms` so it tracks the generated surface; kept short so
-/// the `COMMENT ON DOMAIN` fits one line in type pickers (e.g. Supabase Studio).
+/// `ordering`, `matching`, `containment` (joined), or `storage only` when
+/// term-less. Derived from `Domain::operators` so it tracks the generated
+/// surface (per-element operators included); kept short so the
+/// `COMMENT ON DOMAIN` fits one line in type pickers (e.g. Supabase Studio).
 fn capability_phrase(domain: &Domain) -> String {
-    let ops = Term::operators_for_terms(domain.terms);
+    let ops = domain.operators();
     let mut caps = Vec::new();
     if ops.contains(&"=") {
         caps.push("equality");
@@ -104,6 +114,9 @@ fn capability_phrase(domain: &Domain) -> String {
     if ops.contains(&"@@") {
         caps.push("matching");
     }
+    if ops.contains(&"@>") {
+        caps.push("containment");
+    }
     if caps.is_empty() {
         "storage only".to_string()
     } else {
@@ -134,6 +147,18 @@ pub struct TypesContext {
     pub domains: Vec<DomainBlock>,
 }
 
+/// Keys a [`DomainBlock`] requires to hold a json array that may be empty: the
+/// term keys of an array domain, none otherwise.
+fn array_keys(domain: &Domain) -> Vec<String> {
+    if !domain.is_array() {
+        return vec![];
+    }
+    Term::term_json_keys(domain.terms)
+        .into_iter()
+        .map(sql_str)
+        .collect()
+}
+
 /// Build the per-domain block data (port of `render_domain_block`'s value logic,
 /// minus comment prose and the CHECK skeleton — those are template-resident).
 pub fn domain_block(family_name: &str, domain: &Domain) -> DomainBlock {
@@ -158,6 +183,7 @@ pub fn domain_block(family_name: &str, domain: &Domain) -> DomainBlock {
             .into_iter()
             .map(sql_str)
             .collect(),
+        array_keys: array_keys(domain),
         // Storage domains forbid nothing; the query twin forbids `c`.
         forbidden_keys: vec![],
         comment: scalar_domain_comment(family_name, domain),
@@ -189,6 +215,7 @@ pub fn query_domain_block(family_name: &str, domain: &Domain) -> DomainBlock {
             .into_iter()
             .map(sql_str)
             .collect(),
+        array_keys: array_keys(domain),
         forbidden_keys: vec![sql_str("c")],
         comment: query_domain_comment(family_name, domain),
     }
@@ -210,8 +237,8 @@ pub struct SqlParam {
 pub enum FnEntry {
     Extractor {
         ret: String,       // e.g. eql_v3_internal.hmac_256 (selection STAYS in Rust)
-        extractor: String, // e.g. eq_term
-        ctor: String,      // e.g. hmac_256 (called as {{ internal_schema }}.{{ ctor }})
+        extractor: String, // e.g. eq_term / eq_terms
+        ctor: String, // e.g. hmac_256 / hmac_256_array (called as {{ internal_schema }}.{{ ctor }})
     },
     Wrapper {
         op: String,            // SQL operator used in the body, e.g. =
@@ -256,9 +283,43 @@ pub fn extractor_entry(term: Term) -> FnEntry {
     }
 }
 
+/// The per-element extractor entry for an array domain's term: returns the
+/// term-type array (`eql_v3_internal.hmac_256[]`), built by the term's
+/// `element_ctor`. Panics on a term with no per-element form — the catalog
+/// pins that array domains carry only such terms.
+pub fn element_extractor_entry(term: Term) -> FnEntry {
+    let (Some(extractor), Some(ctor)) = (term.element_extractor(), term.element_ctor()) else {
+        panic!("{term:?} has no per-element form");
+    };
+    FnEntry::Extractor {
+        ret: format!("{INTERNAL_SCHEMA}.{}[]", term.ctor()),
+        extractor: extractor.to_string(),
+        ctor: ctor.to_string(),
+    }
+}
+
+/// The extractor entries a domain's functions file opens with, by shape: one
+/// per distinct extractor for a scalar domain, one per element extractor for
+/// an array domain.
+pub fn extractor_entries(domain: &Domain) -> Vec<FnEntry> {
+    if domain.is_array() {
+        domain
+            .terms
+            .iter()
+            .map(|&t| element_extractor_entry(t))
+            .collect()
+    } else {
+        Term::extractor_terms(domain.terms)
+            .into_iter()
+            .map(extractor_entry)
+            .collect()
+    }
+}
+
 /// Build an inlinable comparison-wrapper entry for a supported operator.
 /// `dom` is the schema-qualified domain name; `op` is the already-resolved
-/// operator (the caller iterates `OPERATORS`, so no symbol re-lookup is needed).
+/// operator (the caller iterates `surface_operators`, so no symbol re-lookup is
+/// needed).
 pub fn wrapper_entry(
     dom: &str,
     op: &Operator,
@@ -376,6 +437,15 @@ pub struct OreFallbackContext {
     pub entries: Vec<OreFallbackEntry>,
 }
 
+/// Context for `index_snapshot.sql` — the cross-family snapshot/replay of
+/// the functional indexes built on the extractors.
+#[derive(serde::Serialize)]
+pub struct IndexSnapshotContext {
+    pub requires: Vec<String>, // dependency paths only; template emits "-- REQUIRE:"
+    pub table: String,         // bare name of the public snapshot table
+    pub extractors: Vec<&'static str>,
+}
+
 /// One poisoned domain in `ore_fallback.sql`: the schema-qualified domain name
 /// (`name` for the identifier position, `name_literal` sql_str-escaped for the
 /// string-literal position in the poison CHECK) and the human-readable
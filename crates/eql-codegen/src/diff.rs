@@ -0,0 +1,423 @@
+//! `diff_catalogs` — compare two `dump-catalog` surfaces and report what a
+//! release adds, removes, or changes. The reusable producer behind
+//! `eql-codegen -- diff-catalog`, used when planning an EQL upgrade.
+//!
+//! Both sides are read as a [`CatalogSnapshot`]: an owned, deserializable view
+//! of the [`crate::dump::CatalogDump`] JSON. The live catalog goes through the
+//! same JSON round-trip as a file on disk, so a dump written by an older release
+//! and the current tree are compared on equal terms.
+//!
+//! Domains are matched by their installed `typname`, which is unique across the
+//! `types`, `arrays` and `stevec` sections. Fields an older dump predates
+//! (`arrays`, a domain's `aggregates`) are treated as unknown rather than empty,
+//! so upgrading across the release that introduced them does not report every
+//! existing domain as having gained them.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use serde::{Deserialize, Serialize};
+
+use crate::dump::dump_catalog;
+
+/// An owned view of one `dump-catalog` output.
+#[derive(Debug, Clone, Deserialize)]
+pub struct CatalogSnapshot {
+    #[serde(default)]
+    types: Vec<TypeSnapshot>,
+    #[serde(default)]
+    arrays: Vec<TypeSnapshot>,
+    #[serde(default)]
+    stevec: Vec<SteVecSnapshot>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct TypeSnapshot {
+    token: String,
+    domains: Vec<DomainSnapshot>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct DomainSnapshot {
+    typname: String,
+    #[serde(default)]
+    supported_ops: Vec<String>,
+    #[serde(default)]
+    terms: Vec<TermSnapshot>,
+    /// `None` for a dump that predates the field.
+    #[serde(default)]
+    aggregates: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct SteVecSnapshot {
+    typname: String,
+    #[serde(default)]
+    terms: Vec<TermSnapshot>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+struct TermSnapshot {
+    key: String,
+    extractor: String,
+    ctor: String,
+}
+
+impl CatalogSnapshot {
+    /// Parse a `dump-catalog` JSON document.
+    pub fn from_json(json: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(json)
+    }
+
+    /// The current tree's catalog, via the same JSON shape a file would carry.
+    pub fn live() -> Self {
+        let value = serde_json::to_value(dump_catalog()).expect("serialize catalog dump");
+        serde_json::from_value(value).expect("catalog dump reads back as a snapshot")
+    }
+
+    /// Every domain keyed by typname, with the family it belongs to.
+    fn surfaces(&self) -> BTreeMap<&str, Surface<'_>> {
+        let mut out = BTreeMap::new();
+        for family in self.types.iter().chain(&self.arrays) {
+            for d in &family.domains {
+                out.insert(
+                    d.typname.as_str(),
+                    Surface {
+                        family: &family.token,
+                        ops: &d.supported_ops,
+                        terms: &d.terms,
+                        aggregates: d.aggregates.as_deref(),
+                    },
+                );
+            }
+        }
+        // The json family's inventory carries no operator list: its surface is
+        // hand-written SQL, so only the domain and its extractors are compared.
+        for d in &self.stevec {
+            out.insert(
+                d.typname.as_str(),
+                Surface {
+                    family: "json",
+                    ops: &[],
+                    terms: &d.terms,
+                    aggregates: None,
+                },
+            );
+        }
+        out
+    }
+}
+
+/// One domain's comparable surface.
+struct Surface<'a> {
+    family: &'a str,
+    ops: &'a [String],
+    terms: &'a [TermSnapshot],
+    aggregates: Option<&'a [String]>,
+}
+
+/// One entry in the changelog. Serializes with a `kind` tag, e.g.
+/// `{"kind":"operator_removed","typname":"eql_v3_text_eq","op":"<>"}`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Change {
+    DomainAdded {
+        family: String,
+        typname: String,
+        supported_ops: Vec<String>,
+    },
+    DomainRemoved {
+        family: String,
+        typname: String,
+        supported_ops: Vec<String>,
+    },
+    OperatorAdded {
+        typname: String,
+        op: String,
+    },
+    OperatorRemoved {
+        typname: String,
+        op: String,
+    },
+    ExtractorAdded {
+        typname: String,
+        extractor: String,
+    },
+    ExtractorRemoved {
+        typname: String,
+        extractor: String,
+    },
+    /// The extractor survives but reads a different payload key or returns a
+    /// different SEM type — an index built on it must be rebuilt.
+    ExtractorChanged {
+        typname: String,
+        extractor: String,
+        from: String,
+        to: String,
+    },
+    AggregateAdded {
+        typname: String,
+        aggregate: String,
+    },
+    AggregateRemoved {
+        typname: String,
+        aggregate: String,
+    },
+}
+
+impl Change {
+    /// True when existing SQL written against the old release can stop working:
+    /// any removal, and any extractor whose term changed underneath it.
+    pub fn is_breaking(&self) -> bool {
+        matches!(
+            self,
+            Self::DomainRemoved { .. }
+                | Self::OperatorRemoved { .. }
+                | Self::ExtractorRemoved { .. }
+                | Self::ExtractorChanged { .. }
+                | Self::AggregateRemoved { .. }
+        )
+    }
+}
+
+/// The changelog between two catalogs, split by whether callers must act.
+#[derive(Debug, Default, Serialize)]
+pub struct CatalogDiff {
+    pub breaking: Vec<Change>,
+    pub additive: Vec<Change>,
+}
+
+impl CatalogDiff {
+    pub fn is_empty(&self) -> bool {
+        self.breaking.is_empty() && self.additive.is_empty()
+    }
+
+    fn push(&mut self, change: Change) {
+        if change.is_breaking() {
+            self.breaking.push(change);
+        } else {
+            self.additive.push(change);
+        }
+    }
+}
+
+/// Compare `old` against `new`. Entries are grouped by domain in typname order;
+/// within a domain, operators and aggregates keep their catalog order.
+pub fn diff_catalogs(old: &CatalogSnapshot, new: &CatalogSnapshot) -> CatalogDiff {
+    let old_surfaces = old.surfaces();
+    let new_surfaces = new.surfaces();
+    let mut diff = CatalogDiff::default();
+
+    let typnames: BTreeSet<&str> = old_surfaces
+        .keys()
+        .chain(new_surfaces.keys())
+        .copied()
+        .collect();
+
+    for typname in typnames {
+        match (old_surfaces.get(typname), new_surfaces.get(typname)) {
+            (Some(o), None) => diff.push(Change::DomainRemoved {
+                family: o.family.to_string(),
+                typname: typname.to_string(),
+                supported_ops: o.ops.to_vec(),
+            }),
+            (None, Some(n)) => diff.push(Change::DomainAdded {
+                family: n.family.to_string(),
+                typname: typname.to_string(),
+                supported_ops: n.ops.to_vec(),
+            }),
+            (Some(o), Some(n)) => diff_domain(typname, o, n, &mut diff),
+            (None, None) => unreachable!("typname drawn from one of the two catalogs"),
+        }
+    }
+    diff
+}
+
+fn diff_domain(typname: &str, old: &Surface, new: &Surface, diff: &mut CatalogDiff) {
+    let t = || typname.to_string();
+
+    for op in removed(old.ops, new.ops) {
+        diff.push(Change::OperatorRemoved { typname: t(), op });
+    }
+    for op in removed(new.ops, old.ops) {
+        diff.push(Change::OperatorAdded { typname: t(), op });
+    }
+
+    for term in old.terms {
+        match new.terms.iter().find(|n| n.extractor == term.extractor) {
+            None => diff.push(Change::ExtractorRemoved {
+                typname: t(),
+                extractor: term.extractor.clone(),
+            }),
+            Some(n) if n != term => diff.push(Change::ExtractorChanged {
+                typname: t(),
+                extractor: term.extractor.clone(),
+                from: format!("{} -> {}", term.key, term.ctor),
+                to: format!("{} -> {}", n.key, n.ctor),
+            }),
+            Some(_) => {}
+        }
+    }
+    for term in new.terms {
+        if !old.terms.iter().any(|o| o.extractor == term.extractor) {
+            diff.push(Change::ExtractorAdded {
+                typname: t(),
+                extractor: term.extractor.clone(),
+            });
+        }
+    }
+
+    if let (Some(old_aggs), Some(new_aggs)) = (old.aggregates, new.aggregates) {
+        for aggregate in removed(old_aggs, new_aggs) {
+            diff.push(Change::AggregateRemoved {
+                typname: t(),
+                aggregate,
+            });
+        }
+        for aggregate in removed(new_aggs, old_aggs) {
+            diff.push(Change::AggregateAdded {
+                typname: t(),
+                aggregate,
+            });
+        }
+    }
+}
+
+/// Entries of `from` that `to` does not carry, in `from` order.
+fn removed(from: &[String], to: &[String]) -> Vec<String> {
+    from.iter().filter(|x| !to.contains(x)).cloned().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(json: serde_json::Value) -> CatalogSnapshot {
+        serde_json::from_value(json).expect("test snapshot")
+    }
+
+    fn text_catalog(eq_ops: &[&str], eq_ctor: &str) -> CatalogSnapshot {
+        snapshot(serde_json::json!({
+            "types": [{ "token": "text", "is_eq_only": false, "domains": [
+                { "segment": "storage", "suffix": "", "typname": "eql_v3_text",
+                  "supported_ops": [], "terms": [], "aggregates": [] },
+                { "segment": "eq", "suffix": "_eq", "typname": "eql_v3_text_eq",
+                  "supported_ops": eq_ops,
+                  "terms": [{ "key": "hm", "extractor": "eq_term", "ctor": eq_ctor }],
+                  "aggregates": [] }
+            ]}],
+            "stevec": []
+        }))
+    }
+
+    #[test]
+    fn live_catalog_has_no_diff_against_itself() {
+        let live = CatalogSnapshot::live();
+        assert!(diff_catalogs(&live, &live).is_empty());
+    }
+
+    #[test]
+    fn removed_operator_and_domain_are_breaking() {
+        let old = text_catalog(&["=", "<>"], "hmac_256");
+        let mut new = text_catalog(&["="], "hmac_256");
+        new.types[0].domains.remove(0);
+
+        let diff = diff_catalogs(&old, &new);
+        assert!(diff.additive.is_empty());
+        assert_eq!(
+            diff.breaking,
+            [
+                Change::DomainRemoved {
+                    family: "text".into(),
+                    typname: "eql_v3_text".into(),
+                    supported_ops: vec![],
+                },
+                Change::OperatorRemoved {
+                    typname: "eql_v3_text_eq".into(),
+                    op: "<>".into(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn added_operator_and_domain_are_additive() {
+        let old = text_catalog(&["="], "hmac_256");
+        let new = CatalogSnapshot::live();
+
+        let diff = diff_catalogs(&old, &new);
+        assert!(diff.breaking.is_empty(), "{:?}", diff.breaking);
+        assert!(diff.additive.contains(&Change::OperatorAdded {
+            typname: "eql_v3_text_eq".into(),
+            op: "<>".into(),
+        }));
+        assert!(diff.additive.contains(&Change::DomainAdded {
+            family: "integer".into(),
+            typname: "eql_v3_integer_ord".into(),
+            supported_ops: ["=", "<>", "<", "<=", ">", ">="].map(String::from).to_vec(),
+        }));
+    }
+
+    #[test]
+    fn extractor_reading_a_different_term_is_breaking() {
+        let old = text_catalog(&["=", "<>"], "hmac_256");
+        let new = text_catalog(&["=", "<>"], "hmac_512");
+
+        let diff = diff_catalogs(&old, &new);
+        assert_eq!(
+            diff.breaking,
+            [Change::ExtractorChanged {
+                typname: "eql_v3_text_eq".into(),
+                extractor: "eq_term".into(),
+                from: "hm -> hmac_256".into(),
+                to: "hm -> hmac_512".into(),
+            }]
+        );
+    }
+
+    #[test]
+    fn dumps_without_aggregates_do_not_report_them_added() {
+        // An older dump: no `arrays` section and no per-domain `aggregates`.
+        let old = snapshot(serde_json::json!({
+            "types": [{ "token": "integer", "is_eq_only": false, "domains": [
+                { "segment": "ord", "suffix": "_ord", "typname": "eql_v3_integer_ord",
+                  "supported_ops": ["=", "<>", "<", "<=", ">", ">="],
+                  "terms": [{ "key": "op", "extractor": "ord_term", "ctor": "ope_cllw" }] }
+            ]}],
+            "stevec": []
+        }));
+        let diff = diff_catalogs(&old, &CatalogSnapshot::live());
+        assert!(!diff.additive.iter().any(|c| matches!(
+            c,
+            Change::AggregateAdded { typname, .. } if typname == "eql_v3_integer_ord"
+        )));
+
+        let mut no_min = CatalogSnapshot::live();
+        let ord = no_min
+            .types
+            .iter_mut()
+            .flat_map(|t| &mut t.domains)
+            .find(|d| d.typname == "eql_v3_integer_ord")
+            .unwrap();
+        ord.aggregates = Some(vec!["max".into()]);
+        let diff = diff_catalogs(&CatalogSnapshot::live(), &no_min);
+        assert_eq!(
+            diff.breaking,
+            [Change::AggregateRemoved {
+                typname: "eql_v3_integer_ord".into(),
+                aggregate: "min".into(),
+            }]
+        );
+    }
+
+    #[test]
+    fn changes_serialize_with_a_kind_tag() {
+        let change = Change::OperatorRemoved {
+            typname: "eql_v3_text_eq".into(),
+            op: "<>".into(),
+        };
+        assert_eq!(
+            serde_json::to_value(&change).unwrap(),
+            serde_json::json!({"kind": "operator_removed", "typname": "eql_v3_text_eq", "op": "<>"})
+        );
+    }
+}
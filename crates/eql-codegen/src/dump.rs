@@ -5,14 +5,23 @@
 //! Stage 1 consumes the `(type, domain)` shape; later stages consume the
 //! per-domain `supported_ops`. Blocked-operator tagging is added in Stage 4.
 
-use eql_domains::Term;
+use eql_domains::{Domain, DomainFamily, Shape};
 use serde::Serialize;
 
-/// The catalog surface: every scalar type and its domains, plus the non-scalar
-/// SteVec (`json`) family.
+use crate::context::{is_ord_capable, AGGREGATE_OPS};
+
+/// The catalog surface: every scalar type and its domains, the array
+/// families, and the non-scalar SteVec (`json`) family.
 #[derive(Serialize)]
 pub struct CatalogDump {
     pub types: Vec<TypeEntry>,
+    /// The array families (`integer_array`, `text_array`): generated like the
+    /// scalar types, but kept out of `types` for the same reason the json
+    /// storage domain is — `types` is the scalar-*matrix* surface, and an
+    /// array domain's containment operators have no `matrix_*` suite. Each
+    /// `_contains` domain's `supported_ops` and `terms` are its per-element
+    /// ones (`eq_terms` / `hmac_256_array`).
+    pub arrays: Vec<TypeEntry>,
     /// The whole `json` family inventory — the SteVec domains
     /// (`public.eql_v3_json_search` / `public.eql_v3_json_entry` /
     /// `eql_v3.query_json`) **and** the bare `public.eql_v3_json` storage
@@ -55,6 +64,9 @@ pub struct DomainEntry {
     pub supported_ops: Vec<&'static str>,
     /// The index terms this domain carries, with their extractor + SEM ctor.
     pub terms: Vec<TermInfo>,
+    /// Generated aggregates over the domain (`min` / `max`), present only on
+    /// ord-capable domains.
+    pub aggregates: Vec<&'static str>,
 }
 
 /// A domain's index term: payload key + generated extractor + SEM constructor
@@ -95,17 +107,62 @@ pub struct SteVecEntry {
     pub scalar: bool,
 }
 
-fn term_infos(terms: &[Term]) -> Vec<TermInfo> {
-    terms
+fn term_infos(domain: &Domain) -> Vec<TermInfo> {
+    domain
+        .terms
         .iter()
-        .map(|t| TermInfo {
-            key: t.json_key(),
-            extractor: t.extractor(),
-            ctor: t.ctor(),
+        .map(|t| match domain.shape {
+            Shape::Array => TermInfo {
+                key: t.json_key(),
+                extractor: t
+                    .element_extractor()
+                    .expect("array domains carry only per-element terms"),
+                ctor: t
+                    .element_ctor()
+                    .expect("array domains carry only per-element terms"),
+            },
+            Shape::Scalar | Shape::SteVec => TermInfo {
+                key: t.json_key(),
+                extractor: t.extractor(),
+                ctor: t.ctor(),
+            },
         })
         .collect()
 }
 
+/// One family's `TypeEntry`: every domain, with its shape-resolved operators.
+fn type_entry(spec: &DomainFamily) -> TypeEntry {
+    let domains = spec
+        .domains
+        .iter()
+        .map(|d| DomainEntry {
+            segment: if d.name.is_empty() {
+                "storage".to_string()
+            } else {
+                d.name.to_string()
+            },
+            typname: d.sql_typname(spec.name),
+            suffix: if d.name.is_empty() {
+                String::new()
+            } else {
+                format!("_{}", d.name)
+            },
+            supported_ops: d.operators(),
+            terms: term_infos(d),
+            aggregates: if is_ord_capable(d.terms) {
+                AGGREGATE_OPS.iter().map(|a| a.name).collect()
+            } else {
+                Vec::new()
+            },
+        })
+        .collect();
+    TypeEntry {
+        token: spec.name,
+        is_eq_only: spec.is_eq_only(),
+        domains,
+    }
+}
+
 /// Index terms for one `json` (SteVec) domain, hardcoded for now.
 ///
 /// The catalog does not model per-SteVec-entry terms — `JSON_DOMAINS` declare
@@ -132,33 +189,12 @@ fn stevec_terms(name: &str) -> Vec<TermInfo> {
 
 /// Build the catalog surface description from `eql_domains::CATALOG`.
 pub fn dump_catalog() -> CatalogDump {
-    let types = eql_domains::scalar_families()
-        .map(|spec| {
-            let domains = spec
-                .domains
-                .iter()
-                .map(|d| DomainEntry {
-                    segment: if d.name.is_empty() {
-                        "storage".to_string()
-                    } else {
-                        d.name.to_string()
-                    },
-                    typname: d.sql_typname(spec.name),
-                    suffix: if d.name.is_empty() {
-                        String::new()
-                    } else {
-                        format!("_{}", d.name)
-                    },
-                    supported_ops: Term::operators_for_terms(d.terms),
-                    terms: term_infos(d.terms),
-                })
-                .collect();
-            TypeEntry {
-                token: spec.name,
-                is_eq_only: spec.is_eq_only(),
-                domains,
-            }
-        })
+    let types = eql_domains::scalar_families().map(type_entry).collect();
+
+    // Generated, but not scalar-matrix types: see `CatalogDump::arrays`.
+    let arrays = eql_domains::families_with_scalar_domains()
+        .filter(|spec| spec.domains.iter().any(Domain::is_array))
+        .map(type_entry)
         .collect();
 
     // The hand-written SteVec (jsonb) family — catalog inventory only. Kept out
@@ -184,7 +220,11 @@ pub fn dump_catalog() -> CatalogDump {
         })
         .collect();
 
-    CatalogDump { types, stevec }
+    CatalogDump {
+        types,
+        arrays,
+        stevec,
+    }
 }
 
 #[cfg(test)]
@@ -225,6 +265,10 @@ mod tests {
             .find(|d| d.segment == "ord_ope")
             .unwrap();
         assert_eq!(ord_ope.supported_ops, ["=", "<>", "<", "<=", ">", ">="]);
+
+        // Only the ordered domains get min/max.
+        assert!(eq.aggregates.is_empty());
+        assert_eq!(ord.aggregates, ["min", "max"]);
     }
 
     #[test]
@@ -329,6 +373,30 @@ mod tests {
         assert_eq!(ord.supported_ops, ["=", "<>", "<", "<=", ">", ">="]);
     }
 
+    #[test]
+    fn array_families_are_dumped_apart_from_the_scalar_types() {
+        let dump = dump_catalog();
+        let tokens: Vec<&str> = dump.arrays.iter().map(|t| t.token).collect();
+        assert_eq!(tokens, ["integer_array", "text_array"]);
+        assert!(!dump.types.iter().any(|t| tokens.contains(&t.token)));
+
+        let text_array = &dump.arrays[1];
+        let segments: Vec<&str> = text_array
+            .domains
+            .iter()
+            .map(|d| d.segment.as_str())
+            .collect();
+        assert_eq!(segments, ["storage", "contains"]);
+        let contains = &text_array.domains[1];
+        assert_eq!(contains.typname, "eql_v3_text_array_contains");
+        assert_eq!(contains.supported_ops, ["@>", "<@", "&&"]);
+        // The per-element extractor, not the scalar `eq_term`.
+        assert_eq!(contains.terms.len(), 1);
+        assert_eq!(contains.terms[0].key, "hm");
+        assert_eq!(contains.terms[0].extractor, "eq_terms");
+        assert_eq!(contains.terms[0].ctor, "hmac_256_array");
+    }
+
     #[test]
     fn dump_catalog_excludes_non_scalar_json() {
         // `dump_catalog` (and, via the same `scalar_families()` filter, the CLI
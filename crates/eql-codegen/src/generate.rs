@@ -5,7 +5,7 @@ use std::path::{Path, PathBuf};
 use eql_domains::{Domain, DomainFamily, Role, Term};
 
 use crate::context::{domain_name, is_ord_capable, query_domain_name};
-use crate::operator_surface::OPERATORS;
+use crate::operator_surface::{surface_operators, OPERATORS};
 
 /// REQUIRE edge for the v3 schema file — pulled in by every generated file.
 const V3_SCHEMA: &str = "src/v3/schema.sql";
@@ -89,13 +89,14 @@ pub fn render_types_file(spec: &DomainFamily) -> String {
     use crate::context::{domain_block, environment, TypesContext};
     let ctx = TypesContext {
         family_name: spec.name.to_string(),
-        // Only scalar domains are generated. For a fully-scalar family this is
-        // every domain (unchanged); for a mixed family (jsonb) it renders the
-        // bare scalar storage domain and skips the hand-written SteVec domains.
+        // Only generated domains are rendered. For a fully-scalar or array
+        // family this is every domain; for the mixed json family it renders
+        // the bare scalar storage domain and skips the hand-written SteVec
+        // domains.
         domains: spec
             .domains
             .iter()
-            .filter(|d| d.is_scalar())
+            .filter(|d| d.is_generated())
             .map(|d| domain_block(spec.name, d))
             .collect(),
     };
@@ -147,20 +148,18 @@ fn functions_requires(family_name: &str, terms: &[Term]) -> Vec<String> {
 pub fn render_functions_file(family_name: &str, domain: &Domain) -> String {
     use crate::consts::sql_str;
     use crate::context::{
-        environment, extractor_entry, unsupported_entry, wrapper_entry, FunctionsContext, SqlParam,
+        environment, extractor_entries, unsupported_entry, wrapper_entry, FunctionsContext,
+        SqlParam,
     };
     let name = domain.full_name(family_name);
     let dom = domain_name(&name);
     let domain_lit = sql_str(&dom);
-    let supported = Term::operators_for_terms(domain.terms);
+    let supported = domain.operators();
     let is_supported = |op: &str| supported.contains(&op);
 
-    let mut entries = Vec::new();
-    for term in Term::extractor_terms(domain.terms) {
-        entries.push(extractor_entry(term));
-    }
-    for op in OPERATORS {
-        let extractor = Term::extractor_for_operator(domain.terms, op.symbol.as_str());
+    let mut entries = extractor_entries(domain);
+    for op in surface_operators(domain) {
+        let extractor = domain.extractor_for_operator(op.symbol.as_str());
         for sig in op.signatures {
             let rendered = sig.render(&dom);
             // A `blocker_only` overload (the `@@` jsonpath predicate) always falls
@@ -205,11 +204,11 @@ pub fn render_operators_file(family_name: &str, domain: &Domain) -> String {
     use crate::context::{environment, operator_entry, OperatorsContext};
     let name = domain.full_name(family_name);
     let dom = domain_name(&name);
-    let supported = Term::operators_for_terms(domain.terms);
+    let supported = domain.operators();
     let is_supported = |op: &str| supported.contains(&op);
 
     let mut operators = Vec::new();
-    for op in OPERATORS {
+    for op in surface_operators(domain) {
         for sig in op.signatures {
             // CREATE OPERATOR only needs the operand types; `rendered.returns` is
             // intentionally discarded here (it matters only for the function body).
@@ -258,21 +257,18 @@ fn query_types_path(family_name: &str) -> String {
 pub fn render_query_functions_file(family_name: &str, domain: &Domain) -> String {
     use crate::consts::sql_str;
     use crate::context::{
-        domain_name, environment, extractor_entry, query_domain_name, wrapper_entry,
+        domain_name, environment, extractor_entries, query_domain_name, wrapper_entry,
         FunctionsContext,
     };
     let name = domain.full_name(family_name);
     let query_name = domain.query_name(family_name);
     let storage_dom = domain_name(&name);
     let query_dom = query_domain_name(&query_name);
-    let supported = Term::operators_for_terms(domain.terms);
+    let supported = domain.operators();
 
-    let mut entries = Vec::new();
     // Extractor overloads on the query domain (the template renders `a {{ dom }}`
     // with dom = the query domain).
-    for term in Term::extractor_terms(domain.terms) {
-        entries.push(extractor_entry(term));
-    }
+    let mut entries = extractor_entries(domain);
     // Comparison wrappers: (storage, query) and its (query, storage) commutator,
     // for supported operators only (a query operand is never sent for a blocked
     // operator). `is_supported(op) ⟹ extractor_for_operator is Some`.
@@ -285,11 +281,12 @@ pub fn render_query_functions_file(family_name: &str, domain: &Domain) -> String
     // unblocked cases are nonsensical `operand <op> operand` / `operand <op>
     // jsonb`, which no caller writes; blocking them would mean emitting the full
     // blocker matrix against every query twin for zero real-world coverage.
-    for op in OPERATORS {
+    for op in surface_operators(domain) {
         if !supported.contains(&op.symbol.as_str()) {
             continue;
         }
-        let extractor = Term::extractor_for_operator(domain.terms, op.symbol.as_str())
+        let extractor = domain
+            .extractor_for_operator(op.symbol.as_str())
             .expect("a supported operator resolves an extractor");
         entries.push(wrapper_entry(
             &query_dom,
@@ -338,10 +335,10 @@ pub fn render_query_operators_file(family_name: &str, domain: &Domain) -> String
     let query_name = domain.query_name(family_name);
     let storage_dom = domain_name(&name);
     let query_dom = query_domain_name(&query_name);
-    let supported = Term::operators_for_terms(domain.terms);
+    let supported = domain.operators();
 
     let mut operators = Vec::new();
-    for op in OPERATORS {
+    for op in surface_operators(domain) {
         if !supported.contains(&op.symbol.as_str()) {
             continue;
         }
@@ -906,6 +903,35 @@ pub fn render_ore_fallback_file() -> String {
         .expect("render ore_fallback.sql")
 }
 
+/// Body for the cross-family `src/v3/scalars/index_snapshot.sql`.
+///
+/// `eql_v3.snapshot_indexes()` / `eql_v3.replay_indexes()` match indexes by the
+/// extractor they call, so the extractor list is taken from the catalog: every
+/// term's scalar extractor plus its per-element one (`eq_terms`), deduplicated
+/// and sorted. The hand-written json extractors share those names.
+pub fn render_index_snapshot_file() -> String {
+    use crate::context::{environment, public_typname, IndexSnapshotContext};
+
+    let mut extractors: Vec<&'static str> = eql_domains::CATALOG
+        .iter()
+        .flat_map(|f| f.domains.iter())
+        .flat_map(|d| d.terms.iter())
+        .flat_map(|t| std::iter::once(t.extractor()).chain(t.element_extractor()))
+        .collect();
+    extractors.sort_unstable();
+    extractors.dedup();
+    let ctx = IndexSnapshotContext {
+        requires: vec![V3_SCHEMA.to_string()],
+        table: public_typname("index_snapshot"),
+        extractors,
+    };
+    environment()
+        .get_template("index_snapshot.sql")
+        .unwrap()
+        .render(&ctx)
+        .expect("render index_snapshot.sql")
+}
+
 use std::fs;
 
 use crate::writer::{
@@ -932,11 +958,11 @@ pub fn render_type(spec: &DomainFamily, out_dir: &Path) -> Vec<(PathBuf, String)
             render_query_types_file(spec),
         ));
     }
-    // Generate only scalar domains: identical to iterating every domain for a
-    // fully-scalar family; for a mixed family (jsonb) it emits the bare scalar
-    // storage surface and skips the hand-written SteVec domains under
-    // `src/v3/json/`.
-    for d in spec.domains.iter().filter(|d| d.is_scalar()) {
+    // Generate only scalar and array domains: identical to iterating every
+    // domain for a fully-scalar or array family; for the json family it emits
+    // the bare scalar storage surface and skips the hand-written SteVec
+    // domains under `src/v3/json/`.
+    for d in spec.domains.iter().filter(|d| d.is_generated()) {
         let name = d.full_name(family_name);
         rendered.push((
             out_dir.join(format!("{name}_functions.sql")),
@@ -1018,9 +1044,9 @@ pub fn generate_type(spec: &DomainFamily, out_dir: &Path) -> Result<Vec<PathBuf>
 pub fn generate_all(out_root: &Path) -> Result<i32, WriteError> {
     let scalars_root = out_root.join(V3_SCALARS_DIR);
     let mut all_written: Vec<PathBuf> = Vec::new();
-    // Every family with at least one scalar domain: fully-scalar families are
-    // unchanged; a mixed family (jsonb) contributes only its scalar storage
-    // domain (the per-domain renderers filter `is_scalar()`).
+    // Every family with at least one scalar domain: fully-scalar and array
+    // families generate every domain; the json family contributes only its
+    // scalar storage domain (the per-domain renderers filter `is_generated()`).
     for spec in eql_domains::families_with_scalar_domains() {
         let family_name = spec.name;
         let out_dir = scalars_root.join(family_name);
@@ -1052,6 +1078,22 @@ pub fn generate_all(out_root: &Path) -> Result<i32, WriteError> {
     }
     all_written.push(fallback_path);
 
+    // Cross-family index snapshot/replay, depth-1 beside ore_fallback.sql.
+    let snapshot_path = scalars_root.join("index_snapshot.sql");
+    ensure_generated_paths_writable(std::slice::from_ref(&snapshot_path), GeneratedKind::Sql)?;
+    write_generated_file(
+        &snapshot_path,
+        &render_index_snapshot_file(),
+        GeneratedKind::Sql,
+    )?;
+    {
+        let rel = snapshot_path
+            .strip_prefix(out_root)
+            .unwrap_or(&snapshot_path);
+        println!("generated {}", rel.display());
+    }
+    all_written.push(snapshot_path);
+
     // Orphan sweep across every scalar type dir. `generate_type` already prunes
     // stale files *within* a regenerated dir, but a type dropped from the catalog
     // entirely leaves a dir the generator never revisits — its generated SQL must
@@ -1078,7 +1120,8 @@ pub fn generate_all(out_root: &Path) -> Result<i32, WriteError> {
                 println!("removed orphan {}", rel.display());
             }
         }
-        // Depth-1 sweep for cross-family generated files (ore_fallback.sql).
+        // Depth-1 sweep for cross-family generated files (ore_fallback.sql,
+        // index_snapshot.sql).
         // Marker-aware, so the hand-written depth-1 functions.sql (no
         // AUTO-GENERATED marker) is never touched.
         for removed in remove_generated_orphans(&scalars_root, GeneratedKind::Sql, &keep)? {
@@ -1919,6 +1962,26 @@ mod tests {
         assert!(!create_fn.contains("RETURNS NULL ON NULL INPUT"));
     }
 
+    #[test]
+    fn index_snapshot_matches_every_catalog_extractor() {
+        // A term added to the catalog must be covered by the snapshot without
+        // touching the template: its extractor (and per-element extractor, if
+        // any) appears in the rendered `proname IN (...)` list.
+        let sql = render_index_snapshot_file();
+        let list = sql
+            .lines()
+            .find(|l| l.trim_start().starts_with("AND p.proname IN ("))
+            .expect("extractor filter present");
+        for term in [Term::Hm, Term::Ore, Term::Bloom, Term::Ope] {
+            assert!(list.contains(&format!("'{}'", term.extractor())), "{list}");
+            if let Some(element) = term.element_extractor() {
+                assert!(list.contains(&format!("'{element}'")), "{list}");
+            }
+        }
+        assert!(sql.contains("CREATE TABLE IF NOT EXISTS public.eql_v3_index_snapshot ("));
+        assert!(sql.contains("CREATE FUNCTION eql_v3.replay_indexes()"));
+    }
+
     #[test]
     fn ore_fallback_poison_constraints_are_not_valid() {
         // ALTER DOMAIN ... ADD CONSTRAINT validates existing stored data, and
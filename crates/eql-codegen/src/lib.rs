@@ -10,10 +10,12 @@ use std::path::PathBuf;
 pub mod bindings;
 pub mod consts;
 pub mod context;
+pub mod diff;
 pub mod dump;
 pub mod generate;
 pub mod operator_surface;
 pub mod ordering;
+pub mod upgrade;
 pub mod writer;
 
 /// The repository root, derived from this crate's manifest dir (the generator
@@ -1,8 +1,10 @@
 use std::path::PathBuf;
 use std::process::ExitCode;
 
+use eql_codegen::diff::{diff_catalogs, CatalogSnapshot};
 use eql_codegen::generate::{clean_all, generate_all};
 use eql_codegen::repo_root;
+use eql_codegen::upgrade::render_upgrade_from_root;
 
 /// Output root for the SQL surface. Defaults to the repo root; overridable via
 /// `EQL_CODEGEN_OUT_ROOT` so `tasks/codegen-parity.sh` can regenerate into a
@@ -14,6 +16,12 @@ fn out_root() -> PathBuf {
     }
 }
 
+/// Read a `dump-catalog` JSON file, for `diff-catalog` and `upgrade`.
+fn read_catalog(path: &str) -> Result<CatalogSnapshot, String> {
+    let json = std::fs::read_to_string(path).map_err(|e| format!("reading {path}: {e}"))?;
+    CatalogSnapshot::from_json(&json).map_err(|e| format!("parsing {path}: {e}"))
+}
+
 fn main() -> ExitCode {
     let args: Vec<String> = std::env::args().collect();
 
@@ -49,6 +57,61 @@ fn main() -> ExitCode {
         return ExitCode::SUCCESS;
     }
 
+    // `diff-catalog <old.json> [<new.json>]`: compare two `dump-catalog`
+    // outputs (or one against the current tree's catalog when `<new.json>` is
+    // omitted) and print the changelog as JSON, breaking changes apart from
+    // additive ones. Used when planning a release upgrade.
+    if (args.len() == 3 || args.len() == 4) && args[1] == "diff-catalog" {
+        let snapshots = read_catalog(&args[2]).and_then(|old| {
+            let new = match args.get(3) {
+                Some(path) => read_catalog(path)?,
+                None => CatalogSnapshot::live(),
+            };
+            Ok((old, new))
+        });
+        match snapshots {
+            Ok((old, new)) => {
+                let diff = diff_catalogs(&old, &new);
+                println!(
+                    "{}",
+                    serde_json::to_string_pretty(&diff).expect("serialize catalog diff")
+                );
+                return ExitCode::SUCCESS;
+            }
+            Err(e) => {
+                eprintln!("error: {e}");
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+
+    // `upgrade <from-version> [<from-catalog.json>]`: print an in-place upgrade
+    // script from release <from-version> to the current surface — the same files
+    // in the same order as `order`, with every drop-and-recreate rewritten to a
+    // replace, so functional indexes on the extractors survive. The optional
+    // catalog (a `dump-catalog` file from the old release) lets it drop
+    // extractors whose term changed up front. Consumed by tasks/build.sh
+    // (`--upgrade-from`).
+    if (args.len() == 3 || args.len() == 4) && args[1] == "upgrade" {
+        let from_catalog = match args.get(3).map(|path| read_catalog(path)).transpose() {
+            Ok(catalog) => catalog,
+            Err(e) => {
+                eprintln!("error: {e}");
+                return ExitCode::FAILURE;
+            }
+        };
+        match render_upgrade_from_root(&out_root(), &args[2], from_catalog.as_ref()) {
+            Ok(script) => {
+                print!("{script}");
+                return ExitCode::SUCCESS;
+            }
+            Err(e) => {
+                eprintln!("error: {e}");
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+
     // `bindings`: regenerate the committed Rust payload bindings under
     // crates/eql-bindings/src/v3. The default no-arg run stays SQL-only; this
     // is wired as the first step of `mise run types:generate`.
@@ -138,6 +201,8 @@ fn main() -> ExitCode {
     eprintln!("       eql-codegen list-types (print catalog tokens)");
     eprintln!("       eql-codegen list-schemas (print owned schemas, public first)");
     eprintln!("       eql-codegen dump-catalog (print catalog surface as JSON)");
+    eprintln!("       eql-codegen diff-catalog <old.json> [<new.json>] (changelog between two dumps; default new = current catalog)");
+    eprintln!("       eql-codegen upgrade <from-version> [<from-catalog.json>] (print an in-place upgrade script)");
     eprintln!("       eql-codegen bindings   (regenerate eql-bindings Rust payload types)");
     ExitCode::from(2)
 }
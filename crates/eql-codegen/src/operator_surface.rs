@@ -50,6 +50,8 @@ pub enum OpSymbol {
     HashMinus,
     /// `||`
     Concat,
+    /// `&&`
+    Overlaps,
 }
 
 impl OpSymbol {
@@ -78,6 +80,7 @@ impl OpSymbol {
             OpSymbol::Minus => "-",
             OpSymbol::HashMinus => "#-",
             OpSymbol::Concat => "||",
+            OpSymbol::Overlaps => "&&",
         }
     }
 }
@@ -222,7 +225,7 @@ const fn sig_blocker(left: TypeSlot, right: TypeSlot, returns: TypeSlot) -> Oper
 }
 
 /// Symmetric boolean overloads (`domain`/`jsonb` convenience pairs), shared by
-/// `=`, `<>`, `<`, `<=`, `>`, `>=`, `@>`, `<@`.
+/// `=`, `<>`, `<`, `<=`, `>`, `>=`, `@>`, `<@`, `&&`.
 const BOOL_SYMMETRIC_SIGNATURES: &[OperatorSignature] = &[
     sig(TypeSlot::Domain, TypeSlot::Domain, TypeSlot::Boolean),
     sig(TypeSlot::Domain, TypeSlot::Jsonb, TypeSlot::Boolean),
@@ -298,11 +301,13 @@ const CONCAT_SIGNATURES: &[OperatorSignature] = &[
     sig(TypeSlot::Jsonb, TypeSlot::Domain, TypeSlot::Jsonb),
 ];
 
-/// Look up the operator metadata for a symbol. Panics on an unknown symbol —
-/// the generator only ever passes catalog symbols, matching Python's KeyError.
+/// Look up the operator metadata for a symbol in `OPERATORS` or
+/// `ARRAY_OPERATORS`. Panics on an unknown symbol — the generator only ever
+/// passes catalog symbols, matching Python's KeyError.
 pub fn operator(symbol: &str) -> Operator {
     OPERATORS
         .iter()
+        .chain(ARRAY_OPERATORS)
         .copied()
         .find(|o| o.symbol.as_str() == symbol)
         .unwrap_or_else(|| panic!("unknown operator symbol: {symbol}"))
@@ -316,13 +321,13 @@ pub fn operator_function_name(symbol: &str) -> &'static str {
 impl Operator {
     /// True for the native-jsonb operators that every encrypted domain
     /// generates as BLOCKERS: those that are neither comparison
-    /// (`=`/`<>`/`<`/`<=`/`>`/`>=`), nor containment (`@>`/`<@`), nor
+    /// (`=`/`<>`/`<`/`<=`/`>`/`>=`), nor containment (`@>`/`<@`/`&&`), nor
     /// path-selectors (`->`/`->>`). Derived by exclusion so a 21st operator
     /// added to `OPERATORS` is automatically classified — no literal list to
     /// drift out of sync.
     pub fn is_native_jsonb_blocker(&self) -> bool {
         const COMPARISON: &[&str] = &["=", "<>", "<", "<=", ">", ">="];
-        const CONTAINMENT: &[&str] = &["@>", "<@"];
+        const CONTAINMENT: &[&str] = &["@>", "<@", "&&"];
         const PATH_SELECTOR: &[&str] = &["->", "->>"];
         let symbol = self.symbol.as_str();
         !COMPARISON.contains(&symbol)
@@ -360,7 +365,8 @@ impl Operator {
             | OpSymbol::HashArrowArrow
             | OpSymbol::Minus
             | OpSymbol::HashMinus
-            | OpSymbol::Concat => self.function_name,
+            | OpSymbol::Concat
+            | OpSymbol::Overlaps => self.function_name,
         }
     }
 
@@ -396,7 +402,8 @@ impl Operator {
             | OpSymbol::HashArrowArrow
             | OpSymbol::Minus
             | OpSymbol::HashMinus
-            | OpSymbol::Concat => self.symbol.as_str(),
+            | OpSymbol::Concat
+            | OpSymbol::Overlaps => self.symbol.as_str(),
         }
     }
 
@@ -456,8 +463,8 @@ const fn cmp_metadata(
     }
 }
 
-/// Containment-operator metadata (`@>` / `<@`): commutator is the mirror
-/// operator, no negator (a non-containment is not another listed operator),
+/// Containment-operator metadata (`@>` / `<@`, and the self-commuting `&&`):
+/// commutator is the mirror operator, no negator (a non-containment is not another listed operator),
 /// containment selectivity estimators.
 const fn containment_metadata(commutator: &'static str) -> OperatorMetadata {
     OperatorMetadata {
@@ -481,6 +488,19 @@ const fn match_metadata() -> OperatorMetadata {
     }
 }
 
+/// The operators a domain's surface is rendered over, in order: `OPERATORS`
+/// for every generated domain, then `ARRAY_OPERATORS` on a
+/// [`eql_domains::Shape::Array`] domain. A scalar domain's surface is exactly
+/// `OPERATORS`.
+pub fn surface_operators(domain: &eql_domains::Domain) -> impl Iterator<Item = &'static Operator> {
+    let array: &'static [Operator] = if domain.is_array() {
+        ARRAY_OPERATORS
+    } else {
+        &[]
+    };
+    OPERATORS.iter().chain(array)
+}
+
 /// The 20-operator catalog. Order is: comparison operators, then path-selector
 /// operators, then the remaining native jsonb operators.
 pub const OPERATORS: &[Operator] = &[
@@ -606,6 +626,18 @@ pub const OPERATORS: &[Operator] = &[
     },
 ];
 
+/// Operators rendered only on [`eql_domains::Shape::Array`] domains, after
+/// `OPERATORS`. `&&` (overlap) is meaningful only between element term sets;
+/// keeping it out of `OPERATORS` leaves every scalar domain's surface — and
+/// its blocker matrix — unchanged. Its wrapper is `eql_v3.overlaps`, array
+/// `&&` over the extracted sets, so the same GIN index as `@>`/`<@` serves it.
+pub const ARRAY_OPERATORS: &[Operator] = &[Operator {
+    symbol: OpSymbol::Overlaps,
+    function_name: "overlaps",
+    signatures: BOOL_SYMMETRIC_SIGNATURES,
+    metadata: containment_metadata("&&"),
+}];
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -901,6 +933,36 @@ mod tests {
         assert_eq!(operator("@>").wrapper_function_name(), "contains");
     }
 
+    #[test]
+    fn overlap_is_an_array_only_containment_operator() {
+        assert!(OPERATORS.iter().all(|o| o.symbol != OpSymbol::Overlaps));
+        let overlaps = operator("&&");
+        assert_eq!(overlaps.function_name, "overlaps");
+        assert_eq!(overlaps.wrapper_function_name(), "overlaps");
+        assert_eq!(overlaps.body_operator(), "&&");
+        assert!(!overlaps.is_native_jsonb_blocker());
+        assert_eq!(
+            overlaps.metadata.render().unwrap(),
+            "COMMUTATOR = &&, RESTRICT = contsel, JOIN = contjoinsel"
+        );
+    }
+
+    #[test]
+    fn surface_operators_append_array_operators_only_for_array_domains() {
+        let symbols = |d: &eql_domains::Domain| -> Vec<&str> {
+            surface_operators(d).map(|o| o.symbol.as_str()).collect()
+        };
+        // The array family's own storage domain is scalar-shaped.
+        let storage = eql_domains::INTEGER_ARRAY.domain_by_name("").unwrap();
+        assert_eq!(symbols(storage).len(), OPERATORS.len());
+        let contains = eql_domains::INTEGER_ARRAY
+            .domain_by_name("contains")
+            .unwrap();
+        let all = symbols(contains);
+        assert_eq!(all.len(), OPERATORS.len() + 1);
+        assert_eq!(all.last(), Some(&"&&"));
+    }
+
     #[test]
     fn native_jsonb_blocker_symbols_are_the_residual_ten() {
         // The residual after removing the 6 comparison + 2 containment + 2
@@ -0,0 +1,878 @@
+//! `render_upgrade` — an in-place upgrade script for an installed `eql_v3`.
+//!
+//! The installer starts with `DROP SCHEMA eql_v3 CASCADE`, which takes every
+//! functional index on an `eql_v3` extractor with it. The upgrade script is the
+//! same surface, in the same [`surface_order`], rewritten so that nothing is
+//! dropped and re-created that can be replaced instead:
+//!
+//! - the schema drops are removed and `CREATE SCHEMA` gains `IF NOT EXISTS`;
+//! - `CREATE FUNCTION` / `CREATE AGGREGATE` become `CREATE OR REPLACE`, which
+//!   keeps the function OID — and so every index and operator built on it —
+//!   as long as the argument and return types are unchanged. A top-level
+//!   function goes through [`REPLACE_FUNCTION`], which falls back to dropping
+//!   and re-creating the one overload `CREATE OR REPLACE` refuses (a renamed
+//!   parameter or a changed return type);
+//! - top-level `CREATE DOMAIN` / `CREATE TYPE` / `CREATE CAST` run only when
+//!   the object is missing;
+//! - top-level `CREATE OPERATOR` runs when the operator is missing, and drops
+//!   and re-creates it only when it is backed by a different function.
+//!
+//! The rewrite never parses argument lists: which functions the new release
+//! defines is read back from the catalog. `CREATE OR REPLACE` rewrites the
+//! `pg_proc` row even when nothing changed, so every routine the script
+//! defined has an `xmin` of the upgrade transaction (`age(xmin) <= 0`), and
+//! any other routine in the owned schemas is one the new release no longer
+//! defines. The sweep after the surface drops those, with the operators they implement,
+//! after first checking that nothing else (an index, above all) depends on
+//! them — so a stale extractor that still backs an index aborts the upgrade
+//! with every blocker listed, before anything is dropped.
+//!
+//! When the previous release's `dump-catalog` output is supplied, extractors
+//! whose term changed (see [`Change::ExtractorChanged`]) are dropped up front,
+//! since `CREATE OR REPLACE` cannot change a return type. An index on such an
+//! extractor has to be rebuilt anyway; the `RESTRICT` drop makes that explicit.
+//!
+//! The whole script runs in one transaction behind a check that the installed
+//! `eql_v3.version()` is the release it upgrades from.
+
+use std::fmt::Write as _;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::consts::{INTERNAL_SCHEMA, SCHEMA};
+use crate::context::query_domain_name;
+use crate::diff::{diff_catalogs, CatalogSnapshot, Change};
+use crate::ordering::{surface_order, walk_v3_surface, OrderError, SURFACE_ROOT};
+
+/// The post-install search_path pin, appended to the installer by
+/// `tasks/build.sh`. `CREATE OR REPLACE` resets a function's `SET` clauses, so
+/// the upgrade has to re-apply it too.
+pub const PIN_SEARCH_PATH: &str = "tasks/pin_search_path_v3.sql";
+
+/// Dollar-quote tag for the blocks the upgrade wraps around statements. Never
+/// used by the surface itself, so a wrapped statement can carry its own `$$`.
+const TAG: &str = "$upgrade$";
+
+/// The procedure every top-level `CREATE FUNCTION` runs through, created
+/// after the version guard and dropped before the stale sweep.
+pub const REPLACE_FUNCTION: &str = "eql_v3_internal.upgrade_replace_function";
+
+/// Schema [`REPLACE_FUNCTION`] creates a throwaway copy of a refused
+/// definition in, to read its argument types from the catalog.
+const SCRATCH_SCHEMA: &str = "eql_v3_upgrade_scratch";
+
+#[derive(Debug, thiserror::Error)]
+pub enum UpgradeError {
+    #[error("reading {}: {source}", path.display())]
+    Io { path: PathBuf, source: io::Error },
+    #[error("ordering the surface: {0}")]
+    Order(#[from] OrderError),
+    #[error("{file}:{line}: {message}")]
+    Parse {
+        file: String,
+        line: usize,
+        message: String,
+    },
+}
+
+/// Render the upgrade script for the surface under `root` (the repo root, or
+/// an `EQL_CODEGEN_OUT_ROOT` tree), upgrading from `from_version`.
+pub fn render_upgrade_from_root(
+    root: &Path,
+    from_version: &str,
+    from_catalog: Option<&CatalogSnapshot>,
+) -> Result<String, UpgradeError> {
+    let read = |rel: &str| {
+        let path = root.join(rel);
+        std::fs::read_to_string(&path).map_err(|source| UpgradeError::Io { path, source })
+    };
+    let files = walk_v3_surface(root).map_err(|source| UpgradeError::Io {
+        path: root.join(SURFACE_ROOT),
+        source,
+    })?;
+    let order = surface_order(&files)?;
+    let sources = order
+        .into_iter()
+        .map(|rel| read(&rel).map(|body| (rel, body)))
+        .collect::<Result<Vec<_>, _>>()?;
+    let epilogue = if root.join(PIN_SEARCH_PATH).is_file() {
+        read(PIN_SEARCH_PATH)?
+    } else {
+        String::new()
+    };
+    render_upgrade(&sources, from_version, from_catalog, &epilogue)
+}
+
+/// Render the upgrade script from ordered `(path, body)` sources, followed by
+/// `epilogue` (the search_path pin) inside the same transaction.
+pub fn render_upgrade(
+    sources: &[(String, String)],
+    from_version: &str,
+    from_catalog: Option<&CatalogSnapshot>,
+    epilogue: &str,
+) -> Result<String, UpgradeError> {
+    let mut rewriter = Rewriter::default();
+    let mut body = String::new();
+    for (path, source) in sources {
+        writeln!(body, "-- {path}").unwrap();
+        rewriter.rewrite(path, source, &mut body)?;
+    }
+
+    let mut out = String::new();
+    writeln!(out, "-- EQL in-place upgrade from {from_version}.").unwrap();
+    writeln!(
+        out,
+        "-- Generated by `eql-codegen upgrade`; replaces the {SCHEMA} surface without dropping it."
+    )
+    .unwrap();
+    out.push('\n');
+    out.push_str("BEGIN;\n\n");
+    out.push_str(&version_guard(from_version));
+    out.push_str(&replace_function_helper());
+    if let Some(old) = from_catalog {
+        out.push_str(&changed_extractor_drops(old));
+    }
+    out.push_str(&body);
+    out.push('\n');
+    writeln!(out, "DROP PROCEDURE {REPLACE_FUNCTION}(text, text);\n").unwrap();
+    // Before the epilogue: the search_path pin rewrites the `pg_proc` row of
+    // every function it alters, stale ones included.
+    out.push_str(&rewriter.stale_sweep());
+    if !epilogue.is_empty() {
+        out.push('\n');
+        out.push_str(epilogue);
+        if !epilogue.ends_with('\n') {
+            out.push('\n');
+        }
+    }
+    out.push_str("\nCOMMIT;\n");
+    Ok(out)
+}
+
+/// SQL string literal.
+fn literal(s: &str) -> String {
+    format!("'{}'", s.replace('\'', "''"))
+}
+
+fn version_guard(from_version: &str) -> String {
+    let from = literal(from_version);
+    format!(
+        "DO {TAG}
+BEGIN
+  IF pg_catalog.to_regprocedure('{SCHEMA}.version()') IS NULL THEN
+    RAISE EXCEPTION 'EQL upgrade: {SCHEMA} is not installed; run cipherstash-encrypt.sql instead';
+  END IF;
+  IF {SCHEMA}.version() IS DISTINCT FROM {from} THEN
+    RAISE EXCEPTION 'EQL upgrade: this script upgrades {SCHEMA} %, but % is installed', {from}, {SCHEMA}.version();
+  END IF;
+END
+{TAG};
+
+"
+    )
+}
+
+/// [`REPLACE_FUNCTION`]: runs a `CREATE OR REPLACE FUNCTION` `statement`, and
+/// when Postgres refuses it (`invalid_function_definition`: a renamed
+/// parameter, or a changed return type), find the overload it collides with
+/// and re-create it. The overload is matched on argument types read from the
+/// catalog — the definition is created once under [`SCRATCH_SCHEMA`] — never
+/// on the header text. Operators on the old function are dropped with it and
+/// re-created by their guarded `CREATE OPERATOR` further on; anything else
+/// depending on it (an index) makes the plain `DROP FUNCTION` fail, which
+/// aborts the upgrade.
+fn replace_function_helper() -> String {
+    format!(
+        "CREATE PROCEDURE {REPLACE_FUNCTION}(name text, statement text)
+  LANGUAGE plpgsql
+AS {TAG}
+DECLARE
+  ident text[] := pg_catalog.parse_ident(name);
+  old regprocedure;
+  op regoperator;
+BEGIN
+  EXECUTE statement;
+EXCEPTION WHEN invalid_function_definition THEN
+  EXECUTE 'CREATE SCHEMA {SCRATCH_SCHEMA}';
+  EXECUTE pg_catalog.overlay(
+    statement,
+    '{SCRATCH_SCHEMA}.' || pg_catalog.quote_ident(ident[2]),
+    pg_catalog.strpos(statement, name),
+    pg_catalog.length(name)
+  );
+  SELECT p.oid::regprocedure INTO old
+  FROM pg_catalog.pg_proc p
+  JOIN pg_catalog.pg_proc s ON s.proargtypes = p.proargtypes
+  WHERE s.pronamespace = '{SCRATCH_SCHEMA}'::regnamespace
+    AND p.pronamespace = ident[1]::regnamespace
+    AND p.proname = ident[2];
+  EXECUTE 'DROP SCHEMA {SCRATCH_SCHEMA} CASCADE';
+  IF old IS NULL THEN
+    RAISE;
+  END IF;
+  FOR op IN
+    SELECT o.oid::regoperator FROM pg_catalog.pg_operator o WHERE o.oprcode = old::oid
+  LOOP
+    RAISE NOTICE 'EQL upgrade: dropping operator % to re-create it', op;
+    EXECUTE pg_catalog.format('DROP OPERATOR %s', op);
+  END LOOP;
+  RAISE NOTICE 'EQL upgrade: re-creating % (a parameter name or the return type changed)', old;
+  EXECUTE pg_catalog.format('DROP FUNCTION %s', old);
+  EXECUTE statement;
+END
+{TAG};
+
+"
+    )
+}
+
+/// `DROP FUNCTION` for each extractor whose term changed since `old`, on both
+/// the column domain and its query-operand twin.
+fn changed_extractor_drops(old: &CatalogSnapshot) -> String {
+    let mut out = String::new();
+    for change in diff_catalogs(old, &CatalogSnapshot::live()).breaking {
+        match change {
+            Change::ExtractorChanged {
+                typname,
+                extractor,
+                from,
+                to,
+            } => {
+                writeln!(
+                    out,
+                    "-- {SCHEMA}.{extractor} on public.{typname} changed ({from} => {to}); \
+                     indexes on it must be dropped and rebuilt."
+                )
+                .unwrap();
+                writeln!(
+                    out,
+                    "DROP FUNCTION IF EXISTS {SCHEMA}.{extractor}(public.{typname});"
+                )
+                .unwrap();
+                if let Some(bare) = typname.strip_prefix(eql_domains::PUBLIC_TYPNAME_PREFIX) {
+                    writeln!(
+                        out,
+                        "DROP FUNCTION IF EXISTS {SCHEMA}.{extractor}({});",
+                        query_domain_name(&format!("query_{bare}"))
+                    )
+                    .unwrap();
+                }
+                out.push('\n');
+            }
+            Change::DomainRemoved { typname, .. } => {
+                writeln!(
+                    out,
+                    "-- public.{typname} is no longer part of EQL: its columns keep their data, \
+                     but its operators and extractors are removed below.\n"
+                )
+                .unwrap();
+            }
+            _ => {}
+        }
+    }
+    out
+}
+
+/// The statement-level rewrite, plus the routine names it saw.
+#[derive(Default)]
+struct Rewriter {
+    /// Top-level definitions: always (re)created, so each must be.
+    required: Vec<String>,
+    /// Definitions inside a `DO` block: created conditionally, so a routine
+    /// of this name that the script did not touch is still current.
+    optional: Vec<String>,
+}
+
+impl Rewriter {
+    fn rewrite(&mut self, path: &str, source: &str, out: &mut String) -> Result<(), UpgradeError> {
+        let lines: Vec<&str> = source
+            .lines()
+            .filter(|l| !l.trim_start().starts_with("-- REQUIRE:"))
+            .collect();
+        let err = |i: usize, message: String| UpgradeError::Parse {
+            file: path.to_string(),
+            line: i + 1,
+            message,
+        };
+
+        let mut i = 0;
+        while i < lines.len() {
+            let line = lines[i];
+            let trimmed = line.trim_start();
+            // Statements nested in a `DO` block are indented; everything the
+            // guards below wrap is a top-level statement.
+            let top = trimmed.len() == line.len();
+
+            if top && trimmed.starts_with("DROP SCHEMA ") {
+                i += 1;
+                continue;
+            }
+            if top && trimmed.starts_with("CREATE SCHEMA ") && !trimmed.contains("IF NOT EXISTS") {
+                out.push_str(&line.replacen("CREATE SCHEMA", "CREATE SCHEMA IF NOT EXISTS", 1));
+                out.push('\n');
+                i += 1;
+                continue;
+            }
+
+            let routine = ["FUNCTION", "AGGREGATE"].into_iter().find_map(|kind| {
+                [
+                    format!("CREATE {kind} "),
+                    format!("CREATE OR REPLACE {kind} "),
+                ]
+                .into_iter()
+                .find(|p| trimmed.starts_with(p.as_str()))
+                .map(|p| (kind, p))
+            });
+            if let Some((kind, prefix)) = routine {
+                let name = routine_name(&trimmed[prefix.len()..])
+                    .ok_or_else(|| err(i, format!("unparseable CREATE {kind} name")))?;
+                let replaced = line.replacen(
+                    &format!("CREATE {kind} "),
+                    &format!("CREATE OR REPLACE {kind} "),
+                    1,
+                );
+                if !top {
+                    self.optional.push(name.to_string());
+                    out.push_str(&replaced);
+                    out.push('\n');
+                    i += 1;
+                    continue;
+                }
+                self.required.push(name.to_string());
+                if kind == "AGGREGATE" {
+                    out.push_str(&replaced);
+                    out.push('\n');
+                    i += 1;
+                    continue;
+                }
+                let end = statement_end(&lines, i)
+                    .ok_or_else(|| err(i, "unterminated statement".to_string()))?;
+                let mut statement = std::iter::once(replaced.as_str())
+                    .chain(lines[i + 1..=end].iter().copied())
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                statement.truncate(statement.trim_end().trim_end_matches(';').len());
+                writeln!(
+                    out,
+                    "CALL {REPLACE_FUNCTION}(\n  {},\n  {TAG}{statement}{TAG}\n);",
+                    literal(name)
+                )
+                .unwrap();
+                i = end + 1;
+                continue;
+            }
+
+            let guarded = top
+                && [
+                    "CREATE OPERATOR ",
+                    "CREATE DOMAIN ",
+                    "CREATE TYPE ",
+                    "CREATE CAST ",
+                ]
+                .iter()
+                .any(|p| trimmed.starts_with(p))
+                && !trimmed.starts_with("CREATE OPERATOR CLASS")
+                && !trimmed.starts_with("CREATE OPERATOR FAMILY");
+            if guarded {
+                let end = (i..lines.len())
+                    .find(|&j| lines[j].trim_end().ends_with(';'))
+                    .ok_or_else(|| err(i, "unterminated statement".to_string()))?;
+                let statement = lines[i..=end].join("\n");
+                let wrapped = guard(&statement).ok_or_else(|| {
+                    err(
+                        i,
+                        format!("cannot guard `{}`", trimmed.trim_end_matches('(')),
+                    )
+                })?;
+                out.push_str(&wrapped);
+                i = end + 1;
+                continue;
+            }
+
+            out.push_str(line);
+            out.push('\n');
+            i += 1;
+        }
+        out.push('\n');
+        Ok(())
+    }
+
+    /// Drop what the previous release defined and this one does not.
+    fn stale_sweep(&self) -> String {
+        let array = |names: &[String]| {
+            if names.is_empty() {
+                return "ARRAY[]::text[]".to_string();
+            }
+            let items: Vec<String> = names
+                .iter()
+                .map(|s| format!("    {}", literal(s)))
+                .collect();
+            format!("ARRAY[\n{}\n  ]", items.join(",\n"))
+        };
+        format!(
+            "-- Drop functions and aggregates the previous release defined and this one does
+-- not, with the operators they implement: every routine in the owned schemas
+-- this transaction did not write (`age(xmin) > 0`), bar those defined inside a
+-- conditional DO block. Anything else depending on them aborts the upgrade
+-- before the first drop, listing every blocker.
+DO {TAG}
+DECLARE
+  required text[] := {required};
+  optional text[] := {optional};
+  missing text[];
+  stale oid[];
+  blockers text[];
+  item record;
+  op regoperator;
+BEGIN
+  SELECT pg_catalog.array_agg(r) INTO missing
+  FROM pg_catalog.unnest(required) r
+  WHERE NOT EXISTS (
+    SELECT 1 FROM pg_catalog.pg_proc p
+    WHERE p.pronamespace = (pg_catalog.parse_ident(r))[1]::regnamespace
+      AND p.proname = (pg_catalog.parse_ident(r))[2]
+      AND pg_catalog.age(p.xmin) <= 0
+  );
+  IF missing IS NOT NULL THEN
+    RAISE EXCEPTION 'EQL upgrade: functions missing after the upgrade: %', missing;
+  END IF;
+
+  SELECT coalesce(pg_catalog.array_agg(p.oid), '{{}}') INTO stale
+  FROM pg_catalog.pg_proc p
+  WHERE p.pronamespace IN ('{SCHEMA}'::regnamespace, '{INTERNAL_SCHEMA}'::regnamespace)
+    AND pg_catalog.age(p.xmin) > 0
+    AND NOT EXISTS (
+      SELECT 1 FROM pg_catalog.unnest(optional) o
+      WHERE p.pronamespace = (pg_catalog.parse_ident(o))[1]::regnamespace
+        AND p.proname = (pg_catalog.parse_ident(o))[2]
+    );
+
+  WITH dropped AS (
+    SELECT 'pg_catalog.pg_proc'::regclass AS classid, oid AS objid
+    FROM pg_catalog.unnest(stale) oid
+    UNION ALL
+    SELECT 'pg_catalog.pg_operator'::regclass, o.oid
+    FROM pg_catalog.pg_operator o WHERE o.oprcode = ANY (stale)
+  )
+  SELECT pg_catalog.array_agg(DISTINCT pg_catalog.format(
+    '%s (used by %s)',
+    pg_catalog.pg_describe_object(d.refclassid, d.refobjid, 0),
+    pg_catalog.pg_describe_object(d.classid, d.objid, d.objsubid)
+  )) INTO blockers
+  FROM pg_catalog.pg_depend d
+  JOIN dropped r ON r.classid = d.refclassid AND r.objid = d.refobjid
+  WHERE d.deptype = 'n'
+    AND NOT EXISTS (
+      SELECT 1 FROM dropped x WHERE x.classid = d.classid AND x.objid = d.objid
+    );
+  IF blockers IS NOT NULL THEN
+    RAISE EXCEPTION 'EQL upgrade: this release no longer defines %',
+      pg_catalog.array_to_string(blockers, '; ')
+      USING HINT = 'Drop the dependent objects (rebuild indexes on the replacement extractor afterwards) and rerun the upgrade; nothing has been changed.';
+  END IF;
+
+  FOR item IN
+    SELECT p.oid, p.oid::regprocedure AS sig, p.prokind
+    FROM pg_catalog.pg_proc p
+    WHERE p.oid = ANY (stale)
+    ORDER BY p.prokind = 'a' DESC, p.oid
+  LOOP
+    FOR op IN
+      SELECT o.oid::regoperator FROM pg_catalog.pg_operator o WHERE o.oprcode = item.oid
+    LOOP
+      RAISE NOTICE 'EQL upgrade: dropping operator %', op;
+      EXECUTE pg_catalog.format('DROP OPERATOR %s', op);
+    END LOOP;
+    RAISE NOTICE 'EQL upgrade: dropping %', item.sig;
+    EXECUTE pg_catalog.format(
+      'DROP %s %s',
+      CASE item.prokind WHEN 'a' THEN 'AGGREGATE' WHEN 'p' THEN 'PROCEDURE' ELSE 'FUNCTION' END,
+      item.sig
+    );
+  END LOOP;
+END
+{TAG};
+",
+            required = array(&self.required),
+            optional = array(&self.optional),
+        )
+    }
+}
+
+/// The routine name of a `CREATE FUNCTION` / `CREATE AGGREGATE` whose header
+/// starts at `rest` (just past the keyword): everything before the argument
+/// list, as written (`eql_v3."->"`). The arguments are never parsed — see the
+/// [module docs](self) for how the catalog stands in for them.
+fn routine_name(rest: &str) -> Option<&str> {
+    let name = rest[..rest.find('(')?].trim();
+    (!name.is_empty() && !name.contains(char::is_whitespace)).then_some(name)
+}
+
+/// Index of the line holding the `;` that ends the statement starting at
+/// `lines[start]` — the first one outside a string, a dollar-quoted body or a
+/// comment.
+fn statement_end(lines: &[&str], start: usize) -> Option<usize> {
+    let mut dollar: Option<&str> = None;
+    let mut quote: Option<bool> = None; // Some(backslash escapes)
+    let mut block_comment = false;
+    for (n, line) in lines.iter().enumerate().skip(start) {
+        let mut k = 0;
+        while k < line.len() {
+            let rest = &line[k..];
+            let c = rest.chars().next().unwrap();
+            if let Some(tag) = dollar {
+                if rest.starts_with(tag) {
+                    dollar = None;
+                    k += tag.len();
+                    continue;
+                }
+            } else if let Some(escapes) = quote {
+                if escapes && c == '\\' {
+                    k += 1 + rest[1..].chars().next().map_or(0, char::len_utf8);
+                    continue;
+                }
+                if rest.starts_with("''") {
+                    k += 2;
+                    continue;
+                }
+                if c == '\'' {
+                    quote = None;
+                }
+            } else if block_comment {
+                if rest.starts_with("*/") {
+                    block_comment = false;
+                    k += 2;
+                    continue;
+                }
+            } else if rest.starts_with("--") {
+                break;
+            } else if rest.starts_with("/*") {
+                block_comment = true;
+                k += 2;
+                continue;
+            } else if c == '\'' {
+                // `E'…'` takes backslash escapes; `name'…'` is not a prefix.
+                let mut before = line[..k].chars().rev();
+                let escapes = matches!(before.next(), Some('E' | 'e'))
+                    && !before
+                        .next()
+                        .is_some_and(|b| b.is_alphanumeric() || b == '_');
+                quote = Some(escapes);
+            } else if c == '$' {
+                if let Some(tag) = dollar_tag(rest) {
+                    dollar = Some(tag);
+                    k += tag.len();
+                    continue;
+                }
+            } else if c == ';' {
+                return Some(n);
+            }
+            k += c.len_utf8();
+        }
+    }
+    None
+}
+
+/// The dollar-quote opener `rest` starts with (`$$`, `$body$`), if any — not
+/// a positional parameter like `$1`.
+fn dollar_tag(rest: &str) -> Option<&str> {
+    let close = rest[1..].find('$')? + 1;
+    let tag = &rest[1..close];
+    let ident = tag.chars().all(|c| c.is_alphanumeric() || c == '_')
+        && !tag.starts_with(|c: char| c.is_ascii_digit());
+    ident.then(|| &rest[..=close])
+}
+
+/// Split on commas outside parentheses (`numeric(10, 2)` stays whole).
+fn split_top_level(s: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let (mut depth, mut start) = (0usize, 0);
+    for (k, c) in s.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                parts.push(s[start..k].trim());
+                start = k + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(s[start..].trim());
+    parts
+}
+
+/// The value of `KEY = value` in a `CREATE OPERATOR (…)` parameter list.
+fn operator_param<'a>(params: &'a str, key: &str) -> Option<&'a str> {
+    split_top_level(params).into_iter().find_map(|p| {
+        let (k, v) = p.split_once('=')?;
+        k.trim().eq_ignore_ascii_case(key).then(|| v.trim())
+    })
+}
+
+/// Wrap one top-level `CREATE` in a block that runs it only when needed.
+fn guard(statement: &str) -> Option<String> {
+    let trimmed = statement.trim_start();
+    let indent = |s: &str| {
+        s.lines()
+            .map(|l| format!("    {l}"))
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+
+    if let Some(rest) = trimmed.strip_prefix("CREATE OPERATOR ") {
+        let open = rest.find('(')?;
+        let name = rest[..open].trim();
+        let params = rest[open + 1..].trim_end().strip_suffix(");")?;
+        let function =
+            operator_param(params, "FUNCTION").or_else(|| operator_param(params, "PROCEDURE"))?;
+        let left = operator_param(params, "LEFTARG");
+        let right = operator_param(params, "RIGHTARG")?;
+        let operands = format!("{},{right}", left.unwrap_or("NONE"));
+        let function_args = match left {
+            Some(left) => format!("{left},{right}"),
+            None => right.to_string(),
+        };
+        let regoperator = literal(&format!("{name}({operands})"));
+        let regprocedure = literal(&format!("{function}({function_args})"));
+        return Some(format!(
+            "DO {TAG}
+DECLARE
+  code oid;
+BEGIN
+  SELECT o.oprcode::oid INTO code FROM pg_catalog.pg_operator o
+  WHERE o.oid = pg_catalog.to_regoperator({regoperator});
+  IF code IS NOT NULL AND code <> 0 THEN
+    IF code = pg_catalog.to_regprocedure({regprocedure})::oid THEN
+      RETURN;
+    END IF;
+    DROP OPERATOR {name} ({operands_spaced});
+  END IF;
+{body}
+END
+{TAG};
+",
+            operands_spaced = operands.replace(',', ", "),
+            body = indent(statement),
+        ));
+    }
+
+    let condition = if let Some(rest) = trimmed
+        .strip_prefix("CREATE DOMAIN ")
+        .or_else(|| trimmed.strip_prefix("CREATE TYPE "))
+    {
+        let name = rest.split_whitespace().next()?;
+        format!("pg_catalog.to_regtype({}) IS NULL", literal(name))
+    } else if let Some(rest) = trimmed.strip_prefix("CREATE CAST ") {
+        let inner = rest.strip_prefix('(')?;
+        let (pair, _) = inner.split_once(')')?;
+        let (source, target) = pair.split_once(" AS ")?;
+        format!(
+            "NOT EXISTS (\n    SELECT 1 FROM pg_catalog.pg_cast\n    WHERE castsource = {}::regtype AND casttarget = {}::regtype\n  )",
+            literal(source.trim()),
+            literal(target.trim())
+        )
+    } else {
+        return None;
+    };
+    Some(format!(
+        "DO {TAG}
+BEGIN
+  IF {condition} THEN
+{body}
+  END IF;
+END
+{TAG};
+",
+        body = indent(statement),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render(source: &str) -> String {
+        render_upgrade(
+            &[("src/v3/x.sql".to_string(), source.to_string())],
+            "3.0.0",
+            None,
+            "",
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn functions_and_aggregates_are_replaced_in_place() {
+        let out = render(
+            "CREATE FUNCTION eql_v3.eq_term(a public.eql_v3_uuid_eq)\n\
+             RETURNS eql_v3_internal.hmac_256 LANGUAGE sql AS $$ SELECT 1; $$;\n\
+             CREATE AGGREGATE eql_v3.min(public.eql_v3_integer_ord) (sfunc = f, stype = x);\n",
+        );
+        assert!(out.contains(
+            "CALL eql_v3_internal.upgrade_replace_function(\n  'eql_v3.eq_term',\n  \
+             $upgrade$CREATE OR REPLACE FUNCTION eql_v3.eq_term(a public.eql_v3_uuid_eq)\n\
+             RETURNS eql_v3_internal.hmac_256 LANGUAGE sql AS $$ SELECT 1; $$$upgrade$\n);"
+        ));
+        assert!(out.contains("CREATE OR REPLACE AGGREGATE eql_v3.min(public.eql_v3_integer_ord)"));
+        assert!(!out.contains("\nCREATE FUNCTION"));
+        // Both are required in the stale sweep, by name: the catalog supplies
+        // the argument types.
+        let required = out.split("required text[] :=").nth(1).unwrap();
+        assert!(required.starts_with(" ARRAY[\n    'eql_v3.eq_term',\n    'eql_v3.min'\n  ];"));
+    }
+
+    #[test]
+    fn argument_lists_are_never_parsed() {
+        // Unnamed multi-word types were once read as `name type`.
+        let out = render(
+            "CREATE FUNCTION eql_v3_internal.scale(double precision, timestamp with time zone)\n\
+             RETURNS double precision LANGUAGE sql AS 'SELECT $1';\n",
+        );
+        assert!(out.contains(
+            "  'eql_v3_internal.scale',\n  $upgrade$CREATE OR REPLACE FUNCTION \
+             eql_v3_internal.scale(double precision, timestamp with time zone)"
+        ));
+        assert_eq!(
+            routine_name(" eql_v3.\"->\"(a jsonb, b text)"),
+            Some("eql_v3.\"->\"")
+        );
+        assert_eq!(routine_name("eql_v3.f"), None);
+        assert_eq!(routine_name("(x int)"), None);
+    }
+
+    #[test]
+    fn statements_end_outside_strings_bodies_and_comments() {
+        let lines = [
+            "CREATE FUNCTION f() RETURNS text -- a ; comment",
+            "/* and ; a block",
+            "   comment */ LANGUAGE plpgsql AS $f$",
+            "BEGIN RETURN 'a;b' || E'c\\';d' || $$;$$; END;",
+            "$f$;",
+            "SELECT 1;",
+        ];
+        assert_eq!(statement_end(&lines, 0), Some(4));
+        assert_eq!(statement_end(&lines, 5), Some(5));
+        assert_eq!(statement_end(&["SELECT '$1;"], 0), None);
+        assert_eq!(dollar_tag("$1;"), None);
+        assert_eq!(dollar_tag("$f$;"), Some("$f$"));
+    }
+
+    #[test]
+    fn the_sweep_checks_dependents_before_dropping() {
+        let out = render("SELECT 1;\n");
+        let sweep = &out[out.find("DO $upgrade$\nDECLARE\n  required").unwrap()..];
+        let check = sweep.find("RAISE EXCEPTION 'EQL upgrade: this release no longer defines %'");
+        let first_drop = sweep.find("EXECUTE pg_catalog.format('DROP OPERATOR %s', op);");
+        assert!(check.unwrap() < first_drop.unwrap());
+        assert!(!sweep.contains("RESTRICT"));
+        // The helper is gone before the sweep reads the catalog, and the
+        // search_path pin runs after it.
+        assert!(
+            out.find("DROP PROCEDURE eql_v3_internal.upgrade_replace_function(text, text);")
+                .unwrap()
+                < out.find("DO $upgrade$\nDECLARE\n  required").unwrap()
+        );
+    }
+
+    #[test]
+    fn schema_drops_are_removed() {
+        let out = render(
+            "DROP SCHEMA IF EXISTS eql_v3 CASCADE;\nCREATE SCHEMA eql_v3;\n\
+             CREATE SCHEMA IF NOT EXISTS extensions;\n",
+        );
+        assert!(!out.lines().any(|l| l.starts_with("DROP SCHEMA")));
+        assert!(out.contains("CREATE SCHEMA IF NOT EXISTS eql_v3;"));
+        assert!(out.contains("CREATE SCHEMA IF NOT EXISTS extensions;"));
+    }
+
+    #[test]
+    fn operators_are_recreated_only_when_their_function_changes() {
+        let out = render(
+            "CREATE OPERATOR = (\n  FUNCTION = eql_v3.eq,\n  \
+             LEFTARG = public.eql_v3_uuid_eq, RIGHTARG = jsonb,\n  \
+             COMMUTATOR = =, NEGATOR = <>\n);\n",
+        );
+        assert!(out.contains("pg_catalog.to_regoperator('=(public.eql_v3_uuid_eq,jsonb)')"));
+        assert!(
+            out.contains("pg_catalog.to_regprocedure('eql_v3.eq(public.eql_v3_uuid_eq,jsonb)')")
+        );
+        assert!(out.contains("DROP OPERATOR = (public.eql_v3_uuid_eq, jsonb);"));
+        assert!(out.contains("    CREATE OPERATOR = ("));
+    }
+
+    #[test]
+    fn types_domains_and_casts_are_created_only_when_missing() {
+        let out = render(
+            "CREATE DOMAIN eql_v3_internal.hmac_256 AS text;\n\
+             CREATE TYPE eql_v3_internal.ore_block_256 AS (\n  terms bytea[]\n);\n\
+             CREATE CAST (public.eql_v3_json_search AS eql_v3.query_json)\n  WITH INOUT AS ASSIGNMENT;\n",
+        );
+        assert!(out.contains("IF pg_catalog.to_regtype('eql_v3_internal.hmac_256') IS NULL THEN"));
+        assert!(
+            out.contains("IF pg_catalog.to_regtype('eql_v3_internal.ore_block_256') IS NULL THEN")
+        );
+        assert!(out.contains(
+            "WHERE castsource = 'public.eql_v3_json_search'::regtype AND casttarget = 'eql_v3.query_json'::regtype"
+        ));
+    }
+
+    #[test]
+    fn definitions_inside_do_blocks_are_optional() {
+        let out = render(
+            "DO $do$\nBEGIN\n  CREATE FUNCTION eql_v3_internal.poison(val jsonb, name text)\n  \
+             RETURNS boolean LANGUAGE plpgsql AS $p$ BEGIN RETURN true; END; $p$;\nEND;\n$do$;\n",
+        );
+        assert!(out.contains("  CREATE OR REPLACE FUNCTION eql_v3_internal.poison"));
+        let optional = out.split("optional text[] :=").nth(1).unwrap();
+        assert!(optional.starts_with(" ARRAY[\n    'eql_v3_internal.poison'\n  ];"));
+        assert!(out.contains("required text[] := ARRAY[]::text[];"));
+    }
+
+    #[test]
+    fn script_is_versioned_and_transactional() {
+        let out = render("SELECT 1;\n");
+        assert!(out.starts_with("-- EQL in-place upgrade from 3.0.0.\n"));
+        assert!(out.contains("BEGIN;\n"));
+        assert!(out.contains("IF eql_v3.version() IS DISTINCT FROM '3.0.0' THEN"));
+        assert!(out.trim_end().ends_with("COMMIT;"));
+    }
+
+    #[test]
+    fn changed_extractors_are_dropped_up_front() {
+        let mut old: serde_json::Value = serde_json::to_value(crate::dump::dump_catalog()).unwrap();
+        let eq = old["types"]
+            .as_array_mut()
+            .unwrap()
+            .iter_mut()
+            .flat_map(|t| t["domains"].as_array_mut().unwrap())
+            .find(|d| d["typname"] == "eql_v3_uuid_eq")
+            .unwrap();
+        eq["terms"][0]["ctor"] = "hmac_128".into();
+        let old: CatalogSnapshot = serde_json::from_value(old).unwrap();
+
+        let out = render_upgrade(&[], "3.0.0", Some(&old), "").unwrap();
+        assert!(out.contains("DROP FUNCTION IF EXISTS eql_v3.eq_term(public.eql_v3_uuid_eq);"));
+        assert!(out.contains("DROP FUNCTION IF EXISTS eql_v3.eq_term(eql_v3.query_uuid_eq);"));
+    }
+
+    /// Every top-level `CREATE` the real surface uses is one the rewrite can
+    /// guard or replace; an unrecognised shape fails here, not at upgrade time.
+    #[test]
+    fn real_surface_renders() {
+        let out = render_upgrade_from_root(&crate::repo_root(), "3.0.0", None).unwrap();
+        for line in out.lines().filter(|l| !l.starts_with(' ')) {
+            assert!(!line.starts_with("DROP SCHEMA"), "{line}");
+            assert!(
+                !line.starts_with("CREATE FUNCTION") && !line.starts_with("CREATE AGGREGATE"),
+                "unreplaced definition: {line}"
+            );
+            if line.starts_with("CREATE OPERATOR") {
+                assert!(
+                    line.starts_with("CREATE OPERATOR CLASS"),
+                    "unguarded: {line}"
+                );
+            }
+        }
+    }
+}
@@ -33,8 +33,8 @@ fn tempdir() -> TempDir {
 /// `bindings` exits 0 and reports the written-file count. Run against a throwaway
 /// `EQL_CODEGEN_OUT_ROOT` tree so the smoke test proves the subcommand honours
 /// the output-root override (test isolation) and never touches the committed
-/// `crates/eql-bindings/src/v3/*.rs`. The count is one file per scalar family
-/// plus the jsonb family's generated `jsonb_storage.rs`, `payload.rs`,
+/// `crates/eql-bindings/src/v3/*.rs`. The count is one file per generated
+/// (scalar or array) family plus the jsonb family's generated `jsonb_storage.rs`, `payload.rs`,
 /// `query_payload.rs`, and `inventory.rs`.
 #[test]
 fn bindings_subcommand_succeeds_and_reports_count() {
@@ -50,7 +50,11 @@ fn bindings_subcommand_succeeds_and_reports_count() {
         String::from_utf8_lossy(&out.stderr)
     );
     let stdout = String::from_utf8_lossy(&out.stdout);
-    let expected = eql_domains::scalar_families().count() + 4;
+    let expected = eql_domains::CATALOG
+        .iter()
+        .filter(|f| f.is_generated())
+        .count()
+        + 4;
     assert!(
         stdout.contains(&format!("bindings: ok ({expected} files)")),
         "expected 'bindings: ok ({expected} files)' in stdout, got:\n{stdout}"
@@ -167,3 +171,47 @@ fn unknown_arg_exits_two() {
         String::from_utf8_lossy(&out.stderr)
     );
 }
+
+/// `diff-catalog` against a dump of the current catalog reports nothing, and
+/// with one argument compares that dump to the live catalog. The stdout is the
+/// JSON changelog, split into `breaking` and `additive`.
+#[test]
+fn diff_catalog_subcommand_reports_an_empty_changelog_for_the_same_catalog() {
+    let dir = tempdir();
+    let dump = Command::new(bin())
+        .arg("dump-catalog")
+        .output()
+        .expect("run eql-codegen dump-catalog");
+    let path = dir.0.join("catalog.json");
+    std::fs::write(&path, &dump.stdout).unwrap();
+
+    for args in [vec![&path, &path], vec![&path]] {
+        let out = Command::new(bin())
+            .arg("diff-catalog")
+            .args(&args)
+            .output()
+            .expect("run eql-codegen diff-catalog");
+        assert!(
+            out.status.success(),
+            "diff-catalog should exit 0; stderr: {}",
+            String::from_utf8_lossy(&out.stderr)
+        );
+        let diff: serde_json::Value = serde_json::from_slice(&out.stdout).expect("JSON changelog");
+        assert_eq!(diff, serde_json::json!({"breaking": [], "additive": []}));
+    }
+}
+
+/// An unreadable dump is an error naming the path, not an empty changelog.
+#[test]
+fn diff_catalog_subcommand_fails_on_a_missing_dump() {
+    let dir = tempdir();
+    let missing = dir.0.join("nope.json");
+    let out = Command::new(bin())
+        .arg("diff-catalog")
+        .arg(&missing)
+        .output()
+        .expect("run eql-codegen diff-catalog");
+    assert_eq!(out.status.code(), Some(1));
+    assert!(out.stdout.is_empty());
+    assert!(String::from_utf8_lossy(&out.stderr).contains("nope.json"));
+}
@@ -0,0 +1,136 @@
+//! PostgreSQL `COPY ... (FORMAT text)` rows: tab-separated fields with
+//! backslash escapes, `\N` for NULL. Decoding follows `CopyReadAttributesText`
+//! (an unknown escape is the literal character); encoding follows
+//! `CopyAttributeOutText`, so a converted dump loads back with `COPY ... FROM`.
+
+/// The text-format NULL marker (the `NULL` option's default).
+pub const NULL: &str = "\\N";
+
+/// The end-of-data marker older clients append to a COPY stream.
+pub const END_OF_DATA: &str = "\\.";
+
+/// Split one row (trailing newline already stripped) into its raw, still
+/// escaped fields. An escaped tab (`\t`) is field data, not a separator.
+pub fn split_fields(row: &str) -> Vec<&str> {
+    row.split('\t').collect()
+}
+
+/// Decode one raw field into its bytes; `None` for the NULL marker.
+pub fn decode_field(raw: &str) -> Option<Vec<u8>> {
+    if raw == NULL {
+        return None;
+    }
+    let bytes = raw.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let b = bytes[i];
+        i += 1;
+        if b != b'\\' || i == bytes.len() {
+            out.push(b);
+            continue;
+        }
+        let c = bytes[i];
+        i += 1;
+        match c {
+            b'0'..=b'7' => {
+                // Up to three octal digits; wraps into a byte like Postgres.
+                let mut value = u32::from(c - b'0');
+                for _ in 0..2 {
+                    match bytes.get(i) {
+                        Some(d @ b'0'..=b'7') => {
+                            value = value * 8 + u32::from(d - b'0');
+                            i += 1;
+                        }
+                        _ => break,
+                    }
+                }
+                out.push((value & 0xff) as u8);
+            }
+            b'x' => {
+                // `\x` needs at least one hex digit, else it is a literal `x`.
+                let mut value = 0u8;
+                let mut digits = 0;
+                while digits < 2 {
+                    match bytes.get(i).and_then(|d| (*d as char).to_digit(16)) {
+                        Some(d) => {
+                            value = value * 16 + d as u8;
+                            i += 1;
+                            digits += 1;
+                        }
+                        None => break,
+                    }
+                }
+                out.push(if digits == 0 { b'x' } else { value });
+            }
+            b'b' => out.push(0x08),
+            b'f' => out.push(0x0c),
+            b'n' => out.push(b'\n'),
+            b'r' => out.push(b'\r'),
+            b't' => out.push(b'\t'),
+            b'v' => out.push(0x0b),
+            other => out.push(other),
+        }
+    }
+    Some(out)
+}
+
+/// Encode one field value for a text-format row, escaping exactly what
+/// `COPY ... TO` escapes.
+pub fn encode_field(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '\u{08}' => out.push_str("\\b"),
+            '\u{0c}' => out.push_str("\\f"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            '\u{0b}' => out.push_str("\\v"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_every_escape_form() {
+        assert_eq!(decode_field(NULL), None);
+        assert_eq!(decode_field("").unwrap(), b"");
+        assert_eq!(
+            decode_field(r"a\\b\tc\nd\re\bf\fg\vh").unwrap(),
+            b"a\\b\tc\nd\re\x08f\x0cg\x0bh"
+        );
+        // Octal and hex escapes, and their short forms.
+        assert_eq!(decode_field(r"\101\7\08").unwrap(), b"A\x07\x008");
+        assert_eq!(decode_field(r"\x41\x4\xg").unwrap(), b"A\x04xg");
+        // An unknown escape is the character itself; a lone trailing
+        // backslash is kept.
+        assert_eq!(decode_field(r#"\"\q\"#).unwrap(), b"\"q\\");
+    }
+
+    #[test]
+    fn encoding_round_trips_through_decoding() {
+        for value in [
+            "",
+            "plain",
+            r#"{"c":"a\"b\\c"}"#,
+            "tab\there\nnew\r\u{0b}\u{0c}\u{08}",
+        ] {
+            let encoded = encode_field(value);
+            assert!(!encoded.contains(['\t', '\n', '\r']), "{encoded:?}");
+            assert_eq!(decode_field(&encoded).unwrap(), value.as_bytes());
+        }
+    }
+
+    #[test]
+    fn splits_on_unescaped_tabs_only() {
+        assert_eq!(split_fields("1\ta\\tb\t\\N"), vec!["1", r"a\tb", NULL]);
+        assert_eq!(split_fields(""), vec![""]);
+    }
+}
@@ -0,0 +1,225 @@
+//! Bulk v2 → v3 payload migration over NDJSON or PostgreSQL `COPY` text
+//! streams — the `eql-convert` binary's engine.
+//!
+//! Every payload goes through [`from_v2`] (or [`from_v2_query`]) for one
+//! explicit [`TargetDomain`], row by row, so a dump of any size converts in
+//! constant memory. A row that fails is never written to the output: it goes
+//! to the rejects stream as one JSON object naming the [`FromV2Error`]
+//! variant, and is counted in the [`Summary`].
+
+use std::collections::BTreeMap;
+use std::io::{self, BufRead, Write};
+
+use eql_bindings::from_v2::{from_v2, from_v2_query, FromV2Error, TargetDomain};
+use serde_json::{json, Value};
+
+pub mod copy_text;
+
+/// The input (and output) row format.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    /// One JSON payload per line.
+    Ndjson,
+    /// `COPY ... TO STDOUT` text rows; one column holds the payload, the
+    /// others pass through unchanged.
+    Copy {
+        /// Zero-based index of the payload column.
+        column: usize,
+    },
+}
+
+/// Which payload the conversion emits.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Mode {
+    /// A stored payload, via [`from_v2`].
+    Stored,
+    /// The target's query operand, via [`from_v2_query`].
+    Query,
+}
+
+/// One conversion run: the target and how rows are read.
+#[derive(Clone, Copy, Debug)]
+pub struct Options {
+    /// The v3 domain every payload converts into.
+    pub target: TargetDomain,
+    /// Stored payload or query operand.
+    pub mode: Mode,
+    /// How rows are framed.
+    pub format: Format,
+}
+
+/// Per-run counts. Rejections are keyed by [`reject_kind`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Summary {
+    /// Rows written with a converted payload.
+    pub converted: u64,
+    /// NULL payloads (`\N`, or a JSON `null` line), passed through as-is.
+    pub nulls: u64,
+    /// Rejected rows per error kind.
+    pub rejected: BTreeMap<&'static str, u64>,
+}
+
+impl Summary {
+    /// Total rejected rows across every kind.
+    pub fn rejected_total(&self) -> u64 {
+        self.rejected.values().sum()
+    }
+}
+
+/// Why a row was rejected: a conversion failure, or a row that never reached
+/// the converter.
+#[derive(Debug)]
+pub enum Reject {
+    /// [`from_v2`] / [`from_v2_query`] refused the payload.
+    Convert(FromV2Error),
+    /// The payload is not JSON.
+    InvalidJson(serde_json::Error),
+    /// The row (or its decoded payload field) is not UTF-8.
+    InvalidUtf8,
+    /// A `COPY` row has fewer columns than the payload column index.
+    MissingColumn {
+        /// Columns the row actually has.
+        found: usize,
+    },
+}
+
+/// The stable name a rejection is recorded and counted under: the
+/// [`FromV2Error`] variant name for a conversion failure.
+pub fn reject_kind(reject: &Reject) -> &'static str {
+    match reject {
+        Reject::Convert(e) => match e {
+            FromV2Error::UnsupportedVersion { .. } => "UnsupportedVersion",
+            FromV2Error::UnknownKind { .. } => "UnknownKind",
+            FromV2Error::UnknownDomain { .. } => "UnknownDomain",
+            FromV2Error::MissingTerm { .. } => "MissingTerm",
+            FromV2Error::UnconvertibleSteVecDocument => "UnconvertibleSteVecDocument",
+            FromV2Error::UnconvertibleSteVecQuery => "UnconvertibleSteVecQuery",
+            FromV2Error::KindMismatch { .. } => "KindMismatch",
+            FromV2Error::BloomOutOfRange { .. } => "BloomOutOfRange",
+            FromV2Error::UnsupportedQueryTarget { .. } => "UnsupportedQueryTarget",
+            FromV2Error::Invalid(_) => "Invalid",
+        },
+        Reject::InvalidJson(_) => "InvalidJson",
+        Reject::InvalidUtf8 => "InvalidUtf8",
+        Reject::MissingColumn { .. } => "MissingColumn",
+    }
+}
+
+fn reject_message(reject: &Reject) -> String {
+    match reject {
+        Reject::Convert(e) => e.to_string(),
+        Reject::InvalidJson(e) => format!("payload is not JSON: {e}"),
+        Reject::InvalidUtf8 => "row is not valid UTF-8".into(),
+        Reject::MissingColumn { found } => {
+            format!("row has {found} column(s), fewer than the payload column")
+        }
+    }
+}
+
+/// What one input row turns into.
+enum Row {
+    /// Write this line to the output.
+    Converted(String),
+    /// A NULL payload; write the input line unchanged.
+    Null,
+    /// A line that is not a row (blank NDJSON, the COPY end marker); write
+    /// it unchanged, count nothing.
+    Passthrough,
+}
+
+impl Options {
+    /// Convert one JSON payload text.
+    fn convert_payload(&self, payload: &str) -> Result<Option<String>, Reject> {
+        let v2: Value = serde_json::from_str(payload).map_err(Reject::InvalidJson)?;
+        if v2.is_null() {
+            return Ok(None);
+        }
+        let v3 = match self.mode {
+            Mode::Stored => from_v2(&v2, self.target),
+            Mode::Query => from_v2_query(&v2, self.target),
+        }
+        .map_err(Reject::Convert)?;
+        Ok(Some(v3.to_string()))
+    }
+
+    /// Convert one input line (newline stripped).
+    fn convert_row(&self, line: &[u8]) -> Result<Row, Reject> {
+        let line = std::str::from_utf8(line).map_err(|_| Reject::InvalidUtf8)?;
+        match self.format {
+            Format::Ndjson if line.trim().is_empty() => Ok(Row::Passthrough),
+            Format::Ndjson => Ok(match self.convert_payload(line)? {
+                Some(v3) => Row::Converted(v3),
+                None => Row::Null,
+            }),
+            Format::Copy { .. } if line == copy_text::END_OF_DATA => Ok(Row::Passthrough),
+            Format::Copy { column } => {
+                let mut fields = copy_text::split_fields(line);
+                let found = fields.len();
+                let raw = fields.get(column).ok_or(Reject::MissingColumn { found })?;
+                let Some(decoded) = copy_text::decode_field(raw) else {
+                    return Ok(Row::Null);
+                };
+                let payload = String::from_utf8(decoded).map_err(|_| Reject::InvalidUtf8)?;
+                let Some(v3) = self.convert_payload(&payload)? else {
+                    return Ok(Row::Null);
+                };
+                let encoded = copy_text::encode_field(&v3);
+                fields[column] = &encoded;
+                Ok(Row::Converted(fields.join("\t")))
+            }
+        }
+    }
+}
+
+/// Convert every row of `input`, writing converted (and NULL) rows to
+/// `output` and one JSON object per rejected row to `rejects`:
+/// `{"line", "kind", "error", "row"}`, with the 1-based input line number,
+/// the [`reject_kind`], its message, and the raw row. Only I/O errors abort
+/// the run; a bad row never does.
+pub fn convert_stream(
+    options: &Options,
+    mut input: impl BufRead,
+    mut output: impl Write,
+    mut rejects: impl Write,
+) -> io::Result<Summary> {
+    let mut summary = Summary::default();
+    let mut buf = Vec::new();
+    let mut line_no = 0u64;
+    loop {
+        buf.clear();
+        if input.read_until(b'\n', &mut buf)? == 0 {
+            break;
+        }
+        line_no += 1;
+        let line = buf.strip_suffix(b"\n").unwrap_or(&buf);
+        match options.convert_row(line) {
+            Ok(Row::Converted(row)) => {
+                summary.converted += 1;
+                writeln!(output, "{row}")?;
+            }
+            Ok(Row::Null) => {
+                summary.nulls += 1;
+                output.write_all(line)?;
+                output.write_all(b"\n")?;
+            }
+            Ok(Row::Passthrough) => {
+                output.write_all(line)?;
+                output.write_all(b"\n")?;
+            }
+            Err(reject) => {
+                let kind = reject_kind(&reject);
+                *summary.rejected.entry(kind).or_default() += 1;
+                let record = json!({
+                    "line": line_no,
+                    "kind": kind,
+                    "error": reject_message(&reject),
+                    "row": String::from_utf8_lossy(line),
+                });
+                writeln!(rejects, "{record}")?;
+            }
+        }
+    }
+    output.flush()?;
+    rejects.flush()?;
+    Ok(summary)
+}
@@ -0,0 +1,146 @@
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::process::ExitCode;
+
+use eql_bindings::from_v2::TargetDomain;
+use eql_convert::{convert_stream, Format, Mode, Options};
+
+const USAGE: &str = "\
+Usage: eql-convert --target <domain> --rejects <path> [--query]
+                   [--format ndjson|copy] [--column <n>] [<input> [<output>]]
+
+Convert v2 EQL payloads to v3, one row at a time.
+
+  --target <domain>  v3 domain to convert into, e.g. eql_v3_text_eq
+  --rejects <path>   write one JSON object per rejected row here
+  --query            emit the target's query operand instead of a stored payload
+  --format <format>  ndjson (default): one JSON payload per line
+                     copy: COPY ... TO STDOUT text rows
+  --column <n>       1-based payload column for --format copy (default 1)
+  <input>/<output>   files; stdin/stdout when omitted or `-`
+
+Exit status: 0 when every row converted, 1 when any row was rejected,
+2 on a usage or I/O error.";
+
+struct Args {
+    options: Options,
+    target: String,
+    rejects: String,
+    input: Option<String>,
+    output: Option<String>,
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Args, String> {
+    let mut target = None;
+    let mut rejects = None;
+    let mut mode = Mode::Stored;
+    let mut format = None;
+    let mut column = None;
+    let mut paths = Vec::new();
+    while let Some(arg) = args.next() {
+        let mut value = |flag: &str| args.next().ok_or(format!("{flag} needs a value"));
+        match arg.as_str() {
+            "--target" => target = Some(value("--target")?),
+            "--rejects" => rejects = Some(value("--rejects")?),
+            "--query" => mode = Mode::Query,
+            "--format" => format = Some(value("--format")?),
+            "--column" => {
+                let n = value("--column")?;
+                match n.parse::<usize>() {
+                    Ok(n) if n >= 1 => column = Some(n - 1),
+                    _ => return Err(format!("--column must be a positive integer, got {n:?}")),
+                }
+            }
+            flag if flag.starts_with("--") => return Err(format!("unknown option {flag}")),
+            _ => paths.push(arg),
+        }
+    }
+
+    let target = target.ok_or("--target is required")?;
+    let rejects = rejects.ok_or("--rejects is required")?;
+    let format = match (format.as_deref(), column) {
+        (None | Some("ndjson"), None) => Format::Ndjson,
+        (None | Some("ndjson"), Some(_)) => return Err("--column needs --format copy".into()),
+        (Some("copy"), column) => Format::Copy {
+            column: column.unwrap_or(0),
+        },
+        (Some(other), _) => return Err(format!("unknown --format {other:?}")),
+    };
+    let domain = TargetDomain::parse(&target).map_err(|e| e.to_string())?;
+    if mode == Mode::Query {
+        if let TargetDomain::Scalar(scalar) = domain {
+            if scalar.term_json_keys().is_empty() {
+                return Err(format!(
+                    "storage-only domain `{target}` has no v3 query operand"
+                ));
+            }
+        }
+    }
+    if paths.len() > 2 {
+        return Err(format!("unexpected argument {:?}", paths[2]));
+    }
+    let mut paths = paths.into_iter().filter(|p| p != "-").map(Some);
+    Ok(Args {
+        options: Options {
+            target: domain,
+            mode,
+            format,
+        },
+        target,
+        rejects,
+        input: paths.next().flatten(),
+        output: paths.next().flatten(),
+    })
+}
+
+fn run(args: &Args) -> io::Result<eql_convert::Summary> {
+    let input: Box<dyn Read> = match &args.input {
+        Some(path) => Box::new(File::open(path)?),
+        None => Box::new(io::stdin().lock()),
+    };
+    let output: Box<dyn Write> = match &args.output {
+        Some(path) => Box::new(File::create(path)?),
+        None => Box::new(io::stdout().lock()),
+    };
+    let rejects = File::create(&args.rejects)?;
+    convert_stream(
+        &args.options,
+        BufReader::new(input),
+        BufWriter::new(output),
+        BufWriter::new(rejects),
+    )
+}
+
+fn main() -> ExitCode {
+    let args = match parse_args(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("error: {e}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+    let summary = match run(&args) {
+        Ok(summary) => summary,
+        Err(e) => {
+            eprintln!("error: {e}");
+            return ExitCode::from(2);
+        }
+    };
+
+    eprintln!(
+        "eql-convert: {} converted, {} rejected, {} null (target {})",
+        summary.converted,
+        summary.rejected_total(),
+        summary.nulls,
+        args.target
+    );
+    for (kind, count) in &summary.rejected {
+        eprintln!("  {kind}: {count}");
+    }
+    if summary.rejected.is_empty() {
+        ExitCode::SUCCESS
+    } else {
+        eprintln!("rejected rows written to {}", args.rejects);
+        ExitCode::FAILURE
+    }
+}
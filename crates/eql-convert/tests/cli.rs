@@ -0,0 +1,196 @@
+//! End-to-end tests for the `eql-convert` binary, driven through the
+//! `CARGO_BIN_EXE_eql-convert` path Cargo injects for integration tests (no
+//! `assert_cmd` in this repo).
+
+use std::path::PathBuf;
+use std::process::{Command, Output};
+
+use eql_bindings::from_v2::{from_v2, from_v2_query, TargetDomain};
+use eql_convert::copy_text;
+use serde_json::{json, Value};
+
+const CIPHERTEXT: &str = "mBbL@V^%dN?0W$;g)1-JP*cmqX%JhW0ZKZ^G?lNn$CfXJH";
+const HEX: &str = "8067db44a848ab32c3056a3dbe4edf16";
+
+/// A throwaway directory under the system temp root, removed on drop.
+struct TempDir(PathBuf);
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
+}
+fn tempdir() -> TempDir {
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos();
+    let mut p = std::env::temp_dir();
+    p.push(format!("eql-convert-cli-{}-{nanos}", std::process::id()));
+    std::fs::create_dir_all(&p).unwrap();
+    TempDir(p)
+}
+
+/// A v2 `k:"ct"` payload carrying `hm` (text_eq convertible) or nothing.
+fn v2(with_hm: bool) -> Value {
+    let mut payload = json!({
+        "v": 2,
+        "k": "ct",
+        "c": CIPHERTEXT,
+        "i": { "t": "users", "c": "email" }
+    });
+    if with_hm {
+        payload["hm"] = json!(HEX);
+    }
+    payload
+}
+
+fn target(name: &str) -> TargetDomain {
+    TargetDomain::parse(name).unwrap()
+}
+
+/// Run the binary over `input` with `args`; returns the process output, the
+/// converted rows, and the parsed reject records.
+fn convert(dir: &TempDir, args: &[&str], input: &str) -> (Output, String, Vec<Value>) {
+    let input_path = dir.0.join("input");
+    let output_path = dir.0.join("output");
+    let rejects_path = dir.0.join("rejects.ndjson");
+    std::fs::write(&input_path, input).unwrap();
+    let out = Command::new(env!("CARGO_BIN_EXE_eql-convert"))
+        .args(args)
+        .arg("--rejects")
+        .arg(&rejects_path)
+        .arg(&input_path)
+        .arg(&output_path)
+        .output()
+        .expect("run eql-convert");
+    let converted = std::fs::read_to_string(&output_path).unwrap_or_default();
+    let rejects = std::fs::read_to_string(&rejects_path)
+        .unwrap_or_default()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    (out, converted, rejects)
+}
+
+#[test]
+fn ndjson_converts_good_rows_and_records_each_rejection() {
+    let dir = tempdir();
+    let input = format!(
+        "{}\n{}\nnot json\nnull\n{}\n",
+        v2(true),
+        v2(false),
+        json!({ "v": 3 })
+    );
+    let (out, converted, rejects) = convert(&dir, &["--target", "eql_v3_text_eq"], &input);
+
+    assert_eq!(out.status.code(), Some(1), "rejections exit 1");
+    let expected = from_v2(&v2(true), target("eql_v3_text_eq")).unwrap();
+    assert_eq!(converted, format!("{expected}\nnull\n"));
+
+    let kinds: Vec<(u64, &str)> = rejects
+        .iter()
+        .map(|r| (r["line"].as_u64().unwrap(), r["kind"].as_str().unwrap()))
+        .collect();
+    assert_eq!(
+        kinds,
+        [
+            (2, "MissingTerm"),
+            (3, "InvalidJson"),
+            (5, "UnsupportedVersion")
+        ]
+    );
+    assert_eq!(rejects[0]["row"], v2(false).to_string());
+    assert!(rejects[0]["error"].as_str().unwrap().contains("`hm`"));
+
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert!(
+        stderr.contains("1 converted, 3 rejected, 1 null"),
+        "{stderr}"
+    );
+    for kind in ["MissingTerm: 1", "InvalidJson: 1", "UnsupportedVersion: 1"] {
+        assert!(stderr.contains(kind), "{kind} missing from:\n{stderr}");
+    }
+}
+
+#[test]
+fn copy_rewrites_only_the_payload_column() {
+    let dir = tempdir();
+    let field = copy_text::encode_field(&v2(true).to_string());
+    let input = format!("1\t{field}\ta\\tb\n2\t\\N\tc\n\\.\n");
+    let (out, converted, rejects) = convert(
+        &dir,
+        &[
+            "--target",
+            "eql_v3_text_eq",
+            "--format",
+            "copy",
+            "--column",
+            "2",
+        ],
+        &input,
+    );
+
+    assert!(
+        out.status.success(),
+        "{}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    assert!(rejects.is_empty());
+    let expected = from_v2(&v2(true), target("eql_v3_text_eq")).unwrap();
+    assert_eq!(
+        converted,
+        format!(
+            "1\t{}\ta\\tb\n2\t\\N\tc\n\\.\n",
+            copy_text::encode_field(&expected.to_string())
+        )
+    );
+}
+
+#[test]
+fn copy_row_without_the_payload_column_is_rejected() {
+    let dir = tempdir();
+    let (out, converted, rejects) = convert(
+        &dir,
+        &[
+            "--target",
+            "eql_v3_text_eq",
+            "--format",
+            "copy",
+            "--column",
+            "3",
+        ],
+        "1\tonly-two\n",
+    );
+    assert_eq!(out.status.code(), Some(1));
+    assert!(converted.is_empty());
+    assert_eq!(rejects[0]["kind"], "MissingColumn");
+}
+
+#[test]
+fn query_mode_emits_the_query_operand() {
+    let dir = tempdir();
+    let (out, converted, _) = convert(
+        &dir,
+        &["--target", "eql_v3_text_eq", "--query"],
+        &format!("{}\n", v2(true)),
+    );
+    assert!(out.status.success());
+    let expected = from_v2_query(&v2(true), target("eql_v3_text_eq")).unwrap();
+    assert_eq!(converted, format!("{expected}\n"));
+}
+
+#[test]
+fn bad_invocations_exit_2_before_reading_input() {
+    let dir = tempdir();
+    for args in [
+        &["--target", "eql_v3_nope"][..],
+        &["--target", "eql_v3_text", "--query"],
+        &["--target", "eql_v3_text_eq", "--column", "2"],
+        &["--target", "eql_v3_text_eq", "--format", "csv"],
+        &["--rejects-missing"],
+    ] {
+        let (out, _, _) = convert(&dir, args, "");
+        assert_eq!(out.status.code(), Some(2), "{args:?}");
+        assert!(String::from_utf8_lossy(&out.stderr).contains("Usage: eql-convert"));
+    }
+}
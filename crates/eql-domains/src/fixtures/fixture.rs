@@ -25,8 +25,13 @@ macro_rules! fixtures {
     (jsonb;   $($s:literal),* $(,)?) => { &[$(Fixture::Jsonb($s)),*] };
     (date;    $($s:literal),* $(,)?) => { &[$(Fixture::Date($s)),*] };
     (timestamp; $($s:literal),* $(,)?) => { &[$(Fixture::Timestamp($s)),*] };
+    (timestamp_naive; $($s:literal),* $(,)?) => { &[$(Fixture::NaiveTimestamp($s)),*] };
     (bool;    $($b:literal),* $(,)?) => { &[$(Fixture::Bool($b)),*] };
     (float;   $($s:literal),* $(,)?) => { &[$(Fixture::Float($s)),*] };
+    (uuid;    $($s:literal),* $(,)?) => { &[$(Fixture::Uuid($s)),*] };
+    (time;    $($s:literal),* $(,)?) => { &[$(Fixture::Time($s)),*] };
+    (interval; $($s:literal),* $(,)?) => { &[$(Fixture::Interval($s)),*] };
+    (bytes;   $($s:literal),* $(,)?) => { &[$(Fixture::Bytes($s)),*] };
 }
 
 /// A single fixture plaintext value, value-kind tagged: `Min`/`Max`/`Zero` are
@@ -54,6 +59,20 @@ pub enum Fixture {
     /// stays zero-dep, so the string is parsed into a `chrono::DateTime<Utc>` in
     /// the SQLx harness, not here. Distinct by literal, like `Date`.
     Timestamp(&'static str),
+    /// A zone-less ISO-8601 wall-clock reading (`"1970-01-01T00:00:00"`) — no
+    /// `Z`, no offset. Parsed into a `chrono::NaiveDateTime` in the SQLx
+    /// harness, not here. Distinct by literal, like `Timestamp`.
+    NaiveTimestamp(&'static str),
+    /// A time of day (`"13:30:00"`, optionally with up to six fractional
+    /// digits). Parsed into a `chrono::NaiveTime` in the SQLx harness, not here.
+    /// Distinct by literal, like `Date`.
+    Time(&'static str),
+    /// An ISO-8601 duration with designators (`"P1M"`, `"PT1H30M"`,
+    /// `"PT-0.000001S"`; a field may carry its own sign). Parsed into Postgres
+    /// `interval` fields in the SQLx harness. Distinct by literal here; the
+    /// harness additionally guards distinctness by canonical span (see
+    /// [`crate::INTERVAL`]), which is what the fixture table keys on.
+    Interval(&'static str),
     /// A boolean plaintext (`true` / `false`). The `bool` scalar is
     /// storage-only, so this fixture is encrypted (ciphertext only, no index
     /// term) and never participates in a comparison pivot. Distinct by value.
@@ -64,6 +83,17 @@ pub enum Fixture {
     /// `float_fixtures_are_distinct_by_value` guard enforces this). NaN and
     /// `-0.0` are deliberately excluded; `±Inf` (`"inf"`/`"-inf"`) ARE fixtures.
     Float(&'static str),
+    /// A UUID in canonical lowercase-hyphenated form
+    /// (`"00000000-0000-0000-0000-000000000000"`). The catalog stays zero-dep,
+    /// so the string is parsed into a `uuid::Uuid` in the SQLx harness, not
+    /// here. Distinct by literal; the canonical-form catalog test makes literal
+    /// distinctness equal value distinctness.
+    Uuid(&'static str),
+    /// A byte string as lowercase hex digits, two per byte, with no `\x`
+    /// prefix (`""` is the empty string, `"00ff"` two bytes). Decoded into a
+    /// `Vec<u8>` in the SQLx harness. Distinct by literal; the canonical-form
+    /// catalog test makes literal distinctness equal value distinctness.
+    Bytes(&'static str),
 }
 
 impl Fixture {
@@ -104,7 +134,12 @@ impl Fixture {
             | Fixture::Jsonb(_)
             | Fixture::Date(_)
             | Fixture::Timestamp(_)
+            | Fixture::NaiveTimestamp(_)
+            | Fixture::Time(_)
+            | Fixture::Interval(_)
             | Fixture::Float(_)
+            | Fixture::Uuid(_)
+            | Fixture::Bytes(_)
             | Fixture::Bool(_) => None,
         }
     }
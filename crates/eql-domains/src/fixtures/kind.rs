@@ -5,7 +5,7 @@
 /// The fixed-width integer kinds — exactly those scalar kinds with an `i128`
 /// range and `MIN`/`MAX`/`Zero` sentinels. These accessors are **total**: every
 /// variant answers every method. The non-integer kinds (`Numeric`/`Text`/
-/// `Jsonb`/`Date`/`Timestamp`/`Bool`/`F32`/`F64`) are simply not representable
+/// `Jsonb`/`Date`/`Timestamp`/`NaiveTimestamp`/`Time`/`Interval`/`Bool`/`F32`/`F64`/`Uuid`/`Bytes`) are simply not representable
 /// here, so there is no partial function to panic — `ScalarKind::Date` cannot
 /// call `min_symbol()` because `Date` is not a `BoundedIntKind`. Reach this type
 /// from a `ScalarKind` via [`ScalarKind::as_bounded_int`]. (Accessors are impl'd
@@ -19,9 +19,10 @@ pub enum BoundedIntKind {
 
 /// The native scalar a domain type maps onto. The integer kinds (`I16`/`I32`/
 /// `I64`) carry i128 bounds; the non-integer kinds (`Numeric`/`Text`/`Jsonb`/
-/// `Date`/`Timestamp`/`Bool`/`F32`/`F64`) have no i128 range and string- or
-/// bool-backed fixtures. All but `Jsonb` and `Bool` are still ORE-orderable —
-/// `Jsonb` has no order, and `Bool` is storage-only (no comparison surface).
+/// `Date`/`Timestamp`/`NaiveTimestamp`/`Time`/`Interval`/`Bool`/`F32`/`F64`/`Uuid`/`Bytes`) have no i128 range and string-
+/// or bool-backed fixtures. All but `Jsonb`, `Bool`, `Uuid` and `Bytes` are still
+/// ORE-orderable — `Jsonb` has no order, `Bool` is storage-only (no comparison
+/// surface), and `Uuid` and `Bytes` are equality-only.
 /// Capability layer only: `CATALOG` declares which kinds actually exist.
 ///
 /// The bounded-numeric accessors live on the total [`BoundedIntKind`], reached
@@ -54,6 +55,31 @@ pub enum ScalarKind {
     /// The value is an instant (Postgres `timestamp with time zone`) wearing the
     /// SQL-standard name `timestamp`, matching the cipherstash cast convention.
     Timestamp,
+    /// Wall-clock timestamp with no zone (`chrono::NaiveDateTime`, Postgres
+    /// `timestamp without time zone`). Ordered like [`ScalarKind::Timestamp`]
+    /// and classified identically (temporal, string-backed, no i128 range), but
+    /// the value is a reading, not an instant: it is never shifted to UTC. The
+    /// client has no naive plaintext, so the harness encrypts the reading's
+    /// fields verbatim as a `Plaintext::Timestamp` at offset zero — an exact,
+    /// order-preserving embedding, so equality and ordering are those of the
+    /// wall-clock fields and a session `TimeZone` can never reorder them.
+    NaiveTimestamp,
+    /// Time of day with no zone (`chrono::NaiveTime`, Postgres `time without
+    /// time zone`), `00:00:00` through `23:59:59.999999` at microsecond
+    /// resolution. Postgres also accepts `24:00:00`; chrono cannot represent it
+    /// and it is not a fixture. Temporal and string-backed like
+    /// [`ScalarKind::Date`]. The client has no time plaintext, so the harness
+    /// encrypts microseconds since midnight as a `big_int` — exact and
+    /// order-preserving over the whole range.
+    Time,
+    /// Elapsed duration (Postgres `interval`: months, days, and microseconds
+    /// held as separate fields). Ordered by its **canonical span**, the same
+    /// rule Postgres's own interval comparison applies — see
+    /// [`crate::INTERVAL`]. Not chrono-backed (chrono has no month-carrying
+    /// duration), so not `is_temporal()`; string-backed (ISO-8601 designators)
+    /// at the catalog layer. Signed: negative intervals order below the zero
+    /// interval.
+    Interval,
     /// Boolean (`bool`). **Encryption-only / storage-only**: it carries no index
     /// term and is *not* `is_int()`/`is_temporal()`/`is_text()`. A two-value
     /// column has such low cardinality that any searchable index (even HMAC
@@ -73,6 +99,22 @@ pub enum ScalarKind {
     /// `float8`). The native width of the float crypto path (`F32` widens into
     /// it); otherwise classified exactly like [`ScalarKind::F32`].
     F64,
+    /// RFC 4122 UUID (`uuid::Uuid`, Postgres `uuid`). **Equality-only**: the
+    /// byte order of a UUID carries no meaning a caller would range-scan on
+    /// (v4 is random; v1 scatters its timestamp across the fields), so the
+    /// catalog gives it storage + `_eq` and no ordering term. String-backed at
+    /// the catalog layer in the canonical lowercase-hyphenated form — the form
+    /// the harness encrypts — so two spellings of one UUID cannot produce two
+    /// different `hm` terms.
+    Uuid,
+    /// Binary string (`Vec<u8>`, Postgres `bytea`) — file digests, tokens, and
+    /// other opaque bytes. **Equality-only**, like [`ScalarKind::Uuid`]: the
+    /// values it is meant for are compared whole (a digest is joined on, never
+    /// range-scanned), so it gets storage + `_eq` and no ordering term.
+    /// String-backed at the catalog layer as lowercase hex, which is also the
+    /// text the harness encrypts: hex is injective, so the `hm` term over it
+    /// is an exact equality key for the bytes.
+    Bytes,
 }
 
 impl BoundedIntKind {
@@ -145,8 +187,13 @@ impl ScalarKind {
             | ScalarKind::Bool
             | ScalarKind::F32
             | ScalarKind::F64
+            | ScalarKind::Uuid
+            | ScalarKind::Bytes
             | ScalarKind::Date
-            | ScalarKind::Timestamp => None,
+            | ScalarKind::Timestamp
+            | ScalarKind::NaiveTimestamp
+            | ScalarKind::Time
+            | ScalarKind::Interval => None,
         }
     }
 
@@ -156,11 +203,20 @@ impl ScalarKind {
         self.as_bounded_int().is_some()
     }
 
-    /// True for chrono-backed temporal kinds (`Date`, `Timestamp`) — the kinds
-    /// whose test `ScalarType` impl is generated by `temporal_values!` rather
-    /// than the integer proc-macro path. Replaces the `[temporal]` marker.
+    /// True for chrono-backed temporal kinds (`Date`, `Timestamp`,
+    /// `NaiveTimestamp`, `Time`) — the kinds whose test `ScalarType` impl is
+    /// hand-written over a chrono type rather than generated by the integer
+    /// proc-macro path (`temporal_values!` for the dated kinds; `Time` by hand,
+    /// since midnight is its minimum rather than an interior origin). Replaces
+    /// the `[temporal]` marker.
     pub const fn is_temporal(self) -> bool {
-        matches!(self, ScalarKind::Date | ScalarKind::Timestamp)
+        matches!(
+            self,
+            ScalarKind::Date
+                | ScalarKind::Timestamp
+                | ScalarKind::NaiveTimestamp
+                | ScalarKind::Time
+        )
     }
 
     /// True for the `Text` kind — an unbounded, owned-`String` scalar. Keeps
@@ -187,7 +243,7 @@ impl ScalarKind {
     /// no JSON leaf to compare against, so its query operands must not bind
     /// `public.eql_v3_json_entry` at all — for ANY operator, not just `=`.
     ///
-    /// `Date`/`Timestamp` are the load-bearing `false` rows. JSON has no
+    /// `Date`/`Timestamp`/`NaiveTimestamp`/`Time`/`Interval` are the load-bearing `false` rows. JSON has no
     /// date/timestamp type; in practice those values are **marshaled into
     /// strings** (ISO-8601/RFC 3339), so a "date leaf" IS a text leaf and is
     /// served by the TEXT surface (`query_text_ord` — ISO-8601 string order is
@@ -225,7 +281,17 @@ impl ScalarKind {
             ScalarKind::Bool => true,
             // No native JSON type — marshaled into strings; the text surface
             // owns those leaves.
-            ScalarKind::Date | ScalarKind::Timestamp => false,
+            ScalarKind::Date
+            | ScalarKind::Timestamp
+            | ScalarKind::NaiveTimestamp
+            | ScalarKind::Time
+            | ScalarKind::Interval => false,
+            // Likewise rendered as a JSON string; and `uuid` carries no `Ope`
+            // term, so it could never reach the ordering seam anyway.
+            ScalarKind::Uuid => false,
+            // JSON has no binary type (bytes travel as base64 or hex strings),
+            // and `bytea` is equality-only too.
+            ScalarKind::Bytes => false,
             // A document, not a scalar leaf.
             ScalarKind::Jsonb => false,
         }
@@ -246,10 +312,15 @@ impl ScalarKind {
             ScalarKind::Text => "String",
             ScalarKind::Date => "chrono::NaiveDate",
             ScalarKind::Timestamp => "chrono::DateTime<Utc>",
+            ScalarKind::NaiveTimestamp => "chrono::NaiveDateTime",
+            ScalarKind::Time => "chrono::NaiveTime",
+            ScalarKind::Interval => "sqlx::postgres::types::PgInterval",
             ScalarKind::Numeric => "rust_decimal::Decimal",
             ScalarKind::Bool => "bool",
             ScalarKind::F32 => "f32",
             ScalarKind::F64 => "f64",
+            ScalarKind::Uuid => "uuid::Uuid",
+            ScalarKind::Bytes => "Vec<u8>",
             ScalarKind::Jsonb => "serde_json::Value",
         }
     }
@@ -16,8 +16,9 @@ pub(crate) mod values;
 pub use fixture::Fixture;
 pub use kind::{BoundedIntKind, ScalarKind};
 pub use record::{
-    kind_for, TypeFixtures, BIGINT_FIXTURES, BOOLEAN_FIXTURES, DATE_FIXTURES, DOUBLE_FIXTURES,
-    FIXTURES, INTEGER_FIXTURES, JSON_FIXTURES, NUMERIC_FIXTURES, REAL_FIXTURES, SMALLINT_FIXTURES,
-    TEXT_FIXTURES, TIMESTAMP_FIXTURES,
+    kind_for, TypeFixtures, BIGINT_FIXTURES, BOOLEAN_FIXTURES, BYTEA_FIXTURES, DATE_FIXTURES,
+    DOUBLE_FIXTURES, FIXTURES, INTEGER_ARRAY_FIXTURES, INTEGER_FIXTURES, INTERVAL_FIXTURES,
+    JSON_FIXTURES, NUMERIC_FIXTURES, REAL_FIXTURES, SMALLINT_FIXTURES, TEXT_ARRAY_FIXTURES,
+    TEXT_FIXTURES, TIMESTAMP_FIXTURES, TIMESTAMP_NAIVE_FIXTURES, TIME_FIXTURES, UUID_FIXTURES,
 };
 pub use values::{BIGINT_VALUES, INTEGER_VALUES, SMALLINT_VALUES, TEXT_VALUES};
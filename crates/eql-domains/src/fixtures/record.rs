@@ -141,6 +141,114 @@ pub const JSON_FIXTURES: TypeFixtures = TypeFixtures {
         "{\"hello\":\"world-3\",\"number\":3,\"nested\":{\"deep\":\"constant\"}}"),
 };
 
+/// uuid fixtures — canonical lowercase-hyphenated strings covering the nil and
+/// max UUIDs, every common version (v1/v3/v4/v5/v7), and a pair differing only
+/// in the final nibble. Equality-only, so there are no ordering pivots; the
+/// harness picks its matrix anchors from this list.
+pub const UUID_FIXTURES: TypeFixtures = TypeFixtures {
+    family: &crate::UUID,
+    kind: ScalarKind::Uuid,
+    values: fixtures!(uuid;
+        "00000000-0000-0000-0000-000000000000",
+        "6ba7b810-9dad-11d1-80b4-00c04fd430c8",
+        "6fa459ea-ee8a-3ca4-894e-db77e160355e",
+        "886313e1-3b8a-5372-9b90-0c9aee199e5d",
+        "0190163d-8694-739b-aea5-966c26f8ad91",
+        "123e4567-e89b-42d3-a456-426614174000",
+        "123e4567-e89b-42d3-a456-426614174001",
+        "9f2c1b0e-7d4a-4e8b-b5c6-1a2b3c4d5e6f",
+        "f47ac10b-58cc-4372-a567-0e02b2c3d479",
+        "ffffffff-ffff-ffff-ffff-ffffffffffff"),
+};
+
+/// timestamp_naive fixtures — zone-less ISO-8601 wall-clock readings; the three
+/// temporal pivots (`1900-01-01T00:00:00`, `1970-01-01T00:00:00`,
+/// `2099-12-31T23:59:59`) MUST be present verbatim. Includes readings that do
+/// not exist (`2021-03-14T02:30:00`) or occur twice (`2021-11-07T01:30:00`) in
+/// US zones observing DST — a wall-clock type must store and order both
+/// without ever consulting a zone.
+pub const TIMESTAMP_NAIVE_FIXTURES: TypeFixtures = TypeFixtures {
+    family: &crate::TIMESTAMP_NAIVE,
+    kind: ScalarKind::NaiveTimestamp,
+    values: fixtures!(timestamp_naive;
+        "1900-01-01T00:00:00", "1950-07-15T06:30:00", "1969-12-31T23:59:59",
+        "1970-01-01T00:00:00", "1970-01-01T00:00:01", "1999-12-31T23:59:59",
+        "2000-01-01T00:00:00", "2021-03-14T01:59:59", "2021-03-14T02:30:00",
+        "2021-03-14T03:00:00", "2021-11-07T01:30:00", "2021-11-07T02:00:00",
+        "2024-02-29T17:30:45", "2038-01-19T03:14:07", "2099-12-31T23:59:59"),
+};
+
+/// time fixtures — `HH:MM:SS[.ffffff]` readings; the pivots `00:00:00` (min),
+/// `12:00:00` (mid) and `23:59:59.999999` (max) MUST be present verbatim. The
+/// one-microsecond neighbours of the extremes and of the hour boundaries pin
+/// the full 6-digit resolution.
+pub const TIME_FIXTURES: TypeFixtures = TypeFixtures {
+    family: &crate::TIME,
+    kind: ScalarKind::Time,
+    values: fixtures!(time;
+        "00:00:00", "00:00:00.000001", "00:59:59", "01:00:00", "06:30:00",
+        "08:59:59.999999", "09:00:00", "11:59:59", "12:00:00", "12:00:01",
+        "13:30:00", "17:45:15", "18:00:00", "23:00:00", "23:59:59",
+        "23:59:59.999999"),
+};
+
+/// interval fixtures — ISO-8601 durations, in ascending **canonical span**
+/// order (see [`crate::INTERVAL`]); `P-1000Y` (min), `PT0S` (zero / mid) and
+/// `P1000Y` (max) MUST be present verbatim. Every span is distinct (`P1D` is
+/// here, so `PT24H` cannot be), and the month/day boundaries are probed from
+/// just below (`P29DT23H59M59S` < `P1M`).
+pub const INTERVAL_FIXTURES: TypeFixtures = TypeFixtures {
+    family: &crate::INTERVAL,
+    kind: ScalarKind::Interval,
+    values: fixtures!(interval;
+        "P-1000Y", "P-1M", "P-1D", "PT-1H", "PT-0.000001S", "PT0S",
+        "PT0.000001S", "PT1S", "PT1H30M", "PT23H59M59S", "P1D",
+        "P29DT23H59M59S", "P1M", "P1M1D", "P1Y", "P1000Y"),
+};
+
+/// bytea fixtures — lowercase hex, two digits per byte. Covers the empty
+/// string, single-byte and length-only neighbours (`00` vs `0000`), the MD5,
+/// SHA-1 and SHA-256 digests of the empty input, and two SHA-256-sized values
+/// differing only in the final byte — the collision an equality key must not
+/// have. Equality-only, so no ordering pivots; the harness picks its matrix
+/// anchors from this list.
+pub const BYTEA_FIXTURES: TypeFixtures = TypeFixtures {
+    family: &crate::BYTEA,
+    kind: ScalarKind::Bytes,
+    values: fixtures!(bytes;
+        "",
+        "00",
+        "0000",
+        "01",
+        "ff",
+        "deadbeef",
+        "d41d8cd98f00b204e9800998ecf8427e",
+        "da39a3ee5e6b4b0d3255bfef95601890afd80709",
+        "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+        "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+        "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ae",
+        "ffffffff"),
+};
+
+/// integer_array fixtures — the ELEMENT fixtures, shared with
+/// [`INTEGER_FIXTURES`]. An array family's plaintext is a list of element
+/// values, each hashed exactly like a scalar of the element kind, so the
+/// element set is the one the harness builds its arrays from; `kind` is the
+/// element kind.
+pub const INTEGER_ARRAY_FIXTURES: TypeFixtures = TypeFixtures {
+    family: &crate::INTEGER_ARRAY,
+    kind: ScalarKind::I32,
+    values: INTEGER_FIXTURES.values,
+};
+
+/// text_array fixtures — the element fixtures, shared with [`TEXT_FIXTURES`]
+/// (see [`INTEGER_ARRAY_FIXTURES`]).
+pub const TEXT_ARRAY_FIXTURES: TypeFixtures = TypeFixtures {
+    family: &crate::TEXT_ARRAY,
+    kind: ScalarKind::Text,
+    values: TEXT_FIXTURES.values,
+};
+
 /// The fixture table — one record per scalar type, in `CATALOG` order. The
 /// fixture-layer mirror of `CATALOG`; the `const _` parity block below pins the
 /// parity at build time.
@@ -156,6 +264,13 @@ pub const FIXTURES: &[TypeFixtures] = &[
     REAL_FIXTURES,
     DOUBLE_FIXTURES,
     JSON_FIXTURES,
+    UUID_FIXTURES,
+    TIMESTAMP_NAIVE_FIXTURES,
+    TIME_FIXTURES,
+    INTERVAL_FIXTURES,
+    BYTEA_FIXTURES,
+    INTEGER_ARRAY_FIXTURES,
+    TEXT_ARRAY_FIXTURES,
 ];
 
 /// The native scalar [`ScalarKind`] of a catalog family, by `family.name`
@@ -206,6 +321,11 @@ const fn kind_tag(kind: ScalarKind) -> u8 {
         ScalarKind::Bool => 8,
         ScalarKind::F32 => 9,
         ScalarKind::F64 => 10,
+        ScalarKind::Uuid => 11,
+        ScalarKind::NaiveTimestamp => 12,
+        ScalarKind::Time => 13,
+        ScalarKind::Interval => 14,
+        ScalarKind::Bytes => 15,
     }
 }
 
@@ -237,6 +357,21 @@ const fn expected_kind(name: &str) -> ScalarKind {
         ScalarKind::F64
     } else if str_eq(name, "json") {
         ScalarKind::Jsonb
+    } else if str_eq(name, "uuid") {
+        ScalarKind::Uuid
+    } else if str_eq(name, "timestamp_naive") {
+        ScalarKind::NaiveTimestamp
+    } else if str_eq(name, "time") {
+        ScalarKind::Time
+    } else if str_eq(name, "interval") {
+        ScalarKind::Interval
+    } else if str_eq(name, "bytea") {
+        ScalarKind::Bytes
+    } else if str_eq(name, "integer_array") {
+        // Array families map onto their ELEMENT kind.
+        ScalarKind::I32
+    } else if str_eq(name, "text_array") {
+        ScalarKind::Text
     } else {
         panic!("unmapped scalar token in expected_kind — name its kind here")
     }
@@ -2,8 +2,8 @@
 //! source of truth for every scalar type and term. Std-only, no dependencies.
 //!
 //! Capability axes are independent: equality covers every kind; order covers
-//! every kind except `jsonb` (the ordering terms compare ciphertext, so they
-//! are plaintext-agnostic — `text`/`date` order like integers); only the integer
+//! every kind except `jsonb`, `uuid` and `bytea` (the ordering terms compare ciphertext,
+//! so they are plaintext-agnostic — `text`/`date` order like integers); only the integer
 //! kinds have an i128 range with `Min`/`Max`/`Zero` sentinels. `numeric_value`
 //! cannot yet express the order of a non-integer fixture set.
 //!
@@ -28,9 +28,11 @@ mod term;
 
 pub use fixtures::{
     kind_for, BoundedIntKind, Fixture, ScalarKind, TypeFixtures, BIGINT_FIXTURES, BIGINT_VALUES,
-    BOOLEAN_FIXTURES, DATE_FIXTURES, DOUBLE_FIXTURES, FIXTURES, INTEGER_FIXTURES, INTEGER_VALUES,
-    JSON_FIXTURES, NUMERIC_FIXTURES, REAL_FIXTURES, SMALLINT_FIXTURES, SMALLINT_VALUES,
-    TEXT_FIXTURES, TEXT_VALUES, TIMESTAMP_FIXTURES,
+    BOOLEAN_FIXTURES, BYTEA_FIXTURES, DATE_FIXTURES, DOUBLE_FIXTURES, FIXTURES,
+    INTEGER_ARRAY_FIXTURES, INTEGER_FIXTURES, INTEGER_VALUES, INTERVAL_FIXTURES, JSON_FIXTURES,
+    NUMERIC_FIXTURES, REAL_FIXTURES, SMALLINT_FIXTURES, SMALLINT_VALUES, TEXT_ARRAY_FIXTURES,
+    TEXT_FIXTURES, TEXT_VALUES, TIMESTAMP_FIXTURES, TIMESTAMP_NAIVE_FIXTURES, TIME_FIXTURES,
+    UUID_FIXTURES,
 };
 
 /// Always-present payload keys required by every generated domain CHECK,
@@ -134,10 +136,14 @@ impl Role {
 /// flat envelope+term set — `Term`, `Role`, `operators_for_terms`, and
 /// `capability_label` continue to assume flat-scalar semantics and are simply
 /// never invoked on a SteVec domain (consumers filter/branch on `Shape`).
+/// [`Shape::Array`] sits between the two: its keys ARE the envelope+term set,
+/// but each term key holds one term per element, so its operator surface comes
+/// from [`Term::element_operators`] rather than [`Term::operators`] (resolved
+/// shape-aware by [`Domain::operators`]).
 ///
 /// Coupling invariant (pinned by `tests::shape_and_terms_are_consistent`): a
-/// non-`Scalar` domain always has empty `terms`, and any domain with non-empty
-/// `terms` is `Scalar`. Empty `terms` here does NOT mean "no index capability":
+/// SteVec domain always has empty `terms`, and any domain with non-empty
+/// `terms` is `Scalar` or `Array`. Empty `terms` here does NOT mean "no index capability":
 /// a SteVec domain is searchable by value-selector presence and, for ordered
 /// path entries, an optional CLLW-OPE `op`. These fields live *inside* the
 /// payload shape rather than as a flat family-level `Term` list. The `terms`
@@ -159,6 +165,13 @@ pub enum Shape {
     /// family's fourth domain, the bare storage domain `public.eql_v3_json`, is
     /// `Shape::Scalar`, not SteVec.
     SteVec,
+    /// A catalog-generated array payload: the flat `{v, i, c, +terms}` envelope
+    /// of an encrypted `integer[]`/`text[]`, except that every term key holds a
+    /// JSON array with one term per element (`hm: [..]`). The root `c` encrypts
+    /// the whole array; the terms are what make it searchable by element
+    /// (`@>`, `<@`, `&&` over the element term sets). Only [`Term::Hm`] has a
+    /// per-element form today.
+    Array,
 }
 
 /// One generated public domain: a bare domain name joined under the family
@@ -227,13 +240,9 @@ const ORDERED_INT_DOMAINS: &[Domain] = &[
 
 /// Equality-only domains: storage (no terms) + `_eq` (hm). The canonical shape
 /// for a scalar type that can hash for equality but is not ORE-orderable.
-/// **Currently unused:** `timestamp` (the previous sole user) was promoted to
+/// Used by `uuid` and `bytea`. (`timestamp` was the previous user, until it was promoted to
 /// the ordered shape once `eql_v3.compare_ore_block_256_term` generalized to N
-/// blocks and could order its native 12-block ORE width. Retained — and still
-/// validated as a known-valid shape by `every_type_uses_a_known_domain_shape` —
-/// so a future non-orderable scalar (e.g. a hash-only type) can reuse it without
-/// reconstructing the shape.
-#[allow(dead_code)]
+/// blocks and could order its native 12-block ORE width.)
 const EQ_ONLY_DOMAINS: &[Domain] = &[
     Domain {
         name: "",
@@ -285,9 +294,8 @@ pub const DATE: DomainFamily = DomainFamily {
 /// Values are UTC-normalized (cipherstash has no tz-preserving type) and encrypt
 /// under the `timestamp` cast. NOTE: the value is an instant (Postgres `timestamp
 /// with time zone`); it wears the SQL-standard name `timestamp` to match the
-/// cipherstash cast/`ColumnType`/`Plaintext` convention. A genuinely
-/// without-time-zone `timestamp` (naive wall-clock) is a future, additive type,
-/// blocked on cipherstash-client ORE pre-indexing for naive datetimes.
+/// cipherstash cast/`ColumnType`/`Plaintext` convention. The genuinely
+/// without-time-zone reading is the separate [`TIMESTAMP_NAIVE`] family.
 ///
 /// Public (like `DATE`) because the SQLx harness reads
 /// `TIMESTAMP_FIXTURES.values` directly to parse the RFC3339 strings into
@@ -525,18 +533,173 @@ pub const JSON: DomainFamily = DomainFamily {
     domains: JSON_DOMAINS,
 };
 
+/// `uuid` — an **equality-only** scalar (`ScalarKind::Uuid`): storage
+/// (`public.eql_v3_uuid`) plus `_eq` (`hm`), no ordering domain. A UUID's byte
+/// order is not a meaningful range key, so no ORE/OPE term is spent on it and
+/// `<`/`<=`/`>`/`>=` are blockers. Equality is HMAC over the canonical
+/// lowercase-hyphenated rendering, which the client normalises before
+/// encrypting — callers no longer hand-normalise into `text_eq`.
+///
+/// Public (like `DATE`) so the SQLx harness reads `UUID_FIXTURES.values`
+/// directly to parse the strings into `uuid::Uuid` (the catalog stays zero-dep).
+pub const UUID: DomainFamily = DomainFamily {
+    name: "uuid",
+    domains: EQ_ONLY_DOMAINS,
+};
+
+/// `timestamp_naive` — an **ordered** wall-clock scalar
+/// (`ScalarKind::NaiveTimestamp`, Postgres `timestamp without time zone`).
+/// Same ordered shape and 12-block ORE width as [`TIMESTAMP`], but the value is
+/// a zone-less reading that is never normalised: the client encrypts its fields
+/// verbatim (as a UTC-offset-zero `Plaintext::Timestamp`), so `=` and the
+/// ordering terms compare wall-clock fields. `2021-03-14T02:30:00` is a valid
+/// value here even though no US DST zone ever shows it. Named with a suffix so
+/// the `timestamp` token keeps its existing (instant) meaning.
+///
+/// Public (like `TIMESTAMP`) so the SQLx harness reads
+/// `TIMESTAMP_NAIVE_FIXTURES.values` to parse the strings into
+/// `chrono::NaiveDateTime` (the catalog stays zero-dep).
+pub const TIMESTAMP_NAIVE: DomainFamily = DomainFamily {
+    name: "timestamp_naive",
+    domains: ORDERED_INT_DOMAINS,
+};
+
+/// `time` — an **ordered** time-of-day scalar (`ScalarKind::Time`, Postgres
+/// `time without time zone`). Reuses `ORDERED_INT_DOMAINS`. The client has no
+/// time plaintext, so a value is encrypted as its microseconds since midnight
+/// under the `big_int` cast (8-block ORE) — a bijection onto
+/// `0..86_400_000_000`, so equality and order are exactly those of the clock
+/// reading. `time with time zone` is deliberately not offered: Postgres itself
+/// discourages it, and an offset without a date has no stable instant.
+///
+/// Public (like `DATE`) so the SQLx harness reads `TIME_FIXTURES.values` to
+/// parse the strings into `chrono::NaiveTime` (the catalog stays zero-dep).
+pub const TIME: DomainFamily = DomainFamily {
+    name: "time",
+    domains: ORDERED_INT_DOMAINS,
+};
+
+/// `interval` — an **ordered** duration scalar (`ScalarKind::Interval`,
+/// Postgres `interval`). Reuses `ORDERED_INT_DOMAINS`.
+///
+/// **Canonical ordering.** A Postgres interval keeps months, days, and
+/// microseconds as separate fields, and its comparison is not field-wise: it
+/// compares the *span*
+///
+/// ```text
+/// span = months × 30 days + days × 24 h + microseconds   (in microseconds)
+/// ```
+///
+/// so `'1 mon' = '30 days' = '720 hours'` and `'1 day' = '24 hours'`, while
+/// `'1 mon' > '29 days 23:59:59'`. The encrypted domains adopt exactly this
+/// rule: the client encrypts the span (an `i64` of microseconds, `big_int`
+/// cast) rather than the fields, so `hm`, `ob`, and `op` all agree with
+/// `=`/`<`/`ORDER BY` on a plaintext `interval` column. Two consequences follow:
+///
+/// - Equality is span equality, not spelling equality — `'1 mon'` and
+///   `'30 days'` are the same value to every encrypted operator, as they are to
+///   Postgres. A caller that needs to tell them apart must not use `interval`.
+/// - The span must fit an `i64` of microseconds (about ±292,000 years). Wider
+///   intervals are valid Postgres values but cannot be encrypted.
+///
+/// Public (like `DATE`) so the SQLx harness reads `INTERVAL_FIXTURES.values`
+/// to parse the ISO-8601 strings into interval fields (the catalog stays
+/// zero-dep).
+pub const INTERVAL: DomainFamily = DomainFamily {
+    name: "interval",
+    domains: ORDERED_INT_DOMAINS,
+};
+
+/// `bytea` — an **equality-only** binary scalar (`ScalarKind::Bytes`, Postgres
+/// `bytea`): storage (`public.eql_v3_bytea`) plus `_eq` (`hm`), no ordering
+/// domain. Built for digests and opaque tokens, which are compared and joined
+/// whole, so an ORE/OPE term would only leak structure nobody queries on. The
+/// client encrypts the lowercase hex rendering, so `hm` is an exact equality
+/// key for the bytes and two columns of digests can be joined on `=` without
+/// the base64-into-`text_eq` workaround.
+///
+/// Public (like `UUID`) so the SQLx harness reads `BYTEA_FIXTURES.values` to
+/// decode the hex strings into `Vec<u8>` (the catalog stays zero-dep).
+pub const BYTEA: DomainFamily = DomainFamily {
+    name: "bytea",
+    domains: EQ_ONLY_DOMAINS,
+};
+
+/// Domains of the array families: the bare ciphertext-only storage domain plus
+/// `_contains`, the [`Shape::Array`] domain whose `hm` holds one HMAC per
+/// element. `_contains` answers "array contains element" (`@>` with a
+/// one-element needle), set containment in both directions (`@>`/`<@`), and
+/// overlap (`&&`), all over the extracted `hmac_256[]` sets, so one functional
+/// GIN index on `eql_v3.eq_terms(col)` serves every operator. Element order and
+/// multiplicity are not observable: no `=`, and no positional access.
+const ARRAY_DOMAINS: &[Domain] = &[
+    Domain {
+        name: "",
+        terms: &[],
+        shape: Shape::Scalar,
+    },
+    Domain {
+        name: "contains",
+        terms: &[Term::Hm],
+        shape: Shape::Array,
+    },
+];
+
+/// `integer_array` — an encrypted `integer[]` (a **mixed** family, like
+/// [`JSON`]): the generated storage domain `public.eql_v3_integer_array` plus
+/// the searchable `public.eql_v3_integer_array_contains`. The client encrypts
+/// the whole array into `c` and each element into one `hm`, under the same
+/// equality key as `integer_eq`, so role and id lists become searchable
+/// without moving them into a JSON document.
+///
+/// Public (like `UUID`) so the SQLx harness reads
+/// `INTEGER_ARRAY_FIXTURES.values` — the element fixtures — directly.
+pub const INTEGER_ARRAY: DomainFamily = DomainFamily {
+    name: "integer_array",
+    domains: ARRAY_DOMAINS,
+};
+
+/// `text_array` — an encrypted `text[]` (a **mixed** family): storage
+/// `public.eql_v3_text_array` plus `public.eql_v3_text_array_contains`. Same
+/// shape as [`INTEGER_ARRAY`]; elements hash exactly like `text_eq` values, so
+/// tag membership is exact (case and whitespace significant) — there is no
+/// per-element `match`.
+pub const TEXT_ARRAY: DomainFamily = DomainFamily {
+    name: "text_array",
+    domains: ARRAY_DOMAINS,
+};
+
 /// The domain-family catalog — the single source of truth. Includes both the
-/// scalar (flat) families and the mixed `json` family (SteVec domains + a bare
+/// scalar (flat) families and the mixed families (the `json` family's SteVec
+/// domains, the array families' [`Shape::Array`] domains, each beside a bare
 /// scalar storage domain); scalar-only consumers should iterate
 /// [`scalar_families`] instead. Order is significant (it drives
 /// inventory/generation order). New types are appended as their SQL surface
 /// lands.
 pub const CATALOG: &[DomainFamily] = &[
-    INTEGER, SMALLINT, BIGINT, DATE, TIMESTAMP, NUMERIC, TEXT, BOOLEAN, REAL, DOUBLE, JSON,
+    INTEGER,
+    SMALLINT,
+    BIGINT,
+    DATE,
+    TIMESTAMP,
+    NUMERIC,
+    TEXT,
+    BOOLEAN,
+    REAL,
+    DOUBLE,
+    JSON,
+    UUID,
+    TIMESTAMP_NAIVE,
+    TIME,
+    INTERVAL,
+    BYTEA,
+    INTEGER_ARRAY,
+    TEXT_ARRAY,
 ];
 
 /// The scalar (flat) families of `CATALOG`, in order — everything except the
-/// mixed `json` family (which is non-scalar because it carries SteVec domains).
+/// mixed families (`json`, which carries SteVec domains, and the array
+/// families, which carry [`Shape::Array`] domains).
 /// The DRY entry point for the ~9 scalar-only consumers (`list-types`, the
 /// scalar matrix) so they never re-inline the `is_scalar()` filter.
 pub fn scalar_families() -> impl Iterator<Item = &'static DomainFamily> {
@@ -548,10 +711,11 @@ pub fn scalar_families() -> impl Iterator<Item = &'static DomainFamily> {
 /// families (a family that is not wholly scalar but has some scalar domain — the
 /// `json` family, whose bare `public.eql_v3_json` storage domain is scalar
 /// while its SteVec domains are not). The seam the SQL/bindings materializers
-/// iterate: they render each family's scalar domains and skip its non-scalar
-/// ones, so a fully-scalar family is handled identically to before, and a mixed
-/// family contributes only its scalar surface. Callers pair this with a
-/// per-domain `d.is_scalar()` filter.
+/// iterate: they render each family's generated domains and skip the
+/// hand-written ones, so a fully-scalar family is handled identically to
+/// before, the json family contributes only its scalar storage surface, and an
+/// array family contributes its storage and array domains. Callers pair this
+/// with a per-domain [`Domain::is_generated`] filter.
 pub fn families_with_scalar_domains() -> impl Iterator<Item = &'static DomainFamily> {
     CATALOG
         .iter()
@@ -18,7 +18,7 @@ fn any_term() -> impl Strategy<Value = Term> {
     ]
 }
 
-/// Strategy over the eleven scalar kinds.
+/// Strategy over the sixteen scalar kinds.
 fn any_kind() -> impl Strategy<Value = ScalarKind> {
     prop_oneof![
         Just(ScalarKind::I16),
@@ -30,8 +30,13 @@ fn any_kind() -> impl Strategy<Value = ScalarKind> {
         Just(ScalarKind::Bool),
         Just(ScalarKind::Date),
         Just(ScalarKind::Timestamp),
+        Just(ScalarKind::NaiveTimestamp),
+        Just(ScalarKind::Time),
+        Just(ScalarKind::Interval),
         Just(ScalarKind::F32),
         Just(ScalarKind::F64),
+        Just(ScalarKind::Uuid),
+        Just(ScalarKind::Bytes),
     ]
 }
 
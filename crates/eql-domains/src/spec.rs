@@ -4,7 +4,7 @@
 //! (no `ord` domain).
 //! Definitions for [`DomainFamily`] and [`Domain`] live in `lib.rs`.
 
-use crate::{Domain, DomainFamily};
+use crate::{Domain, DomainFamily, Term};
 
 impl Domain {
     /// The full (unqualified) domain name for this domain under `family_name`:
@@ -87,9 +87,46 @@ impl Domain {
         matches!(self.shape, crate::Shape::Scalar)
     }
 
+    /// True when this domain carries the [`crate::Shape::Array`] payload: the
+    /// flat envelope with one term per element under each term key.
+    pub const fn is_array(&self) -> bool {
+        matches!(self.shape, crate::Shape::Array)
+    }
+
+    /// True when codegen owns this domain's SQL surface and payload struct —
+    /// every shape except the hand-written SteVec domains of the `json`
+    /// family. The per-domain filter the SQL/bindings materializers pair with
+    /// [`crate::families_with_scalar_domains`].
+    pub const fn is_generated(&self) -> bool {
+        matches!(self.shape, crate::Shape::Scalar | crate::Shape::Array)
+    }
+
+    /// The SQL operators this domain supports, resolved by shape: the flat
+    /// [`Term::operators_for_terms`] for a scalar domain, the per-element
+    /// [`Term::element_operators_for_terms`] for an array domain, and none for
+    /// a SteVec domain (its surface is hand-written; its `terms` are empty).
+    pub fn operators(&self) -> Vec<&'static str> {
+        match self.shape {
+            crate::Shape::Scalar => Term::operators_for_terms(self.terms),
+            crate::Shape::Array => Term::element_operators_for_terms(self.terms),
+            crate::Shape::SteVec => Vec::new(),
+        }
+    }
+
+    /// The extractor backing `op` on this domain, resolved by shape like
+    /// [`Self::operators`], or `None` when the domain does not support `op`.
+    pub fn extractor_for_operator(&self, op: &str) -> Option<&'static str> {
+        match self.shape {
+            crate::Shape::Scalar => Term::extractor_for_operator(self.terms, op),
+            crate::Shape::Array => Term::element_extractor_for_operator(self.terms, op),
+            crate::Shape::SteVec => None,
+        }
+    }
+
     /// The Rust/TS struct identifier that represents this domain's payload —
     /// shape-aware, unlike [`Self::struct_ident`]. For [`crate::Shape::Scalar`]
-    /// this is exactly `struct_ident` (derived from the domain name). The
+    /// and the generated [`crate::Shape::Array`] this is exactly `struct_ident`
+    /// (derived from the domain name). The
     /// SteVec shapes' struct bodies are hand-written (not name-derivable — see
     /// `crates/eql-bindings/src/v3/json.rs`), but their identifiers ARE
     /// name-derivable (`"SteVec" + capitalize(name)`), with one irregular case:
@@ -99,7 +136,7 @@ impl Domain {
     /// renderer calls this instead of carrying its own copy of the shape match.
     pub fn rust_struct_name(&self, family_name: &str) -> String {
         match self.shape {
-            crate::Shape::Scalar => self.struct_ident(family_name),
+            crate::Shape::Scalar | crate::Shape::Array => self.struct_ident(family_name),
             crate::Shape::SteVec if self.name == "search" => "SteVecDocument".to_string(),
             crate::Shape::SteVec => format!("SteVec{}", capitalize(self.name)),
         }
@@ -162,10 +199,17 @@ impl DomainFamily {
     }
 
     /// True when every domain in this family is [`crate::Shape::Scalar`] — i.e. it
-    /// is a flat scalar family, not the SteVec `json` family.
+    /// is a flat scalar family, not the SteVec `json` family or an array family.
     pub fn is_scalar(&self) -> bool {
         self.domains.iter().all(|d| d.is_scalar())
     }
+
+    /// True when every domain in this family is [`Domain::is_generated`] — the
+    /// scalar families and the array families, whose whole bindings module is
+    /// catalog-derived. False for `json`, whose SteVec structs are hand-written.
+    pub fn is_generated(&self) -> bool {
+        self.domains.iter().all(|d| d.is_generated())
+    }
 }
 
 #[cfg(test)]
@@ -218,10 +262,12 @@ mod tests {
 
     #[test]
     fn scalar_families_exclude_non_scalar_families_after_jsonb_flip() {
-        use crate::{scalar_families, CATALOG, JSON};
+        use crate::{scalar_families, CATALOG, INTEGER_ARRAY, JSON, TEXT_ARRAY};
         let names: Vec<&str> = scalar_families().map(|f| f.name).collect();
-        assert_eq!(names.len(), CATALOG.len() - 1);
-        assert!(!names.contains(&JSON.name));
+        assert_eq!(names.len(), CATALOG.len() - 3);
+        for mixed in [JSON, INTEGER_ARRAY, TEXT_ARRAY] {
+            assert!(!names.contains(&mixed.name), "{} is mixed", mixed.name);
+        }
         for f in scalar_families() {
             assert!(f.is_scalar());
         }
@@ -266,6 +312,31 @@ mod tests {
         assert_eq!(JSON.domains[3].rust_struct_name(JSON.name), "Json");
     }
 
+    #[test]
+    fn array_domains_are_generated_and_resolve_element_operators() {
+        use crate::{families_with_scalar_domains, INTEGER_ARRAY, JSON, TEXT_ARRAY};
+        for f in [INTEGER_ARRAY, TEXT_ARRAY] {
+            let contains = f.domain_by_name("contains").expect("contains domain");
+            assert!(contains.is_array() && contains.is_generated());
+            assert_eq!(contains.operators(), ["@>", "<@", "&&"]);
+            assert_eq!(contains.extractor_for_operator("&&"), Some("eq_terms"));
+            assert_eq!(contains.extractor_for_operator("="), None);
+            // Mixed, but wholly generated: unlike json, no hand-written module.
+            assert!(!f.is_scalar() && f.is_generated());
+            assert!(families_with_scalar_domains().any(|g| g.name == f.name));
+        }
+        assert_eq!(
+            INTEGER_ARRAY.domains[1].rust_struct_name(INTEGER_ARRAY.name),
+            "IntegerArrayContains"
+        );
+        assert_eq!(
+            TEXT_ARRAY.domain_name(&TEXT_ARRAY.domains[1]),
+            "eql_v3_text_array_contains"
+        );
+        assert!(!JSON.is_generated());
+        assert!(JSON.domains[0].operators().is_empty());
+    }
+
     #[test]
     fn is_eq_only_is_false_for_the_non_scalar_json_family() {
         // `is_eq_only` describes the flat-scalar shape (storage + `_eq`, no
@@ -109,6 +109,38 @@ impl Term {
         matches!(self, Term::Ore | Term::Ope)
     }
 
+    /// The extractor for this term's per-element form on a [`crate::Shape::Array`]
+    /// domain, where the term key holds one term per element: `"eq_terms"`
+    /// for `Hm`. `None` for a term with no per-element form — an ORE/OPE or
+    /// Bloom term per element would order or fuzzy-match elements, which no
+    /// array operator asks for.
+    pub const fn element_extractor(self) -> Option<&'static str> {
+        match self {
+            Term::Hm => Some("eq_terms"),
+            Term::Ore | Term::Bloom | Term::Ope => None,
+        }
+    }
+
+    /// Constructor (unqualified) for the per-element term array, the
+    /// `<ctor>[]` counterpart of [`Term::ctor`]: `"hmac_256_array"` for `Hm`.
+    /// `None` exactly when [`Term::element_extractor`] is.
+    pub const fn element_ctor(self) -> Option<&'static str> {
+        match self {
+            Term::Hm => Some("hmac_256_array"),
+            Term::Ore | Term::Bloom | Term::Ope => None,
+        }
+    }
+
+    /// SQL operators this term supports as a per-element term set: array
+    /// containment both ways plus overlap, each a comparison of the two
+    /// extracted term arrays. Empty for a term with no per-element form.
+    pub const fn element_operators(self) -> &'static [&'static str] {
+        match self {
+            Term::Hm => &["@>", "<@", "&&"],
+            Term::Ore | Term::Bloom | Term::Ope => &[],
+        }
+    }
+
     /// JSON key whose payload must be a NON-EMPTY array for this term to be
     /// well-formed, or `None` if the term imposes no such structural rule. The
     /// ORE term (`ob`) is an array of block terms; an empty array (`ob: []`) is
@@ -203,6 +235,26 @@ impl Term {
             .map(|t| t.extractor())
     }
 
+    /// Supported operators for the union of an array domain's per-element
+    /// terms (catalog order, deduped) — the [`crate::Shape::Array`] counterpart
+    /// of [`Term::operators_for_terms`].
+    pub fn element_operators_for_terms(terms: &[Term]) -> Vec<&'static str> {
+        Self::dedupe_preserving_order(
+            terms
+                .iter()
+                .flat_map(|t| t.element_operators().iter().copied()),
+        )
+    }
+
+    /// The per-element extractor that supports `op` for an array domain
+    /// carrying `terms`, or `None`. First supporting term wins.
+    pub fn element_extractor_for_operator(terms: &[Term], op: &str) -> Option<&'static str> {
+        terms
+            .iter()
+            .find(|t| t.element_operators().contains(&op))
+            .and_then(|t| t.element_extractor())
+    }
+
     /// Generated-file [`Role`] for a domain with these terms. No terms =>
     /// [`Role::Storage`]; otherwise the **richest** role across the terms by
     /// [`Role::rank`] precedence (`Ord > Eq > Match > Storage`). For the current
@@ -232,6 +284,38 @@ mod tests {
         assert_eq!(Term::Ope.binding_newtype(), "OpeCllw");
     }
 
+    #[test]
+    fn only_hm_has_a_per_element_form() {
+        assert_eq!(Term::Hm.element_extractor(), Some("eq_terms"));
+        assert_eq!(Term::Hm.element_ctor(), Some("hmac_256_array"));
+        for t in [Term::Ore, Term::Bloom, Term::Ope] {
+            assert_eq!(t.element_extractor(), None, "{t:?}");
+            assert_eq!(t.element_ctor(), None, "{t:?}");
+            assert!(t.element_operators().is_empty(), "{t:?}");
+        }
+        // The per-element extractor must never shadow the scalar one: a
+        // functional index on `eq_term(col)` and one on `eq_terms(col)` are
+        // different expressions over different domains.
+        assert_ne!(Term::Hm.element_extractor(), Some(Term::Hm.extractor()));
+    }
+
+    #[test]
+    fn element_operators_route_through_the_element_extractor() {
+        assert_eq!(
+            Term::element_operators_for_terms(&[Term::Hm]),
+            ["@>", "<@", "&&"]
+        );
+        for op in ["@>", "<@", "&&"] {
+            assert_eq!(
+                Term::element_extractor_for_operator(&[Term::Hm], op),
+                Some("eq_terms")
+            );
+        }
+        // Element equality is not an array operator.
+        assert_eq!(Term::element_extractor_for_operator(&[Term::Hm], "="), None);
+        assert!(Term::element_operators_for_terms(&[]).is_empty());
+    }
+
     #[test]
     fn payload_terms_is_one_field_per_json_key_in_order() {
         let keys: Vec<&str> = Term::payload_terms(&[Term::Hm, Term::Ore])
@@ -43,8 +43,13 @@ mod rust_tests {
         assert_eq!(ScalarKind::Jsonb.as_bounded_int(), None);
         assert_eq!(ScalarKind::Date.as_bounded_int(), None);
         assert_eq!(ScalarKind::Timestamp.as_bounded_int(), None);
+        assert_eq!(ScalarKind::NaiveTimestamp.as_bounded_int(), None);
+        assert_eq!(ScalarKind::Time.as_bounded_int(), None);
+        assert_eq!(ScalarKind::Interval.as_bounded_int(), None);
         assert_eq!(ScalarKind::F32.as_bounded_int(), None);
         assert_eq!(ScalarKind::F64.as_bounded_int(), None);
+        assert_eq!(ScalarKind::Uuid.as_bounded_int(), None);
+        assert_eq!(ScalarKind::Bytes.as_bounded_int(), None);
     }
 
     #[test]
@@ -83,8 +88,13 @@ mod rust_tests {
         assert!(!ScalarKind::Jsonb.is_int());
         assert!(!ScalarKind::Date.is_int());
         assert!(!ScalarKind::Timestamp.is_int());
+        assert!(!ScalarKind::NaiveTimestamp.is_int());
+        assert!(!ScalarKind::Time.is_int());
+        assert!(!ScalarKind::Interval.is_int());
         assert!(!ScalarKind::F32.is_int());
         assert!(!ScalarKind::F64.is_int());
+        assert!(!ScalarKind::Uuid.is_int());
+        assert!(!ScalarKind::Bytes.is_int());
     }
 
     #[test]
@@ -98,8 +108,13 @@ mod rust_tests {
             ScalarKind::Jsonb,
             ScalarKind::Date,
             ScalarKind::Timestamp,
+            ScalarKind::NaiveTimestamp,
+            ScalarKind::Time,
+            ScalarKind::Interval,
             ScalarKind::F32,
             ScalarKind::F64,
+            ScalarKind::Uuid,
+            ScalarKind::Bytes,
         ] {
             assert!(!k.is_text());
         }
@@ -140,6 +155,42 @@ mod rust_tests {
         assert_eq!(ScalarKind::Timestamp.as_bounded_int(), None);
     }
 
+    #[test]
+    fn naive_timestamp_maps_to_naive_datetime() {
+        // Same classification as `Timestamp` (temporal, no i128 range); only
+        // the plaintext type differs — a zone-less reading, not an instant.
+        assert_eq!(
+            ScalarKind::NaiveTimestamp.rust_type(),
+            "chrono::NaiveDateTime"
+        );
+        assert!(ScalarKind::NaiveTimestamp.is_temporal());
+        assert!(!ScalarKind::NaiveTimestamp.is_int());
+        assert_eq!(ScalarKind::NaiveTimestamp.as_bounded_int(), None);
+    }
+
+    #[test]
+    fn time_maps_to_naive_time() {
+        // A time of day is temporal like the timestamps, but bounded to one
+        // day with midnight as its minimum.
+        assert_eq!(ScalarKind::Time.rust_type(), "chrono::NaiveTime");
+        assert!(ScalarKind::Time.is_temporal());
+        assert!(!ScalarKind::Time.is_int());
+        assert_eq!(ScalarKind::Time.as_bounded_int(), None);
+    }
+
+    #[test]
+    fn interval_maps_to_pg_interval() {
+        // chrono has no month-carrying duration, so the plaintext is sqlx's
+        // three-field interval and the kind is not classed as temporal.
+        assert_eq!(
+            ScalarKind::Interval.rust_type(),
+            "sqlx::postgres::types::PgInterval"
+        );
+        assert!(!ScalarKind::Interval.is_temporal());
+        assert!(!ScalarKind::Interval.is_int());
+        assert_eq!(ScalarKind::Interval.as_bounded_int(), None);
+    }
+
     #[test]
     fn text_maps_to_string() {
         // `rust_type()` is the canonical Rust *plaintext* type name, not the SQL
@@ -200,11 +251,18 @@ mod rust_tests {
     fn is_temporal_classifies_chrono_kinds() {
         assert!(ScalarKind::Date.is_temporal());
         assert!(ScalarKind::Timestamp.is_temporal());
+        assert!(ScalarKind::NaiveTimestamp.is_temporal());
+        assert!(ScalarKind::Time.is_temporal());
+        // Not chrono-backed: an interval carries months, which no chrono
+        // duration can.
+        assert!(!ScalarKind::Interval.is_temporal());
         assert!(!ScalarKind::I16.is_temporal());
         assert!(!ScalarKind::I32.is_temporal());
         assert!(!ScalarKind::I64.is_temporal());
         assert!(!ScalarKind::F32.is_temporal());
         assert!(!ScalarKind::F64.is_temporal());
+        assert!(!ScalarKind::Uuid.is_temporal());
+        assert!(!ScalarKind::Bytes.is_temporal());
     }
 
     #[test]
@@ -219,17 +277,16 @@ mod rust_tests {
             "timestamp is now ordered (native 12-block ORE, comparator generalized to N blocks)"
         );
 
-        // No catalog type is currently eq-only, so exercise `is_eq_only()`'s
-        // positive path with a synthetic spec built on the retained
-        // `EQ_ONLY_DOMAINS` shape (storage + `_eq`, no `_ord`).
-        let eq_only = DomainFamily {
-            name: "synthetic_eq_only",
-            domains: EQ_ONLY_DOMAINS,
-        };
-        assert!(
-            eq_only.is_eq_only(),
-            "a storage+_eq spec (no _ord) must be detected as eq-only"
-        );
+        // `uuid` and `bytea` are the catalog's eq-only types (storage + `_eq`,
+        // no `_ord`): `is_eq_only()`'s positive path without a synthetic spec.
+        for name in ["uuid", "bytea"] {
+            let spec = CATALOG.iter().find(|s| s.name == name).unwrap();
+            assert!(
+                spec.is_eq_only(),
+                "a storage+_eq spec (no _ord) must be detected as eq-only"
+            );
+            assert!(!spec.is_storage_only(), "{name} has an _eq domain");
+        }
     }
 }
 
@@ -536,6 +593,19 @@ mod fixture_tests {
             Fixture::Timestamp("1970-01-01T00:00:00Z").numeric_value(ScalarKind::Timestamp),
             None
         );
+        assert_eq!(
+            Fixture::NaiveTimestamp("1970-01-01T00:00:00")
+                .numeric_value(ScalarKind::NaiveTimestamp),
+            None
+        );
+        assert_eq!(
+            Fixture::Time("12:00:00").numeric_value(ScalarKind::Time),
+            None
+        );
+        assert_eq!(
+            Fixture::Interval("PT0S").numeric_value(ScalarKind::Interval),
+            None
+        );
     }
 
     #[test]
@@ -599,6 +669,25 @@ mod fixture_tests {
                 Fixture::Timestamp("2099-12-31T23:59:59Z")
             ]
         );
+        const READINGS: &[Fixture] = fixtures!(timestamp_naive; "2021-03-14T02:30:00");
+        assert_eq!(READINGS, &[Fixture::NaiveTimestamp("2021-03-14T02:30:00")]);
+        const TIMES: &[Fixture] = fixtures!(time; "00:00:00", "23:59:59.999999");
+        assert_eq!(
+            TIMES,
+            &[Fixture::Time("00:00:00"), Fixture::Time("23:59:59.999999")]
+        );
+        const SPANS: &[Fixture] = fixtures!(interval; "P-1D", "P1M");
+        assert_eq!(
+            SPANS,
+            &[Fixture::Interval("P-1D"), Fixture::Interval("P1M")]
+        );
+        const UUIDS: &[Fixture] = fixtures!(uuid; "00000000-0000-0000-0000-000000000000");
+        assert_eq!(
+            UUIDS,
+            &[Fixture::Uuid("00000000-0000-0000-0000-000000000000")]
+        );
+        const DIGESTS: &[Fixture] = fixtures!(bytes; "", "00ff");
+        assert_eq!(DIGESTS, &[Fixture::Bytes(""), Fixture::Bytes("00ff")]);
     }
 
     #[test]
@@ -652,7 +741,14 @@ mod catalog_tests {
                 "boolean",
                 "real",
                 "double",
-                "json"
+                "json",
+                "uuid",
+                "timestamp_naive",
+                "time",
+                "interval",
+                "bytea",
+                "integer_array",
+                "text_array"
             ]
         );
     }
@@ -682,6 +778,108 @@ mod catalog_tests {
         assert_eq!(bf.values, &[Fixture::Bool(false), Fixture::Bool(true)]);
     }
 
+    #[test]
+    fn uuid_spec_is_eq_only() {
+        let u = scalar("uuid");
+        let uf = fixtures("uuid");
+        assert_eq!(uf.kind, ScalarKind::Uuid);
+        assert_eq!(uf.kind.rust_type(), "uuid::Uuid");
+        // Storage + `_eq` (hm) only — no ordering domain, so no ORE/OPE term.
+        let shape: Vec<(&str, &[Term])> = u.domains.iter().map(|d| (d.name, d.terms)).collect();
+        assert_eq!(shape, vec![("", &[] as &[Term]), ("eq", &[Term::Hm][..])]);
+        assert!(u.is_eq_only());
+        assert!(!u.is_storage_only());
+        assert!(u.domain_by_name("ord").is_none());
+        // None of the bounded / temporal / text / float classifications apply.
+        assert!(!uf.kind.is_int());
+        assert!(!uf.kind.is_temporal());
+        assert!(!uf.kind.is_text());
+        assert!(!uf.kind.is_float());
+        assert!(!uf.kind.has_native_json_leaf());
+        assert_eq!(uf.kind.as_bounded_int(), None);
+    }
+
+    /// Equality is HMAC over the encrypted rendering, so every uuid fixture
+    /// must already be in the canonical lowercase-hyphenated 8-4-4-4-12 form —
+    /// otherwise literal distinctness (what `fixture_values_are_distinct_by_resolved_number`
+    /// checks) would not imply value distinctness. Also pins the nil UUID,
+    /// which the harness relies on as a fixture row.
+    #[test]
+    fn uuid_fixtures_are_canonical() {
+        let strings: Vec<&str> = fixtures("uuid")
+            .values
+            .iter()
+            .map(|f| match f {
+                Fixture::Uuid(s) => *s,
+                other => panic!("non-uuid fixture in uuid row: {other:?}"),
+            })
+            .collect();
+        for s in &strings {
+            assert_eq!(s.len(), 36, "{s} is not 36 chars");
+            for (i, c) in s.char_indices() {
+                if matches!(i, 8 | 13 | 18 | 23) {
+                    assert_eq!(c, '-', "{s}: expected '-' at {i}");
+                } else {
+                    assert!(
+                        c.is_ascii_digit() || ('a'..='f').contains(&c),
+                        "{s}: {c:?} at {i} is not lowercase hex"
+                    );
+                }
+            }
+        }
+        assert!(strings.contains(&"00000000-0000-0000-0000-000000000000"));
+    }
+
+    #[test]
+    fn bytea_spec_is_eq_only() {
+        let b = scalar("bytea");
+        let bf = fixtures("bytea");
+        assert_eq!(bf.kind, ScalarKind::Bytes);
+        assert_eq!(bf.kind.rust_type(), "Vec<u8>");
+        // Storage + `_eq` (hm) only: digests are joined on, never range-scanned.
+        let shape: Vec<(&str, &[Term])> = b.domains.iter().map(|d| (d.name, d.terms)).collect();
+        assert_eq!(shape, vec![("", &[] as &[Term]), ("eq", &[Term::Hm][..])]);
+        assert!(b.is_eq_only());
+        assert!(!b.is_storage_only());
+        assert!(b.domain_by_name("ord").is_none());
+        assert!(!bf.kind.is_int());
+        assert!(!bf.kind.is_temporal());
+        assert!(!bf.kind.is_text());
+        assert!(!bf.kind.is_float());
+        assert!(!bf.kind.has_native_json_leaf());
+        assert_eq!(bf.kind.as_bounded_int(), None);
+    }
+
+    /// The harness encrypts the hex rendering, so each fixture must already be
+    /// canonical — lowercase, two digits per byte — for literal distinctness to
+    /// be byte distinctness. Pins the empty string and a pair of same-length
+    /// digests that differ only in their last byte.
+    #[test]
+    fn bytea_fixtures_are_canonical_hex() {
+        let strings: Vec<&str> = fixtures("bytea")
+            .values
+            .iter()
+            .map(|f| match f {
+                Fixture::Bytes(s) => *s,
+                other => panic!("non-bytes fixture in bytea row: {other:?}"),
+            })
+            .collect();
+        for s in &strings {
+            assert_eq!(s.len() % 2, 0, "{s:?} has an odd number of hex digits");
+            assert!(
+                s.chars()
+                    .all(|c| c.is_ascii_digit() || ('a'..='f').contains(&c)),
+                "{s:?} is not lowercase hex"
+            );
+        }
+        assert!(strings.contains(&""), "the empty byte string is a fixture");
+        let digest = "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015a";
+        for last in ["d", "e"] {
+            let s = format!("{digest}{last}");
+            assert!(strings.contains(&s.as_str()), "missing digest {s}");
+        }
+    }
+
     #[test]
     fn storage_only_is_exclusive_to_bool() {
         // Only `bool` is storage-only today; every comparison-capable type has at
@@ -818,7 +1016,13 @@ mod catalog_tests {
         // query term from a temporal plaintext (OrderableTerm::try_from returns
         // Err(invalid_type) for NaiveDate/Timestamp, 0.38.1), so a
         // json_entry <-> query_date_ord operator could never see a real operand.
-        for kind in [ScalarKind::Date, ScalarKind::Timestamp] {
+        for kind in [
+            ScalarKind::Date,
+            ScalarKind::Timestamp,
+            ScalarKind::NaiveTimestamp,
+            ScalarKind::Time,
+            ScalarKind::Interval,
+        ] {
             assert!(
                 !kind.has_native_json_leaf(),
                 "{kind:?} has no native JSON representation — dates marshal to \
@@ -977,6 +1181,83 @@ mod catalog_tests {
         }
     }
 
+    /// The naive analogue of `timestamp_fixtures_include_pivot_plaintexts`,
+    /// plus the zone-less contract: no fixture carries a `Z` or an offset.
+    #[test]
+    fn timestamp_naive_fixtures_are_zoneless_and_include_pivots() {
+        let strings: Vec<&str> = fixtures("timestamp_naive")
+            .values
+            .iter()
+            .map(|f| match f {
+                Fixture::NaiveTimestamp(s) => *s,
+                other => panic!("non-naive fixture in timestamp_naive row: {other:?}"),
+            })
+            .collect();
+        for pivot in [
+            "1900-01-01T00:00:00",
+            "1970-01-01T00:00:00",
+            "2099-12-31T23:59:59",
+        ] {
+            assert!(
+                strings.contains(&pivot),
+                "timestamp_naive fixtures missing temporal pivot {pivot}"
+            );
+        }
+        for s in &strings {
+            // `YYYY-MM-DDTHH:MM:SS` is exactly 19 bytes; anything longer is a
+            // zone designator or fraction the wall-clock contract does not use.
+            assert_eq!(
+                s.len(),
+                19,
+                "{s} is not a zone-less second-resolution reading"
+            );
+            assert!(
+                !s.ends_with('Z') && !s[10..].contains('+'),
+                "{s} carries a zone"
+            );
+        }
+    }
+
+    #[test]
+    fn time_fixtures_span_the_day_and_include_pivots() {
+        let strings: Vec<&str> = fixtures("time")
+            .values
+            .iter()
+            .map(|f| match f {
+                Fixture::Time(s) => *s,
+                other => panic!("non-time fixture in time row: {other:?}"),
+            })
+            .collect();
+        // Midnight is the domain minimum, noon its midpoint, and the last
+        // microsecond its maximum; `24:00:00` is deliberately absent (see
+        // `TIME_FIXTURES`).
+        for pivot in ["00:00:00", "12:00:00", "23:59:59.999999"] {
+            assert!(
+                strings.contains(&pivot),
+                "time fixtures missing pivot {pivot}"
+            );
+        }
+        assert!(!strings.iter().any(|s| s.starts_with("24:")));
+    }
+
+    #[test]
+    fn interval_fixtures_include_signed_pivots() {
+        let strings: Vec<&str> = fixtures("interval")
+            .values
+            .iter()
+            .map(|f| match f {
+                Fixture::Interval(s) => *s,
+                other => panic!("non-interval fixture in interval row: {other:?}"),
+            })
+            .collect();
+        for pivot in ["P-1000Y", "PT0S", "P1000Y"] {
+            assert!(
+                strings.contains(&pivot),
+                "interval fixtures missing pivot {pivot}"
+            );
+        }
+    }
+
     #[test]
     fn every_type_uses_a_known_domain_shape() {
         // Each scalar's domain shape must be one of the known-valid shapes:
@@ -1040,16 +1321,19 @@ mod catalog_tests {
 
     #[test]
     fn ordered_and_eq_only_shapes_are_used_as_declared() {
-        // No catalog type is the two-domain equality-only shape: the ordered
-        // types use the four-domain shape (timestamp was promoted to ordered
+        // `uuid` and `bytea` are the only two-domain equality-only types: the
+        // ordered types use the ordered shape (timestamp was promoted to ordered
         // once the ORE comparator generalized to N blocks — see the numeric/ORE
         // work), and `bool` is the one-domain storage-only shape (strictly
-        // smaller than eq-only). So `domains.len() == 2` should appear nowhere.
-        for s in CATALOG {
+        // smaller than eq-only). So `domains.len() == 2` should appear on those
+        // two alone. Flat scalars only: the array families also pair storage
+        // with one searchable domain, but theirs is `_contains`, not `_eq`.
+        for s in crate::scalar_families() {
             let is_eq_only = s.domains.len() == 2;
-            assert!(
-                !is_eq_only,
-                "{} is unexpectedly eq-only; no catalog type is eq-only currently",
+            assert_eq!(
+                is_eq_only,
+                matches!(s.name, "uuid" | "bytea"),
+                "{} eq-only classification is wrong; only uuid and bytea are eq-only",
                 s.name
             );
         }
@@ -1063,7 +1347,7 @@ mod catalog_tests {
         for rec in FIXTURES.iter().filter(|r| r.kind.is_int()) {
             let expected = match rec.family.name {
                 "smallint" => ScalarKind::I16,
-                "integer" => ScalarKind::I32,
+                "integer" | "integer_array" => ScalarKind::I32,
                 "bigint" => ScalarKind::I64,
                 other => panic!("unmapped integer scalar token {other}"),
             };
@@ -1091,12 +1375,20 @@ mod catalog_tests {
                 "bigint" => ScalarKind::I64,
                 "date" => ScalarKind::Date,
                 "timestamp" => ScalarKind::Timestamp,
+                "timestamp_naive" => ScalarKind::NaiveTimestamp,
+                "time" => ScalarKind::Time,
+                "interval" => ScalarKind::Interval,
                 "numeric" => ScalarKind::Numeric,
                 "text" => ScalarKind::Text,
                 "boolean" => ScalarKind::Bool,
                 "real" => ScalarKind::F32,
                 "double" => ScalarKind::F64,
                 "json" => ScalarKind::Jsonb,
+                "uuid" => ScalarKind::Uuid,
+                "bytea" => ScalarKind::Bytes,
+                // Array families carry their element kind.
+                "integer_array" => ScalarKind::I32,
+                "text_array" => ScalarKind::Text,
                 other => panic!("unmapped scalar token {other} in FIXTURES"),
             };
             assert_eq!(
@@ -1406,10 +1698,18 @@ mod invariant_tests {
             | Fixture::Jsonb(s)
             | Fixture::Date(s)
             | Fixture::Timestamp(s)
+            | Fixture::NaiveTimestamp(s)
+            | Fixture::Time(s)
+            | Fixture::Interval(s)
             // Float fixtures dedupe by their literal here, like the other
             // string-backed kinds (every float literal is distinct; the harness
             // `float_fixtures_are_distinct_by_value` guard pins value-distinctness).
-            | Fixture::Float(s) => DistinctKey::Str(s),
+            | Fixture::Float(s)
+            // Uuid literals are canonical (`uuid_fixtures_are_canonical`), so
+            // literal distinctness is value distinctness.
+            | Fixture::Uuid(s)
+            // Likewise bytea hex (`bytea_fixtures_are_canonical_hex`).
+            | Fixture::Bytes(s) => DistinctKey::Str(s),
             // `bool` is storage-only and string-backed for distinctness: the two
             // values dedupe by their literal, like the other non-numeric kinds.
             Fixture::Bool(b) => DistinctKey::Str(if b { "true" } else { "false" }),
@@ -1551,22 +1851,40 @@ mod shape_tests {
         // no separate chain of the JSONB const needed.
         for f in CATALOG {
             for d in f.domains {
-                let scalar = matches!(d.shape, Shape::Scalar);
-                // Non-scalar ⇒ empty terms; non-empty terms ⇒ scalar.
-                if !scalar {
+                // SteVec ⇒ empty terms; non-empty terms ⇒ scalar or array.
+                if matches!(d.shape, Shape::SteVec) {
                     assert!(
                         d.terms.is_empty(),
-                        "non-scalar {}.{} must have empty terms",
+                        "SteVec {}.{} must have empty terms",
                         f.name,
                         d.name
                     );
                 }
                 if !d.terms.is_empty() {
                     assert!(
-                        scalar,
-                        "termful {}.{} must be Shape::Scalar",
-                        f.name, d.name
+                        matches!(d.shape, Shape::Scalar | Shape::Array),
+                        "termful {}.{} must be Shape::Scalar or Shape::Array",
+                        f.name,
+                        d.name
+                    );
+                }
+                // An array domain is searchable only through per-element
+                // terms, so every term it carries must have that form.
+                if matches!(d.shape, Shape::Array) {
+                    assert!(
+                        !d.terms.is_empty(),
+                        "array {}.{} has no terms",
+                        f.name,
+                        d.name
                     );
+                    for t in d.terms {
+                        assert!(
+                            t.element_extractor().is_some(),
+                            "array {}.{} carries {t:?}, which has no per-element form",
+                            f.name,
+                            d.name
+                        );
+                    }
                 }
             }
         }
@@ -1592,4 +1910,36 @@ mod shape_tests {
             "JSON must be catalogued at the flip"
         );
     }
+
+    #[test]
+    fn array_families_pair_storage_with_a_contains_domain() {
+        use crate::{ScalarKind, TEXT_ARRAY_FIXTURES};
+        use crate::{Shape, Term, INTEGER_ARRAY, INTEGER_ARRAY_FIXTURES, TEXT_ARRAY};
+        for f in [INTEGER_ARRAY, TEXT_ARRAY] {
+            let shape: Vec<(&str, &[Term], Shape)> = f
+                .domains
+                .iter()
+                .map(|d| (d.name, d.terms, d.shape))
+                .collect();
+            assert_eq!(
+                shape,
+                [
+                    ("", &[] as &[Term], Shape::Scalar),
+                    ("contains", &[Term::Hm][..], Shape::Array),
+                ],
+                "{}",
+                f.name
+            );
+            // Not eq-only and not storage-only: both are flat-scalar shapes.
+            assert!(!f.is_eq_only() && !f.is_storage_only());
+        }
+        // The fixtures are the element fixtures, under the element kind.
+        assert_eq!(INTEGER_ARRAY_FIXTURES.kind, ScalarKind::I32);
+        assert_eq!(
+            INTEGER_ARRAY_FIXTURES.values,
+            crate::INTEGER_FIXTURES.values
+        );
+        assert_eq!(TEXT_ARRAY_FIXTURES.kind, ScalarKind::Text);
+        assert_eq!(TEXT_ARRAY_FIXTURES.values, crate::TEXT_FIXTURES.values);
+    }
 }
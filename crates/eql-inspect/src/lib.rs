@@ -0,0 +1,214 @@
+//! Explain a raw EQL payload — the `eql-inspect` binary's engine.
+//!
+//! [`inspect`] tries the blob against every domain in the v3 inventory
+//! ([`all`] for stored payloads and the SteVec shapes, [`all_query`] for the
+//! query operands) with the strict [`DomainType::parse_value`], and reports
+//! the envelope it can read regardless of whether anything accepts it. Since
+//! cross-family payloads are byte-identical on the wire, a scalar payload is
+//! accepted by the same capability in every family — the list says which
+//! domains *could* hold it, never which one it came from.
+
+use std::collections::BTreeSet;
+use std::fmt;
+
+use eql_bindings::v3::{all, all_query, DomainType};
+use serde::Serialize;
+use serde_json::Value;
+
+/// Envelope and structural keys: not terms, never "unknown".
+const ENVELOPE_KEYS: &[&str] = &["v", "k", "i", "c", "h", "sv", "a", "s"];
+
+/// What a payload is.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Inspection {
+    /// The envelope version `v`, as carried (`null` when absent).
+    pub version: Value,
+    /// The `k` form discriminator (`"ct"`/`"sv"`), if any.
+    pub form: Option<Value>,
+    /// The `i` identifier (`{t, c}`), if any.
+    pub identifier: Option<Value>,
+    /// Qualified names of every domain the payload strictly parses as, in
+    /// inventory order (stored domains, then query operands).
+    pub accepted_by: Vec<&'static str>,
+    /// Term keys (`hm`/`ob`/`bf`/`op`) the payload carries.
+    pub terms_present: Vec<&'static str>,
+    /// With [`inspect_against`], the terms that domain requires and the
+    /// payload lacks. Empty from [`inspect`], which names no domain: a term
+    /// only some other domain needs is not missing.
+    pub terms_missing: Vec<&'static str>,
+    /// Top-level keys no domain defines.
+    pub unknown_keys: Vec<String>,
+    /// The SteVec entries, for a payload with an `sv` array.
+    pub ste_vec: Option<SteVecSummary>,
+    /// The requested domain's verdict, from [`inspect_against`].
+    pub against: Option<Verdict>,
+}
+
+/// The shape of a SteVec document's (or needle's) `sv` array.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct SteVecSummary {
+    /// Number of entries.
+    pub entries: usize,
+    /// Indexes of entries carrying the array marker `a: true`.
+    pub array_entries: Vec<usize>,
+    /// Indexes of entries carrying an `op` ordering term.
+    pub ordered_entries: Vec<usize>,
+}
+
+/// Why one named domain does or does not accept the payload.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Verdict {
+    /// The domain's qualified name.
+    pub domain: &'static str,
+    /// The strict parse error; `None` when the domain accepts the payload.
+    pub error: Option<String>,
+}
+
+/// Every inventory domain, stored then query, each once.
+fn inventory() -> Vec<Box<dyn DomainType>> {
+    let mut seen = BTreeSet::new();
+    all()
+        .into_iter()
+        .chain(all_query())
+        .filter(|d| seen.insert(d.sql_domain()))
+        .collect()
+}
+
+/// Every flat term key any domain requires, in first-seen inventory order.
+fn known_terms(inventory: &[Box<dyn DomainType>]) -> Vec<&'static str> {
+    let mut terms = Vec::new();
+    for key in inventory
+        .iter()
+        .filter_map(|d| d.term_json_keys())
+        .flatten()
+    {
+        if !terms.contains(key) {
+            terms.push(*key);
+        }
+    }
+    terms
+}
+
+/// Inspect `payload` against the whole inventory.
+pub fn inspect(payload: &Value) -> Inspection {
+    let inventory = inventory();
+    let known = known_terms(&inventory);
+    let object = payload.as_object();
+    let has = |key: &str| object.is_some_and(|o| o.contains_key(key));
+
+    let terms_present = known.iter().copied().filter(|key| has(key)).collect();
+    let unknown_keys = object
+        .into_iter()
+        .flat_map(|o| o.keys())
+        .filter(|key| !ENVELOPE_KEYS.contains(&key.as_str()) && !known.contains(&key.as_str()))
+        .cloned()
+        .collect();
+    let ste_vec = payload.get("sv").and_then(Value::as_array).map(|entries| {
+        let indexes = |pred: &dyn Fn(&Value) -> bool| {
+            entries
+                .iter()
+                .enumerate()
+                .filter(|(_, entry)| pred(entry))
+                .map(|(i, _)| i)
+                .collect()
+        };
+        SteVecSummary {
+            entries: entries.len(),
+            array_entries: indexes(&|e| e.get("a") == Some(&Value::Bool(true))),
+            ordered_entries: indexes(&|e| e.get("op").is_some()),
+        }
+    });
+
+    Inspection {
+        version: payload.get("v").cloned().unwrap_or(Value::Null),
+        form: payload.get("k").cloned(),
+        identifier: payload.get("i").cloned(),
+        accepted_by: inventory
+            .iter()
+            .filter(|d| d.parse_value(payload).is_ok())
+            .map(|d| d.sql_domain())
+            .collect(),
+        terms_present,
+        terms_missing: Vec::new(),
+        unknown_keys,
+        ste_vec,
+        against: None,
+    }
+}
+
+/// [`inspect`], plus why `domain` (qualified, `public.eql_v3_text_eq`, or
+/// unqualified, `eql_v3_text_eq` / `query_text_eq`) does or does not accept
+/// the payload. Missing terms are narrowed to the ones `domain` requires.
+/// `None` when no inventory domain has that name.
+pub fn inspect_against(payload: &Value, domain: &str) -> Option<Inspection> {
+    let target = inventory()
+        .into_iter()
+        .find(|d| d.sql_domain() == domain || d.domain() == domain)?;
+    let mut inspection = inspect(payload);
+    if let Some(required) = target.term_json_keys() {
+        inspection.terms_missing = required
+            .iter()
+            .copied()
+            .filter(|key| !inspection.terms_present.contains(key))
+            .collect();
+    }
+    inspection.against = Some(Verdict {
+        domain: target.sql_domain(),
+        error: target.parse_value(payload).err().map(|e| e.to_string()),
+    });
+    Some(inspection)
+}
+
+fn list<T: fmt::Display>(items: &[T]) -> String {
+    if items.is_empty() {
+        return "(none)".into();
+    }
+    items
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+impl fmt::Display for Inspection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let version = match &self.version {
+            Value::Null => "(absent)".to_string(),
+            v @ Value::Number(n) if n.as_u64() == Some(2) => format!("{v} (legacy v2 payload)"),
+            v => v.to_string(),
+        };
+        writeln!(f, "version:       {version}")?;
+        if let Some(form) = &self.form {
+            writeln!(f, "form (k):      {form}")?;
+        }
+        match &self.identifier {
+            Some(i) => writeln!(f, "identifier:    {i}")?,
+            None => writeln!(f, "identifier:    (absent)")?,
+        }
+        writeln!(f, "terms present: {}", list(&self.terms_present))?;
+        if self.against.is_some() {
+            writeln!(f, "terms missing: {}", list(&self.terms_missing))?;
+        }
+        writeln!(f, "unknown keys:  {}", list(&self.unknown_keys))?;
+        if let Some(sv) = &self.ste_vec {
+            writeln!(f, "sv entries:    {}", sv.entries)?;
+            writeln!(f, "  array (a):   {}", list(&sv.array_entries))?;
+            writeln!(f, "  ordered (op): {}", list(&sv.ordered_entries))?;
+        }
+        if self.accepted_by.is_empty() {
+            writeln!(f, "accepted by:   no v3 domain")?;
+        } else {
+            writeln!(f, "accepted by:   {} domain(s)", self.accepted_by.len())?;
+            for domain in &self.accepted_by {
+                writeln!(f, "  {domain}")?;
+            }
+        }
+        if let Some(Verdict { domain, error }) = &self.against {
+            match error {
+                None => writeln!(f, "{domain}: accepts")?,
+                Some(e) => writeln!(f, "{domain}: rejects — {e}")?,
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,85 @@
+use std::io::Read;
+use std::process::ExitCode;
+
+use eql_inspect::{inspect, inspect_against};
+use serde_json::Value;
+
+const USAGE: &str = "\
+Usage: eql-inspect [--json] [--domain <domain>] [<file>]
+
+Explain a raw EQL payload: envelope, terms, SteVec entries, and every v3
+domain that strictly accepts it. Reads stdin when <file> is omitted or `-`.
+
+  --json             print the report as JSON
+  --domain <domain>  also explain why <domain> accepts or rejects it
+
+Exit status: 0 when some domain (or the --domain) accepts the payload,
+1 when none does, 2 on a usage, I/O or JSON syntax error.";
+
+fn main() -> ExitCode {
+    let mut json = false;
+    let mut domain = None;
+    let mut path = None;
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--json" => json = true,
+            "--domain" => match args.next() {
+                Some(d) => domain = Some(d),
+                None => return usage("--domain needs a value"),
+            },
+            flag if flag.starts_with("--") => return usage(&format!("unknown option {flag}")),
+            _ if path.is_some() => return usage(&format!("unexpected argument {arg:?}")),
+            _ => path = Some(arg),
+        }
+    }
+
+    let text = match path.as_deref() {
+        None | Some("-") => {
+            let mut text = String::new();
+            std::io::stdin().read_to_string(&mut text).map(|_| text)
+        }
+        Some(path) => std::fs::read_to_string(path),
+    };
+    let payload: Value = match text
+        .map_err(|e| e.to_string())
+        .and_then(|t| serde_json::from_str(&t).map_err(|e| format!("not JSON: {e}")))
+    {
+        Ok(payload) => payload,
+        Err(e) => {
+            eprintln!("error: {e}");
+            return ExitCode::from(2);
+        }
+    };
+
+    let inspection = match &domain {
+        None => inspect(&payload),
+        Some(d) => match inspect_against(&payload, d) {
+            Some(inspection) => inspection,
+            None => return usage(&format!("unknown domain {d:?}")),
+        },
+    };
+    if json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&inspection).expect("serialize inspection")
+        );
+    } else {
+        print!("{inspection}");
+    }
+
+    let accepted = match &inspection.against {
+        Some(verdict) => verdict.error.is_none(),
+        None => !inspection.accepted_by.is_empty(),
+    };
+    if accepted {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn usage(error: &str) -> ExitCode {
+    eprintln!("error: {error}\n\n{USAGE}");
+    ExitCode::from(2)
+}
@@ -0,0 +1,68 @@
+//! Smoke tests for the `eql-inspect` binary: exit status and the `--json`
+//! report, driven through `CARGO_BIN_EXE_eql-inspect`.
+
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+use serde_json::{json, Value};
+
+fn run(args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_eql-inspect"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("run eql-inspect");
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+fn text_eq() -> String {
+    json!({
+        "v": 3,
+        "i": { "t": "users", "c": "email" },
+        "c": "mBbL@V^%dN?0W$;g)1-JP*cmqX%JhW0ZKZ^G?lNn$CfXJH",
+        "hm": "8067db44a848ab32c3056a3dbe4edf16"
+    })
+    .to_string()
+}
+
+#[test]
+fn json_report_for_an_accepted_payload() {
+    let out = run(&["--json"], &text_eq());
+    assert!(out.status.success());
+    let report: Value = serde_json::from_slice(&out.stdout).unwrap();
+    assert_eq!(report["terms_present"], json!(["hm"]));
+    assert!(report["accepted_by"]
+        .as_array()
+        .unwrap()
+        .contains(&json!("public.eql_v3_text_eq")));
+}
+
+#[test]
+fn exit_status_follows_the_verdict() {
+    assert_eq!(run(&[], "{}").status.code(), Some(1));
+    assert_eq!(
+        run(&["--domain", "eql_v3_text_search"], &text_eq())
+            .status
+            .code(),
+        Some(1)
+    );
+    let out = run(&["--domain", "eql_v3_text_eq"], &text_eq());
+    assert!(out.status.success());
+    assert!(String::from_utf8_lossy(&out.stdout).contains("public.eql_v3_text_eq: accepts"));
+
+    for (args, stdin) in [
+        (&["--domain", "eql_v3_nope"][..], text_eq()),
+        (&[], "not json".into()),
+        (&["--bogus"], text_eq()),
+    ] {
+        assert_eq!(run(args, &stdin).status.code(), Some(2), "{args:?}");
+    }
+}
@@ -0,0 +1,125 @@
+//! [`inspect`] / [`inspect_against`] over representative payloads: every
+//! accepting domain found, terms classified, SteVec entries summarised.
+
+use eql_bindings::v3::{all, all_query};
+use eql_inspect::{inspect, inspect_against, SteVecSummary};
+use serde_json::{json, Value};
+
+const CIPHERTEXT: &str = "mBbL@V^%dN?0W$;g)1-JP*cmqX%JhW0ZKZ^G?lNn$CfXJH";
+const HEX: &str = "8067db44a848ab32c3056a3dbe4edf16";
+const SELECTOR: &str = "9493d6010fe7845d52149b697729c745";
+
+fn ident() -> Value {
+    json!({ "t": "users", "c": "email" })
+}
+
+fn text_eq() -> Value {
+    json!({ "v": 3, "i": ident(), "c": CIPHERTEXT, "hm": HEX })
+}
+
+#[test]
+fn lists_exactly_the_domains_whose_strict_parse_succeeds() {
+    let payload = text_eq();
+    let expected: Vec<&str> = all()
+        .iter()
+        .chain(all_query().iter())
+        .filter(|d| d.parse_value(&payload).is_ok())
+        .map(|d| d.sql_domain())
+        .collect();
+    let inspection = inspect(&payload);
+    assert_eq!(inspection.accepted_by, expected);
+    // The same capability in every family, and nothing else.
+    assert!(inspection.accepted_by.contains(&"public.eql_v3_text_eq"));
+    assert!(inspection.accepted_by.contains(&"public.eql_v3_integer_eq"));
+    assert!(!inspection.accepted_by.contains(&"public.eql_v3_text"));
+
+    assert_eq!(inspection.version, json!(3));
+    assert_eq!(inspection.identifier, Some(ident()));
+    assert_eq!(inspection.form, None);
+    assert_eq!(inspection.terms_present, ["hm"]);
+    assert!(inspection.unknown_keys.is_empty());
+    assert_eq!(inspection.ste_vec, None);
+}
+
+#[test]
+fn a_well_formed_eq_payload_reports_no_missing_terms() {
+    // No domain named, so nothing is missing: `ob`/`bf`/`op` belong to
+    // other domains, not to the `_eq` payload this is.
+    let inspection = inspect(&text_eq());
+    assert!(inspection.terms_missing.is_empty());
+    assert!(!inspection.to_string().contains("terms missing"));
+    let inspection = inspect_against(&text_eq(), "eql_v3_text_eq").unwrap();
+    assert!(inspection.terms_missing.is_empty());
+}
+
+#[test]
+fn a_query_operand_is_accepted_by_its_query_domains() {
+    let operand = json!({ "v": 3, "i": ident(), "hm": HEX });
+    let inspection = inspect(&operand);
+    assert!(inspection.accepted_by.contains(&"eql_v3.query_text_eq"));
+    assert!(inspection
+        .accepted_by
+        .iter()
+        .all(|d| d.starts_with("eql_v3.query_")));
+}
+
+#[test]
+fn unknown_keys_and_legacy_payloads_are_accepted_by_nothing() {
+    let mut payload = text_eq();
+    payload["zz"] = json!(1);
+    let inspection = inspect(&payload);
+    assert!(inspection.accepted_by.is_empty());
+    assert_eq!(inspection.unknown_keys, ["zz"]);
+
+    let v2 = json!({ "v": 2, "k": "ct", "i": ident(), "c": CIPHERTEXT, "hm": HEX });
+    let inspection = inspect(&v2);
+    assert!(inspection.accepted_by.is_empty());
+    assert_eq!(inspection.version, json!(2));
+    assert_eq!(inspection.form, Some(json!("ct")));
+    assert!(inspection.to_string().contains("legacy v2 payload"));
+}
+
+#[test]
+fn against_a_domain_names_its_missing_terms_and_parse_error() {
+    let inspection = inspect_against(&text_eq(), "eql_v3_text_search").unwrap();
+    assert_eq!(inspection.terms_missing, ["op", "bf"]);
+    assert!(inspection.to_string().contains("terms missing: op, bf"));
+    let verdict = inspection.against.unwrap();
+    assert_eq!(verdict.domain, "public.eql_v3_text_search");
+    assert!(verdict.error.unwrap().contains("op"));
+
+    // Qualified and query names resolve too; an accepting domain has no error.
+    let inspection = inspect_against(&text_eq(), "public.eql_v3_text_eq").unwrap();
+    assert_eq!(inspection.against.unwrap().error, None);
+    assert!(inspect_against(&text_eq(), "query_text_eq").is_some());
+    assert!(inspect_against(&text_eq(), "eql_v3_nope").is_none());
+}
+
+#[test]
+fn summarises_ste_vec_entries() {
+    let document = json!({
+        "v": 3,
+        "k": "sv",
+        "i": ident(),
+        "h": "mp_base85_key_header",
+        "sv": [
+            { "s": SELECTOR, "c": CIPHERTEXT },
+            { "s": SELECTOR, "c": CIPHERTEXT, "a": true },
+            { "s": SELECTOR, "c": CIPHERTEXT, "op": HEX },
+            { "s": SELECTOR, "c": CIPHERTEXT, "a": true, "op": HEX }
+        ]
+    });
+    let inspection = inspect(&document);
+    assert_eq!(inspection.accepted_by, ["public.eql_v3_json_search"]);
+    assert_eq!(
+        inspection.ste_vec,
+        Some(SteVecSummary {
+            entries: 4,
+            array_entries: vec![1, 3],
+            ordered_entries: vec![2, 3],
+        })
+    );
+    // Flat terms do not apply to a SteVec document.
+    assert!(inspection.terms_present.is_empty());
+    assert!(inspection.terms_missing.is_empty());
+}
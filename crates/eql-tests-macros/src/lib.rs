@@ -131,6 +131,16 @@ fn is_float_token(token: &str) -> bool {
     )
 }
 
+/// True when `token`'s catalog row is the `interval` kind (sqlx `PgInterval`).
+/// Ordered but neither integer nor chrono-backed, so it stamps the `interval`
+/// fixture discriminator and draws its values from `interval_values()`.
+fn is_interval_token(token: &str) -> bool {
+    matches!(
+        fixtures_for_token(token).kind,
+        eql_domains::ScalarKind::Interval
+    )
+}
+
 /// True when `token`'s catalog row declares no ordered domain — equality-only.
 /// Replaces the `[eq_only]` marker. Consumed by [`matrix_suite_for_entry`] to
 /// keep an eq-only type out of the ordered matrix (which exercises ordering
@@ -279,15 +289,22 @@ fn scalar_fixture_modules_tokens(list: &ScalarList) -> TokenStream2 {
                 format_ident!("numeric")
             } else if is_float_token(&token_str) {
                 format_ident!("float")
+            } else if is_interval_token(&token_str) {
+                format_ident!("interval")
             } else if is_storage_only_token(&token_str) {
                 // Storage-only (encryption-only) scalars (`bool`): the fixture
                 // carries no index term (no `hm`/`ob`/`bf`), just the encrypted
                 // value, and asserts the storage-domain shape only.
                 format_ident!("storage")
+            } else if is_eq_only_token(&token_str) {
+                // Equality-only scalars (`uuid`, `bytea`): the fixture carries `hm` and
+                // no ordering term. Checked after storage-only, which is also
+                // eq-only but has no `_eq` domain to index.
+                format_ident!("eq")
             } else {
                 panic!(
                     "scalar token `{token_str}` is neither integer, temporal, text, \
-                     numeric, float, nor storage-only — no fixture discriminator is wired for its kind"
+                     numeric, float, interval, storage-only, nor eq-only — no fixture discriminator is wired for its kind"
                 )
             };
             quote! {
@@ -531,12 +548,17 @@ mod tests {
     fn temporal_is_read_from_catalog_not_a_marker() {
         assert!(!is_temporal_token("integer"));
         assert!(is_temporal_token("date"));
+        assert!(is_temporal_token("timestamp_naive"));
     }
 
     #[test]
     fn eq_only_is_read_from_catalog_not_a_marker() {
         assert!(!is_eq_only_token("integer"));
         assert!(!is_eq_only_token("date"));
+        assert!(is_eq_only_token("uuid"));
+        assert!(!is_storage_only_token("uuid"));
+        assert!(is_eq_only_token("bytea"));
+        assert!(!is_storage_only_token("bytea"));
     }
 
     #[test]
@@ -616,6 +638,22 @@ mod tests {
         assert!(!is_float_token("numeric"));
     }
 
+    #[test]
+    fn time_is_temporal_and_interval_has_its_own_discriminator() {
+        assert!(is_temporal_token("time"));
+        assert!(!is_temporal_token("interval"));
+        assert!(is_interval_token("interval"));
+        assert!(!is_interval_token("time"));
+        let list = syn::parse_str::<ScalarList>(
+            "time => chrono::NaiveTime, interval => eql_tests::scalar_domains::Interval",
+        )
+        .unwrap();
+        let mods = norm(&scalar_fixture_modules_tokens(&list));
+        assert!(mods.contains("temporal ,"), "got: {mods}");
+        assert!(mods.contains("interval ,"), "got: {mods}");
+        assert!(mods.contains("interval_values"), "got: {mods}");
+    }
+
     #[test]
     fn ordered_entry_emits_scalar_matrix_with_eq_ord_caps() {
         let token: Ident = syn::parse_str("integer").unwrap();
@@ -630,11 +668,10 @@ mod tests {
 
     #[test]
     fn eq_only_entry_emits_scalar_matrix_with_eq_caps_only() {
-        // No eq-only row exists in the live catalog yet, so pass the shape
-        // directly: an eq-only token routes to the `caps = [eq]` arm (empty
+        // An eq-only token routes to the `caps = [eq]` arm (empty
         // ord_domains), never the ordered `caps = [eq, ord]` arm.
-        let token: Ident = syn::parse_str("timestamp").unwrap();
-        let rust_type: Type = syn::parse_str("chrono::DateTime<chrono::Utc>").unwrap();
+        let token: Ident = syn::parse_str("uuid").unwrap();
+        let rust_type: Type = syn::parse_str("uuid::Uuid").unwrap();
         let out = norm(&matrix_suite_for_entry(
             &token, &rust_type, false, true, false,
         ));
@@ -728,6 +765,40 @@ mod tests {
         );
     }
 
+    #[test]
+    fn uuid_entry_skips_impl_and_stamps_eq_fixture() {
+        // `uuid` is eq-only: the impl emitter skips it (hand-written in
+        // scalar_domains.rs), the fixture module stamps the `eq` discriminator
+        // drawing from `uuid_values()`, and the matrix takes the `caps = [eq]` arm.
+        let list = syn::parse_str::<ScalarList>("integer => i32, uuid => uuid::Uuid").unwrap();
+        let impls = norm(&scalar_type_impls_tokens(&list));
+        assert!(!impls.contains("impl ScalarType for uuid"), "got: {impls}");
+        let mods = norm(&scalar_fixture_modules_tokens(&list));
+        assert!(mods.contains("pub mod eql_v3_uuid"));
+        assert!(mods.contains("(eq ,"), "got: {mods}");
+        assert!(mods.contains("uuid_values"), "got: {mods}");
+        let suites = norm(&scalar_matrix_suites_tokens(&list));
+        assert!(suites.contains("pub mod uuid"));
+        assert!(suites.contains("caps = [eq]"));
+        assert!(!suites.contains("caps = [eq , ord] , suite = uuid"));
+    }
+
+    #[test]
+    fn bytea_entry_stamps_eq_fixture_over_the_harness_newtype() {
+        // `bytea` takes the same eq-only route as `uuid`, with the hand-written
+        // `Bytes` newtype as its Rust type and `bytea_values()` as its source.
+        let list =
+            syn::parse_str::<ScalarList>("bytea => eql_tests::scalar_domains::Bytes").unwrap();
+        let impls = norm(&scalar_type_impls_tokens(&list));
+        assert!(!impls.contains("impl ScalarType"), "got: {impls}");
+        let mods = norm(&scalar_fixture_modules_tokens(&list));
+        assert!(mods.contains("pub mod eql_v3_bytea"));
+        assert!(mods.contains("(eq ,"), "got: {mods}");
+        assert!(mods.contains("bytea_values"), "got: {mods}");
+        let suites = norm(&scalar_matrix_suites_tokens(&list));
+        assert!(suites.contains("caps = [eq]"), "got: {suites}");
+    }
+
     #[test]
     #[should_panic(expected = "not in eql-domains::FIXTURES")]
     fn unknown_token_fails_loudly() {
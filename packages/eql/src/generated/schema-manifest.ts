@@ -5,6 +5,8 @@ export const schemaNames = [
   'eql_v3_bigint_ord',
   'eql_v3_bigint',
   'eql_v3_boolean',
+  'eql_v3_bytea_eq',
+  'eql_v3_bytea',
   'eql_v3_date_eq',
   'eql_v3_date_ord_ope',
   'eql_v3_date_ord_ore',
@@ -15,11 +17,18 @@ export const schemaNames = [
   'eql_v3_double_ord_ore',
   'eql_v3_double_ord',
   'eql_v3_double',
+  'eql_v3_integer_array_contains',
+  'eql_v3_integer_array',
   'eql_v3_integer_eq',
   'eql_v3_integer_ord_ope',
   'eql_v3_integer_ord_ore',
   'eql_v3_integer_ord',
   'eql_v3_integer',
+  'eql_v3_interval_eq',
+  'eql_v3_interval_ord_ope',
+  'eql_v3_interval_ord_ore',
+  'eql_v3_interval_ord',
+  'eql_v3_interval',
   'eql_v3_json_entry',
   'eql_v3_json_search',
   'eql_v3_json',
@@ -38,6 +47,8 @@ export const schemaNames = [
   'eql_v3_smallint_ord_ore',
   'eql_v3_smallint_ord',
   'eql_v3_smallint',
+  'eql_v3_text_array_contains',
+  'eql_v3_text_array',
   'eql_v3_text_eq',
   'eql_v3_text_match',
   'eql_v3_text_ord_ope',
@@ -46,15 +57,28 @@ export const schemaNames = [
   'eql_v3_text_search_ore',
   'eql_v3_text_search',
   'eql_v3_text',
+  'eql_v3_time_eq',
+  'eql_v3_time_ord_ope',
+  'eql_v3_time_ord_ore',
+  'eql_v3_time_ord',
+  'eql_v3_time',
   'eql_v3_timestamp_eq',
+  'eql_v3_timestamp_naive_eq',
+  'eql_v3_timestamp_naive_ord_ope',
+  'eql_v3_timestamp_naive_ord_ore',
+  'eql_v3_timestamp_naive_ord',
+  'eql_v3_timestamp_naive',
   'eql_v3_timestamp_ord_ope',
   'eql_v3_timestamp_ord_ore',
   'eql_v3_timestamp_ord',
   'eql_v3_timestamp',
+  'eql_v3_uuid_eq',
+  'eql_v3_uuid',
   'query_bigint_eq',
   'query_bigint_ord_ope',
   'query_bigint_ord_ore',
   'query_bigint_ord',
+  'query_bytea_eq',
   'query_date_eq',
   'query_date_ord_ope',
   'query_date_ord_ore',
@@ -63,10 +87,15 @@ export const schemaNames = [
   'query_double_ord_ope',
   'query_double_ord_ore',
   'query_double_ord',
+  'query_integer_array_contains',
   'query_integer_eq',
   'query_integer_ord_ope',
   'query_integer_ord_ore',
   'query_integer_ord',
+  'query_interval_eq',
+  'query_interval_ord_ope',
+  'query_interval_ord_ore',
+  'query_interval_ord',
   'query_json',
   'query_numeric_eq',
   'query_numeric_ord_ope',
@@ -80,6 +109,7 @@ export const schemaNames = [
   'query_smallint_ord_ope',
   'query_smallint_ord_ore',
   'query_smallint_ord',
+  'query_text_array_contains',
   'query_text_eq',
   'query_text_match',
   'query_text_ord_ope',
@@ -87,10 +117,19 @@ export const schemaNames = [
   'query_text_ord',
   'query_text_search_ore',
   'query_text_search',
+  'query_time_eq',
+  'query_time_ord_ope',
+  'query_time_ord_ore',
+  'query_time_ord',
   'query_timestamp_eq',
+  'query_timestamp_naive_eq',
+  'query_timestamp_naive_ord_ope',
+  'query_timestamp_naive_ord_ore',
+  'query_timestamp_naive_ord',
   'query_timestamp_ord_ope',
   'query_timestamp_ord_ore',
   'query_timestamp_ord',
+  'query_uuid_eq',
 ] as const
 
 export const schemaIds = {
@@ -100,6 +139,8 @@ export const schemaIds = {
   "eql_v3_bigint_ord": "https://schemas.cipherstash.com/eql/v3/eql_v3_bigint_ord.json",
   "eql_v3_bigint": "https://schemas.cipherstash.com/eql/v3/eql_v3_bigint.json",
   "eql_v3_boolean": "https://schemas.cipherstash.com/eql/v3/eql_v3_boolean.json",
+  "eql_v3_bytea_eq": "https://schemas.cipherstash.com/eql/v3/eql_v3_bytea_eq.json",
+  "eql_v3_bytea": "https://schemas.cipherstash.com/eql/v3/eql_v3_bytea.json",
   "eql_v3_date_eq": "https://schemas.cipherstash.com/eql/v3/eql_v3_date_eq.json",
   "eql_v3_date_ord_ope": "https://schemas.cipherstash.com/eql/v3/eql_v3_date_ord_ope.json",
   "eql_v3_date_ord_ore": "https://schemas.cipherstash.com/eql/v3/eql_v3_date_ord_ore.json",
@@ -110,11 +151,18 @@ export const schemaIds = {
   "eql_v3_double_ord_ore": "https://schemas.cipherstash.com/eql/v3/eql_v3_double_ord_ore.json",
   "eql_v3_double_ord": "https://schemas.cipherstash.com/eql/v3/eql_v3_double_ord.json",
   "eql_v3_double": "https://schemas.cipherstash.com/eql/v3/eql_v3_double.json",
+  "eql_v3_integer_array_contains": "https://schemas.cipherstash.com/eql/v3/eql_v3_integer_array_contains.json",
+  "eql_v3_integer_array": "https://schemas.cipherstash.com/eql/v3/eql_v3_integer_array.json",
   "eql_v3_integer_eq": "https://schemas.cipherstash.com/eql/v3/eql_v3_integer_eq.json",
   "eql_v3_integer_ord_ope": "https://schemas.cipherstash.com/eql/v3/eql_v3_integer_ord_ope.json",
   "eql_v3_integer_ord_ore": "https://schemas.cipherstash.com/eql/v3/eql_v3_integer_ord_ore.json",
   "eql_v3_integer_ord": "https://schemas.cipherstash.com/eql/v3/eql_v3_integer_ord.json",
   "eql_v3_integer": "https://schemas.cipherstash.com/eql/v3/eql_v3_integer.json",
+  "eql_v3_interval_eq": "https://schemas.cipherstash.com/eql/v3/eql_v3_interval_eq.json",
+  "eql_v3_interval_ord_ope": "https://schemas.cipherstash.com/eql/v3/eql_v3_interval_ord_ope.json",
+  "eql_v3_interval_ord_ore": "https://schemas.cipherstash.com/eql/v3/eql_v3_interval_ord_ore.json",
+  "eql_v3_interval_ord": "https://schemas.cipherstash.com/eql/v3/eql_v3_interval_ord.json",
+  "eql_v3_interval": "https://schemas.cipherstash.com/eql/v3/eql_v3_interval.json",
   "eql_v3_json_entry": "https://schemas.cipherstash.com/eql/v3/eql_v3_json_entry.json",
   "eql_v3_json_search": "https://schemas.cipherstash.com/eql/v3/eql_v3_json_search.json",
   "eql_v3_json": "https://schemas.cipherstash.com/eql/v3/eql_v3_json.json",
@@ -133,6 +181,8 @@ export const schemaIds = {
   "eql_v3_smallint_ord_ore": "https://schemas.cipherstash.com/eql/v3/eql_v3_smallint_ord_ore.json",
   "eql_v3_smallint_ord": "https://schemas.cipherstash.com/eql/v3/eql_v3_smallint_ord.json",
   "eql_v3_smallint": "https://schemas.cipherstash.com/eql/v3/eql_v3_smallint.json",
+  "eql_v3_text_array_contains": "https://schemas.cipherstash.com/eql/v3/eql_v3_text_array_contains.json",
+  "eql_v3_text_array": "https://schemas.cipherstash.com/eql/v3/eql_v3_text_array.json",
   "eql_v3_text_eq": "https://schemas.cipherstash.com/eql/v3/eql_v3_text_eq.json",
   "eql_v3_text_match": "https://schemas.cipherstash.com/eql/v3/eql_v3_text_match.json",
   "eql_v3_text_ord_ope": "https://schemas.cipherstash.com/eql/v3/eql_v3_text_ord_ope.json",
@@ -141,15 +191,28 @@ export const schemaIds = {
   "eql_v3_text_search_ore": "https://schemas.cipherstash.com/eql/v3/eql_v3_text_search_ore.json",
   "eql_v3_text_search": "https://schemas.cipherstash.com/eql/v3/eql_v3_text_search.json",
   "eql_v3_text": "https://schemas.cipherstash.com/eql/v3/eql_v3_text.json",
+  "eql_v3_time_eq": "https://schemas.cipherstash.com/eql/v3/eql_v3_time_eq.json",
+  "eql_v3_time_ord_ope": "https://schemas.cipherstash.com/eql/v3/eql_v3_time_ord_ope.json",
+  "eql_v3_time_ord_ore": "https://schemas.cipherstash.com/eql/v3/eql_v3_time_ord_ore.json",
+  "eql_v3_time_ord": "https://schemas.cipherstash.com/eql/v3/eql_v3_time_ord.json",
+  "eql_v3_time": "https://schemas.cipherstash.com/eql/v3/eql_v3_time.json",
   "eql_v3_timestamp_eq": "https://schemas.cipherstash.com/eql/v3/eql_v3_timestamp_eq.json",
+  "eql_v3_timestamp_naive_eq": "https://schemas.cipherstash.com/eql/v3/eql_v3_timestamp_naive_eq.json",
+  "eql_v3_timestamp_naive_ord_ope": "https://schemas.cipherstash.com/eql/v3/eql_v3_timestamp_naive_ord_ope.json",
+  "eql_v3_timestamp_naive_ord_ore": "https://schemas.cipherstash.com/eql/v3/eql_v3_timestamp_naive_ord_ore.json",
+  "eql_v3_timestamp_naive_ord": "https://schemas.cipherstash.com/eql/v3/eql_v3_timestamp_naive_ord.json",
+  "eql_v3_timestamp_naive": "https://schemas.cipherstash.com/eql/v3/eql_v3_timestamp_naive.json",
   "eql_v3_timestamp_ord_ope": "https://schemas.cipherstash.com/eql/v3/eql_v3_timestamp_ord_ope.json",
   "eql_v3_timestamp_ord_ore": "https://schemas.cipherstash.com/eql/v3/eql_v3_timestamp_ord_ore.json",
   "eql_v3_timestamp_ord": "https://schemas.cipherstash.com/eql/v3/eql_v3_timestamp_ord.json",
   "eql_v3_timestamp": "https://schemas.cipherstash.com/eql/v3/eql_v3_timestamp.json",
+  "eql_v3_uuid_eq": "https://schemas.cipherstash.com/eql/v3/eql_v3_uuid_eq.json",
+  "eql_v3_uuid": "https://schemas.cipherstash.com/eql/v3/eql_v3_uuid.json",
   "query_bigint_eq": "https://schemas.cipherstash.com/eql/v3/query_bigint_eq.json",
   "query_bigint_ord_ope": "https://schemas.cipherstash.com/eql/v3/query_bigint_ord_ope.json",
   "query_bigint_ord_ore": "https://schemas.cipherstash.com/eql/v3/query_bigint_ord_ore.json",
   "query_bigint_ord": "https://schemas.cipherstash.com/eql/v3/query_bigint_ord.json",
+  "query_bytea_eq": "https://schemas.cipherstash.com/eql/v3/query_bytea_eq.json",
   "query_date_eq": "https://schemas.cipherstash.com/eql/v3/query_date_eq.json",
   "query_date_ord_ope": "https://schemas.cipherstash.com/eql/v3/query_date_ord_ope.json",
   "query_date_ord_ore": "https://schemas.cipherstash.com/eql/v3/query_date_ord_ore.json",
@@ -158,10 +221,15 @@ export const schemaIds = {
   "query_double_ord_ope": "https://schemas.cipherstash.com/eql/v3/query_double_ord_ope.json",
   "query_double_ord_ore": "https://schemas.cipherstash.com/eql/v3/query_double_ord_ore.json",
   "query_double_ord": "https://schemas.cipherstash.com/eql/v3/query_double_ord.json",
+  "query_integer_array_contains": "https://schemas.cipherstash.com/eql/v3/query_integer_array_contains.json",
   "query_integer_eq": "https://schemas.cipherstash.com/eql/v3/query_integer_eq.json",
   "query_integer_ord_ope": "https://schemas.cipherstash.com/eql/v3/query_integer_ord_ope.json",
   "query_integer_ord_ore": "https://schemas.cipherstash.com/eql/v3/query_integer_ord_ore.json",
   "query_integer_ord": "https://schemas.cipherstash.com/eql/v3/query_integer_ord.json",
+  "query_interval_eq": "https://schemas.cipherstash.com/eql/v3/query_interval_eq.json",
+  "query_interval_ord_ope": "https://schemas.cipherstash.com/eql/v3/query_interval_ord_ope.json",
+  "query_interval_ord_ore": "https://schemas.cipherstash.com/eql/v3/query_interval_ord_ore.json",
+  "query_interval_ord": "https://schemas.cipherstash.com/eql/v3/query_interval_ord.json",
   "query_json": "https://schemas.cipherstash.com/eql/v3/query_json.json",
   "query_numeric_eq": "https://schemas.cipherstash.com/eql/v3/query_numeric_eq.json",
   "query_numeric_ord_ope": "https://schemas.cipherstash.com/eql/v3/query_numeric_ord_ope.json",
@@ -175,6 +243,7 @@ export const schemaIds = {
   "query_smallint_ord_ope": "https://schemas.cipherstash.com/eql/v3/query_smallint_ord_ope.json",
   "query_smallint_ord_ore": "https://schemas.cipherstash.com/eql/v3/query_smallint_ord_ore.json",
   "query_smallint_ord": "https://schemas.cipherstash.com/eql/v3/query_smallint_ord.json",
+  "query_text_array_contains": "https://schemas.cipherstash.com/eql/v3/query_text_array_contains.json",
   "query_text_eq": "https://schemas.cipherstash.com/eql/v3/query_text_eq.json",
   "query_text_match": "https://schemas.cipherstash.com/eql/v3/query_text_match.json",
   "query_text_ord_ope": "https://schemas.cipherstash.com/eql/v3/query_text_ord_ope.json",
@@ -182,8 +251,17 @@ export const schemaIds = {
   "query_text_ord": "https://schemas.cipherstash.com/eql/v3/query_text_ord.json",
   "query_text_search_ore": "https://schemas.cipherstash.com/eql/v3/query_text_search_ore.json",
   "query_text_search": "https://schemas.cipherstash.com/eql/v3/query_text_search.json",
+  "query_time_eq": "https://schemas.cipherstash.com/eql/v3/query_time_eq.json",
+  "query_time_ord_ope": "https://schemas.cipherstash.com/eql/v3/query_time_ord_ope.json",
+  "query_time_ord_ore": "https://schemas.cipherstash.com/eql/v3/query_time_ord_ore.json",
+  "query_time_ord": "https://schemas.cipherstash.com/eql/v3/query_time_ord.json",
   "query_timestamp_eq": "https://schemas.cipherstash.com/eql/v3/query_timestamp_eq.json",
+  "query_timestamp_naive_eq": "https://schemas.cipherstash.com/eql/v3/query_timestamp_naive_eq.json",
+  "query_timestamp_naive_ord_ope": "https://schemas.cipherstash.com/eql/v3/query_timestamp_naive_ord_ope.json",
+  "query_timestamp_naive_ord_ore": "https://schemas.cipherstash.com/eql/v3/query_timestamp_naive_ord_ore.json",
+  "query_timestamp_naive_ord": "https://schemas.cipherstash.com/eql/v3/query_timestamp_naive_ord.json",
   "query_timestamp_ord_ope": "https://schemas.cipherstash.com/eql/v3/query_timestamp_ord_ope.json",
   "query_timestamp_ord_ore": "https://schemas.cipherstash.com/eql/v3/query_timestamp_ord_ore.json",
   "query_timestamp_ord": "https://schemas.cipherstash.com/eql/v3/query_timestamp_ord.json",
+  "query_uuid_eq": "https://schemas.cipherstash.com/eql/v3/query_uuid_eq.json",
 } as const
@@ -7,5 +7,13 @@
  * wire (8 for the int scalars, 12 for timestamp, 14 for numeric) — the
  * array just carries more block strings. Extracted by `eql_v3.ord_term_ore`;
  * SQL-side constructor: `eql_v3_internal.ore_block_256`.
+ *
+ * `Ord` and `Eq` are the SQL comparator's, not the strings': two encryptions
+ * of one plaintext carry different right halves yet compare equal, and hash
+ * alike. Terms compare block-term by block-term, an empty array first. Where
+ * the database would raise instead — a block term that is not valid hex or
+ * not `49 * N + 16` bytes, or two terms of different widths — the order
+ * stays total: malformed terms sort after well-formed ones (by their text),
+ * and a narrower term before a wider one.
  */
 export type OreBlock256 = Array<string>;
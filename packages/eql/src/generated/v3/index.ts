@@ -9,6 +9,9 @@ export type * from './BigintOrdOreQuery'
 export type * from './BigintOrdQuery'
 export type * from './BloomFilter'
 export type * from './Boolean'
+export type * from './Bytea'
+export type * from './ByteaEq'
+export type * from './ByteaEqQuery'
 export type * from './Ciphertext'
 export type * from './Date'
 export type * from './DateEq'
@@ -32,6 +35,9 @@ export type * from './EntryCiphertext'
 export type * from './Hmac256'
 export type * from './Identifier'
 export type * from './Integer'
+export type * from './IntegerArray'
+export type * from './IntegerArrayContains'
+export type * from './IntegerArrayContainsQuery'
 export type * from './IntegerEq'
 export type * from './IntegerEqQuery'
 export type * from './IntegerOrd'
@@ -40,6 +46,15 @@ export type * from './IntegerOrdOpeQuery'
 export type * from './IntegerOrdOre'
 export type * from './IntegerOrdOreQuery'
 export type * from './IntegerOrdQuery'
+export type * from './Interval'
+export type * from './IntervalEq'
+export type * from './IntervalEqQuery'
+export type * from './IntervalOrd'
+export type * from './IntervalOrdOpe'
+export type * from './IntervalOrdOpeQuery'
+export type * from './IntervalOrdOre'
+export type * from './IntervalOrdOreQuery'
+export type * from './IntervalOrdQuery'
 export type * from './Json'
 export type * from './KeyHeader'
 export type * from './Numeric'
@@ -79,6 +94,9 @@ export type * from './SteVecForm'
 export type * from './SteVecQuery'
 export type * from './SteVecQueryEntry'
 export type * from './Text'
+export type * from './TextArray'
+export type * from './TextArrayContains'
+export type * from './TextArrayContainsQuery'
 export type * from './TextEq'
 export type * from './TextEqQuery'
 export type * from './TextMatch'
@@ -93,12 +111,33 @@ export type * from './TextSearch'
 export type * from './TextSearchOre'
 export type * from './TextSearchOreQuery'
 export type * from './TextSearchQuery'
+export type * from './Time'
+export type * from './TimeEq'
+export type * from './TimeEqQuery'
+export type * from './TimeOrd'
+export type * from './TimeOrdOpe'
+export type * from './TimeOrdOpeQuery'
+export type * from './TimeOrdOre'
+export type * from './TimeOrdOreQuery'
+export type * from './TimeOrdQuery'
 export type * from './Timestamp'
 export type * from './TimestampEq'
 export type * from './TimestampEqQuery'
+export type * from './TimestampNaive'
+export type * from './TimestampNaiveEq'
+export type * from './TimestampNaiveEqQuery'
+export type * from './TimestampNaiveOrd'
+export type * from './TimestampNaiveOrdOpe'
+export type * from './TimestampNaiveOrdOpeQuery'
+export type * from './TimestampNaiveOrdOre'
+export type * from './TimestampNaiveOrdOreQuery'
+export type * from './TimestampNaiveOrdQuery'
 export type * from './TimestampOrd'
 export type * from './TimestampOrdOpe'
 export type * from './TimestampOrdOpeQuery'
 export type * from './TimestampOrdOre'
 export type * from './TimestampOrdOreQuery'
 export type * from './TimestampOrdQuery'
+export type * from './Uuid'
+export type * from './UuidEq'
+export type * from './UuidEqQuery'
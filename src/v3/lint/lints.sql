@@ -12,11 +12,14 @@
 --!   * Not `SECURITY DEFINER`
 --!   * Single-statement SELECT body
 --!
---! @note The single-statement SELECT body condition is **not yet checked** by
---! this lint. A `LANGUAGE sql` function with a multi-statement body, a CTE,
---! or any pre-SELECT statement will pass all four implemented checks while
---! remaining non-inlinable. Implementing the check requires walking `prosrc`
---! (or `pg_get_functiondef`); tracked as a follow-up.
+--! @note The single-statement SELECT body condition is checked textually on
+--! `prosrc`, after comments and standard `'...'` string literals are removed:
+--! the body must be one statement that starts with `SELECT`. Escape strings
+--! (`E'it\'s'`) and dollar-quoted literals are not recognised, so a `;` or a
+--! backslash-escaped quote inside one can raise a false `inlinability_body`
+--! error. It does not model the planner's other refusals (a FROM clause,
+--! aggregates, sub-selects). SQL-standard bodies (`BEGIN ATOMIC` / `RETURN`)
+--! keep `prosrc` empty and are not checked.
 --!
 --! Operators on `eql_v3` types (the jsonb-backed encrypted-domain families and
 --! the SEM index-term type `eql_v3_internal.ore_block_256`) whose
@@ -34,6 +37,11 @@
 --!   `inlinability_volatility` — implementation function is VOLATILE.
 --!   `inlinability_set_clause` — implementation function has a `SET` clause.
 --!   `inlinability_secdef`     — implementation function is `SECURITY DEFINER`.
+--!   `inlinability_body`       — implementation function is `LANGUAGE sql`
+--!                                but its body is not a single SELECT
+--!                                statement (several statements, a leading
+--!                                `WITH`, `VALUES`, or a DML/utility
+--!                                statement).
 --!   `inlinability_transitive` — implementation function is itself inlinable
 --!                                but its body invokes a non-inlinable function
 --!                                (depth 1; the planner can't peek through
@@ -56,6 +64,14 @@
 --!                                `eql_v3` encrypted domain. Opclasses on
 --!                                domains bypass operator resolution; use a
 --!                                functional index on the extractor instead.
+--!   `index_record_ops`        — an index column over an EQL index-term type
+--!                                bound the generic `record_ops` /
+--!                                `record_image_ops` opclass. That happens to
+--!                                a btree on `eql_v3.ord_term_ore(col)` when
+--!                                the superuser-gated ORE opclass was skipped
+--!                                at install: the CREATE INDEX succeeds, but
+--!                                the index never engages and stores record
+--!                                order, not ORE order.
 --!   `schema_placement`        — a naked composite or enum TYPE lives in the
 --!                                public `eql_v3` schema. Internal index-term
 --!                                types (e.g. `ore_block_256_term`) belong in
@@ -238,6 +254,43 @@ AS $$
       WHERE b.oid = op_impl.impl_oid
     )
 
+  UNION ALL
+
+  SELECT
+    'error',
+    'inlinability_body',
+    format('operator %s(%s, %s) -> %s', opname, lhs, rhs, impl_signature),
+    format(
+      'Operator implementation function body is not a single SELECT statement (%s). The planner only inlines a `LANGUAGE sql` function whose body is one plain SELECT; rewrite it as a single SELECT expression.',
+      CASE WHEN stripped.body LIKE '%;%'
+           THEN 'it has more than one statement'
+           ELSE coalesce(
+             'it starts with `' || pg_catalog.upper(pg_catalog.substring(stripped.body, '^[(\s]*(\w+)')) || '`',
+             'it is not a SELECT')
+      END)
+  FROM op_impl
+  -- String literals (they may hold `;` or `--`) and comments (they may hold
+  -- apostrophes) in one left-to-right pass, so whichever opens first wins: a
+  -- literal becomes `''`, a comment nothing. Then the surrounding whitespace
+  -- and trailing semicolons. The block comment is spelled out rather than
+  -- `/\*.*?\*/`: an alternation makes the whole pattern greedy, which would
+  -- run one comment on to the last `*/`.
+  CROSS JOIN LATERAL (
+    SELECT pg_catalog.btrim(
+      pg_catalog.regexp_replace(
+        op_impl.body,
+        '('')(?:[^'']|'''')*''|--[^\n]*|/\*[^*]*\*+(?:[^*/][^*]*\*+)*/',
+        '\1\1', 'g'),
+      E' \t\r\n;') AS body
+  ) AS stripped
+  WHERE lang = 'sql'
+    AND stripped.body <> ''
+    AND (stripped.body LIKE '%;%' OR stripped.body !~* '^[(\s]*select\M')
+    AND NOT EXISTS (
+      SELECT 1 FROM encrypted_domain_blockers b
+      WHERE b.oid = op_impl.impl_oid
+    )
+
   -- ┌─────────────────────────────────────────────────────────────────┐
   -- │ Transitive inlinability: an operator implementation function    │
   -- │ that's itself inlinable can still fail to inline if its body    │
@@ -368,6 +421,32 @@ AS $$
   JOIN pg_catalog.pg_namespace cn ON cn.oid = oc.opcnamespace
   WHERE t.oid IN (SELECT typid FROM encrypted_domain_types)
 
+  -- ┌─────────────────────────────────────────────────────────────────┐
+  -- │ record_ops fallback: with no default btree opclass for an       │
+  -- │ index-term composite (the ORE class is superuser-gated),        │
+  -- │ Postgres binds record_ops instead. The index builds but never   │
+  -- │ serves the EQL operators.                                       │
+  -- └─────────────────────────────────────────────────────────────────┘
+
+  UNION ALL
+
+  SELECT
+    'error',
+    'index_record_ops',
+    format('index %I.%I', n.nspname, ic.relname),
+    format(
+      'Index `%s.%s` column %s (type `%s`) uses operator class `%s`, which does not contain the EQL comparison operators: the index never engages and does not store the encrypted order. The ORE operator class was likely skipped at install (it requires superuser). Drop the index, then re-install EQL as a superuser and re-create it, or move the column to an `_ord_ope` domain and index `eql_v3.ord_term(col)` instead.',
+      n.nspname, ic.relname, k.n, a.atttypid::regtype, oc.opcname)
+  FROM pg_catalog.pg_index i
+  CROSS JOIN LATERAL pg_catalog.unnest(i.indclass::oid[]) WITH ORDINALITY AS k(opc, n)
+  JOIN pg_catalog.pg_opclass oc ON oc.oid = k.opc
+  JOIN pg_catalog.pg_attribute a ON a.attrelid = i.indexrelid AND a.attnum = k.n
+  JOIN pg_catalog.pg_type t ON t.oid = a.atttypid
+  JOIN pg_catalog.pg_class ic ON ic.oid = i.indexrelid
+  JOIN pg_catalog.pg_namespace n ON n.oid = ic.relnamespace
+  WHERE oc.opcname IN ('record_ops', 'record_image_ops')
+    AND t.typnamespace IN (SELECT oid FROM pg_namespace WHERE nspname = ANY(eql_v3_internal.owned_schemas()))
+
   -- ┌─────────────────────────────────────────────────────────────────┐
   -- │ Schema placement: the public `eql_v3` schema must hold only the  │
   -- │ jsonb-backed encrypted-domain types. A naked composite/enum type │
@@ -0,0 +1,638 @@
+-- AUTOMATICALLY GENERATED FILE.
+-- REQUIRE: src/v3/schema.sql
+-- REQUIRE: src/v3/scalars/bytea/bytea_types.sql
+-- REQUIRE: src/v3/scalars/functions.sql
+-- REQUIRE: src/v3/sem/hmac_256/functions.sql
+
+--! @file encrypted_domain/bytea/bytea_eq_functions.sql
+--! @brief Functions for public.eql_v3_bytea_eq.
+
+--! @brief Index extractor for public.eql_v3_bytea_eq.
+--! @param a public.eql_v3_bytea_eq
+--! @return eql_v3_internal.hmac_256
+CREATE FUNCTION eql_v3.eq_term(a public.eql_v3_bytea_eq)
+RETURNS eql_v3_internal.hmac_256
+LANGUAGE sql IMMUTABLE STRICT PARALLEL SAFE
+AS $$ SELECT eql_v3_internal.hmac_256(a::jsonb) $$;
+
+--! @brief Operator wrapper for public.eql_v3_bytea_eq.
+--! @param a public.eql_v3_bytea_eq
+--! @param b public.eql_v3_bytea_eq
+--! @return boolean
+CREATE FUNCTION eql_v3.eq(a public.eql_v3_bytea_eq, b public.eql_v3_bytea_eq)
+RETURNS boolean LANGUAGE sql IMMUTABLE STRICT PARALLEL SAFE
+AS $$ SELECT eql_v3.eq_term(a) = eql_v3.eq_term(b) $$;
+
+--! @brief Operator wrapper for public.eql_v3_bytea_eq.
+--! @param a public.eql_v3_bytea_eq
+--! @param b jsonb
+--! @return boolean
+CREATE FUNCTION eql_v3.eq(a public.eql_v3_bytea_eq, b jsonb)
+RETURNS boolean LANGUAGE sql IMMUTABLE STRICT PARALLEL SAFE
+AS $$ SELECT eql_v3.eq_term(a) = eql_v3.eq_term(b::public.eql_v3_bytea_eq) $$;
+
+--! @brief Operator wrapper for public.eql_v3_bytea_eq.
+--! @param a jsonb
+--! @param b public.eql_v3_bytea_eq
+--! @return boolean
+CREATE FUNCTION eql_v3.eq(a jsonb, b public.eql_v3_bytea_eq)
+RETURNS boolean LANGUAGE sql IMMUTABLE STRICT PARALLEL SAFE
+AS $$ SELECT eql_v3.eq_term(a::public.eql_v3_bytea_eq) = eql_v3.eq_term(b) $$;
+
+--! @brief Operator wrapper for public.eql_v3_bytea_eq.
+--! @param a public.eql_v3_bytea_eq
+--! @param b public.eql_v3_bytea_eq
+--! @return boolean
+CREATE FUNCTION eql_v3.neq(a public.eql_v3_bytea_eq, b public.eql_v3_bytea_eq)
+RETURNS boolean LANGUAGE sql IMMUTABLE STRICT PARALLEL SAFE
+AS $$ SELECT eql_v3.eq_term(a) <> eql_v3.eq_term(b) $$;
+
+--! @brief Operator wrapper for public.eql_v3_bytea_eq.
+--! @param a public.eql_v3_bytea_eq
+--! @param b jsonb
+--! @return boolean
+CREATE FUNCTION eql_v3.neq(a public.eql_v3_bytea_eq, b jsonb)
+RETURNS boolean LANGUAGE sql IMMUTABLE STRICT PARALLEL SAFE
+AS $$ SELECT eql_v3.eq_term(a) <> eql_v3.eq_term(b::public.eql_v3_bytea_eq) $$;
+
+--! @brief Operator wrapper for public.eql_v3_bytea_eq.
+--! @param a jsonb
+--! @param b public.eql_v3_bytea_eq
+--! @return boolean
+CREATE FUNCTION eql_v3.neq(a jsonb, b public.eql_v3_bytea_eq)
+RETURNS boolean LANGUAGE sql IMMUTABLE STRICT PARALLEL SAFE
+AS $$ SELECT eql_v3.eq_term(a::public.eql_v3_bytea_eq) <> eql_v3.eq_term(b) $$;
+
+--! @brief Unsupported operator blocker for public.eql_v3_bytea_eq.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_bytea_eq and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a public.eql_v3_bytea_eq left operand of the blocked operator
+--! @param b public.eql_v3_bytea_eq right operand of the blocked operator
+--! @return boolean never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal.lt(a public.eql_v3_bytea_eq, b public.eql_v3_bytea_eq)
+RETURNS boolean IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '<', 'public.eql_v3_bytea_eq'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_bytea_eq.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_bytea_eq and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a public.eql_v3_bytea_eq left operand of the blocked operator
+--! @param b jsonb right operand of the blocked operator
+--! @return boolean never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal.lt(a public.eql_v3_bytea_eq, b jsonb)
+RETURNS boolean IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '<', 'public.eql_v3_bytea_eq'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_bytea_eq.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_bytea_eq and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a jsonb left operand of the blocked operator
+--! @param b public.eql_v3_bytea_eq right operand of the blocked operator
+--! @return boolean never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal.lt(a jsonb, b public.eql_v3_bytea_eq)
+RETURNS boolean IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '<', 'public.eql_v3_bytea_eq'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_bytea_eq.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_bytea_eq and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a public.eql_v3_bytea_eq left operand of the blocked operator
+--! @param b public.eql_v3_bytea_eq right operand of the blocked operator
+--! @return boolean never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal.lte(a public.eql_v3_bytea_eq, b public.eql_v3_bytea_eq)
+RETURNS boolean IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '<=', 'public.eql_v3_bytea_eq'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_bytea_eq.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_bytea_eq and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a public.eql_v3_bytea_eq left operand of the blocked operator
+--! @param b jsonb right operand of the blocked operator
+--! @return boolean never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal.lte(a public.eql_v3_bytea_eq, b jsonb)
+RETURNS boolean IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '<=', 'public.eql_v3_bytea_eq'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_bytea_eq.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_bytea_eq and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a jsonb left operand of the blocked operator
+--! @param b public.eql_v3_bytea_eq right operand of the blocked operator
+--! @return boolean never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal.lte(a jsonb, b public.eql_v3_bytea_eq)
+RETURNS boolean IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '<=', 'public.eql_v3_bytea_eq'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_bytea_eq.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_bytea_eq and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a public.eql_v3_bytea_eq left operand of the blocked operator
+--! @param b public.eql_v3_bytea_eq right operand of the blocked operator
+--! @return boolean never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal.gt(a public.eql_v3_bytea_eq, b public.eql_v3_bytea_eq)
+RETURNS boolean IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '>', 'public.eql_v3_bytea_eq'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_bytea_eq.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_bytea_eq and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a public.eql_v3_bytea_eq left operand of the blocked operator
+--! @param b jsonb right operand of the blocked operator
+--! @return boolean never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal.gt(a public.eql_v3_bytea_eq, b jsonb)
+RETURNS boolean IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '>', 'public.eql_v3_bytea_eq'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_bytea_eq.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_bytea_eq and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a jsonb left operand of the blocked operator
+--! @param b public.eql_v3_bytea_eq right operand of the blocked operator
+--! @return boolean never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal.gt(a jsonb, b public.eql_v3_bytea_eq)
+RETURNS boolean IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '>', 'public.eql_v3_bytea_eq'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_bytea_eq.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_bytea_eq and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a public.eql_v3_bytea_eq left operand of the blocked operator
+--! @param b public.eql_v3_bytea_eq right operand of the blocked operator
+--! @return boolean never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal.gte(a public.eql_v3_bytea_eq, b public.eql_v3_bytea_eq)
+RETURNS boolean IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '>=', 'public.eql_v3_bytea_eq'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_bytea_eq.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_bytea_eq and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a public.eql_v3_bytea_eq left operand of the blocked operator
+--! @param b jsonb right operand of the blocked operator
+--! @return boolean never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal.gte(a public.eql_v3_bytea_eq, b jsonb)
+RETURNS boolean IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '>=', 'public.eql_v3_bytea_eq'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_bytea_eq.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_bytea_eq and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a jsonb left operand of the blocked operator
+--! @param b public.eql_v3_bytea_eq right operand of the blocked operator
+--! @return boolean never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal.gte(a jsonb, b public.eql_v3_bytea_eq)
+RETURNS boolean IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '>=', 'public.eql_v3_bytea_eq'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_bytea_eq.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_bytea_eq and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a public.eql_v3_bytea_eq left operand of the blocked operator
+--! @param b public.eql_v3_bytea_eq right operand of the blocked operator
+--! @return boolean never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal.contains(a public.eql_v3_bytea_eq, b public.eql_v3_bytea_eq)
+RETURNS boolean IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '@>', 'public.eql_v3_bytea_eq'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_bytea_eq.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_bytea_eq and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a public.eql_v3_bytea_eq left operand of the blocked operator
+--! @param b jsonb right operand of the blocked operator
+--! @return boolean never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal.contains(a public.eql_v3_bytea_eq, b jsonb)
+RETURNS boolean IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '@>', 'public.eql_v3_bytea_eq'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_bytea_eq.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_bytea_eq and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a jsonb left operand of the blocked operator
+--! @param b public.eql_v3_bytea_eq right operand of the blocked operator
+--! @return boolean never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal.contains(a jsonb, b public.eql_v3_bytea_eq)
+RETURNS boolean IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '@>', 'public.eql_v3_bytea_eq'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_bytea_eq.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_bytea_eq and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a public.eql_v3_bytea_eq left operand of the blocked operator
+--! @param b public.eql_v3_bytea_eq right operand of the blocked operator
+--! @return boolean never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal.contained_by(a public.eql_v3_bytea_eq, b public.eql_v3_bytea_eq)
+RETURNS boolean IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '<@', 'public.eql_v3_bytea_eq'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_bytea_eq.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_bytea_eq and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a public.eql_v3_bytea_eq left operand of the blocked operator
+--! @param b jsonb right operand of the blocked operator
+--! @return boolean never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal.contained_by(a public.eql_v3_bytea_eq, b jsonb)
+RETURNS boolean IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '<@', 'public.eql_v3_bytea_eq'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_bytea_eq.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_bytea_eq and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a jsonb left operand of the blocked operator
+--! @param b public.eql_v3_bytea_eq right operand of the blocked operator
+--! @return boolean never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal.contained_by(a jsonb, b public.eql_v3_bytea_eq)
+RETURNS boolean IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '<@', 'public.eql_v3_bytea_eq'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_bytea_eq.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_bytea_eq and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a public.eql_v3_bytea_eq left operand of the blocked operator
+--! @param selector text right operand of the blocked operator
+--! @return public.eql_v3_bytea_eq never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal."->"(a public.eql_v3_bytea_eq, selector text)
+RETURNS public.eql_v3_bytea_eq IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '->', 'public.eql_v3_bytea_eq'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_bytea_eq.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_bytea_eq and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a public.eql_v3_bytea_eq left operand of the blocked operator
+--! @param selector integer right operand of the blocked operator
+--! @return public.eql_v3_bytea_eq never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal."->"(a public.eql_v3_bytea_eq, selector integer)
+RETURNS public.eql_v3_bytea_eq IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '->', 'public.eql_v3_bytea_eq'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_bytea_eq.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_bytea_eq and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a jsonb left operand of the blocked operator
+--! @param selector public.eql_v3_bytea_eq right operand of the blocked operator
+--! @return public.eql_v3_bytea_eq never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal."->"(a jsonb, selector public.eql_v3_bytea_eq)
+RETURNS public.eql_v3_bytea_eq IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '->', 'public.eql_v3_bytea_eq'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_bytea_eq.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_bytea_eq and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a public.eql_v3_bytea_eq left operand of the blocked operator
+--! @param selector text right operand of the blocked operator
+--! @return text never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal."->>"(a public.eql_v3_bytea_eq, selector text)
+RETURNS text IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '->>', 'public.eql_v3_bytea_eq'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_bytea_eq.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_bytea_eq and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a public.eql_v3_bytea_eq left operand of the blocked operator
+--! @param selector integer right operand of the blocked operator
+--! @return text never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal."->>"(a public.eql_v3_bytea_eq, selector integer)
+RETURNS text IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '->>', 'public.eql_v3_bytea_eq'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_bytea_eq.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_bytea_eq and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a jsonb left operand of the blocked operator
+--! @param selector public.eql_v3_bytea_eq right operand of the blocked operator
+--! @return text never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal."->>"(a jsonb, selector public.eql_v3_bytea_eq)
+RETURNS text IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '->>', 'public.eql_v3_bytea_eq'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_bytea_eq.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_bytea_eq and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a public.eql_v3_bytea_eq left operand of the blocked operator
+--! @param b text right operand of the blocked operator
+--! @return boolean never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal."?"(a public.eql_v3_bytea_eq, b text)
+RETURNS boolean IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '?', 'public.eql_v3_bytea_eq'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_bytea_eq.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_bytea_eq and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a public.eql_v3_bytea_eq left operand of the blocked operator
+--! @param b text[] right operand of the blocked operator
+--! @return boolean never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal."?|"(a public.eql_v3_bytea_eq, b text[])
+RETURNS boolean IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '?|', 'public.eql_v3_bytea_eq'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_bytea_eq.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_bytea_eq and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a public.eql_v3_bytea_eq left operand of the blocked operator
+--! @param b text[] right operand of the blocked operator
+--! @return boolean never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal."?&"(a public.eql_v3_bytea_eq, b text[])
+RETURNS boolean IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '?&', 'public.eql_v3_bytea_eq'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_bytea_eq.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_bytea_eq and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a public.eql_v3_bytea_eq left operand of the blocked operator
+--! @param b jsonpath right operand of the blocked operator
+--! @return boolean never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal."@?"(a public.eql_v3_bytea_eq, b jsonpath)
+RETURNS boolean IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '@?', 'public.eql_v3_bytea_eq'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_bytea_eq.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_bytea_eq and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a public.eql_v3_bytea_eq left operand of the blocked operator
+--! @param b public.eql_v3_bytea_eq right operand of the blocked operator
+--! @return boolean never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal."@@"(a public.eql_v3_bytea_eq, b public.eql_v3_bytea_eq)
+RETURNS boolean IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '@@', 'public.eql_v3_bytea_eq'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_bytea_eq.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_bytea_eq and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a public.eql_v3_bytea_eq left operand of the blocked operator
+--! @param b jsonb right operand of the blocked operator
+--! @return boolean never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal."@@"(a public.eql_v3_bytea_eq, b jsonb)
+RETURNS boolean IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '@@', 'public.eql_v3_bytea_eq'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_bytea_eq.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_bytea_eq and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a jsonb left operand of the blocked operator
+--! @param b public.eql_v3_bytea_eq right operand of the blocked operator
+--! @return boolean never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal."@@"(a jsonb, b public.eql_v3_bytea_eq)
+RETURNS boolean IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '@@', 'public.eql_v3_bytea_eq'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_bytea_eq.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_bytea_eq and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a public.eql_v3_bytea_eq left operand of the blocked operator
+--! @param b jsonpath right operand of the blocked operator
+--! @return boolean never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal."@@"(a public.eql_v3_bytea_eq, b jsonpath)
+RETURNS boolean IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '@@', 'public.eql_v3_bytea_eq'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_bytea_eq.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_bytea_eq and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a public.eql_v3_bytea_eq left operand of the blocked operator
+--! @param b text[] right operand of the blocked operator
+--! @return jsonb never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal."#>"(a public.eql_v3_bytea_eq, b text[])
+RETURNS jsonb IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '#>', 'public.eql_v3_bytea_eq'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_bytea_eq.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_bytea_eq and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a public.eql_v3_bytea_eq left operand of the blocked operator
+--! @param b text[] right operand of the blocked operator
+--! @return text never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal."#>>"(a public.eql_v3_bytea_eq, b text[])
+RETURNS text IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '#>>', 'public.eql_v3_bytea_eq'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_bytea_eq.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_bytea_eq and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a public.eql_v3_bytea_eq left operand of the blocked operator
+--! @param b text right operand of the blocked operator
+--! @return jsonb never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal."-"(a public.eql_v3_bytea_eq, b text)
+RETURNS jsonb IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '-', 'public.eql_v3_bytea_eq'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_bytea_eq.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_bytea_eq and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a public.eql_v3_bytea_eq left operand of the blocked operator
+--! @param b integer right operand of the blocked operator
+--! @return jsonb never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal."-"(a public.eql_v3_bytea_eq, b integer)
+RETURNS jsonb IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '-', 'public.eql_v3_bytea_eq'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_bytea_eq.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_bytea_eq and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a public.eql_v3_bytea_eq left operand of the blocked operator
+--! @param b text[] right operand of the blocked operator
+--! @return jsonb never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal."-"(a public.eql_v3_bytea_eq, b text[])
+RETURNS jsonb IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '-', 'public.eql_v3_bytea_eq'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_bytea_eq.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_bytea_eq and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a public.eql_v3_bytea_eq left operand of the blocked operator
+--! @param b text[] right operand of the blocked operator
+--! @return jsonb never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal."#-"(a public.eql_v3_bytea_eq, b text[])
+RETURNS jsonb IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '#-', 'public.eql_v3_bytea_eq'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_bytea_eq.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_bytea_eq and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a public.eql_v3_bytea_eq left operand of the blocked operator
+--! @param b public.eql_v3_bytea_eq right operand of the blocked operator
+--! @return jsonb never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal."||"(a public.eql_v3_bytea_eq, b public.eql_v3_bytea_eq)
+RETURNS jsonb IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '||', 'public.eql_v3_bytea_eq'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_bytea_eq.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_bytea_eq and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a public.eql_v3_bytea_eq left operand of the blocked operator
+--! @param b jsonb right operand of the blocked operator
+--! @return jsonb never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal."||"(a public.eql_v3_bytea_eq, b jsonb)
+RETURNS jsonb IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '||', 'public.eql_v3_bytea_eq'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_bytea_eq.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_bytea_eq and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a jsonb left operand of the blocked operator
+--! @param b public.eql_v3_bytea_eq right operand of the blocked operator
+--! @return jsonb never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal."||"(a jsonb, b public.eql_v3_bytea_eq)
+RETURNS jsonb IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '||', 'public.eql_v3_bytea_eq'; END; $$
+LANGUAGE plpgsql;
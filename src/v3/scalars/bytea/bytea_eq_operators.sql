@@ -0,0 +1,248 @@
+-- AUTOMATICALLY GENERATED FILE.
+-- REQUIRE: src/v3/schema.sql
+-- REQUIRE: src/v3/scalars/bytea/bytea_types.sql
+-- REQUIRE: src/v3/scalars/bytea/bytea_eq_functions.sql
+
+--! @file encrypted_domain/bytea/bytea_eq_operators.sql
+--! @brief Operators for public.eql_v3_bytea_eq.
+
+CREATE OPERATOR = (
+  FUNCTION = eql_v3.eq,
+  LEFTARG = public.eql_v3_bytea_eq, RIGHTARG = public.eql_v3_bytea_eq,
+  COMMUTATOR = =, NEGATOR = <>, RESTRICT = eqsel, JOIN = eqjoinsel
+);
+
+CREATE OPERATOR = (
+  FUNCTION = eql_v3.eq,
+  LEFTARG = public.eql_v3_bytea_eq, RIGHTARG = jsonb,
+  COMMUTATOR = =, NEGATOR = <>, RESTRICT = eqsel, JOIN = eqjoinsel
+);
+
+CREATE OPERATOR = (
+  FUNCTION = eql_v3.eq,
+  LEFTARG = jsonb, RIGHTARG = public.eql_v3_bytea_eq,
+  COMMUTATOR = =, NEGATOR = <>, RESTRICT = eqsel, JOIN = eqjoinsel
+);
+
+CREATE OPERATOR <> (
+  FUNCTION = eql_v3.neq,
+  LEFTARG = public.eql_v3_bytea_eq, RIGHTARG = public.eql_v3_bytea_eq,
+  COMMUTATOR = <>, NEGATOR = =, RESTRICT = neqsel, JOIN = neqjoinsel
+);
+
+CREATE OPERATOR <> (
+  FUNCTION = eql_v3.neq,
+  LEFTARG = public.eql_v3_bytea_eq, RIGHTARG = jsonb,
+  COMMUTATOR = <>, NEGATOR = =, RESTRICT = neqsel, JOIN = neqjoinsel
+);
+
+CREATE OPERATOR <> (
+  FUNCTION = eql_v3.neq,
+  LEFTARG = jsonb, RIGHTARG = public.eql_v3_bytea_eq,
+  COMMUTATOR = <>, NEGATOR = =, RESTRICT = neqsel, JOIN = neqjoinsel
+);
+
+CREATE OPERATOR < (
+  FUNCTION = eql_v3_internal.lt,
+  LEFTARG = public.eql_v3_bytea_eq, RIGHTARG = public.eql_v3_bytea_eq
+);
+
+CREATE OPERATOR < (
+  FUNCTION = eql_v3_internal.lt,
+  LEFTARG = public.eql_v3_bytea_eq, RIGHTARG = jsonb
+);
+
+CREATE OPERATOR < (
+  FUNCTION = eql_v3_internal.lt,
+  LEFTARG = jsonb, RIGHTARG = public.eql_v3_bytea_eq
+);
+
+CREATE OPERATOR <= (
+  FUNCTION = eql_v3_internal.lte,
+  LEFTARG = public.eql_v3_bytea_eq, RIGHTARG = public.eql_v3_bytea_eq
+);
+
+CREATE OPERATOR <= (
+  FUNCTION = eql_v3_internal.lte,
+  LEFTARG = public.eql_v3_bytea_eq, RIGHTARG = jsonb
+);
+
+CREATE OPERATOR <= (
+  FUNCTION = eql_v3_internal.lte,
+  LEFTARG = jsonb, RIGHTARG = public.eql_v3_bytea_eq
+);
+
+CREATE OPERATOR > (
+  FUNCTION = eql_v3_internal.gt,
+  LEFTARG = public.eql_v3_bytea_eq, RIGHTARG = public.eql_v3_bytea_eq
+);
+
+CREATE OPERATOR > (
+  FUNCTION = eql_v3_internal.gt,
+  LEFTARG = public.eql_v3_bytea_eq, RIGHTARG = jsonb
+);
+
+CREATE OPERATOR > (
+  FUNCTION = eql_v3_internal.gt,
+  LEFTARG = jsonb, RIGHTARG = public.eql_v3_bytea_eq
+);
+
+CREATE OPERATOR >= (
+  FUNCTION = eql_v3_internal.gte,
+  LEFTARG = public.eql_v3_bytea_eq, RIGHTARG = public.eql_v3_bytea_eq
+);
+
+CREATE OPERATOR >= (
+  FUNCTION = eql_v3_internal.gte,
+  LEFTARG = public.eql_v3_bytea_eq, RIGHTARG = jsonb
+);
+
+CREATE OPERATOR >= (
+  FUNCTION = eql_v3_internal.gte,
+  LEFTARG = jsonb, RIGHTARG = public.eql_v3_bytea_eq
+);
+
+CREATE OPERATOR @> (
+  FUNCTION = eql_v3_internal.contains,
+  LEFTARG = public.eql_v3_bytea_eq, RIGHTARG = public.eql_v3_bytea_eq
+);
+
+CREATE OPERATOR @> (
+  FUNCTION = eql_v3_internal.contains,
+  LEFTARG = public.eql_v3_bytea_eq, RIGHTARG = jsonb
+);
+
+CREATE OPERATOR @> (
+  FUNCTION = eql_v3_internal.contains,
+  LEFTARG = jsonb, RIGHTARG = public.eql_v3_bytea_eq
+);
+
+CREATE OPERATOR <@ (
+  FUNCTION = eql_v3_internal.contained_by,
+  LEFTARG = public.eql_v3_bytea_eq, RIGHTARG = public.eql_v3_bytea_eq
+);
+
+CREATE OPERATOR <@ (
+  FUNCTION = eql_v3_internal.contained_by,
+  LEFTARG = public.eql_v3_bytea_eq, RIGHTARG = jsonb
+);
+
+CREATE OPERATOR <@ (
+  FUNCTION = eql_v3_internal.contained_by,
+  LEFTARG = jsonb, RIGHTARG = public.eql_v3_bytea_eq
+);
+
+CREATE OPERATOR -> (
+  FUNCTION = eql_v3_internal."->",
+  LEFTARG = public.eql_v3_bytea_eq, RIGHTARG = text
+);
+
+CREATE OPERATOR -> (
+  FUNCTION = eql_v3_internal."->",
+  LEFTARG = public.eql_v3_bytea_eq, RIGHTARG = integer
+);
+
+CREATE OPERATOR -> (
+  FUNCTION = eql_v3_internal."->",
+  LEFTARG = jsonb, RIGHTARG = public.eql_v3_bytea_eq
+);
+
+CREATE OPERATOR ->> (
+  FUNCTION = eql_v3_internal."->>",
+  LEFTARG = public.eql_v3_bytea_eq, RIGHTARG = text
+);
+
+CREATE OPERATOR ->> (
+  FUNCTION = eql_v3_internal."->>",
+  LEFTARG = public.eql_v3_bytea_eq, RIGHTARG = integer
+);
+
+CREATE OPERATOR ->> (
+  FUNCTION = eql_v3_internal."->>",
+  LEFTARG = jsonb, RIGHTARG = public.eql_v3_bytea_eq
+);
+
+CREATE OPERATOR ? (
+  FUNCTION = eql_v3_internal."?",
+  LEFTARG = public.eql_v3_bytea_eq, RIGHTARG = text
+);
+
+CREATE OPERATOR ?| (
+  FUNCTION = eql_v3_internal."?|",
+  LEFTARG = public.eql_v3_bytea_eq, RIGHTARG = text[]
+);
+
+CREATE OPERATOR ?& (
+  FUNCTION = eql_v3_internal."?&",
+  LEFTARG = public.eql_v3_bytea_eq, RIGHTARG = text[]
+);
+
+CREATE OPERATOR @? (
+  FUNCTION = eql_v3_internal."@?",
+  LEFTARG = public.eql_v3_bytea_eq, RIGHTARG = jsonpath
+);
+
+CREATE OPERATOR @@ (
+  FUNCTION = eql_v3_internal."@@",
+  LEFTARG = public.eql_v3_bytea_eq, RIGHTARG = public.eql_v3_bytea_eq
+);
+
+CREATE OPERATOR @@ (
+  FUNCTION = eql_v3_internal."@@",
+  LEFTARG = public.eql_v3_bytea_eq, RIGHTARG = jsonb
+);
+
+CREATE OPERATOR @@ (
+  FUNCTION = eql_v3_internal."@@",
+  LEFTARG = jsonb, RIGHTARG = public.eql_v3_bytea_eq
+);
+
+CREATE OPERATOR @@ (
+  FUNCTION = eql_v3_internal."@@",
+  LEFTARG = public.eql_v3_bytea_eq, RIGHTARG = jsonpath
+);
+
+CREATE OPERATOR #> (
+  FUNCTION = eql_v3_internal."#>",
+  LEFTARG = public.eql_v3_bytea_eq, RIGHTARG = text[]
+);
+
+CREATE OPERATOR #>> (
+  FUNCTION = eql_v3_internal."#>>",
+  LEFTARG = public.eql_v3_bytea_eq, RIGHTARG = text[]
+);
+
+CREATE OPERATOR - (
+  FUNCTION = eql_v3_internal."-",
+  LEFTARG = public.eql_v3_bytea_eq, RIGHTARG = text
+);
+
+CREATE OPERATOR - (
+  FUNCTION = eql_v3_internal."-",
+  LEFTARG = public.eql_v3_bytea_eq, RIGHTARG = integer
+);
+
+CREATE OPERATOR - (
+  FUNCTION = eql_v3_internal."-",
+  LEFTARG = public.eql_v3_bytea_eq, RIGHTARG = text[]
+);
+
+CREATE OPERATOR #- (
+  FUNCTION = eql_v3_internal."#-",
+  LEFTARG = public.eql_v3_bytea_eq, RIGHTARG = text[]
+);
+
+CREATE OPERATOR || (
+  FUNCTION = eql_v3_internal."||",
+  LEFTARG = public.eql_v3_bytea_eq, RIGHTARG = public.eql_v3_bytea_eq
+);
+
+CREATE OPERATOR || (
+  FUNCTION = eql_v3_internal."||",
+  LEFTARG = public.eql_v3_bytea_eq, RIGHTARG = jsonb
+);
+
+CREATE OPERATOR || (
+  FUNCTION = eql_v3_internal."||",
+  LEFTARG = jsonb, RIGHTARG = public.eql_v3_bytea_eq
+);
@@ -0,0 +1,242 @@
+-- AUTOMATICALLY GENERATED FILE.
+-- REQUIRE: src/v3/schema.sql
+-- REQUIRE: src/v3/scalars/bytea/bytea_types.sql
+-- REQUIRE: src/v3/scalars/bytea/bytea_functions.sql
+
+--! @file encrypted_domain/bytea/bytea_operators.sql
+--! @brief Operators for public.eql_v3_bytea.
+
+CREATE OPERATOR = (
+  FUNCTION = eql_v3_internal.eq,
+  LEFTARG = public.eql_v3_bytea, RIGHTARG = public.eql_v3_bytea
+);
+
+CREATE OPERATOR = (
+  FUNCTION = eql_v3_internal.eq,
+  LEFTARG = public.eql_v3_bytea, RIGHTARG = jsonb
+);
+
+CREATE OPERATOR = (
+  FUNCTION = eql_v3_internal.eq,
+  LEFTARG = jsonb, RIGHTARG = public.eql_v3_bytea
+);
+
+CREATE OPERATOR <> (
+  FUNCTION = eql_v3_internal.neq,
+  LEFTARG = public.eql_v3_bytea, RIGHTARG = public.eql_v3_bytea
+);
+
+CREATE OPERATOR <> (
+  FUNCTION = eql_v3_internal.neq,
+  LEFTARG = public.eql_v3_bytea, RIGHTARG = jsonb
+);
+
+CREATE OPERATOR <> (
+  FUNCTION = eql_v3_internal.neq,
+  LEFTARG = jsonb, RIGHTARG = public.eql_v3_bytea
+);
+
+CREATE OPERATOR < (
+  FUNCTION = eql_v3_internal.lt,
+  LEFTARG = public.eql_v3_bytea, RIGHTARG = public.eql_v3_bytea
+);
+
+CREATE OPERATOR < (
+  FUNCTION = eql_v3_internal.lt,
+  LEFTARG = public.eql_v3_bytea, RIGHTARG = jsonb
+);
+
+CREATE OPERATOR < (
+  FUNCTION = eql_v3_internal.lt,
+  LEFTARG = jsonb, RIGHTARG = public.eql_v3_bytea
+);
+
+CREATE OPERATOR <= (
+  FUNCTION = eql_v3_internal.lte,
+  LEFTARG = public.eql_v3_bytea, RIGHTARG = public.eql_v3_bytea
+);
+
+CREATE OPERATOR <= (
+  FUNCTION = eql_v3_internal.lte,
+  LEFTARG = public.eql_v3_bytea, RIGHTARG = jsonb
+);
+
+CREATE OPERATOR <= (
+  FUNCTION = eql_v3_internal.lte,
+  LEFTARG = jsonb, RIGHTARG = public.eql_v3_bytea
+);
+
+CREATE OPERATOR > (
+  FUNCTION = eql_v3_internal.gt,
+  LEFTARG = public.eql_v3_bytea, RIGHTARG = public.eql_v3_bytea
+);
+
+CREATE OPERATOR > (
+  FUNCTION = eql_v3_internal.gt,
+  LEFTARG = public.eql_v3_bytea, RIGHTARG = jsonb
+);
+
+CREATE OPERATOR > (
+  FUNCTION = eql_v3_internal.gt,
+  LEFTARG = jsonb, RIGHTARG = public.eql_v3_bytea
+);
+
+CREATE OPERATOR >= (
+  FUNCTION = eql_v3_internal.gte,
+  LEFTARG = public.eql_v3_bytea, RIGHTARG = public.eql_v3_bytea
+);
+
+CREATE OPERATOR >= (
+  FUNCTION = eql_v3_internal.gte,
+  LEFTARG = public.eql_v3_bytea, RIGHTARG = jsonb
+);
+
+CREATE OPERATOR >= (
+  FUNCTION = eql_v3_internal.gte,
+  LEFTARG = jsonb, RIGHTARG = public.eql_v3_bytea
+);
+
+CREATE OPERATOR @> (
+  FUNCTION = eql_v3_internal.contains,
+  LEFTARG = public.eql_v3_bytea, RIGHTARG = public.eql_v3_bytea
+);
+
+CREATE OPERATOR @> (
+  FUNCTION = eql_v3_internal.contains,
+  LEFTARG = public.eql_v3_bytea, RIGHTARG = jsonb
+);
+
+CREATE OPERATOR @> (
+  FUNCTION = eql_v3_internal.contains,
+  LEFTARG = jsonb, RIGHTARG = public.eql_v3_bytea
+);
+
+CREATE OPERATOR <@ (
+  FUNCTION = eql_v3_internal.contained_by,
+  LEFTARG = public.eql_v3_bytea, RIGHTARG = public.eql_v3_bytea
+);
+
+CREATE OPERATOR <@ (
+  FUNCTION = eql_v3_internal.contained_by,
+  LEFTARG = public.eql_v3_bytea, RIGHTARG = jsonb
+);
+
+CREATE OPERATOR <@ (
+  FUNCTION = eql_v3_internal.contained_by,
+  LEFTARG = jsonb, RIGHTARG = public.eql_v3_bytea
+);
+
+CREATE OPERATOR -> (
+  FUNCTION = eql_v3_internal."->",
+  LEFTARG = public.eql_v3_bytea, RIGHTARG = text
+);
+
+CREATE OPERATOR -> (
+  FUNCTION = eql_v3_internal."->",
+  LEFTARG = public.eql_v3_bytea, RIGHTARG = integer
+);
+
+CREATE OPERATOR -> (
+  FUNCTION = eql_v3_internal."->",
+  LEFTARG = jsonb, RIGHTARG = public.eql_v3_bytea
+);
+
+CREATE OPERATOR ->> (
+  FUNCTION = eql_v3_internal."->>",
+  LEFTARG = public.eql_v3_bytea, RIGHTARG = text
+);
+
+CREATE OPERATOR ->> (
+  FUNCTION = eql_v3_internal."->>",
+  LEFTARG = public.eql_v3_bytea, RIGHTARG = integer
+);
+
+CREATE OPERATOR ->> (
+  FUNCTION = eql_v3_internal."->>",
+  LEFTARG = jsonb, RIGHTARG = public.eql_v3_bytea
+);
+
+CREATE OPERATOR ? (
+  FUNCTION = eql_v3_internal."?",
+  LEFTARG = public.eql_v3_bytea, RIGHTARG = text
+);
+
+CREATE OPERATOR ?| (
+  FUNCTION = eql_v3_internal."?|",
+  LEFTARG = public.eql_v3_bytea, RIGHTARG = text[]
+);
+
+CREATE OPERATOR ?& (
+  FUNCTION = eql_v3_internal."?&",
+  LEFTARG = public.eql_v3_bytea, RIGHTARG = text[]
+);
+
+CREATE OPERATOR @? (
+  FUNCTION = eql_v3_internal."@?",
+  LEFTARG = public.eql_v3_bytea, RIGHTARG = jsonpath
+);
+
+CREATE OPERATOR @@ (
+  FUNCTION = eql_v3_internal."@@",
+  LEFTARG = public.eql_v3_bytea, RIGHTARG = public.eql_v3_bytea
+);
+
+CREATE OPERATOR @@ (
+  FUNCTION = eql_v3_internal."@@",
+  LEFTARG = public.eql_v3_bytea, RIGHTARG = jsonb
+);
+
+CREATE OPERATOR @@ (
+  FUNCTION = eql_v3_internal."@@",
+  LEFTARG = jsonb, RIGHTARG = public.eql_v3_bytea
+);
+
+CREATE OPERATOR @@ (
+  FUNCTION = eql_v3_internal."@@",
+  LEFTARG = public.eql_v3_bytea, RIGHTARG = jsonpath
+);
+
+CREATE OPERATOR #> (
+  FUNCTION = eql_v3_internal."#>",
+  LEFTARG = public.eql_v3_bytea, RIGHTARG = text[]
+);
+
+CREATE OPERATOR #>> (
+  FUNCTION = eql_v3_internal."#>>",
+  LEFTARG = public.eql_v3_bytea, RIGHTARG = text[]
+);
+
+CREATE OPERATOR - (
+  FUNCTION = eql_v3_internal."-",
+  LEFTARG = public.eql_v3_bytea, RIGHTARG = text
+);
+
+CREATE OPERATOR - (
+  FUNCTION = eql_v3_internal."-",
+  LEFTARG = public.eql_v3_bytea, RIGHTARG = integer
+);
+
+CREATE OPERATOR - (
+  FUNCTION = eql_v3_internal."-",
+  LEFTARG = public.eql_v3_bytea, RIGHTARG = text[]
+);
+
+CREATE OPERATOR #- (
+  FUNCTION = eql_v3_internal."#-",
+  LEFTARG = public.eql_v3_bytea, RIGHTARG = text[]
+);
+
+CREATE OPERATOR || (
+  FUNCTION = eql_v3_internal."||",
+  LEFTARG = public.eql_v3_bytea, RIGHTARG = public.eql_v3_bytea
+);
+
+CREATE OPERATOR || (
+  FUNCTION = eql_v3_internal."||",
+  LEFTARG = public.eql_v3_bytea, RIGHTARG = jsonb
+);
+
+CREATE OPERATOR || (
+  FUNCTION = eql_v3_internal."||",
+  LEFTARG = jsonb, RIGHTARG = public.eql_v3_bytea
+);
@@ -0,0 +1,31 @@
+-- AUTOMATICALLY GENERATED FILE.
+-- REQUIRE: src/v3/schema.sql
+-- REQUIRE: src/v3/scalars/bytea/query_bytea_types.sql
+-- REQUIRE: src/v3/scalars/bytea/query_bytea_eq_functions.sql
+
+--! @file encrypted_domain/bytea/query_bytea_eq_operators.sql
+--! @brief Operators for eql_v3.query_bytea_eq.
+
+CREATE OPERATOR = (
+  FUNCTION = eql_v3.eq,
+  LEFTARG = public.eql_v3_bytea_eq, RIGHTARG = eql_v3.query_bytea_eq,
+  COMMUTATOR = =, NEGATOR = <>, RESTRICT = eqsel, JOIN = eqjoinsel
+);
+
+CREATE OPERATOR = (
+  FUNCTION = eql_v3.eq,
+  LEFTARG = eql_v3.query_bytea_eq, RIGHTARG = public.eql_v3_bytea_eq,
+  COMMUTATOR = =, NEGATOR = <>, RESTRICT = eqsel, JOIN = eqjoinsel
+);
+
+CREATE OPERATOR <> (
+  FUNCTION = eql_v3.neq,
+  LEFTARG = public.eql_v3_bytea_eq, RIGHTARG = eql_v3.query_bytea_eq,
+  COMMUTATOR = <>, NEGATOR = =, RESTRICT = neqsel, JOIN = neqjoinsel
+);
+
+CREATE OPERATOR <> (
+  FUNCTION = eql_v3.neq,
+  LEFTARG = eql_v3.query_bytea_eq, RIGHTARG = public.eql_v3_bytea_eq,
+  COMMUTATOR = <>, NEGATOR = =, RESTRICT = neqsel, JOIN = neqjoinsel
+);
@@ -0,0 +1,35 @@
+-- AUTOMATICALLY GENERATED FILE.
+-- REQUIRE: src/v3/schema.sql
+
+--! @file v3/scalars/bytea/query_bytea_types.sql
+--! @brief Query-operand domains for bytea (index-terms-only, no ciphertext).
+--! @note Query-operand domains live in `eql_v3` (not `public`): they are
+--!       never valid column types, so they don't belong in the column-type
+--!       namespace, and dropping the EQL-owned schema can never drop an
+--!       application column.
+--! @note Cast a query operand explicitly to its `query_` domain in a predicate
+--!       (e.g. `WHERE col = $1::eql_v3.query_bytea_eq`). A bare,
+--!       uncast literal RHS is ambiguous between the `query_` and `jsonb`
+--!       operator overloads and will not resolve.
+
+DO $$
+BEGIN
+  --! @brief Query-operand domain eql_v3.query_bytea_eq (term-only; no `c`).
+  IF NOT EXISTS (
+    SELECT 1 FROM pg_type
+    WHERE typname = 'query_bytea_eq' AND typnamespace = 'eql_v3'::regnamespace
+  ) THEN
+    CREATE DOMAIN eql_v3.query_bytea_eq AS jsonb
+      CHECK (
+        jsonb_typeof(VALUE) = 'object'
+        AND VALUE ? 'v'
+        AND VALUE ? 'i'
+        AND VALUE ? 'hm'
+        AND NOT (VALUE ? 'c')
+        AND VALUE->>'v' = '3'
+      );
+  END IF;
+
+  COMMENT ON DOMAIN eql_v3.query_bytea_eq IS 'EQL bytea query operand (equality)';
+END
+$$;
@@ -0,0 +1,135 @@
+-- AUTOMATICALLY GENERATED FILE.
+-- REQUIRE: src/v3/schema.sql
+
+--! @file v3/scalars/index_snapshot.sql
+--! @brief Snapshot and replay the functional indexes built on EQL extractors.
+--!
+--! Re-installing or uninstalling EQL runs `DROP SCHEMA eql_v3 CASCADE`,
+--! which takes every index whose expression (or partial-index predicate) calls
+--! an extractor — `eql_v3.eq_term`, `eql_v3.eq_terms`, `eql_v3.match_term`, `eql_v3.ord_term`, `eql_v3.ord_term_ore` —
+--! down with it. The application tables and their data survive (the column
+--! domains live in `public`); the indexes do not.
+--!
+--! `eql_v3.snapshot_indexes()` records the definition of every such
+--! index in `public.eql_v3_index_snapshot`, which lives outside the EQL schemas and so
+--! survives the drop. The uninstaller and the installer call it before their
+--! `DROP SCHEMA` whenever an earlier install provides it. After the install,
+--! `eql_v3.replay_indexes()` returns one `CREATE INDEX CONCURRENTLY`
+--! statement per recorded index that is missing. `CONCURRENTLY` cannot run
+--! inside a function or a transaction block, so the statements are returned
+--! rather than executed — run them from psql with `\gexec`:
+--!
+--! @code{.sql}
+--! SELECT eql_v3.snapshot_indexes();      -- before uninstall / reinstall
+--! SELECT eql_v3.replay_indexes() \gexec  -- after install
+--! @endcode
+--!
+--! The extractor names above are rendered from the catalog's index terms, so a
+--! new term's extractor is covered without editing this file.
+
+--! @brief Every index built on an EQL extractor, with its definition.
+--!
+--! Indexes are matched through pg_depend (an index records a dependency on
+--! each function its expressions and predicate call), so quoting, casts and
+--! nesting in the expression do not matter. Partition children of a
+--! partitioned index are skipped: re-creating the parent re-creates them.
+--! @internal
+CREATE FUNCTION eql_v3_internal.extractor_indexes()
+RETURNS TABLE (schema_name name, index_name name, table_name name, index_def text)
+STABLE
+LANGUAGE sql
+AS $$
+  SELECT DISTINCT
+    n.nspname,
+    ic.relname,
+    tc.relname,
+    pg_catalog.pg_get_indexdef(i.indexrelid)
+  FROM pg_catalog.pg_index i
+  JOIN pg_catalog.pg_class ic ON ic.oid = i.indexrelid
+  JOIN pg_catalog.pg_class tc ON tc.oid = i.indrelid
+  JOIN pg_catalog.pg_namespace n ON n.oid = ic.relnamespace
+  JOIN pg_catalog.pg_depend d
+    ON d.classid = 'pg_catalog.pg_class'::pg_catalog.regclass
+   AND d.objid = i.indexrelid
+   AND d.refclassid = 'pg_catalog.pg_proc'::pg_catalog.regclass
+  JOIN pg_catalog.pg_proc p ON p.oid = d.refobjid
+  WHERE p.pronamespace = 'eql_v3'::pg_catalog.regnamespace
+    AND p.proname IN ('eq_term', 'eq_terms', 'match_term', 'ord_term', 'ord_term_ore')
+    AND NOT EXISTS (
+      SELECT 1 FROM pg_catalog.pg_inherits h WHERE h.inhrelid = i.indexrelid
+    );
+$$;
+
+--! @brief Record every index built on an EQL extractor in public.eql_v3_index_snapshot.
+--!
+--! Re-running replaces the recorded definition of an index with the same
+--! schema and name. Creates the table on first use, and only when there is
+--! something to record.
+--!
+--! @return integer Number of indexes recorded
+CREATE FUNCTION eql_v3.snapshot_indexes()
+RETURNS integer
+LANGUAGE plpgsql
+AS $$
+DECLARE
+  recorded integer;
+BEGIN
+  IF NOT EXISTS (SELECT 1 FROM eql_v3_internal.extractor_indexes()) THEN
+    RETURN 0;
+  END IF;
+
+  CREATE TABLE IF NOT EXISTS public.eql_v3_index_snapshot (
+    schema_name name NOT NULL,
+    index_name name NOT NULL,
+    table_name name NOT NULL,
+    index_def text NOT NULL,
+    recorded_at timestamptz NOT NULL DEFAULT pg_catalog.now(),
+    PRIMARY KEY (schema_name, index_name)
+  );
+  INSERT INTO public.eql_v3_index_snapshot (schema_name, index_name, table_name, index_def)
+  SELECT x.schema_name, x.index_name, x.table_name, x.index_def
+  FROM eql_v3_internal.extractor_indexes() x
+  ON CONFLICT (schema_name, index_name) DO UPDATE
+    SET table_name = EXCLUDED.table_name,
+        index_def = EXCLUDED.index_def,
+        recorded_at = EXCLUDED.recorded_at;
+  GET DIAGNOSTICS recorded = ROW_COUNT;
+  RETURN recorded;
+END;
+$$;
+
+--! @brief Statements that re-create the recorded indexes which are missing.
+--!
+--! One `CREATE INDEX CONCURRENTLY IF NOT EXISTS` per row of
+--! public.eql_v3_index_snapshot whose index no longer exists and whose table still does.
+--! An index on a partitioned table is re-created without `CONCURRENTLY`, which
+--! Postgres does not support there, and without the `ONLY` its recorded
+--! definition carries, so the partitions are indexed too. Returns nothing when
+--! no snapshot was taken.
+--!
+--! @return SETOF text One statement per missing index, for psql's \gexec
+CREATE FUNCTION eql_v3.replay_indexes()
+RETURNS SETOF text
+LANGUAGE plpgsql
+AS $$
+BEGIN
+  IF pg_catalog.to_regclass('public.eql_v3_index_snapshot') IS NULL THEN
+    RETURN;
+  END IF;
+  RETURN QUERY
+    SELECT CASE WHEN t.relkind = 'p'
+      THEN pg_catalog.regexp_replace(
+             pg_catalog.regexp_replace(s.index_def, '^CREATE (UNIQUE )?INDEX ', 'CREATE \1INDEX IF NOT EXISTS '),
+             ' ON ONLY ', ' ON ')
+      ELSE pg_catalog.regexp_replace(s.index_def, '^CREATE (UNIQUE )?INDEX ', 'CREATE \1INDEX CONCURRENTLY IF NOT EXISTS ')
+    END
+    FROM public.eql_v3_index_snapshot s
+    JOIN pg_catalog.pg_namespace n ON n.nspname = s.schema_name
+    JOIN pg_catalog.pg_class t ON t.relnamespace = n.oid AND t.relname = s.table_name
+    WHERE NOT EXISTS (
+      SELECT 1 FROM pg_catalog.pg_class c
+      WHERE c.relnamespace = n.oid AND c.relname = s.index_name
+    )
+    ORDER BY s.schema_name, s.table_name, s.index_name;
+END;
+$$;
@@ -0,0 +1,662 @@
+-- AUTOMATICALLY GENERATED FILE.
+-- REQUIRE: src/v3/schema.sql
+-- REQUIRE: src/v3/scalars/integer_array/integer_array_types.sql
+-- REQUIRE: src/v3/scalars/functions.sql
+-- REQUIRE: src/v3/sem/hmac_256/functions.sql
+
+--! @file encrypted_domain/integer_array/integer_array_contains_functions.sql
+--! @brief Functions for public.eql_v3_integer_array_contains.
+
+--! @brief Index extractor for public.eql_v3_integer_array_contains.
+--! @param a public.eql_v3_integer_array_contains
+--! @return eql_v3_internal.hmac_256[]
+CREATE FUNCTION eql_v3.eq_terms(a public.eql_v3_integer_array_contains)
+RETURNS eql_v3_internal.hmac_256[]
+LANGUAGE sql IMMUTABLE STRICT PARALLEL SAFE
+AS $$ SELECT eql_v3_internal.hmac_256_array(a::jsonb) $$;
+
+--! @brief Unsupported operator blocker for public.eql_v3_integer_array_contains.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_integer_array_contains and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a public.eql_v3_integer_array_contains left operand of the blocked operator
+--! @param b public.eql_v3_integer_array_contains right operand of the blocked operator
+--! @return boolean never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal.eq(a public.eql_v3_integer_array_contains, b public.eql_v3_integer_array_contains)
+RETURNS boolean IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '=', 'public.eql_v3_integer_array_contains'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_integer_array_contains.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_integer_array_contains and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a public.eql_v3_integer_array_contains left operand of the blocked operator
+--! @param b jsonb right operand of the blocked operator
+--! @return boolean never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal.eq(a public.eql_v3_integer_array_contains, b jsonb)
+RETURNS boolean IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '=', 'public.eql_v3_integer_array_contains'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_integer_array_contains.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_integer_array_contains and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a jsonb left operand of the blocked operator
+--! @param b public.eql_v3_integer_array_contains right operand of the blocked operator
+--! @return boolean never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal.eq(a jsonb, b public.eql_v3_integer_array_contains)
+RETURNS boolean IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '=', 'public.eql_v3_integer_array_contains'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_integer_array_contains.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_integer_array_contains and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a public.eql_v3_integer_array_contains left operand of the blocked operator
+--! @param b public.eql_v3_integer_array_contains right operand of the blocked operator
+--! @return boolean never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal.neq(a public.eql_v3_integer_array_contains, b public.eql_v3_integer_array_contains)
+RETURNS boolean IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '<>', 'public.eql_v3_integer_array_contains'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_integer_array_contains.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_integer_array_contains and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a public.eql_v3_integer_array_contains left operand of the blocked operator
+--! @param b jsonb right operand of the blocked operator
+--! @return boolean never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal.neq(a public.eql_v3_integer_array_contains, b jsonb)
+RETURNS boolean IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '<>', 'public.eql_v3_integer_array_contains'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_integer_array_contains.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_integer_array_contains and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a jsonb left operand of the blocked operator
+--! @param b public.eql_v3_integer_array_contains right operand of the blocked operator
+--! @return boolean never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal.neq(a jsonb, b public.eql_v3_integer_array_contains)
+RETURNS boolean IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '<>', 'public.eql_v3_integer_array_contains'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_integer_array_contains.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_integer_array_contains and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a public.eql_v3_integer_array_contains left operand of the blocked operator
+--! @param b public.eql_v3_integer_array_contains right operand of the blocked operator
+--! @return boolean never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal.lt(a public.eql_v3_integer_array_contains, b public.eql_v3_integer_array_contains)
+RETURNS boolean IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '<', 'public.eql_v3_integer_array_contains'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_integer_array_contains.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_integer_array_contains and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a public.eql_v3_integer_array_contains left operand of the blocked operator
+--! @param b jsonb right operand of the blocked operator
+--! @return boolean never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal.lt(a public.eql_v3_integer_array_contains, b jsonb)
+RETURNS boolean IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '<', 'public.eql_v3_integer_array_contains'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_integer_array_contains.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_integer_array_contains and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a jsonb left operand of the blocked operator
+--! @param b public.eql_v3_integer_array_contains right operand of the blocked operator
+--! @return boolean never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal.lt(a jsonb, b public.eql_v3_integer_array_contains)
+RETURNS boolean IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '<', 'public.eql_v3_integer_array_contains'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_integer_array_contains.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_integer_array_contains and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a public.eql_v3_integer_array_contains left operand of the blocked operator
+--! @param b public.eql_v3_integer_array_contains right operand of the blocked operator
+--! @return boolean never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal.lte(a public.eql_v3_integer_array_contains, b public.eql_v3_integer_array_contains)
+RETURNS boolean IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '<=', 'public.eql_v3_integer_array_contains'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_integer_array_contains.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_integer_array_contains and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a public.eql_v3_integer_array_contains left operand of the blocked operator
+--! @param b jsonb right operand of the blocked operator
+--! @return boolean never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal.lte(a public.eql_v3_integer_array_contains, b jsonb)
+RETURNS boolean IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '<=', 'public.eql_v3_integer_array_contains'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_integer_array_contains.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_integer_array_contains and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a jsonb left operand of the blocked operator
+--! @param b public.eql_v3_integer_array_contains right operand of the blocked operator
+--! @return boolean never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal.lte(a jsonb, b public.eql_v3_integer_array_contains)
+RETURNS boolean IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '<=', 'public.eql_v3_integer_array_contains'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_integer_array_contains.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_integer_array_contains and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a public.eql_v3_integer_array_contains left operand of the blocked operator
+--! @param b public.eql_v3_integer_array_contains right operand of the blocked operator
+--! @return boolean never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal.gt(a public.eql_v3_integer_array_contains, b public.eql_v3_integer_array_contains)
+RETURNS boolean IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '>', 'public.eql_v3_integer_array_contains'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_integer_array_contains.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_integer_array_contains and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a public.eql_v3_integer_array_contains left operand of the blocked operator
+--! @param b jsonb right operand of the blocked operator
+--! @return boolean never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal.gt(a public.eql_v3_integer_array_contains, b jsonb)
+RETURNS boolean IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '>', 'public.eql_v3_integer_array_contains'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_integer_array_contains.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_integer_array_contains and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a jsonb left operand of the blocked operator
+--! @param b public.eql_v3_integer_array_contains right operand of the blocked operator
+--! @return boolean never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal.gt(a jsonb, b public.eql_v3_integer_array_contains)
+RETURNS boolean IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '>', 'public.eql_v3_integer_array_contains'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_integer_array_contains.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_integer_array_contains and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a public.eql_v3_integer_array_contains left operand of the blocked operator
+--! @param b public.eql_v3_integer_array_contains right operand of the blocked operator
+--! @return boolean never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal.gte(a public.eql_v3_integer_array_contains, b public.eql_v3_integer_array_contains)
+RETURNS boolean IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '>=', 'public.eql_v3_integer_array_contains'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_integer_array_contains.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_integer_array_contains and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a public.eql_v3_integer_array_contains left operand of the blocked operator
+--! @param b jsonb right operand of the blocked operator
+--! @return boolean never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal.gte(a public.eql_v3_integer_array_contains, b jsonb)
+RETURNS boolean IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '>=', 'public.eql_v3_integer_array_contains'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_integer_array_contains.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_integer_array_contains and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a jsonb left operand of the blocked operator
+--! @param b public.eql_v3_integer_array_contains right operand of the blocked operator
+--! @return boolean never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal.gte(a jsonb, b public.eql_v3_integer_array_contains)
+RETURNS boolean IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '>=', 'public.eql_v3_integer_array_contains'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Operator wrapper for public.eql_v3_integer_array_contains.
+--! @param a public.eql_v3_integer_array_contains
+--! @param b public.eql_v3_integer_array_contains
+--! @return boolean
+CREATE FUNCTION eql_v3.contains(a public.eql_v3_integer_array_contains, b public.eql_v3_integer_array_contains)
+RETURNS boolean LANGUAGE sql IMMUTABLE STRICT PARALLEL SAFE
+AS $$ SELECT eql_v3.eq_terms(a) @> eql_v3.eq_terms(b) $$;
+
+--! @brief Operator wrapper for public.eql_v3_integer_array_contains.
+--! @param a public.eql_v3_integer_array_contains
+--! @param b jsonb
+--! @return boolean
+CREATE FUNCTION eql_v3.contains(a public.eql_v3_integer_array_contains, b jsonb)
+RETURNS boolean LANGUAGE sql IMMUTABLE STRICT PARALLEL SAFE
+AS $$ SELECT eql_v3.eq_terms(a) @> eql_v3.eq_terms(b::public.eql_v3_integer_array_contains) $$;
+
+--! @brief Operator wrapper for public.eql_v3_integer_array_contains.
+--! @param a jsonb
+--! @param b public.eql_v3_integer_array_contains
+--! @return boolean
+CREATE FUNCTION eql_v3.contains(a jsonb, b public.eql_v3_integer_array_contains)
+RETURNS boolean LANGUAGE sql IMMUTABLE STRICT PARALLEL SAFE
+AS $$ SELECT eql_v3.eq_terms(a::public.eql_v3_integer_array_contains) @> eql_v3.eq_terms(b) $$;
+
+--! @brief Operator wrapper for public.eql_v3_integer_array_contains.
+--! @param a public.eql_v3_integer_array_contains
+--! @param b public.eql_v3_integer_array_contains
+--! @return boolean
+CREATE FUNCTION eql_v3.contained_by(a public.eql_v3_integer_array_contains, b public.eql_v3_integer_array_contains)
+RETURNS boolean LANGUAGE sql IMMUTABLE STRICT PARALLEL SAFE
+AS $$ SELECT eql_v3.eq_terms(a) <@ eql_v3.eq_terms(b) $$;
+
+--! @brief Operator wrapper for public.eql_v3_integer_array_contains.
+--! @param a public.eql_v3_integer_array_contains
+--! @param b jsonb
+--! @return boolean
+CREATE FUNCTION eql_v3.contained_by(a public.eql_v3_integer_array_contains, b jsonb)
+RETURNS boolean LANGUAGE sql IMMUTABLE STRICT PARALLEL SAFE
+AS $$ SELECT eql_v3.eq_terms(a) <@ eql_v3.eq_terms(b::public.eql_v3_integer_array_contains) $$;
+
+--! @brief Operator wrapper for public.eql_v3_integer_array_contains.
+--! @param a jsonb
+--! @param b public.eql_v3_integer_array_contains
+--! @return boolean
+CREATE FUNCTION eql_v3.contained_by(a jsonb, b public.eql_v3_integer_array_contains)
+RETURNS boolean LANGUAGE sql IMMUTABLE STRICT PARALLEL SAFE
+AS $$ SELECT eql_v3.eq_terms(a::public.eql_v3_integer_array_contains) <@ eql_v3.eq_terms(b) $$;
+
+--! @brief Unsupported operator blocker for public.eql_v3_integer_array_contains.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_integer_array_contains and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a public.eql_v3_integer_array_contains left operand of the blocked operator
+--! @param selector text right operand of the blocked operator
+--! @return public.eql_v3_integer_array_contains never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal."->"(a public.eql_v3_integer_array_contains, selector text)
+RETURNS public.eql_v3_integer_array_contains IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '->', 'public.eql_v3_integer_array_contains'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_integer_array_contains.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_integer_array_contains and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a public.eql_v3_integer_array_contains left operand of the blocked operator
+--! @param selector integer right operand of the blocked operator
+--! @return public.eql_v3_integer_array_contains never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal."->"(a public.eql_v3_integer_array_contains, selector integer)
+RETURNS public.eql_v3_integer_array_contains IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '->', 'public.eql_v3_integer_array_contains'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_integer_array_contains.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_integer_array_contains and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a jsonb left operand of the blocked operator
+--! @param selector public.eql_v3_integer_array_contains right operand of the blocked operator
+--! @return public.eql_v3_integer_array_contains never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal."->"(a jsonb, selector public.eql_v3_integer_array_contains)
+RETURNS public.eql_v3_integer_array_contains IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '->', 'public.eql_v3_integer_array_contains'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_integer_array_contains.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_integer_array_contains and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a public.eql_v3_integer_array_contains left operand of the blocked operator
+--! @param selector text right operand of the blocked operator
+--! @return text never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal."->>"(a public.eql_v3_integer_array_contains, selector text)
+RETURNS text IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '->>', 'public.eql_v3_integer_array_contains'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_integer_array_contains.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_integer_array_contains and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a public.eql_v3_integer_array_contains left operand of the blocked operator
+--! @param selector integer right operand of the blocked operator
+--! @return text never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal."->>"(a public.eql_v3_integer_array_contains, selector integer)
+RETURNS text IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '->>', 'public.eql_v3_integer_array_contains'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_integer_array_contains.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_integer_array_contains and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a jsonb left operand of the blocked operator
+--! @param selector public.eql_v3_integer_array_contains right operand of the blocked operator
+--! @return text never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal."->>"(a jsonb, selector public.eql_v3_integer_array_contains)
+RETURNS text IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '->>', 'public.eql_v3_integer_array_contains'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_integer_array_contains.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_integer_array_contains and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a public.eql_v3_integer_array_contains left operand of the blocked operator
+--! @param b text right operand of the blocked operator
+--! @return boolean never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal."?"(a public.eql_v3_integer_array_contains, b text)
+RETURNS boolean IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '?', 'public.eql_v3_integer_array_contains'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_integer_array_contains.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_integer_array_contains and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a public.eql_v3_integer_array_contains left operand of the blocked operator
+--! @param b text[] right operand of the blocked operator
+--! @return boolean never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal."?|"(a public.eql_v3_integer_array_contains, b text[])
+RETURNS boolean IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '?|', 'public.eql_v3_integer_array_contains'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_integer_array_contains.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_integer_array_contains and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a public.eql_v3_integer_array_contains left operand of the blocked operator
+--! @param b text[] right operand of the blocked operator
+--! @return boolean never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal."?&"(a public.eql_v3_integer_array_contains, b text[])
+RETURNS boolean IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '?&', 'public.eql_v3_integer_array_contains'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_integer_array_contains.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_integer_array_contains and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a public.eql_v3_integer_array_contains left operand of the blocked operator
+--! @param b jsonpath right operand of the blocked operator
+--! @return boolean never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal."@?"(a public.eql_v3_integer_array_contains, b jsonpath)
+RETURNS boolean IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '@?', 'public.eql_v3_integer_array_contains'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_integer_array_contains.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_integer_array_contains and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a public.eql_v3_integer_array_contains left operand of the blocked operator
+--! @param b public.eql_v3_integer_array_contains right operand of the blocked operator
+--! @return boolean never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal."@@"(a public.eql_v3_integer_array_contains, b public.eql_v3_integer_array_contains)
+RETURNS boolean IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '@@', 'public.eql_v3_integer_array_contains'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_integer_array_contains.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_integer_array_contains and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a public.eql_v3_integer_array_contains left operand of the blocked operator
+--! @param b jsonb right operand of the blocked operator
+--! @return boolean never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal."@@"(a public.eql_v3_integer_array_contains, b jsonb)
+RETURNS boolean IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '@@', 'public.eql_v3_integer_array_contains'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_integer_array_contains.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_integer_array_contains and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a jsonb left operand of the blocked operator
+--! @param b public.eql_v3_integer_array_contains right operand of the blocked operator
+--! @return boolean never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal."@@"(a jsonb, b public.eql_v3_integer_array_contains)
+RETURNS boolean IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '@@', 'public.eql_v3_integer_array_contains'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_integer_array_contains.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_integer_array_contains and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a public.eql_v3_integer_array_contains left operand of the blocked operator
+--! @param b jsonpath right operand of the blocked operator
+--! @return boolean never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal."@@"(a public.eql_v3_integer_array_contains, b jsonpath)
+RETURNS boolean IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '@@', 'public.eql_v3_integer_array_contains'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_integer_array_contains.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_integer_array_contains and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a public.eql_v3_integer_array_contains left operand of the blocked operator
+--! @param b text[] right operand of the blocked operator
+--! @return jsonb never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal."#>"(a public.eql_v3_integer_array_contains, b text[])
+RETURNS jsonb IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '#>', 'public.eql_v3_integer_array_contains'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_integer_array_contains.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_integer_array_contains and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a public.eql_v3_integer_array_contains left operand of the blocked operator
+--! @param b text[] right operand of the blocked operator
+--! @return text never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal."#>>"(a public.eql_v3_integer_array_contains, b text[])
+RETURNS text IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '#>>', 'public.eql_v3_integer_array_contains'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_integer_array_contains.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_integer_array_contains and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a public.eql_v3_integer_array_contains left operand of the blocked operator
+--! @param b text right operand of the blocked operator
+--! @return jsonb never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal."-"(a public.eql_v3_integer_array_contains, b text)
+RETURNS jsonb IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '-', 'public.eql_v3_integer_array_contains'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_integer_array_contains.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_integer_array_contains and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a public.eql_v3_integer_array_contains left operand of the blocked operator
+--! @param b integer right operand of the blocked operator
+--! @return jsonb never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal."-"(a public.eql_v3_integer_array_contains, b integer)
+RETURNS jsonb IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '-', 'public.eql_v3_integer_array_contains'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_integer_array_contains.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_integer_array_contains and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a public.eql_v3_integer_array_contains left operand of the blocked operator
+--! @param b text[] right operand of the blocked operator
+--! @return jsonb never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal."-"(a public.eql_v3_integer_array_contains, b text[])
+RETURNS jsonb IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '-', 'public.eql_v3_integer_array_contains'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_integer_array_contains.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_integer_array_contains and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a public.eql_v3_integer_array_contains left operand of the blocked operator
+--! @param b text[] right operand of the blocked operator
+--! @return jsonb never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal."#-"(a public.eql_v3_integer_array_contains, b text[])
+RETURNS jsonb IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '#-', 'public.eql_v3_integer_array_contains'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_integer_array_contains.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_integer_array_contains and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a public.eql_v3_integer_array_contains left operand of the blocked operator
+--! @param b public.eql_v3_integer_array_contains right operand of the blocked operator
+--! @return jsonb never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal."||"(a public.eql_v3_integer_array_contains, b public.eql_v3_integer_array_contains)
+RETURNS jsonb IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '||', 'public.eql_v3_integer_array_contains'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_integer_array_contains.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_integer_array_contains and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a public.eql_v3_integer_array_contains left operand of the blocked operator
+--! @param b jsonb right operand of the blocked operator
+--! @return jsonb never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal."||"(a public.eql_v3_integer_array_contains, b jsonb)
+RETURNS jsonb IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '||', 'public.eql_v3_integer_array_contains'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Unsupported operator blocker for public.eql_v3_integer_array_contains.
+--!
+--! Intercepts an operator that is not supported on public.eql_v3_integer_array_contains and always raises;
+--! it never returns a value. The declared signature exists only so the operator
+--! resolves to this blocker instead of a base-type fallback.
+--!
+--! @param a jsonb left operand of the blocked operator
+--! @param b public.eql_v3_integer_array_contains right operand of the blocked operator
+--! @return jsonb never returned — the function always raises "operator not supported"
+CREATE FUNCTION eql_v3_internal."||"(a jsonb, b public.eql_v3_integer_array_contains)
+RETURNS jsonb IMMUTABLE PARALLEL SAFE
+AS $$ BEGIN RAISE EXCEPTION 'operator % is not supported for %', '||', 'public.eql_v3_integer_array_contains'; END; $$
+LANGUAGE plpgsql;
+
+--! @brief Operator wrapper for public.eql_v3_integer_array_contains.
+--! @param a public.eql_v3_integer_array_contains
+--! @param b public.eql_v3_integer_array_contains
+--! @return boolean
+CREATE FUNCTION eql_v3.overlaps(a public.eql_v3_integer_array_contains, b public.eql_v3_integer_array_contains)
+RETURNS boolean LANGUAGE sql IMMUTABLE STRICT PARALLEL SAFE
+AS $$ SELECT eql_v3.eq_terms(a) && eql_v3.eq_terms(b) $$;
+
+--! @brief Operator wrapper for public.eql_v3_integer_array_contains.
+--! @param a public.eql_v3_integer_array_contains
+--! @param b jsonb
+--! @return boolean
+CREATE FUNCTION eql_v3.overlaps(a public.eql_v3_integer_array_contains, b jsonb)
+RETURNS boolean LANGUAGE sql IMMUTABLE STRICT PARALLEL SAFE
+AS $$ SELECT eql_v3.eq_terms(a) && eql_v3.eq_terms(b::public.eql_v3_integer_array_contains) $$;
+
+--! @brief Operator wrapper for public.eql_v3_integer_array_contains.
+--! @param a jsonb
+--! @param b public.eql_v3_integer_array_contains
+--! @return boolean
+CREATE FUNCTION eql_v3.overlaps(a jsonb, b public.eql_v3_integer_array_contains)
+RETURNS boolean LANGUAGE sql IMMUTABLE STRICT PARALLEL SAFE
+AS $$ SELECT eql_v3.eq_terms(a::public.eql_v3_integer_array_contains) && eql_v3.eq_terms(b) $$;
@@ -0,0 +1,266 @@
+-- AUTOMATICALLY GENERATED FILE.
+-- REQUIRE: src/v3/schema.sql
+-- REQUIRE: src/v3/scalars/integer_array/integer_array_types.sql
+-- REQUIRE: src/v3/scalars/integer_array/integer_array_contains_functions.sql
+
+--! @file encrypted_domain/integer_array/integer_array_contains_operators.sql
+--! @brief Operators for public.eql_v3_integer_array_contains.
+
+CREATE OPERATOR = (
+  FUNCTION = eql_v3_internal.eq,
+  LEFTARG = public.eql_v3_integer_array_contains, RIGHTARG = public.eql_v3_integer_array_contains
+);
+
+CREATE OPERATOR = (
+  FUNCTION = eql_v3_internal.eq,
+  LEFTARG = public.eql_v3_integer_array_contains, RIGHTARG = jsonb
+);
+
+CREATE OPERATOR = (
+  FUNCTION = eql_v3_internal.eq,
+  LEFTARG = jsonb, RIGHTARG = public.eql_v3_integer_array_contains
+);
+
+CREATE OPERATOR <> (
+  FUNCTION = eql_v3_internal.neq,
+  LEFTARG = public.eql_v3_integer_array_contains, RIGHTARG = public.eql_v3_integer_array_contains
+);
+
+CREATE OPERATOR <> (
+  FUNCTION = eql_v3_internal.neq,
+  LEFTARG = public.eql_v3_integer_array_contains, RIGHTARG = jsonb
+);
+
+CREATE OPERATOR <> (
+  FUNCTION = eql_v3_internal.neq,
+  LEFTARG = jsonb, RIGHTARG = public.eql_v3_integer_array_contains
+);
+
+CREATE OPERATOR < (
+  FUNCTION = eql_v3_internal.lt,
+  LEFTARG = public.eql_v3_integer_array_contains, RIGHTARG = public.eql_v3_integer_array_contains
+);
+
+CREATE OPERATOR < (
+  FUNCTION = eql_v3_internal.lt,
+  LEFTARG = public.eql_v3_integer_array_contains, RIGHTARG = jsonb
+);
+
+CREATE OPERATOR < (
+  FUNCTION = eql_v3_internal.lt,
+  LEFTARG = jsonb, RIGHTARG = public.eql_v3_integer_array_contains
+);
+
+CREATE OPERATOR <= (
+  FUNCTION = eql_v3_internal.lte,
+  LEFTARG = public.eql_v3_integer_array_contains, RIGHTARG = public.eql_v3_integer_array_contains
+);
+
+CREATE OPERATOR <= (
+  FUNCTION = eql_v3_internal.lte,
+  LEFTARG = public.eql_v3_integer_array_contains, RIGHTARG = jsonb
+);
+
+CREATE OPERATOR <= (
+  FUNCTION = eql_v3_internal.lte,
+  LEFTARG = jsonb, RIGHTARG = public.eql_v3_integer_array_contains
+);
+
+CREATE OPERATOR > (
+  FUNCTION = eql_v3_internal.gt,
+  LEFTARG = public.eql_v3_integer_array_contains, RIGHTARG = public.eql_v3_integer_array_contains
+);
+
+CREATE OPERATOR > (
+  FUNCTION = eql_v3_internal.gt,
+  LEFTARG = public.eql_v3_integer_array_contains, RIGHTARG = jsonb
+);
+
+CREATE OPERATOR > (
+  FUNCTION = eql_v3_internal.gt,
+  LEFTARG = jsonb, RIGHTARG = public.eql_v3_integer_array_contains
+);
+
+CREATE OPERATOR >= (
+  FUNCTION = eql_v3_internal.gte,
+  LEFTARG = public.eql_v3_integer_array_contains, RIGHTARG = public.eql_v3_integer_array_contains
+);
+
+CREATE OPERATOR >= (
+  FUNCTION = eql_v3_internal.gte,
+  LEFTARG = public.eql_v3_integer_array_contains, RIGHTARG = jsonb
+);
+
+CREATE OPERATOR >= (
+  FUNCTION = eql_v3_internal.gte,
+  LEFTARG = jsonb, RIGHTARG = public.eql_v3_integer_array_contains
+);
+
+CREATE OPERATOR @> (
+  FUNCTION = eql_v3.contains,
+  LEFTARG = public.eql_v3_integer_array_contains, RIGHTARG = public.eql_v3_integer_array_contains,
+  COMMUTATOR = <@, RESTRICT = contsel, JOIN = contjoinsel
+);
+
+CREATE OPERATOR @> (
+  FUNCTION = eql_v3.contains,
+  LEFTARG = public.eql_v3_integer_array_contains, RIGHTARG = jsonb,
+  COMMUTATOR = <@, RESTRICT = contsel, JOIN = contjoinsel
+);
+
+CREATE OPERATOR @> (
+  FUNCTION = eql_v3.contains,
+  LEFTARG = jsonb, RIGHTARG = public.eql_v3_integer_array_contains,
+  COMMUTATOR = <@, RESTRICT = contsel, JOIN = contjoinsel
+);
+
+CREATE OPERATOR <@ (
+  FUNCTION = eql_v3.contained_by,
+  LEFTARG = public.eql_v3_integer_array_contains, RIGHTARG = public.eql_v3_integer_array_contains,
+  COMMUTATOR = @>, RESTRICT = contsel, JOIN = contjoinsel
+);
+
+CREATE OPERATOR <@ (
+  FUNCTION = eql_v3.contained_by,
+  LEFTARG = public.eql_v3_integer_array_contains, RIGHTARG = jsonb,
+  COMMUTATOR = @>, RESTRICT = contsel, JOIN = contjoinsel
+);
+
+CREATE OPERATOR <@ (
+  FUNCTION = eql_v3.contained_by,
+  LEFTARG = jsonb, RIGHTARG = public.eql_v3_integer_array_contains,
+  COMMUTATOR = @>, RESTRICT = contsel, JOIN = contjoinsel
+);
+
+CREATE OPERATOR -> (
+  FUNCTION = eql_v3_internal."->",
+  LEFTARG = public.eql_v3_integer_array_contains, RIGHTARG = text
+);
+
+CREATE OPERATOR -> (
+  FUNCTION = eql_v3_internal."->",
+  LEFTARG = public.eql_v3_integer_array_contains, RIGHTARG = integer
+);
+
+CREATE OPERATOR -> (
+  FUNCTION = eql_v3_internal."->",
+  LEFTARG = jsonb, RIGHTARG = public.eql_v3_integer_array_contains
+);
+
+CREATE OPERATOR ->> (
+  FUNCTION = eql_v3_internal."->>",
+  LEFTARG = public.eql_v3_integer_array_contains, RIGHTARG = text
+);
+
+CREATE OPERATOR ->> (
+  FUNCTION = eql_v3_internal."->>",
+  LEFTARG = public.eql_v3_integer_array_contains, RIGHTARG = integer
+);
+
+CREATE OPERATOR ->> (
+  FUNCTION = eql_v3_internal."->>",
+  LEFTARG = jsonb, RIGHTARG = public.eql_v3_integer_array_contains
+);
+
+CREATE OPERATOR ? (
+  FUNCTION = eql_v3_internal."?",
+  LEFTARG = public.eql_v3_integer_array_contains, RIGHTARG = text
+);
+
+CREATE OPERATOR ?| (
+  FUNCTION = eql_v3_internal."?|",
+  LEFTARG = public.eql_v3_integer_array_contains, RIGHTARG = text[]
+);
+
+CREATE OPERATOR ?& (
+  FUNCTION = eql_v3_internal."?&",
+  LEFTARG = public.eql_v3_integer_array_contains, RIGHTARG = text[]
+);
+
+CREATE OPERATOR @? (
+  FUNCTION = eql_v3_internal."@?",
+  LEFTARG = public.eql_v3_integer_array_contains, RIGHTARG = jsonpath
+);
+
+CREATE OPERATOR @@ (
+  FUNCTION = eql_v3_internal."@@",
+  LEFTARG = public.eql_v3_integer_array_contains, RIGHTARG = public.eql_v3_integer_array_contains
+);
+
+CREATE OPERATOR @@ (
+  FUNCTION = eql_v3_internal."@@",
+  LEFTARG = public.eql_v3_integer_array_contains, RIGHTARG = jsonb
+);
+
+CREATE OPERATOR @@ (
+  FUNCTION = eql_v3_internal."@@",
+  LEFTARG = jsonb, RIGHTARG = public.eql_v3_integer_array_contains
+);
+
+CREATE OPERATOR @@ (
+  FUNCTION = eql_v3_internal."@@",
+  LEFTARG = public.eql_v3_integer_array_contains, RIGHTARG = jsonpath
+);
+
+CREATE OPERATOR #> (
+  FUNCTION = eql_v3_internal."#>",
+  LEFTARG = public.eql_v3_integer_array_contains, RIGHTARG = text[]
+);
+
+CREATE OPERATOR #>> (
+  FUNCTION = eql_v3_internal."#>>",
+  LEFTARG = public.eql_v3_integer_array_contains, RIGHTARG = text[]
+);
+
+CREATE OPERATOR - (
+  FUNCTION = eql_v3_internal."-",
+  LEFTARG = public.eql_v3_integer_array_contains, RIGHTARG = text
+);
+
+CREATE OPERATOR - (
+  FUNCTION = eql_v3_internal."-",
+  LEFTARG = public.eql_v3_integer_array_contains, RIGHTARG = integer
+);
+
+CREATE OPERATOR - (
+  FUNCTION = eql_v3_internal."-",
+  LEFTARG = public.eql_v3_integer_array_contains, RIGHTARG = text[]
+);
+
+CREATE OPERATOR #- (
+  FUNCTION = eql_v3_internal."#-",
+  LEFTARG = public.eql_v3_integer_array_contains, RIGHTARG = text[]
+);
+
+CREATE OPERATOR || (
+  FUNCTION = eql_v3_internal."||",
+  LEFTARG = public.eql_v3_integer_array_contains, RIGHTARG = public.eql_v3_integer_array_contains
+);
+
+CREATE OPERATOR || (
+  FUNCTION = eql_v3_internal."||",
+  LEFTARG = public.eql_v3_integer_array_contains, RIGHTARG = jsonb
+);
+
+CREATE OPERATOR || (
+  FUNCTION = eql_v3_internal."||",
+  LEFTARG = jsonb, RIGHTARG = public.eql_v3_integer_array_contains
+);
+
+CREATE OPERATOR && (
+  FUNCTION = eql_v3.overlaps,
+  LEFTARG = public.eql_v3_integer_array_contains, RIGHTARG = public.eql_v3_integer_array_contains,
+  COMMUTATOR = &&, RESTRICT = contsel, JOIN = contjoinsel
+);
+
+CREATE OPERATOR && (
+  FUNCTION = eql_v3.overlaps,
+  LEFTARG = public.eql_v3_integer_array_contains, RIGHTARG = jsonb,
+  COMMUTATOR = &&, RESTRICT = contsel, JOIN = contjoinsel
+);
+
+CREATE OPERATOR && (
+  FUNCTION = eql_v3.overlaps,
+  LEFTARG = jsonb, RIGHTARG = public.eql_v3_integer_array_contains,
+  COMMUTATOR = &&, RESTRICT = contsel, JOIN = contjoinsel
+);
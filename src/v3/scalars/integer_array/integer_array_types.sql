@@ -0,0 +1,45 @@
+-- AUTOMATICALLY GENERATED FILE.
+-- REQUIRE: src/v3/schema.sql
+
+--! @file v3/scalars/integer_array/integer_array_types.sql
+--! @brief Encrypted-domain types for integer_array.
+
+DO $$
+BEGIN
+  --! @brief Encrypted domain public.eql_v3_integer_array.
+  IF NOT EXISTS (
+    SELECT 1 FROM pg_type
+    WHERE typname = 'eql_v3_integer_array' AND typnamespace = 'public'::regnamespace
+  ) THEN
+    CREATE DOMAIN public.eql_v3_integer_array AS jsonb
+      CHECK (
+        jsonb_typeof(VALUE) = 'object'
+        AND VALUE ? 'v'
+        AND VALUE ? 'i'
+        AND VALUE ? 'c'
+        AND VALUE->>'v' = '3'
+      );
+  END IF;
+
+  COMMENT ON DOMAIN public.eql_v3_integer_array IS 'EQL encrypted integer_array (storage only)';
+
+  --! @brief Encrypted domain public.eql_v3_integer_array_contains.
+  IF NOT EXISTS (
+    SELECT 1 FROM pg_type
+    WHERE typname = 'eql_v3_integer_array_contains' AND typnamespace = 'public'::regnamespace
+  ) THEN
+    CREATE DOMAIN public.eql_v3_integer_array_contains AS jsonb
+      CHECK (
+        jsonb_typeof(VALUE) = 'object'
+        AND VALUE ? 'v'
+        AND VALUE ? 'i'
+        AND VALUE ? 'c'
+        AND VALUE ? 'hm'
+        AND jsonb_typeof(VALUE -> 'hm') = 'array'
+        AND VALUE->>'v' = '3'
+      );
+  END IF;
+
+  COMMENT ON DOMAIN public.eql_v3_integer_array_contains IS 'EQL encrypted integer_array (containment)';
+END
+$$;
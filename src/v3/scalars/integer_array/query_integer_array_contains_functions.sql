@@ -0,0 +1,63 @@
+-- AUTOMATICALLY GENERATED FILE.
+-- REQUIRE: src/v3/schema.sql
+-- REQUIRE: src/v3/scalars/integer_array/query_integer_array_types.sql
+-- REQUIRE: src/v3/scalars/integer_array/integer_array_contains_functions.sql
+
+--! @file encrypted_domain/integer_array/query_integer_array_contains_functions.sql
+--! @brief Functions for eql_v3.query_integer_array_contains.
+
+--! @brief Index extractor for eql_v3.query_integer_array_contains.
+--! @param a eql_v3.query_integer_array_contains
+--! @return eql_v3_internal.hmac_256[]
+CREATE FUNCTION eql_v3.eq_terms(a eql_v3.query_integer_array_contains)
+RETURNS eql_v3_internal.hmac_256[]
+LANGUAGE sql IMMUTABLE STRICT PARALLEL SAFE
+AS $$ SELECT eql_v3_internal.hmac_256_array(a::jsonb) $$;
+
+--! @brief Operator wrapper for eql_v3.query_integer_array_contains.
+--! @param a public.eql_v3_integer_array_contains
+--! @param b eql_v3.query_integer_array_contains
+--! @return boolean
+CREATE FUNCTION eql_v3.contains(a public.eql_v3_integer_array_contains, b eql_v3.query_integer_array_contains)
+RETURNS boolean LANGUAGE sql IMMUTABLE STRICT PARALLEL SAFE
+AS $$ SELECT eql_v3.eq_terms(a) @> eql_v3.eq_terms(b) $$;
+
+--! @brief Operator wrapper for eql_v3.query_integer_array_contains.
+--! @param a eql_v3.query_integer_array_contains
+--! @param b public.eql_v3_integer_array_contains
+--! @return boolean
+CREATE FUNCTION eql_v3.contains(a eql_v3.query_integer_array_contains, b public.eql_v3_integer_array_contains)
+RETURNS boolean LANGUAGE sql IMMUTABLE STRICT PARALLEL SAFE
+AS $$ SELECT eql_v3.eq_terms(a) @> eql_v3.eq_terms(b) $$;
+
+--! @brief Operator wrapper for eql_v3.query_integer_array_contains.
+--! @param a public.eql_v3_integer_array_contains
+--! @param b eql_v3.query_integer_array_contains
+--! @return boolean
+CREATE FUNCTION eql_v3.contained_by(a public.eql_v3_integer_array_contains, b eql_v3.query_integer_array_contains)
+RETURNS boolean LANGUAGE sql IMMUTABLE STRICT PARALLEL SAFE
+AS $$ SELECT eql_v3.eq_terms(a) <@ eql_v3.eq_terms(b) $$;
+
+--! @brief Operator wrapper for eql_v3.query_integer_array_contains.
+--! @param a eql_v3.query_integer_array_contains
+--! @param b public.eql_v3_integer_array_contains
+--! @return boolean
+CREATE FUNCTION eql_v3.contained_by(a eql_v3.query_integer_array_contains, b public.eql_v3_integer_array_contains)
+RETURNS boolean LANGUAGE sql IMMUTABLE STRICT PARALLEL SAFE
+AS $$ SELECT eql_v3.eq_terms(a) <@ eql_v3.eq_terms(b) $$;
+
+--! @brief Operator wrapper for eql_v3.query_integer_array_contains.
+--! @param a public.eql_v3_integer_array_contains
+--! @param b eql_v3.query_integer_array_contains
+--! @return boolean
+CREATE FUNCTION eql_v3.overlaps(a public.eql_v3_integer_array_contains, b eql_v3.query_integer_array_contains)
+RETURNS boolean LANGUAGE sql IMMUTABLE STRICT PARALLEL SAFE
+AS $$ SELECT eql_v3.eq_terms(a) && eql_v3.eq_terms(b) $$;
+
+--! @brief Operator wrapper for eql_v3.query_integer_array_contains.
+--! @param a eql_v3.query_integer_array_contains
+--! @param b public.eql_v3_integer_array_contains
+--! @return boolean
+CREATE FUNCTION eql_v3.overlaps(a eql_v3.query_integer_array_contains, b public.eql_v3_integer_array_contains)
+RETURNS boolean LANGUAGE sql IMMUTABLE STRICT PARALLEL SAFE
+AS $$ SELECT eql_v3.eq_terms(a) && eql_v3.eq_terms(b) $$;
@@ -19,6 +19,12 @@
 -- REQUIRE: src/v3/scalars/real/query_real_types.sql
 -- REQUIRE: src/v3/scalars/double/double_types.sql
 -- REQUIRE: src/v3/scalars/double/query_double_types.sql
+-- REQUIRE: src/v3/scalars/timestamp_naive/timestamp_naive_types.sql
+-- REQUIRE: src/v3/scalars/timestamp_naive/query_timestamp_naive_types.sql
+-- REQUIRE: src/v3/scalars/time/time_types.sql
+-- REQUIRE: src/v3/scalars/time/query_time_types.sql
+-- REQUIRE: src/v3/scalars/interval/interval_types.sql
+-- REQUIRE: src/v3/scalars/interval/query_interval_types.sql
 
 --! @file v3/scalars/ore_fallback.sql
 --! @brief Disable the ORE-backed encrypted domains when the ORE operator class is absent.
@@ -85,68 +91,114 @@ BEGIN
   -- NOT VALID: skip validating existing stored data (rows written under an
   -- earlier superuser install must stay readable, and re-installing over them
   -- must not abort). Domain coercion still enforces the CHECK on every new
-  -- cast/insert regardless of validation status.
+  -- cast/insert regardless of validation status. Each constraint is dropped
+  -- first so an in-place upgrade over an already-poisoned install re-adds it
+  -- instead of failing on the duplicate name.
 
+  ALTER DOMAIN public.eql_v3_integer_ord_ore DROP CONSTRAINT IF EXISTS eql_ore_unavailable;
   ALTER DOMAIN public.eql_v3_integer_ord_ore ADD CONSTRAINT eql_ore_unavailable
     CHECK (eql_v3_internal.ore_domain_unavailable(VALUE, 'public.eql_v3_integer_ord_ore', 'public.eql_v3_integer_eq (equality) or public.eql_v3_integer_ord (ordering) or public.eql_v3_integer_ord_ope (ordering)')) NOT VALID;
 
+  ALTER DOMAIN eql_v3.query_integer_ord_ore DROP CONSTRAINT IF EXISTS eql_ore_unavailable;
   ALTER DOMAIN eql_v3.query_integer_ord_ore ADD CONSTRAINT eql_ore_unavailable
     CHECK (eql_v3_internal.ore_domain_unavailable(VALUE, 'eql_v3.query_integer_ord_ore', 'eql_v3.query_integer_eq (equality) or eql_v3.query_integer_ord (ordering) or eql_v3.query_integer_ord_ope (ordering)')) NOT VALID;
 
+  ALTER DOMAIN public.eql_v3_smallint_ord_ore DROP CONSTRAINT IF EXISTS eql_ore_unavailable;
   ALTER DOMAIN public.eql_v3_smallint_ord_ore ADD CONSTRAINT eql_ore_unavailable
     CHECK (eql_v3_internal.ore_domain_unavailable(VALUE, 'public.eql_v3_smallint_ord_ore', 'public.eql_v3_smallint_eq (equality) or public.eql_v3_smallint_ord (ordering) or public.eql_v3_smallint_ord_ope (ordering)')) NOT VALID;
 
+  ALTER DOMAIN eql_v3.query_smallint_ord_ore DROP CONSTRAINT IF EXISTS eql_ore_unavailable;
   ALTER DOMAIN eql_v3.query_smallint_ord_ore ADD CONSTRAINT eql_ore_unavailable
     CHECK (eql_v3_internal.ore_domain_unavailable(VALUE, 'eql_v3.query_smallint_ord_ore', 'eql_v3.query_smallint_eq (equality) or eql_v3.query_smallint_ord (ordering) or eql_v3.query_smallint_ord_ope (ordering)')) NOT VALID;
 
+  ALTER DOMAIN public.eql_v3_bigint_ord_ore DROP CONSTRAINT IF EXISTS eql_ore_unavailable;
   ALTER DOMAIN public.eql_v3_bigint_ord_ore ADD CONSTRAINT eql_ore_unavailable
     CHECK (eql_v3_internal.ore_domain_unavailable(VALUE, 'public.eql_v3_bigint_ord_ore', 'public.eql_v3_bigint_eq (equality) or public.eql_v3_bigint_ord (ordering) or public.eql_v3_bigint_ord_ope (ordering)')) NOT VALID;
 
+  ALTER DOMAIN eql_v3.query_bigint_ord_ore DROP CONSTRAINT IF EXISTS eql_ore_unavailable;
   ALTER DOMAIN eql_v3.query_bigint_ord_ore ADD CONSTRAINT eql_ore_unavailable
     CHECK (eql_v3_internal.ore_domain_unavailable(VALUE, 'eql_v3.query_bigint_ord_ore', 'eql_v3.query_bigint_eq (equality) or eql_v3.query_bigint_ord (ordering) or eql_v3.query_bigint_ord_ope (ordering)')) NOT VALID;
 
+  ALTER DOMAIN public.eql_v3_date_ord_ore DROP CONSTRAINT IF EXISTS eql_ore_unavailable;
   ALTER DOMAIN public.eql_v3_date_ord_ore ADD CONSTRAINT eql_ore_unavailable
     CHECK (eql_v3_internal.ore_domain_unavailable(VALUE, 'public.eql_v3_date_ord_ore', 'public.eql_v3_date_eq (equality) or public.eql_v3_date_ord (ordering) or public.eql_v3_date_ord_ope (ordering)')) NOT VALID;
 
+  ALTER DOMAIN eql_v3.query_date_ord_ore DROP CONSTRAINT IF EXISTS eql_ore_unavailable;
   ALTER DOMAIN eql_v3.query_date_ord_ore ADD CONSTRAINT eql_ore_unavailable
     CHECK (eql_v3_internal.ore_domain_unavailable(VALUE, 'eql_v3.query_date_ord_ore', 'eql_v3.query_date_eq (equality) or eql_v3.query_date_ord (ordering) or eql_v3.query_date_ord_ope (ordering)')) NOT VALID;
 
+  ALTER DOMAIN public.eql_v3_timestamp_ord_ore DROP CONSTRAINT IF EXISTS eql_ore_unavailable;
   ALTER DOMAIN public.eql_v3_timestamp_ord_ore ADD CONSTRAINT eql_ore_unavailable
     CHECK (eql_v3_internal.ore_domain_unavailable(VALUE, 'public.eql_v3_timestamp_ord_ore', 'public.eql_v3_timestamp_eq (equality) or public.eql_v3_timestamp_ord (ordering) or public.eql_v3_timestamp_ord_ope (ordering)')) NOT VALID;
 
+  ALTER DOMAIN eql_v3.query_timestamp_ord_ore DROP CONSTRAINT IF EXISTS eql_ore_unavailable;
   ALTER DOMAIN eql_v3.query_timestamp_ord_ore ADD CONSTRAINT eql_ore_unavailable
     CHECK (eql_v3_internal.ore_domain_unavailable(VALUE, 'eql_v3.query_timestamp_ord_ore', 'eql_v3.query_timestamp_eq (equality) or eql_v3.query_timestamp_ord (ordering) or eql_v3.query_timestamp_ord_ope (ordering)')) NOT VALID;
 
+  ALTER DOMAIN public.eql_v3_numeric_ord_ore DROP CONSTRAINT IF EXISTS eql_ore_unavailable;
   ALTER DOMAIN public.eql_v3_numeric_ord_ore ADD CONSTRAINT eql_ore_unavailable
     CHECK (eql_v3_internal.ore_domain_unavailable(VALUE, 'public.eql_v3_numeric_ord_ore', 'public.eql_v3_numeric_eq (equality) or public.eql_v3_numeric_ord (ordering) or public.eql_v3_numeric_ord_ope (ordering)')) NOT VALID;
 
+  ALTER DOMAIN eql_v3.query_numeric_ord_ore DROP CONSTRAINT IF EXISTS eql_ore_unavailable;
   ALTER DOMAIN eql_v3.query_numeric_ord_ore ADD CONSTRAINT eql_ore_unavailable
     CHECK (eql_v3_internal.ore_domain_unavailable(VALUE, 'eql_v3.query_numeric_ord_ore', 'eql_v3.query_numeric_eq (equality) or eql_v3.query_numeric_ord (ordering) or eql_v3.query_numeric_ord_ope (ordering)')) NOT VALID;
 
+  ALTER DOMAIN public.eql_v3_text_ord_ore DROP CONSTRAINT IF EXISTS eql_ore_unavailable;
   ALTER DOMAIN public.eql_v3_text_ord_ore ADD CONSTRAINT eql_ore_unavailable
     CHECK (eql_v3_internal.ore_domain_unavailable(VALUE, 'public.eql_v3_text_ord_ore', 'public.eql_v3_text_eq (equality) or public.eql_v3_text_match (match) or public.eql_v3_text_ord (ordering) or public.eql_v3_text_ord_ope (ordering) or public.eql_v3_text_search (ordering)')) NOT VALID;
 
+  ALTER DOMAIN eql_v3.query_text_ord_ore DROP CONSTRAINT IF EXISTS eql_ore_unavailable;
   ALTER DOMAIN eql_v3.query_text_ord_ore ADD CONSTRAINT eql_ore_unavailable
     CHECK (eql_v3_internal.ore_domain_unavailable(VALUE, 'eql_v3.query_text_ord_ore', 'eql_v3.query_text_eq (equality) or eql_v3.query_text_match (match) or eql_v3.query_text_ord (ordering) or eql_v3.query_text_ord_ope (ordering) or eql_v3.query_text_search (ordering)')) NOT VALID;
 
+  ALTER DOMAIN public.eql_v3_text_search_ore DROP CONSTRAINT IF EXISTS eql_ore_unavailable;
   ALTER DOMAIN public.eql_v3_text_search_ore ADD CONSTRAINT eql_ore_unavailable
     CHECK (eql_v3_internal.ore_domain_unavailable(VALUE, 'public.eql_v3_text_search_ore', 'public.eql_v3_text_eq (equality) or public.eql_v3_text_match (match) or public.eql_v3_text_ord (ordering) or public.eql_v3_text_ord_ope (ordering) or public.eql_v3_text_search (ordering)')) NOT VALID;
 
+  ALTER DOMAIN eql_v3.query_text_search_ore DROP CONSTRAINT IF EXISTS eql_ore_unavailable;
   ALTER DOMAIN eql_v3.query_text_search_ore ADD CONSTRAINT eql_ore_unavailable
     CHECK (eql_v3_internal.ore_domain_unavailable(VALUE, 'eql_v3.query_text_search_ore', 'eql_v3.query_text_eq (equality) or eql_v3.query_text_match (match) or eql_v3.query_text_ord (ordering) or eql_v3.query_text_ord_ope (ordering) or eql_v3.query_text_search (ordering)')) NOT VALID;
 
+  ALTER DOMAIN public.eql_v3_real_ord_ore DROP CONSTRAINT IF EXISTS eql_ore_unavailable;
   ALTER DOMAIN public.eql_v3_real_ord_ore ADD CONSTRAINT eql_ore_unavailable
     CHECK (eql_v3_internal.ore_domain_unavailable(VALUE, 'public.eql_v3_real_ord_ore', 'public.eql_v3_real_eq (equality) or public.eql_v3_real_ord (ordering) or public.eql_v3_real_ord_ope (ordering)')) NOT VALID;
 
+  ALTER DOMAIN eql_v3.query_real_ord_ore DROP CONSTRAINT IF EXISTS eql_ore_unavailable;
   ALTER DOMAIN eql_v3.query_real_ord_ore ADD CONSTRAINT eql_ore_unavailable
     CHECK (eql_v3_internal.ore_domain_unavailable(VALUE, 'eql_v3.query_real_ord_ore', 'eql_v3.query_real_eq (equality) or eql_v3.query_real_ord (ordering) or eql_v3.query_real_ord_ope (ordering)')) NOT VALID;
 
+  ALTER DOMAIN public.eql_v3_double_ord_ore DROP CONSTRAINT IF EXISTS eql_ore_unavailable;
   ALTER DOMAIN public.eql_v3_double_ord_ore ADD CONSTRAINT eql_ore_unavailable
     CHECK (eql_v3_internal.ore_domain_unavailable(VALUE, 'public.eql_v3_double_ord_ore', 'public.eql_v3_double_eq (equality) or public.eql_v3_double_ord (ordering) or public.eql_v3_double_ord_ope (ordering)')) NOT VALID;
 
+  ALTER DOMAIN eql_v3.query_double_ord_ore DROP CONSTRAINT IF EXISTS eql_ore_unavailable;
   ALTER DOMAIN eql_v3.query_double_ord_ore ADD CONSTRAINT eql_ore_unavailable
     CHECK (eql_v3_internal.ore_domain_unavailable(VALUE, 'eql_v3.query_double_ord_ore', 'eql_v3.query_double_eq (equality) or eql_v3.query_double_ord (ordering) or eql_v3.query_double_ord_ope (ordering)')) NOT VALID;
 
-  RAISE NOTICE 'EQL: ORE operator class absent (creation requires superuser) — 20 ORE-backed domains disabled and will raise on use; use the _ord_ope (ordering) and _eq (equality) domains — and text_match for text pattern match — instead';
+  ALTER DOMAIN public.eql_v3_timestamp_naive_ord_ore DROP CONSTRAINT IF EXISTS eql_ore_unavailable;
+  ALTER DOMAIN public.eql_v3_timestamp_naive_ord_ore ADD CONSTRAINT eql_ore_unavailable
+    CHECK (eql_v3_internal.ore_domain_unavailable(VALUE, 'public.eql_v3_timestamp_naive_ord_ore', 'public.eql_v3_timestamp_naive_eq (equality) or public.eql_v3_timestamp_naive_ord (ordering) or public.eql_v3_timestamp_naive_ord_ope (ordering)')) NOT VALID;
+
+  ALTER DOMAIN eql_v3.query_timestamp_naive_ord_ore DROP CONSTRAINT IF EXISTS eql_ore_unavailable;
+  ALTER DOMAIN eql_v3.query_timestamp_naive_ord_ore ADD CONSTRAINT eql_ore_unavailable
+    CHECK (eql_v3_internal.ore_domain_unavailable(VALUE, 'eql_v3.query_timestamp_naive_ord_ore', 'eql_v3.query_timestamp_naive_eq (equality) or eql_v3.query_timestamp_naive_ord (ordering) or eql_v3.query_timestamp_naive_ord_ope (ordering)')) NOT VALID;
+
+  ALTER DOMAIN public.eql_v3_time_ord_ore DROP CONSTRAINT IF EXISTS eql_ore_unavailable;
+  ALTER DOMAIN public.eql_v3_time_ord_ore ADD CONSTRAINT eql_ore_unavailable
+    CHECK (eql_v3_internal.ore_domain_unavailable(VALUE, 'public.eql_v3_time_ord_ore', 'public.eql_v3_time_eq (equality) or public.eql_v3_time_ord (ordering) or public.eql_v3_time_ord_ope (ordering)')) NOT VALID;
+
+  ALTER DOMAIN eql_v3.query_time_ord_ore DROP CONSTRAINT IF EXISTS eql_ore_unavailable;
+  ALTER DOMAIN eql_v3.query_time_ord_ore ADD CONSTRAINT eql_ore_unavailable
+    CHECK (eql_v3_internal.ore_domain_unavailable(VALUE, 'eql_v3.query_time_ord_ore', 'eql_v3.query_time_eq (equality) or eql_v3.query_time_ord (ordering) or eql_v3.query_time_ord_ope (ordering)')) NOT VALID;
+
+  ALTER DOMAIN public.eql_v3_interval_ord_ore DROP CONSTRAINT IF EXISTS eql_ore_unavailable;
+  ALTER DOMAIN public.eql_v3_interval_ord_ore ADD CONSTRAINT eql_ore_unavailable
+    CHECK (eql_v3_internal.ore_domain_unavailable(VALUE, 'public.eql_v3_interval_ord_ore', 'public.eql_v3_interval_eq (equality) or public.eql_v3_interval_ord (ordering) or public.eql_v3_interval_ord_ope (ordering)')) NOT VALID;
+
+  ALTER DOMAIN eql_v3.query_interval_ord_ore DROP CONSTRAINT IF EXISTS eql_ore_unavailable;
+  ALTER DOMAIN eql_v3.query_interval_ord_ore ADD CONSTRAINT eql_ore_unavailable
+    CHECK (eql_v3_internal.ore_domain_unavailable(VALUE, 'eql_v3.query_interval_ord_ore', 'eql_v3.query_interval_eq (equality) or eql_v3.query_interval_ord (ordering) or eql_v3.query_interval_ord_ope (ordering)')) NOT VALID;
+
+  RAISE NOTICE 'EQL: ORE operator class absent (creation requires superuser) — 26 ORE-backed domains disabled and will raise on use; use the _ord_ope (ordering) and _eq (equality) domains — and text_match for text pattern match — instead';
 END;
 $do$;
@@ -0,0 +1,43 @@
+-- AUTOMATICALLY GENERATED FILE.
+-- REQUIRE: src/v3/schema.sql
+-- REQUIRE: src/v3/scalars/text_array/query_text_array_types.sql
+-- REQUIRE: src/v3/scalars/text_array/query_text_array_contains_functions.sql
+
+--! @file encrypted_domain/text_array/query_text_array_contains_operators.sql
+--! @brief Operators for eql_v3.query_text_array_contains.
+
+CREATE OPERATOR @> (
+  FUNCTION = eql_v3.contains,
+  LEFTARG = public.eql_v3_text_array_contains, RIGHTARG = eql_v3.query_text_array_contains,
+  COMMUTATOR = <@, RESTRICT = contsel, JOIN = contjoinsel
+);
+
+CREATE OPERATOR @> (
+  FUNCTION = eql_v3.contains,
+  LEFTARG = eql_v3.query_text_array_contains, RIGHTARG = public.eql_v3_text_array_contains,
+  COMMUTATOR = <@, RESTRICT = contsel, JOIN = contjoinsel
+);
+
+CREATE OPERATOR <@ (
+  FUNCTION = eql_v3.contained_by,
+  LEFTARG = public.eql_v3_text_array_contains, RIGHTARG = eql_v3.query_text_array_contains,
+  COMMUTATOR = @>, RESTRICT = contsel, JOIN = contjoinsel
+);
+
+CREATE OPERATOR <@ (
+  FUNCTION = eql_v3.contained_by,
+  LEFTARG = eql_v3.query_text_array_contains, RIGHTARG = public.eql_v3_text_array_contains,
+  COMMUTATOR = @>, RESTRICT = contsel, JOIN = contjoinsel
+);
+
+CREATE OPERATOR && (
+  FUNCTION = eql_v3.overlaps,
+  LEFTARG = public.eql_v3_text_array_contains, RIGHTARG = eql_v3.query_text_array_contains,
+  COMMUTATOR = &&, RESTRICT = contsel, JOIN = contjoinsel
+);
+
+CREATE OPERATOR && (
+  FUNCTION = eql_v3.overlaps,
+  LEFTARG = eql_v3.query_text_array_contains, RIGHTARG = public.eql_v3_text_array_contains,
+  COMMUTATOR = &&, RESTRICT = contsel, JOIN = contjoinsel
+);
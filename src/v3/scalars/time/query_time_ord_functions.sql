@@ -0,0 +1,111 @@
+-- AUTOMATICALLY GENERATED FILE.
+-- REQUIRE: src/v3/schema.sql
+-- REQUIRE: src/v3/scalars/time/query_time_types.sql
+-- REQUIRE: src/v3/scalars/time/time_ord_functions.sql
+
+--! @file encrypted_domain/time/query_time_ord_functions.sql
+--! @brief Functions for eql_v3.query_time_ord.
+
+--! @brief Index extractor for eql_v3.query_time_ord.
+--! @param a eql_v3.query_time_ord
+--! @return eql_v3_internal.ope_cllw
+CREATE FUNCTION eql_v3.ord_term(a eql_v3.query_time_ord)
+RETURNS eql_v3_internal.ope_cllw
+LANGUAGE sql IMMUTABLE STRICT PARALLEL SAFE
+AS $$ SELECT eql_v3_internal.ope_cllw(a::jsonb) $$;
+
+--! @brief Operator wrapper for eql_v3.query_time_ord.
+--! @param a public.eql_v3_time_ord
+--! @param b eql_v3.query_time_ord
+--! @return boolean
+CREATE FUNCTION eql_v3.eq(a public.eql_v3_time_ord, b eql_v3.query_time_ord)
+RETURNS boolean LANGUAGE sql IMMUTABLE STRICT PARALLEL SAFE
+AS $$ SELECT eql_v3.ord_term(a) = eql_v3.ord_term(b) $$;
+
+--! @brief Operator wrapper for eql_v3.query_time_ord.
+--! @param a eql_v3.query_time_ord
+--! @param b public.eql_v3_time_ord
+--! @return boolean
+CREATE FUNCTION eql_v3.eq(a eql_v3.query_time_ord, b public.eql_v3_time_ord)
+RETURNS boolean LANGUAGE sql IMMUTABLE STRICT PARALLEL SAFE
+AS $$ SELECT eql_v3.ord_term(a) = eql_v3.ord_term(b) $$;
+
+--! @brief Operator wrapper for eql_v3.query_time_ord.
+--! @param a public.eql_v3_time_ord
+--! @param b eql_v3.query_time_ord
+--! @return boolean
+CREATE FUNCTION eql_v3.neq(a public.eql_v3_time_ord, b eql_v3.query_time_ord)
+RETURNS boolean LANGUAGE sql IMMUTABLE STRICT PARALLEL SAFE
+AS $$ SELECT eql_v3.ord_term(a) <> eql_v3.ord_term(b) $$;
+
+--! @brief Operator wrapper for eql_v3.query_time_ord.
+--! @param a eql_v3.query_time_ord
+--! @param b public.eql_v3_time_ord
+--! @return boolean
+CREATE FUNCTION eql_v3.neq(a eql_v3.query_time_ord, b public.eql_v3_time_ord)
+RETURNS boolean LANGUAGE sql IMMUTABLE STRICT PARALLEL SAFE
+AS $$ SELECT eql_v3.ord_term(a) <> eql_v3.ord_term(b) $$;
+
+--! @brief Operator wrapper for eql_v3.query_time_ord.
+--! @param a public.eql_v3_time_ord
+--! @param b eql_v3.query_time_ord
+--! @return boolean
+CREATE FUNCTION eql_v3.lt(a public.eql_v3_time_ord, b eql_v3.query_time_ord)
+RETURNS boolean LANGUAGE sql IMMUTABLE STRICT PARALLEL SAFE
+AS $$ SELECT eql_v3.ord_term(a) < eql_v3.ord_term(b) $$;
+
+--! @brief Operator wrapper for eql_v3.query_time_ord.
+--! @param a eql_v3.query_time_ord
+--! @param b public.eql_v3_time_ord
+--! @return boolean
+CREATE FUNCTION eql_v3.lt(a eql_v3.query_time_ord, b public.eql_v3_time_ord)
+RETURNS boolean LANGUAGE sql IMMUTABLE STRICT PARALLEL SAFE
+AS $$ SELECT eql_v3.ord_term(a) < eql_v3.ord_term(b) $$;
+
+--! @brief Operator wrapper for eql_v3.query_time_ord.
+--! @param a public.eql_v3_time_ord
+--! @param b eql_v3.query_time_ord
+--! @return boolean
+CREATE FUNCTION eql_v3.lte(a public.eql_v3_time_ord, b eql_v3.query_time_ord)
+RETURNS boolean LANGUAGE sql IMMUTABLE STRICT PARALLEL SAFE
+AS $$ SELECT eql_v3.ord_term(a) <= eql_v3.ord_term(b) $$;
+
+--! @brief Operator wrapper for eql_v3.query_time_ord.
+--! @param a eql_v3.query_time_ord
+--! @param b public.eql_v3_time_ord
+--! @return boolean
+CREATE FUNCTION eql_v3.lte(a eql_v3.query_time_ord, b public.eql_v3_time_ord)
+RETURNS boolean LANGUAGE sql IMMUTABLE STRICT PARALLEL SAFE
+AS $$ SELECT eql_v3.ord_term(a) <= eql_v3.ord_term(b) $$;
+
+--! @brief Operator wrapper for eql_v3.query_time_ord.
+--! @param a public.eql_v3_time_ord
+--! @param b eql_v3.query_time_ord
+--! @return boolean
+CREATE FUNCTION eql_v3.gt(a public.eql_v3_time_ord, b eql_v3.query_time_ord)
+RETURNS boolean LANGUAGE sql IMMUTABLE STRICT PARALLEL SAFE
+AS $$ SELECT eql_v3.ord_term(a) > eql_v3.ord_term(b) $$;
+
+--! @brief Operator wrapper for eql_v3.query_time_ord.
+--! @param a eql_v3.query_time_ord
+--! @param b public.eql_v3_time_ord
+--! @return boolean
+CREATE FUNCTION eql_v3.gt(a eql_v3.query_time_ord, b public.eql_v3_time_ord)
+RETURNS boolean LANGUAGE sql IMMUTABLE STRICT PARALLEL SAFE
+AS $$ SELECT eql_v3.ord_term(a) > eql_v3.ord_term(b) $$;
+
+--! @brief Operator wrapper for eql_v3.query_time_ord.
+--! @param a public.eql_v3_time_ord
+--! @param b eql_v3.query_time_ord
+--! @return boolean
+CREATE FUNCTION eql_v3.gte(a public.eql_v3_time_ord, b eql_v3.query_time_ord)
+RETURNS boolean LANGUAGE sql IMMUTABLE STRICT PARALLEL SAFE
+AS $$ SELECT eql_v3.ord_term(a) >= eql_v3.ord_term(b) $$;
+
+--! @brief Operator wrapper for eql_v3.query_time_ord.
+--! @param a eql_v3.query_time_ord
+--! @param b public.eql_v3_time_ord
+--! @return boolean
+CREATE FUNCTION eql_v3.gte(a eql_v3.query_time_ord, b public.eql_v3_time_ord)
+RETURNS boolean LANGUAGE sql IMMUTABLE STRICT PARALLEL SAFE
+AS $$ SELECT eql_v3.ord_term(a) >= eql_v3.ord_term(b) $$;
@@ -34,6 +34,25 @@
 --!       reaches an internal object indirectly (a public operator/aggregate
 --!       whose backing state-fn/blocker lives there), grant it deliberately.
 
+--! @brief Record the indexes the drop below is about to take
+--! @note The CASCADE drops every functional index built on an eql_v3
+--!       extractor. When the installed release provides
+--!       eql_v3.snapshot_indexes() (src/v3/scalars/index_snapshot.sql), their
+--!       definitions are saved to public.eql_v3_index_snapshot first, so
+--!       `SELECT eql_v3.replay_indexes() \gexec` can re-create them.
+DO $$
+DECLARE
+  recorded integer;
+BEGIN
+  IF pg_catalog.to_regprocedure('eql_v3.snapshot_indexes()') IS NOT NULL THEN
+    recorded := eql_v3.snapshot_indexes();
+    IF recorded > 0 THEN
+      RAISE NOTICE 'EQL: recorded % index(es) on eql_v3 extractors in public.eql_v3_index_snapshot; after install, re-create any that were dropped with: SELECT eql_v3.replay_indexes() \gexec', recorded;
+    END IF;
+  END IF;
+END;
+$$;
+
 --! @brief Drop existing EQL v3 schema
 --! @warning CASCADE will drop all dependent objects
 DROP SCHEMA IF EXISTS eql_v3 CASCADE;
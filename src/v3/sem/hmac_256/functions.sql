@@ -27,6 +27,32 @@ AS $$
 $$;
 
 
+--! @brief Extract the per-element HMAC-SHA256 index terms from an array payload
+--!
+--! The array-shaped domains (`eql_v3_integer_array_contains`, ...) carry one
+--! HMAC per element under `hm`. Unlike the scalar extractor above this is NOT
+--! inlinable: the `ARRAY(SELECT ...)` sublink, and a `CASE` body under
+--! `STRICT`, each keep the planner from folding it in. A functional GIN index
+--! on `eql_v3.eq_terms(col)` still engages for `@>` / `<@` / `&&`, because
+--! `eq_terms` reduces to the same opaque `hmac_256_array(col::jsonb)` call in
+--! the index expression and in the query. Returns NULL when `hm` is absent or
+--! not a json array rather than raising inside `jsonb_array_elements_text`; an
+--! empty `hm` array yields an empty set (contains nothing, contained by
+--! everything).
+--!
+--! @param val jsonb containing an encrypted array payload
+--! @return eql_v3_internal.hmac_256[] One term per element, or NULL when `hm` is absent or not an array
+CREATE FUNCTION eql_v3_internal.hmac_256_array(val jsonb)
+  RETURNS eql_v3_internal.hmac_256[]
+  LANGUAGE sql
+  IMMUTABLE STRICT PARALLEL SAFE
+AS $$
+  SELECT CASE WHEN jsonb_typeof(val -> 'hm') = 'array'
+    THEN ARRAY(SELECT jsonb_array_elements_text(val -> 'hm'))::eql_v3_internal.hmac_256[]
+  END
+$$;
+
+
 --! @brief Check if JSONB payload contains HMAC-SHA256 index term
 --!
 --! @param val jsonb containing encrypted EQL payload
@@ -25,6 +25,16 @@
 
 DO $do$
 BEGIN
+  -- Already present: an in-place upgrade keeps the existing class (and every
+  -- index built on it) rather than failing on the duplicate family.
+  IF EXISTS (
+    SELECT 1 FROM pg_catalog.pg_opclass
+    WHERE opcname = 'ore_block_256_operator_class'
+      AND opcnamespace = 'eql_v3_internal'::regnamespace
+  ) THEN
+    RETURN;
+  END IF;
+
   EXECUTE 'CREATE OPERATOR FAMILY eql_v3_internal.ore_block_256_operator_family USING btree';
 
   EXECUTE $ddl$
@@ -4,6 +4,8 @@
 #MISE sources=["src/v3/**/*.sql", "src/v3/version.template", "tasks/pin_search_path_v3.sql", "tasks/uninstall-v3.sql", "crates/eql-domains/src/**/*.rs", "crates/eql-codegen/src/**/*.rs", "Cargo.toml", "Cargo.lock", "crates/eql-codegen/Cargo.toml", "crates/eql-domains/Cargo.toml", "tasks/build/ordering.sh", "tasks/test/verify_symbol_order_v3.sh", "tasks/test/verify_installer_complete.sh", "tasks/test/symbol_order_allowlist.txt"]
 #MISE outputs=["release/cipherstash-encrypt.sql","release/cipherstash-encrypt-uninstall.sql","src/deps-ordered-v3.txt"]
 #USAGE flag "--version <version>" help="Specify release version of EQL" default="DEV"
+#USAGE flag "--upgrade-from <version>" help="Also emit an in-place upgrade script from this installed release"
+#USAGE flag "--upgrade-from-catalog <path>" help="dump-catalog JSON of the --upgrade-from release, to drop extractors whose return type changed"
 
 #!/bin/bash
 
@@ -21,7 +23,7 @@ set -euo pipefail
 source tasks/build/ordering.sh
 
 # A failed `eql-codegen order` leaves its temp behind; don't strand it.
-trap 'rm -f src/deps-ordered-v3.txt.tmp' EXIT
+trap 'rm -f src/deps-ordered-v3.txt.tmp release/cipherstash-encrypt-upgrade.sql.tmp' EXIT
 
 # Regenerate encrypted-domain SQL from the Rust catalog before building.
 # The generated files (src/v3/scalars/<T>/<T>_*.sql) are COMMITTED in place and
@@ -47,6 +49,7 @@ mkdir -p release
 
 rm -f release/cipherstash-encrypt.sql
 rm -f release/cipherstash-encrypt-uninstall.sql
+rm -f release/cipherstash-encrypt-upgrade.sql
 rm -f src/deps-ordered-v3.txt src/deps-ordered-v3.txt.tmp
 rm -f src/v3/version.sql
 
@@ -97,6 +100,17 @@ bash tasks/test/verify_installer_complete.sh src/deps-ordered-v3.txt release/cip
 
 cat tasks/uninstall-v3.sql >> release/cipherstash-encrypt-uninstall.sql
 
+# Optional in-place upgrade. Unlike the installer it never drops the eql_v3
+# schema: functions are CREATE OR REPLACE'd so their OIDs — and every functional
+# index built on an extractor — survive. The script refuses to run against any
+# installed version other than --upgrade-from. Like version.sql above, it is
+# rendered from the surface on disk, so it must follow `eql-codegen order`.
+if [ -n "${usage_upgrade_from:-}" ]; then
+  cargo run -q -p eql-codegen -- upgrade "$usage_upgrade_from" ${usage_upgrade_from_catalog:+"$usage_upgrade_from_catalog"} \
+    > release/cipherstash-encrypt-upgrade.sql.tmp
+  mv release/cipherstash-encrypt-upgrade.sql.tmp release/cipherstash-encrypt-upgrade.sql
+fi
+
 
 echo
 echo '###############################################'
@@ -108,3 +122,8 @@ echo '    release/cipherstash-encrypt.sql'
 echo
 echo 'Uninstaller:'
 echo '    release/cipherstash-encrypt-uninstall.sql'
+if [ -n "${usage_upgrade_from:-}" ]; then
+  echo
+  echo "Upgrade (from $usage_upgrade_from):"
+  echo '    release/cipherstash-encrypt-upgrade.sql'
+fi
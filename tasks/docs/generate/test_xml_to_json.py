@@ -41,6 +41,16 @@ CATALOG_JSON = """{
         ] }
     ]}
   ],
+  "arrays": [
+    { "token": "text_array", "is_eq_only": false, "domains": [
+      { "segment": "storage", "suffix": "", "typname": "eql_v3_text_array",
+        "supported_ops": [], "terms": [] },
+      { "segment": "contains", "suffix": "_contains",
+        "typname": "eql_v3_text_array_contains",
+        "supported_ops": ["@>", "<@", "&&"],
+        "terms": [{"key": "hm", "extractor": "eq_terms", "ctor": "hmac_256_array"}] }
+    ]}
+  ],
   "stevec": [
     { "full_name": "json", "typname": "eql_v3_json", "name": "",
       "terms": [], "scalar": true },
@@ -136,6 +146,16 @@ def test_load_domains():
     assert by_name["public.eql_v3_text_match"]["capabilities"] == ["match"]
     # text_search carries all three capabilities, derived from its operators.
     assert by_name["public.eql_v3_text_search"]["capabilities"] == ["equality", "order", "match"]
+    # Array families come from the `arrays` section and are tagged as such; the
+    # per-element `eq_terms` extractor backs containment and overlap.
+    contains = by_name["public.eql_v3_text_array_contains"]
+    assert contains["type"] == "text_array"
+    assert contains["variant"] == "contains"
+    assert contains["capabilities"] == ["containment"]
+    assert contains["termFunctions"] == ["eql_v3.eq_terms"]
+    assert contains["shape"] == "array"
+    assert by_name["public.eql_v3_text_array"]["capabilities"] == ["storage"]
+    assert "shape" not in by_name["public.eql_v3_text_eq"]
     # json-family domains come from the `stevec` section. Term extractors are
     # hardcoded on `json_entry` (the sv element type) ONLY — op -> ord_term; the
     # `json_search` document and `query_json` needle carry none.
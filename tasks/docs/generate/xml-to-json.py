@@ -86,9 +86,10 @@ def _capabilities_from_ops(ops):
     # silently lost `match` from its capability list.
     if "@@" in ops:
         caps.append("match")
-    # Containment on encrypted JSON keeps `@>` / `<@`; it is a distinct
-    # capability from text fuzzy match, not a spelling of it.
-    if any(o in ops for o in ("@>", "<@")):
+    # Containment on encrypted JSON and arrays keeps `@>` / `<@` (arrays add
+    # `&&`); it is a distinct capability from text fuzzy match, not a spelling
+    # of it.
+    if any(o in ops for o in ("@>", "<@", "&&")):
         caps.append("containment")
     return caps or ["storage"]
 
@@ -107,13 +108,17 @@ def load_domains(catalog_path: Path) -> list:
     catalog = json.loads(catalog_path.read_text())
     domains = []
 
-    # Scalar families: capability + operators + extractor functions.
-    for type_entry in catalog.get("types", []):
+    # Scalar and array families: capability + operators + extractor functions.
+    # Array families dump under their own key, so their domains are tagged with
+    # `shape: "array"` (an encrypted `integer[]` is not an encrypted `integer`).
+    families = [(t, None) for t in catalog.get("types", [])]
+    families += [(t, "array") for t in catalog.get("arrays", [])]
+    for type_entry, shape in families:
         token = type_entry["token"]
         for dom in type_entry["domains"]:
             suffix = dom.get("suffix", "")
             ops = dom.get("supported_ops", [])
-            domains.append({
+            domain = {
                 # v3 user domains live in the `public` schema (public-domain
                 # migration on eql_v3); the dump's `typname` is the installed
                 # unqualified name, carrying the eql_v3_ version prefix
@@ -125,7 +130,10 @@ def load_domains(catalog_path: Path) -> list:
                 "capabilities": _capabilities_from_ops(ops),
                 "supportedOperators": ops,
                 "termFunctions": _term_functions(dom.get("terms", [])),
-            })
+            }
+            if shape:
+                domain["shape"] = shape
+            domains.append(domain)
 
     # The json family: hand-written SQL, catalog inventory only. The column-type
     # domains (`eql_v3_json`, `eql_v3_json_search`, `eql_v3_json_entry`) live in
@@ -1,3 +1,14 @@
+-- Record the functional indexes on eql_v3 extractors before the CASCADE below
+-- drops them (public.eql_v3_index_snapshot survives the uninstall); after the
+-- next install, `SELECT eql_v3.replay_indexes() \gexec` re-creates them.
+DO $$
+BEGIN
+  IF pg_catalog.to_regprocedure('eql_v3.snapshot_indexes()') IS NOT NULL THEN
+    PERFORM eql_v3.snapshot_indexes();
+  END IF;
+END;
+$$;
+
 -- Uninstall the standalone eql_v3 surface. CASCADE removes the domains, SEM
 -- types, operators, opclass, and any columns typed with the eql_v3 domains.
 DROP SCHEMA IF EXISTS eql_v3 CASCADE;
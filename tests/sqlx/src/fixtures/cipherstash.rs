@@ -25,6 +25,12 @@
 //! index names (`"unique"`, `"ore"`, …) and the typed `IndexType` enum
 //! cipherstash-config uses. Unknown names raise immediately so a typo at
 //! spec construction fails fast.
+//!
+//! With the `fake-keys` feature every public helper here short-circuits to
+//! the sibling [`fake_keys`](super::fake_keys) module instead of building a
+//! cipher: same payload shapes and term semantics, no ZeroKMS and no `CS_*`
+//! credentials. The `ColumnConfig` is still built first, so a fixture whose
+//! index set the real client would reject fails the same way offline.
 
 use std::borrow::Cow;
 use std::sync::Arc;
@@ -41,6 +47,7 @@ use cipherstash_client::zerokms::{EnvKeyProvider, RecordWithNonce, ZeroKMSBuilde
 use cipherstash_client::AutoStrategy;
 
 use super::eql_plaintext::{Cast, EqlPlaintext};
+use super::fake_keys;
 use super::index_kind::IndexKind;
 
 /// Build a fresh `ScopedCipher`. Performs `AutoStrategy::detect()`, the
@@ -188,6 +195,10 @@ pub async fn encrypt_store<T: EqlPlaintext>(
         return Ok(Vec::new());
     }
 
+    if cfg!(feature = "fake-keys") {
+        return fake_keys::encrypt_store(table, column, values, indexes);
+    }
+
     let cipher = build_cipher().await?;
 
     // `Identifier::new` does two `String` allocations per call — cheap
@@ -313,6 +324,9 @@ fn query_payload_str(payload: &serde_json::Value, key: &str) -> Result<String> {
 /// field if the keyset or the document shape changes, which is exactly how
 /// `SEL_HELLO_OP` came to point at `$.number`.
 pub async fn ste_vec_query_selector(table: &str, column: &str, path: &str) -> Result<String> {
+    if cfg!(feature = "fake-keys") {
+        return Ok(fake_keys::ste_vec_selector(path));
+    }
     let payload =
         encrypt_ste_vec_query(table, column, &path.to_owned(), QueryOp::SteVecSelector).await?;
     // The v3 selector query payload is the bare tokenized-selector hex string.
@@ -340,6 +354,9 @@ pub async fn ste_vec_query_term<T: EqlPlaintext>(
     column: &str,
     value: &T,
 ) -> Result<String> {
+    if cfg!(feature = "fake-keys") {
+        return fake_keys::ste_vec_term(&value.to_plaintext());
+    }
     let payload = encrypt_ste_vec_query(table, column, value, QueryOp::SteVecTerm).await?;
     query_payload_str(&payload, "op")
 }
@@ -361,6 +378,9 @@ pub async fn ste_vec_query_value_selector(
     path: &str,
     value: &serde_json::Value,
 ) -> Result<serde_json::Value> {
+    if cfg!(feature = "fake-keys") {
+        return Ok(fake_keys::ste_vec_value_needle(path, value));
+    }
     let input = serde_json::json!({ "path": path, "value": value });
     let payload =
         encrypt_ste_vec_query(table, column, &input, QueryOp::SteVecValueSelector).await?;
@@ -397,6 +417,10 @@ pub async fn ste_vec_query_value_selector(
 pub async fn decrypt_ste_vec_entries_fallible(
     entries: &[serde_json::Value],
 ) -> Result<Vec<std::result::Result<Vec<u8>, String>>> {
+    if cfg!(feature = "fake-keys") {
+        return fake_keys::decrypt_ste_vec_entries_fallible(entries);
+    }
+
     fn record(entry: &serde_json::Value) -> Result<RecordWithNonce> {
         let obj = entry
             .as_object()
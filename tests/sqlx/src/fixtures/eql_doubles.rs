@@ -33,6 +33,9 @@ pub const DOUBLES_TOKENS: &[&str] = &[
     "timestamp",
     "numeric",
     "text",
+    "timestamp_naive",
+    "time",
+    "interval",
 ];
 
 /// How many distinct plaintexts to double. Small on purpose — the test only
@@ -89,6 +92,9 @@ pub async fn generate(token: &str) -> Result<()> {
         "timestamp" => generate_doubles_for::<chrono::DateTime<chrono::Utc>>().await,
         "numeric" => generate_doubles_for::<rust_decimal::Decimal>().await,
         "text" => generate_doubles_for::<String>().await,
+        "timestamp_naive" => generate_doubles_for::<chrono::NaiveDateTime>().await,
+        "time" => generate_doubles_for::<chrono::NaiveTime>().await,
+        "interval" => generate_doubles_for::<crate::scalar_domains::Interval>().await,
         other => anyhow::bail!(
             "no doubles generator wired for token '{other}'; add it to \
              fixtures::eql_doubles (DOUBLES_TOKENS + the generate dispatch)"
@@ -64,12 +64,20 @@ impl PlaintextSqlType {
     // oracle column must stay `timestamp with time zone` so sqlx binds
     // `DateTime<Utc>` correctly.
     pub const TIMESTAMPTZ: PlaintextSqlType = PlaintextSqlType("timestamp with time zone");
+    // The zone-less native type, for `timestamp_naive`. Its oracle column holds
+    // the same wall-clock reading that was encrypted, so it stays faithful under
+    // any session `TimeZone`: no value ever crosses a `timestamptz` boundary.
+    pub const TIMESTAMP: PlaintextSqlType = PlaintextSqlType("timestamp without time zone");
     pub const TEXT: PlaintextSqlType = PlaintextSqlType("text");
     pub const JSONB: PlaintextSqlType = PlaintextSqlType("jsonb");
     pub const NUMERIC: PlaintextSqlType = PlaintextSqlType("numeric");
     pub const BOOLEAN: PlaintextSqlType = PlaintextSqlType("boolean");
     pub const REAL: PlaintextSqlType = PlaintextSqlType("real");
     pub const DOUBLE_PRECISION: PlaintextSqlType = PlaintextSqlType("double precision");
+    pub const UUID: PlaintextSqlType = PlaintextSqlType("uuid");
+    pub const TIME: PlaintextSqlType = PlaintextSqlType("time without time zone");
+    pub const INTERVAL: PlaintextSqlType = PlaintextSqlType("interval");
+    pub const BYTEA: PlaintextSqlType = PlaintextSqlType("bytea");
 
     /// `const` so `ScalarType::PLAINTEXT_SQL_TYPE` impls can derive their
     /// `&'static str` from this newtype in a const initializer.
@@ -86,7 +94,7 @@ impl fmt::Display for PlaintextSqlType {
 
 /// The EQL `cast_as` for a scalar kind, drawn from the `Cast` allowlist.
 ///
-/// Only the wired kinds (the integer kinds, `Text`, plus `Date` / `Timestamp`)
+/// Only the wired kinds (the integer kinds, `Text`, plus the temporal kinds)
 /// have `EqlPlaintext` impls, so only those resolve; the remaining kinds mirror the
 /// `eql_domains` accessor convention and `panic!`, since no impl can ever reach
 /// them.
@@ -97,11 +105,26 @@ const fn cast_for_kind(kind: ScalarKind) -> Cast {
         ScalarKind::I64 => Cast::BIG_INT,
         ScalarKind::Date => Cast::DATE,
         ScalarKind::Timestamp => Cast::TIMESTAMP,
+        // cipherstash-client has no zone-less timestamp: a naive reading is
+        // encrypted as the instant with the same fields at offset zero, which
+        // preserves both equality and ordering of the wall-clock values.
+        ScalarKind::NaiveTimestamp => Cast::TIMESTAMP,
         ScalarKind::Text => Cast::TEXT,
         ScalarKind::Numeric => Cast::DECIMAL,
         ScalarKind::Bool => Cast::BOOLEAN,
         ScalarKind::F32 => Cast::REAL,
         ScalarKind::F64 => Cast::DOUBLE,
+        // cipherstash-client has no uuid column type: a uuid is encrypted as
+        // its canonical lowercase-hyphenated string under the `text` cast.
+        ScalarKind::Uuid => Cast::TEXT,
+        // No time-of-day or interval column type either: both are encrypted
+        // as a signed microsecond count (since midnight / canonical span), whose
+        // integer order is exactly the Postgres order of the plaintext.
+        ScalarKind::Time => Cast::BIG_INT,
+        ScalarKind::Interval => Cast::BIG_INT,
+        // Nor a binary one: bytes are encrypted as their lowercase hex text.
+        // Hex is injective, so `hm` over it is still an exact equality key.
+        ScalarKind::Bytes => Cast::TEXT,
         ScalarKind::Jsonb => {
             panic!("EqlPlaintext is only implemented for the wired scalar kinds")
         }
@@ -118,11 +141,16 @@ const fn plaintext_sql_type_for_kind(kind: ScalarKind) -> PlaintextSqlType {
         ScalarKind::I64 => PlaintextSqlType::BIGINT,
         ScalarKind::Date => PlaintextSqlType::DATE,
         ScalarKind::Timestamp => PlaintextSqlType::TIMESTAMPTZ,
+        ScalarKind::NaiveTimestamp => PlaintextSqlType::TIMESTAMP,
         ScalarKind::Text => PlaintextSqlType::TEXT,
         ScalarKind::Numeric => PlaintextSqlType::NUMERIC,
         ScalarKind::Bool => PlaintextSqlType::BOOLEAN,
         ScalarKind::F32 => PlaintextSqlType::REAL,
         ScalarKind::F64 => PlaintextSqlType::DOUBLE_PRECISION,
+        ScalarKind::Uuid => PlaintextSqlType::UUID,
+        ScalarKind::Time => PlaintextSqlType::TIME,
+        ScalarKind::Interval => PlaintextSqlType::INTERVAL,
+        ScalarKind::Bytes => PlaintextSqlType::BYTEA,
         ScalarKind::Jsonb => {
             panic!("EqlPlaintext is only implemented for the wired scalar kinds")
         }
@@ -136,12 +164,17 @@ mod sealed {
     impl Sealed for i64 {}
     impl Sealed for chrono::NaiveDate {}
     impl Sealed for chrono::DateTime<chrono::Utc> {}
+    impl Sealed for chrono::NaiveDateTime {}
     impl Sealed for String {}
     impl Sealed for serde_json::Value {}
     impl Sealed for rust_decimal::Decimal {}
     impl Sealed for bool {}
     impl Sealed for crate::scalar_domains::F4 {}
     impl Sealed for crate::scalar_domains::F8 {}
+    impl Sealed for uuid::Uuid {}
+    impl Sealed for chrono::NaiveTime {}
+    impl Sealed for crate::scalar_domains::Interval {}
+    impl Sealed for crate::scalar_domains::Bytes {}
 }
 
 /// A Rust type usable as a fixture `plaintext` value, carrying its EQL cast
@@ -209,6 +242,16 @@ impl EqlPlaintext for chrono::DateTime<chrono::Utc> {
     }
 }
 
+impl EqlPlaintext for chrono::NaiveDateTime {
+    const KIND: ScalarKind = ScalarKind::NaiveTimestamp;
+
+    /// The wall-clock fields are taken verbatim as a UTC instant — never
+    /// converted from a local zone — so the reading round-trips unchanged.
+    fn to_plaintext(&self) -> Plaintext {
+        Plaintext::Timestamp(Some(self.and_utc()))
+    }
+}
+
 impl EqlPlaintext for String {
     const KIND: ScalarKind = ScalarKind::Text;
 
@@ -271,6 +314,52 @@ impl EqlPlaintext for crate::scalar_domains::F8 {
     }
 }
 
+impl EqlPlaintext for uuid::Uuid {
+    const KIND: ScalarKind = ScalarKind::Uuid;
+
+    /// Always the canonical lowercase-hyphenated rendering — the `hm` term is
+    /// over the string bytes, so any other spelling would break equality.
+    fn to_plaintext(&self) -> Plaintext {
+        Plaintext::Text(Some(self.hyphenated().to_string()))
+    }
+}
+
+impl EqlPlaintext for chrono::NaiveTime {
+    const KIND: ScalarKind = ScalarKind::Time;
+
+    /// Microseconds since midnight — the resolution Postgres `time` stores.
+    fn to_plaintext(&self) -> Plaintext {
+        use chrono::Timelike;
+        let micros = i64::from(self.num_seconds_from_midnight()) * 1_000_000
+            + i64::from(self.nanosecond() / 1_000);
+        Plaintext::BigInt(Some(micros))
+    }
+}
+
+impl EqlPlaintext for crate::scalar_domains::Interval {
+    const KIND: ScalarKind = ScalarKind::Interval;
+
+    /// The canonical span (see [`Interval::span`]), so `'1 mon'` and
+    /// `'30 days'` encrypt to the same value, as they compare in Postgres.
+    ///
+    /// [`Interval::span`]: crate::scalar_domains::Interval::span
+    fn to_plaintext(&self) -> Plaintext {
+        let span = i64::try_from(self.span())
+            .expect("interval span must fit the i64 microseconds it is encrypted as");
+        Plaintext::BigInt(Some(span))
+    }
+}
+
+impl EqlPlaintext for crate::scalar_domains::Bytes {
+    const KIND: ScalarKind = ScalarKind::Bytes;
+
+    /// Lowercase hex, two digits per byte. Any other rendering (uppercase,
+    /// base64, a `\x` prefix) would hash differently for the same bytes.
+    fn to_plaintext(&self) -> Plaintext {
+        Plaintext::Text(Some(self.to_hex()))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -394,6 +483,36 @@ mod tests {
         }
     }
 
+    #[test]
+    fn naive_datetime_casts_to_timestamp() {
+        assert_eq!(
+            <chrono::NaiveDateTime as EqlPlaintext>::CAST,
+            Cast::TIMESTAMP
+        );
+    }
+
+    #[test]
+    fn naive_datetime_plaintext_sql_type_is_timestamp_without_time_zone() {
+        assert_eq!(
+            <chrono::NaiveDateTime as EqlPlaintext>::PLAINTEXT_SQL_TYPE.as_str(),
+            "timestamp without time zone"
+        );
+    }
+
+    #[test]
+    fn naive_datetime_to_plaintext_keeps_wall_clock_fields() {
+        // 02:30 on the US spring-forward date does not exist in New York; the
+        // reading must still encrypt with its fields untouched.
+        let ts = chrono::NaiveDate::from_ymd_opt(2021, 3, 14)
+            .unwrap()
+            .and_hms_opt(2, 30, 0)
+            .unwrap();
+        match ts.to_plaintext() {
+            Plaintext::Timestamp(Some(value)) => assert_eq!(value.naive_utc(), ts),
+            other => panic!("expected Plaintext::Timestamp(Some(_)), got {other:?}"),
+        }
+    }
+
     #[test]
     fn string_cast_is_text() {
         assert_eq!(<String as EqlPlaintext>::CAST, Cast::TEXT);
@@ -513,4 +632,109 @@ mod tests {
             other => panic!("expected Plaintext::Float(Some(1.5)), got {other:?}"),
         }
     }
+
+    #[test]
+    fn uuid_casts_to_text() {
+        assert_eq!(<uuid::Uuid as EqlPlaintext>::CAST, Cast::TEXT);
+    }
+
+    #[test]
+    fn uuid_plaintext_sql_type_is_uuid() {
+        assert_eq!(
+            <uuid::Uuid as EqlPlaintext>::PLAINTEXT_SQL_TYPE,
+            PlaintextSqlType::UUID
+        );
+    }
+
+    #[test]
+    fn uuid_to_plaintext_is_canonical_text() {
+        // Parsed from an uppercase, braced spelling: the plaintext must still
+        // be the canonical lowercase-hyphenated form the fixtures carry.
+        let u = uuid::Uuid::parse_str("{F47AC10B-58CC-4372-A567-0E02B2C3D479}").unwrap();
+        match u.to_plaintext() {
+            Plaintext::Text(Some(ref s)) => {
+                assert_eq!(s, "f47ac10b-58cc-4372-a567-0e02b2c3d479")
+            }
+            other => panic!("expected Plaintext::Text(Some(_)), got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn naive_time_casts_to_big_int() {
+        assert_eq!(<chrono::NaiveTime as EqlPlaintext>::CAST, Cast::BIG_INT);
+    }
+
+    #[test]
+    fn naive_time_plaintext_sql_type_is_time_without_time_zone() {
+        assert_eq!(
+            <chrono::NaiveTime as EqlPlaintext>::PLAINTEXT_SQL_TYPE,
+            PlaintextSqlType::TIME
+        );
+    }
+
+    #[test]
+    fn naive_time_to_plaintext_is_micros_since_midnight() {
+        let t = chrono::NaiveTime::from_hms_micro_opt(1, 0, 0, 7).unwrap();
+        match t.to_plaintext() {
+            Plaintext::BigInt(Some(v)) => assert_eq!(v, 3_600_000_007),
+            other => panic!("expected Plaintext::BigInt(Some(_)), got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn interval_casts_to_big_int() {
+        use crate::scalar_domains::Interval;
+        assert_eq!(<Interval as EqlPlaintext>::CAST, Cast::BIG_INT);
+    }
+
+    #[test]
+    fn interval_plaintext_sql_type_is_interval() {
+        use crate::scalar_domains::Interval;
+        assert_eq!(
+            <Interval as EqlPlaintext>::PLAINTEXT_SQL_TYPE,
+            PlaintextSqlType::INTERVAL
+        );
+    }
+
+    #[test]
+    fn interval_to_plaintext_is_the_canonical_span() {
+        use crate::scalar_domains::Interval;
+        // One month and thirty days are the same span, so the same plaintext.
+        for s in ["P1M", "P30D"] {
+            match Interval::parse_iso8601(s).unwrap().to_plaintext() {
+                Plaintext::BigInt(Some(v)) => assert_eq!(v, 30 * 86_400_000_000, "{s}"),
+                other => panic!("expected Plaintext::BigInt(Some(_)), got {other:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn bytea_casts_to_text() {
+        use crate::scalar_domains::Bytes;
+        assert_eq!(<Bytes as EqlPlaintext>::CAST, Cast::TEXT);
+    }
+
+    #[test]
+    fn bytea_plaintext_sql_type_is_bytea() {
+        use crate::scalar_domains::Bytes;
+        assert_eq!(
+            <Bytes as EqlPlaintext>::PLAINTEXT_SQL_TYPE,
+            PlaintextSqlType::BYTEA
+        );
+    }
+
+    #[test]
+    fn bytea_to_plaintext_is_lowercase_hex() {
+        use crate::scalar_domains::Bytes;
+        // Decoded from uppercase: the plaintext is still the canonical form.
+        let b = Bytes::from_hex("DEADBEEF").unwrap();
+        match b.to_plaintext() {
+            Plaintext::Text(Some(ref s)) => assert_eq!(s, "deadbeef"),
+            other => panic!("expected Plaintext::Text(Some(_)), got {other:?}"),
+        }
+        match Bytes::default().to_plaintext() {
+            Plaintext::Text(Some(ref s)) => assert_eq!(s, ""),
+            other => panic!("expected Plaintext::Text(Some(_)), got {other:?}"),
+        }
+    }
 }
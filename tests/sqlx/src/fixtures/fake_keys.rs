@@ -0,0 +1,766 @@
+//! Offline fake-keys encryptor — a credential-free stand-in for the ZeroKMS
+//! oracle in the sibling `cipherstash` module.
+//!
+//! Enabled by the `fake-keys` cargo feature: `cipherstash::encrypt_store` and
+//! the SteVec query/decrypt helpers route here instead of building a
+//! `ScopedCipher`, so `FixtureSpec::run()`, `mise run fixture:generate:all`
+//! and the e2e property oracles run with no `CS_*` credentials and no network.
+//!
+//! Nothing here is secret or secure. Every key derives from the public
+//! [`ROOT_KEY`] and the "ciphertexts" are keyed-MAC-authenticated XOR streams.
+//! What the module does guarantee is the part the SQL surface can observe:
+//!
+//! - **Shape.** Scalar payloads are `{v, i, c, hm?, ob?, op?, bf?}` and
+//!   documents are `{v, k: "sv", i, h, sv}`, exactly the keys the real client's
+//!   v3 assembler emits for the same index set, so the domain CHECKs and the
+//!   strict `eql-bindings` types accept them.
+//! - **Equality.** `hm`, `op` and the `ob` left halves are deterministic, so
+//!   equal plaintexts compare equal across independent encryptions. `c`, the
+//!   `ob` right halves and the document `h` are fresh per encryption, as they
+//!   are for real ciphertext.
+//! - **Order.** `op` is an order-preserving byte string (native bytea order),
+//!   and `ob` is a genuine N-block ORE term built for the comparator in
+//!   `src/v3/sem/ore_block_256/functions.sql` — same wire layout, same AES
+//!   indicator bit — so `ord_term_ore` orders it without a special case.
+//! - **Match.** `bf` is a 2048-bit, 6-hash bloom filter over downcased
+//!   character trigrams; a string shorter than three characters has an empty
+//!   filter, as with the real `match` index defaults.
+//!
+//! The encodings follow the real client where a test pins its behaviour:
+//! `op` and `hm` keep the sign of a float zero while `ob` canonicalizes it
+//! (issue #387, see `known_failure`), scale-equivalent decimals collide, and
+//! JSON numbers order as `f64`. Selectors are keyed by [`STE_VEC_PREFIX`] and
+//! the path only, so a fake selector is as stable as a real one — but it is a
+//! different hex string, which is why the pinned selector constants
+//! (`v3_ste_vec::SEL_HELLO_OP`, `v3_doc_integer::SELECTOR`) have a
+//! `fake-keys` value of their own.
+
+use std::collections::BTreeSet;
+use std::hash::{BuildHasher, Hasher};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::OnceLock;
+
+use aes::cipher::{BlockEncrypt, KeyInit};
+use aes::{Aes128, Block};
+use anyhow::{anyhow, Context, Result};
+use chrono::Datelike;
+use cipherstash_client::encryption::Plaintext;
+use hmac::{Hmac, Mac};
+use serde_json::{json, Map, Value};
+use sha2::Sha256;
+
+use super::cipherstash::STE_VEC_PREFIX;
+use super::eql_plaintext::EqlPlaintext;
+use super::index_kind::IndexKind;
+
+/// The root of every fake key. Public on purpose: anything encrypted here is
+/// test data, and a fixed root keeps `hm`, `op` and selectors stable across
+/// runs and machines.
+const ROOT_KEY: &[u8] = b"eql fake-keys root key -- not a secret";
+
+/// Bloom filter width in bits and hash count — the `match` index defaults.
+const BLOOM_BITS: u16 = 2048;
+const BLOOM_HASHES: usize = 6;
+
+/// ORE block width of one text term. Text is split into 8-byte chunks, one
+/// 8-block term each, so `compare_ore_block_256_terms` compares the chunk
+/// arrays lexicographically.
+const TEXT_ORE_CHUNK: usize = 8;
+
+/// What a SteVec value entry's `c` decrypts to. The leading `0xff` is not a
+/// valid `Plaintext` serialization header, so the sentinel can never be read
+/// back as a genuine leaf value (least of all an empty string).
+const VALUE_ENTRY_SENTINEL: &[u8] = b"\xffeql-fake-value-entry";
+
+/// The RFC 1924 base85 alphabet, in digit order.
+const BASE85_ALPHABET: &[u8; 85] =
+    b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz!#$%&()*+-;<=>?@^_`{|}~";
+
+/// Domain tags separating JSON numbers from strings inside a SteVec `op`.
+const OPE_TAG_NUMBER: u8 = 0x01;
+const OPE_TAG_STRING: u8 = 0x02;
+
+/// HMAC-SHA-256 under [`ROOT_KEY`] over a label and length-prefixed parts, so
+/// no two `(label, parts)` lists share an input.
+fn mac(label: &str, parts: &[&[u8]]) -> [u8; 32] {
+    let mut mac =
+        <Hmac<Sha256> as Mac>::new_from_slice(ROOT_KEY).expect("HMAC accepts any key length");
+    for part in std::iter::once(label.as_bytes()).chain(parts.iter().copied()) {
+        mac.update(&(part.len() as u64).to_be_bytes());
+        mac.update(part);
+    }
+    mac.finalize().into_bytes().into()
+}
+
+/// A fresh 16-byte nonce: a MAC over a per-process random seed and a counter.
+/// Gives `c`, the `ob` right halves and `h` the per-encryption variation real
+/// ciphertext has, without a RNG dependency.
+fn fresh_nonce() -> [u8; 16] {
+    static SEED: OnceLock<u64> = OnceLock::new();
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let seed = *SEED.get_or_init(|| {
+        std::collections::hash_map::RandomState::new()
+            .build_hasher()
+            .finish()
+    });
+    let count = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let digest = mac("nonce", &[&seed.to_be_bytes(), &count.to_be_bytes()]);
+    digest[..16].try_into().expect("16 of 32 bytes")
+}
+
+/// Encrypt `plaintext` under `key_material`, bound to `aad`: a 16-byte tag,
+/// then the plaintext XORed with a MAC keystream.
+fn seal(key_material: &[u8], aad: &[u8], plaintext: &[u8]) -> Vec<u8> {
+    let tag = mac("tag", &[key_material, aad, plaintext]);
+    let mut out = tag[..16].to_vec();
+    out.extend(xor_keystream(key_material, aad, plaintext));
+    out
+}
+
+/// Invert [`seal`], failing when the tag does not authenticate — a ciphertext
+/// moved to another selector or document does not open.
+fn open(key_material: &[u8], aad: &[u8], sealed: &[u8]) -> Result<Vec<u8>> {
+    anyhow::ensure!(sealed.len() >= 16, "ciphertext shorter than its tag");
+    let (tag, body) = sealed.split_at(16);
+    let plaintext = xor_keystream(key_material, aad, body);
+    anyhow::ensure!(
+        mac("tag", &[key_material, aad, &plaintext])[..16] == *tag,
+        "ciphertext does not authenticate under this key header and selector"
+    );
+    Ok(plaintext)
+}
+
+fn xor_keystream(key_material: &[u8], aad: &[u8], data: &[u8]) -> Vec<u8> {
+    data.chunks(32)
+        .enumerate()
+        .flat_map(|(i, chunk)| {
+            let block = mac("stream", &[key_material, aad, &(i as u64).to_be_bytes()]);
+            chunk
+                .iter()
+                .zip(block)
+                .map(|(b, k)| b ^ k)
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+/// Big-endian two's complement with the sign bit flipped: unsigned byte order
+/// is signed integer order.
+fn int_key(value: i64) -> Vec<u8> {
+    ((value as u64) ^ (1 << 63)).to_be_bytes().to_vec()
+}
+
+/// The IEEE 754 total order as unsigned bytes. `canonical_zero` folds `-0.0`
+/// into `+0.0` first (what block-ORE does); without it the two zeroes stay
+/// distinct, matching `f64::total_cmp`.
+fn float_key(value: f64, canonical_zero: bool) -> Vec<u8> {
+    let value = if canonical_zero && value == 0.0 {
+        0.0
+    } else {
+        value
+    };
+    let bits = value.to_bits();
+    let ordered = if bits >> 63 == 1 {
+        !bits
+    } else {
+        bits ^ (1 << 63)
+    };
+    ordered.to_be_bytes().to_vec()
+}
+
+/// A 14-byte order-preserving decimal key: one byte of sign and decimal
+/// exponent, then the normalized digits left-aligned to 29 places in 13 bytes
+/// (inverted for negatives). Normalizing first makes `1` and `1.0` collide.
+fn decimal_key(value: rust_decimal::Decimal) -> Vec<u8> {
+    let value = value.normalize();
+    if value.is_zero() {
+        return [0x80].into_iter().chain([0; 13]).collect();
+    }
+    let mantissa = value.mantissa().unsigned_abs();
+    let digits = mantissa.to_string().len() as u32;
+    let exponent = digits as i32 - value.scale() as i32;
+    let aligned = mantissa * 10u128.pow(29 - digits);
+    let digit_bytes = &aligned.to_be_bytes()[3..];
+    let biased = (exponent + 64) as u8;
+    if value.is_sign_negative() {
+        std::iter::once(0x7f - biased)
+            .chain(digit_bytes.iter().map(|b| !b))
+            .collect()
+    } else {
+        std::iter::once(0x80 + biased)
+            .chain(digit_bytes.iter().copied())
+            .collect()
+    }
+}
+
+/// The order-preserving bytes of a scalar plaintext. Byte-wise comparison of
+/// two keys of one kind is the plaintext order. `canonical_zero` is passed to
+/// [`float_key`].
+fn order_key(plaintext: &Plaintext, canonical_zero: bool) -> Result<Vec<u8>> {
+    Ok(match plaintext {
+        Plaintext::Int(Some(v)) => int_key(i64::from(*v)),
+        Plaintext::SmallInt(Some(v)) => int_key(i64::from(*v)),
+        Plaintext::BigInt(Some(v)) => int_key(*v),
+        Plaintext::NaiveDate(Some(v)) => int_key(i64::from(v.num_days_from_ce())),
+        Plaintext::Timestamp(Some(v)) => {
+            let mut key = int_key(v.timestamp());
+            key.extend(v.timestamp_subsec_nanos().to_be_bytes());
+            key
+        }
+        Plaintext::Decimal(Some(v)) => decimal_key(*v),
+        Plaintext::Float(Some(v)) => float_key(*v, canonical_zero),
+        Plaintext::Text(Some(v)) => v.as_bytes().to_vec(),
+        Plaintext::Boolean(Some(v)) => vec![u8::from(*v)],
+        other => return Err(anyhow!("fake-keys has no scalar encoding for {other:?}")),
+    })
+}
+
+/// One ORE term over `key`, one block per byte, laid out as the SQL
+/// comparator reads it:
+///
+/// `[ N PRP bytes ][ N*16B left blocks ][ 16B nonce ][ N*32B right blocks ]`
+///
+/// The PRP is the identity, so the PRP byte of block `i` is `key[i]`, and the
+/// left block is a MAC of the prefix through `key[i]`. Bit `j` of right block
+/// `i` is `(j > key[i])` XOR the low bit of `AES(nonce, left_i(prefix, j))` —
+/// exactly the indicator `compare_ore_block_256_term` recomputes from the
+/// other term's left block.
+fn ore_term(key: &[u8]) -> Vec<u8> {
+    let left_block = |prefix: &[u8], byte: u8| -> [u8; 16] {
+        mac("ore-left", &[prefix, &[byte]])[..16]
+            .try_into()
+            .expect("16 of 32 bytes")
+    };
+    let nonce = fresh_nonce();
+    let cipher = Aes128::new(&Block::from(nonce));
+
+    let mut term = key.to_vec();
+    for i in 0..key.len() {
+        term.extend(left_block(&key[..i], key[i]));
+    }
+    term.extend(nonce);
+    for i in 0..key.len() {
+        let mut right = [0u8; 32];
+        for j in 0..=255u8 {
+            let mut block = Block::from(left_block(&key[..i], j));
+            cipher.encrypt_block(&mut block);
+            let bit = u8::from(j > key[i]) ^ (block[0] & 1);
+            right[usize::from(j / 8)] |= bit << (j % 8);
+        }
+        term.extend(right);
+    }
+    term
+}
+
+/// The `ob` array: one term over the whole key, except text, whose key is
+/// chunked so arbitrarily long strings order lexicographically. The empty
+/// string has no chunks and so encrypts to `ob: []`, like the real client.
+fn ore_terms(plaintext: &Plaintext) -> Result<Vec<String>> {
+    let key = order_key(plaintext, true)?;
+    let terms = match plaintext {
+        Plaintext::Text(_) => key
+            .chunks(TEXT_ORE_CHUNK)
+            .map(|chunk| {
+                let mut padded = chunk.to_vec();
+                padded.resize(TEXT_ORE_CHUNK, 0);
+                ore_term(&padded)
+            })
+            .collect(),
+        _ => vec![ore_term(&key)],
+    };
+    Ok(terms.iter().map(hex::encode).collect())
+}
+
+/// The `op` term: a zero domain-tag byte then the order key, so `""` is the
+/// bare `"00"` and sorts before every non-empty string.
+fn ope_term(plaintext: &Plaintext) -> Result<String> {
+    let mut bytes = vec![0x00];
+    bytes.extend(order_key(plaintext, false)?);
+    Ok(hex::encode(bytes))
+}
+
+/// The `hm` term: a MAC of the same bytes `op` encodes, so `=` over `hm` and
+/// over `op` agree — including on the float zero sign.
+fn hmac_term(plaintext: &Plaintext) -> Result<String> {
+    Ok(hex::encode(mac("hm", &[&order_key(plaintext, false)?])))
+}
+
+/// The `bf` term: bit positions set by each distinct downcased trigram.
+fn bloom_term(plaintext: &Plaintext) -> Result<Vec<i16>> {
+    let Plaintext::Text(Some(text)) = plaintext else {
+        return Err(anyhow!("the match index needs text, got {plaintext:?}"));
+    };
+    let chars: Vec<char> = text.to_lowercase().chars().collect();
+    let trigrams: BTreeSet<String> = chars.windows(3).map(|w| w.iter().collect()).collect();
+    let mut bits = BTreeSet::new();
+    for trigram in &trigrams {
+        let digest = mac("bf", &[trigram.as_bytes()]);
+        for pair in digest.chunks(2).take(BLOOM_HASHES) {
+            bits.insert((u16::from_be_bytes([pair[0], pair[1]]) % BLOOM_BITS) as i16);
+        }
+    }
+    Ok(bits.into_iter().collect())
+}
+
+/// The wire form of `c` and `h`: RFC 1924 base85 of one MessagePack `bin`
+/// value (mp_base85), so fake payloads pass `parse_strict_encoding` like real
+/// ones. A final partial group is zero-padded and truncated to `len + 1`
+/// digits.
+fn mp_base85(bytes: &[u8]) -> String {
+    let mut packed = match (u8::try_from(bytes.len()), u16::try_from(bytes.len())) {
+        (Ok(len), _) => vec![0xc4, len],
+        (_, Ok(len)) => [&[0xc5][..], &len.to_be_bytes()].concat(),
+        _ => {
+            let len = u32::try_from(bytes.len()).expect("fake ciphertexts are small");
+            [&[0xc6][..], &len.to_be_bytes()].concat()
+        }
+    };
+    packed.extend_from_slice(bytes);
+    let mut text = String::with_capacity(packed.len().div_ceil(4) * 5);
+    for chunk in packed.chunks(4) {
+        let mut word = [0u8; 4];
+        word[..chunk.len()].copy_from_slice(chunk);
+        let mut word = u32::from_be_bytes(word);
+        let mut digits = [0u8; 5];
+        for digit in digits.iter_mut().rev() {
+            *digit = BASE85_ALPHABET[(word % 85) as usize];
+            word /= 85;
+        }
+        text.extend(digits[..=chunk.len()].iter().map(|&d| char::from(d)));
+    }
+    text
+}
+
+/// The bytes [`mp_base85`] wrapped. A missing final digit decodes as the
+/// highest one, which undoes the zero padding.
+fn from_mp_base85(text: &str) -> Result<Vec<u8>> {
+    let mut packed = Vec::with_capacity(text.len() / 5 * 4 + 3);
+    for group in text.as_bytes().chunks(5) {
+        if group.len() == 1 {
+            return Err(anyhow!("{text:?} ends in a lone base85 digit"));
+        }
+        let mut word: u64 = 0;
+        for i in 0..5 {
+            let digit = match group.get(i) {
+                Some(c) => BASE85_ALPHABET
+                    .iter()
+                    .position(|d| d == c)
+                    .ok_or_else(|| anyhow!("{text:?} is not base85"))?,
+                None => 84,
+            };
+            word = word * 85 + digit as u64;
+        }
+        let word = u32::try_from(word).map_err(|_| anyhow!("{text:?} is not base85"))?;
+        packed.extend_from_slice(&word.to_be_bytes()[..group.len() - 1]);
+    }
+    let (header, len) = match packed.as_slice() {
+        [0xc4, len, ..] => (2, usize::from(*len)),
+        [0xc5, a, b, ..] => (3, usize::from(u16::from_be_bytes([*a, *b]))),
+        [0xc6, a, b, c, d, ..] => (5, u32::from_be_bytes([*a, *b, *c, *d]) as usize),
+        _ => return Err(anyhow!("{text:?} does not hold a MessagePack bin value")),
+    };
+    if packed.len() != header + len {
+        return Err(anyhow!(
+            "{text:?} does not hold exactly one MessagePack bin value"
+        ));
+    }
+    Ok(packed.split_off(header))
+}
+
+/// A v3 scalar storage payload carrying one term per requested index.
+fn encrypt_scalar(
+    table: &str,
+    column: &str,
+    plaintext: &Plaintext,
+    indexes: &[IndexKind],
+) -> Result<Value> {
+    let nonce = fresh_nonce();
+    let mut ciphertext = nonce.to_vec();
+    ciphertext.extend(seal(&nonce, b"", &plaintext.to_vec()));
+
+    let mut payload = Map::new();
+    payload.insert("v".into(), json!(3));
+    payload.insert("i".into(), json!({ "t": table, "c": column }));
+    payload.insert("c".into(), json!(mp_base85(&ciphertext)));
+    for index in indexes {
+        let (key, term) = match index {
+            IndexKind::Unique => ("hm", json!(hmac_term(plaintext)?)),
+            IndexKind::Ore => ("ob", json!(ore_terms(plaintext)?)),
+            IndexKind::Ope => ("op", json!(ope_term(plaintext)?)),
+            IndexKind::Match => ("bf", json!(bloom_term(plaintext)?)),
+            IndexKind::SteVec => unreachable!("documents are encrypted by encrypt_document"),
+        };
+        payload.insert(key.into(), term);
+    }
+    Ok(Value::Object(payload))
+}
+
+/// The path selector for a JSONPath (`$`, `$.hello`, `$.list[*]`).
+pub fn ste_vec_selector(path: &str) -> String {
+    hex::encode(&mac("selector", &[STE_VEC_PREFIX.as_bytes(), path.as_bytes()])[..16])
+}
+
+/// The value-inclusive selector of a leaf: path, JSON type and canonical
+/// value. Numbers canonicalize through `Decimal` where they fit, so `2` and
+/// `2.0` share a selector while `9007199254740993` and `9007199254740992` do
+/// not.
+fn value_selector(path: &str, value: &Value) -> String {
+    let (kind, canonical) = match value {
+        Value::Null => ("null", String::new()),
+        Value::Bool(b) => ("bool", b.to_string()),
+        Value::Number(n) => (
+            "number",
+            n.to_string()
+                .parse::<rust_decimal::Decimal>()
+                .map(|d| d.normalize().to_string())
+                .unwrap_or_else(|_| n.to_string()),
+        ),
+        Value::String(s) => ("string", s.clone()),
+        Value::Array(_) | Value::Object(_) => unreachable!("only leaves carry value selectors"),
+    };
+    hex::encode(
+        &mac(
+            "value-selector",
+            &[
+                STE_VEC_PREFIX.as_bytes(),
+                path.as_bytes(),
+                kind.as_bytes(),
+                canonical.as_bytes(),
+            ],
+        )[..16],
+    )
+}
+
+/// The `op` of an ordered SteVec leaf. Numbers order as `f64` (lossy above
+/// 2^53, as the real term is); strings by their UTF-8 bytes.
+fn ste_vec_leaf_term(value: &Value) -> Option<String> {
+    let bytes = match value {
+        Value::Number(n) => std::iter::once(OPE_TAG_NUMBER)
+            .chain(float_key(n.as_f64()?, true))
+            .collect::<Vec<_>>(),
+        Value::String(s) => std::iter::once(OPE_TAG_STRING).chain(s.bytes()).collect(),
+        _ => return None,
+    };
+    Some(hex::encode(bytes))
+}
+
+/// The `op` a SteVec query operand carries for a scalar plaintext. Only
+/// numbers and strings have one.
+pub fn ste_vec_term(plaintext: &Plaintext) -> Result<String> {
+    let value = match plaintext {
+        Plaintext::Int(Some(v)) => json!(v),
+        Plaintext::SmallInt(Some(v)) => json!(v),
+        Plaintext::BigInt(Some(v)) => json!(v),
+        Plaintext::Float(Some(v)) => json!(v),
+        Plaintext::Text(Some(v)) => json!(v),
+        other => {
+            return Err(anyhow!(
+                "a SteVec term needs a number or a string, got {other:?}"
+            ))
+        }
+    };
+    ste_vec_leaf_term(&value).ok_or_else(|| anyhow!("{value} has no SteVec ordering term"))
+}
+
+/// The `query_json` needle for an exact value at a path.
+pub fn ste_vec_value_needle(path: &str, value: &Value) -> Value {
+    json!({ "sv": [{ "s": value_selector(path, value) }] })
+}
+
+/// Append the entries for `node` at `path`: a path entry (with `op` for a
+/// number or string), plus a value entry for a leaf, then the children.
+fn push_entries(entries: &mut Vec<Value>, header: &[u8], path: &str, node: &Value, in_array: bool) {
+    let mut push = |selector: String, plaintext: &[u8], op: Option<String>| {
+        let mut entry = Map::new();
+        entry.insert(
+            "c".into(),
+            json!(mp_base85(&seal(header, selector.as_bytes(), plaintext))),
+        );
+        entry.insert("s".into(), json!(selector));
+        if in_array {
+            entry.insert("a".into(), json!(true));
+        }
+        if let Some(op) = op {
+            entry.insert("op".into(), json!(op));
+        }
+        entries.push(Value::Object(entry));
+    };
+    push(
+        ste_vec_selector(path),
+        &Plaintext::Json(Some(node.clone())).to_vec(),
+        ste_vec_leaf_term(node),
+    );
+    match node {
+        Value::Object(fields) => {
+            for (key, child) in fields {
+                push_entries(entries, header, &format!("{path}.{key}"), child, false);
+            }
+        }
+        Value::Array(items) => {
+            for child in items {
+                push_entries(entries, header, &format!("{path}[*]"), child, true);
+            }
+        }
+        leaf => push(value_selector(path, leaf), VALUE_ENTRY_SENTINEL, None),
+    }
+}
+
+/// A v3 SteVec document: the key header `h` once, then one entry per path and
+/// per leaf value, every entry sealed under `h` and bound to its selector.
+fn encrypt_document(table: &str, column: &str, plaintext: &Plaintext) -> Result<Value> {
+    let Plaintext::Json(Some(document)) = plaintext else {
+        return Err(anyhow!(
+            "the ste_vec index needs a JSON document, got {plaintext:?}"
+        ));
+    };
+    let header = fresh_nonce();
+    let mut entries = Vec::new();
+    push_entries(&mut entries, &header, "$", document, false);
+    Ok(json!({
+        "v": 3,
+        "k": "sv",
+        "i": { "t": table, "c": column },
+        "h": mp_base85(&header),
+        "sv": entries,
+    }))
+}
+
+/// Fake-keys counterpart of `cipherstash::encrypt_store`: one v3 storage
+/// payload per value, a SteVec document when `indexes` holds
+/// [`IndexKind::SteVec`].
+pub fn encrypt_store<T: EqlPlaintext>(
+    table: &str,
+    column: &str,
+    values: &[T],
+    indexes: &[IndexKind],
+) -> Result<Vec<Value>> {
+    values
+        .iter()
+        .map(|value| {
+            let plaintext = value.to_plaintext();
+            if indexes.contains(&IndexKind::SteVec) {
+                encrypt_document(table, column, &plaintext)
+            } else {
+                encrypt_scalar(table, column, &plaintext, indexes)
+            }
+        })
+        .collect::<Result<_>>()
+        .with_context(|| format!("fake-keys encryption for {table}.{column}"))
+}
+
+/// Fake-keys counterpart of `cipherstash::decrypt_ste_vec_entries_fallible`:
+/// opens each SQL-extracted `{h, s, c, …}` entry, keeping authentication
+/// failures per item.
+pub fn decrypt_ste_vec_entries_fallible(
+    entries: &[Value],
+) -> Result<Vec<std::result::Result<Vec<u8>, String>>> {
+    entries
+        .iter()
+        .map(|entry| {
+            let field = |key: &str| {
+                entry.get(key).and_then(Value::as_str).ok_or_else(|| {
+                    anyhow!("extracted SteVec entry has no string `{key}`; got {entry}")
+                })
+            };
+            let header = from_mp_base85(field("h")?).context("decoding the entry key header")?;
+            let sealed = from_mp_base85(field("c")?).context("decoding the entry ciphertext")?;
+            Ok(open(&header, field("s")?.as_bytes(), &sealed).map_err(|error| error.to_string()))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Mirror of `compare_ore_block_256_term`: first differing block, then the
+    /// AES indicator bit from the right half of `b`.
+    fn ore_compare(a: &[u8], b: &[u8]) -> std::cmp::Ordering {
+        let n = (a.len() - 16) / 49;
+        let Some(k) = (0..n).find(|&i| {
+            a[i] != b[i] || a[n + 16 * i..n + 16 * (i + 1)] != b[n + 16 * i..n + 16 * (i + 1)]
+        }) else {
+            return std::cmp::Ordering::Equal;
+        };
+        let nonce = &b[17 * n..17 * n + 16];
+        let mut block =
+            Block::from(<[u8; 16]>::try_from(&a[n + 16 * k..n + 16 * (k + 1)]).unwrap());
+        Aes128::new_from_slice(nonce)
+            .unwrap()
+            .encrypt_block(&mut block);
+        let right = &b[17 * n + 16 + 32 * k..17 * n + 16 + 32 * (k + 1)];
+        let j = usize::from(a[k]);
+        if (block[0] & 1) ^ ((right[j / 8] >> (j % 8)) & 1) == 1 {
+            std::cmp::Ordering::Greater
+        } else {
+            std::cmp::Ordering::Less
+        }
+    }
+
+    #[test]
+    fn ore_terms_order_like_the_plaintexts() {
+        let values = [i64::MIN, -300, -1, 0, 1, 255, 256, 70_000, i64::MAX];
+        // Two independent encryptions per value, so equal plaintexts are
+        // compared across different right-half nonces.
+        let encrypt = || values.map(|v| ore_term(&int_key(v)));
+        let (left, right) = (encrypt(), encrypt());
+        for (a, ta) in values.iter().zip(&left) {
+            assert_eq!(ta.len(), 49 * 8 + 16);
+            for (b, tb) in values.iter().zip(&right) {
+                assert_eq!(ore_compare(ta, tb), a.cmp(b), "{a} vs {b}");
+            }
+        }
+    }
+
+    #[test]
+    fn decimal_keys_order_like_decimals_and_collide_on_scale() {
+        let values: Vec<rust_decimal::Decimal> = [
+            "-79228162514264337593543950335",
+            "-12.5",
+            "-1",
+            "-0.001",
+            "0",
+            "0.0000000000000000000000000001",
+            "0.5",
+            "1",
+            "1.0",
+            "10",
+            "79228162514264337593543950335",
+        ]
+        .iter()
+        .map(|s| s.parse().unwrap())
+        .collect();
+        for a in &values {
+            let ka = decimal_key(*a);
+            assert_eq!(ka.len(), 14);
+            for b in &values {
+                assert_eq!(ka.cmp(&decimal_key(*b)), a.cmp(b), "{a} vs {b}");
+            }
+        }
+    }
+
+    #[test]
+    fn float_keys_follow_total_order_unless_zero_is_canonical() {
+        let values = [
+            f64::NEG_INFINITY,
+            -2.5,
+            -0.0,
+            0.0,
+            1e-300,
+            3.0,
+            f64::INFINITY,
+        ];
+        for a in values {
+            for b in values {
+                assert_eq!(
+                    float_key(a, false).cmp(&float_key(b, false)),
+                    a.total_cmp(&b)
+                );
+            }
+        }
+        assert_ne!(float_key(-0.0, false), float_key(0.0, false));
+        assert_eq!(float_key(-0.0, true), float_key(0.0, true));
+    }
+
+    #[test]
+    fn bloom_contains_the_trigrams_of_a_substring() {
+        let bloom = |s: &str| bloom_term(&Plaintext::Text(Some(s.into()))).unwrap();
+        let haystack: BTreeSet<i16> = bloom("Aardvark").into_iter().collect();
+        assert!(bloom("dvar").iter().all(|bit| haystack.contains(bit)));
+        assert!(
+            bloom("pq").is_empty(),
+            "a sub-trigram string has an empty filter"
+        );
+        assert!(bloom("aardvark").iter().all(|bit| (0..2048).contains(bit)));
+    }
+
+    #[cfg(feature = "fake-keys")]
+    #[test]
+    fn pinned_selectors_match_the_fake_keyset() {
+        assert_eq!(
+            ste_vec_selector("$.hello"),
+            super::super::v3_ste_vec::SEL_HELLO_OP
+        );
+        assert_eq!(
+            ste_vec_selector("$.field"),
+            super::super::v3_doc_integer::SELECTOR
+        );
+    }
+
+    #[test]
+    fn mp_base85_round_trips_every_bin_width() {
+        for len in [0, 1, 3, 4, 16, 255, 256, 65_535, 65_536] {
+            let bytes: Vec<u8> = (0..len).map(|i| (i * 7 + 255) as u8).collect();
+            let text = mp_base85(&bytes);
+            assert_eq!(from_mp_base85(&text).unwrap(), bytes, "{len} bytes");
+        }
+        assert!(
+            from_mp_base85("00000").is_err(),
+            "a positive fixint, not bin"
+        );
+        assert!(
+            from_mp_base85(&mp_base85(b"abc")[..4]).is_err(),
+            "truncated"
+        );
+    }
+
+    /// Fake payloads carry the encodings `parse_strict_encoding` checks, for
+    /// every text scalar domain and for a SteVec document.
+    #[test]
+    fn payloads_pass_the_strict_encoding_check() {
+        let value = Plaintext::Text(Some("aardvark".into()));
+        for domain in eql_bindings::v3::all() {
+            let name = domain.domain();
+            if !name.starts_with("eql_v3_text") || name.contains("array") {
+                continue;
+            }
+            let indexes: Vec<IndexKind> = domain
+                .term_json_keys()
+                .unwrap_or_default()
+                .iter()
+                .map(|key| match *key {
+                    "hm" => IndexKind::Unique,
+                    "ob" => IndexKind::Ore,
+                    "op" => IndexKind::Ope,
+                    "bf" => IndexKind::Match,
+                    other => panic!("{name}: unexpected term key {other}"),
+                })
+                .collect();
+            let payload = encrypt_scalar("t", "c", &value, &indexes).unwrap();
+            domain
+                .parse_strict_encoding(&payload)
+                .unwrap_or_else(|e| panic!("{name}: {e}"));
+        }
+
+        let document = Plaintext::Json(Some(json!({ "hello": "world", "n": [1, 2] })));
+        let payload = encrypt_document("t", "c", &document).unwrap();
+        eql_bindings::v3::all()
+            .iter()
+            .find(|domain| domain.domain() == "eql_v3_json_search")
+            .unwrap()
+            .parse_strict_encoding(&payload)
+            .unwrap();
+        let entries: Vec<Value> = payload["sv"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|entry| {
+                let mut entry = entry.clone();
+                entry["h"] = payload["h"].clone();
+                entry
+            })
+            .collect();
+        assert!(decrypt_ste_vec_entries_fallible(&entries)
+            .unwrap()
+            .iter()
+            .all(Result::is_ok));
+    }
+
+    #[test]
+    fn sealed_entries_do_not_open_under_another_selector() {
+        let sealed = seal(b"header", b"selector-a", b"payload");
+        assert_eq!(open(b"header", b"selector-a", &sealed).unwrap(), b"payload");
+        assert!(open(b"header", b"selector-b", &sealed).is_err());
+        assert!(open(b"other", b"selector-a", &sealed).is_err());
+    }
+}
@@ -24,6 +24,10 @@ pub mod scalar_fixture;
 
 pub mod cipherstash;
 
+// Credential-free stand-in for `cipherstash`, selected by the `fake-keys`
+// feature. Always compiled so its term encoders stay unit-tested.
+pub mod fake_keys;
+
 pub mod driver;
 
 // The v2 → v3 envelope conversion seam: generated client payloads (`v: 2`)
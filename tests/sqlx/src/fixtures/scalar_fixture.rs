@@ -14,13 +14,15 @@
 /// property-test module for a scalar fixture.
 ///
 /// The leading **kind** discriminator (`int` / `temporal` / `text` / `numeric` / `float`
-/// / `storage`) selects which property asserts are stamped and which index set
+/// / `interval` / `storage` / `eq`) selects which property asserts are stamped and which index set
 /// the fixture declares — the rest of the expansion is identical:
 ///
 /// - `storage` — storage-only / encryption-only (`bool`): NO index, so the
 ///   payload is `{v,i,c}` with no term key. Asserts both values are present and
 ///   no index is declared (the type is not `OrderedScalar`, so there are no
 ///   comparison pivots to check).
+/// - `eq` — equality-only (`uuid`, `bytea`): indexes `Unique` alone, so the
+///   payload carries `hm` and no ordering term. Asserts the matrix pivots are present.
 ///
 /// - `int` — signed-extreme asserts (`<$ty>::MIN`/`MAX`, `contains(&0)`,
 ///   `any(|v| v < 0)`). These typecheck only for integer plaintexts. Indexes
@@ -206,6 +208,36 @@ macro_rules! scalar_fixture {
         }
     };
 
+    // Interval scalars (the harness `Interval` newtype over `PgInterval`):
+    // ordered, non-chrono. Same shape as `float`; the newtype's `Eq`/`Ord` are
+    // by canonical span, which is what the pivot-presence asserts compare with.
+    (interval, $name:literal, $ty:ty, $values:expr $(,)?) => {
+        $crate::scalar_fixture!(@common $name, $ty, $values, [Unique, Ore, Ope]);
+
+        #[cfg(test)]
+        mod tests {
+            use super::*;
+            use $crate::scalar_domains::OrderedScalar;
+
+            #[test]
+            fn spec_is_complete() {
+                assert!(spec().check_complete().is_ok());
+            }
+
+            #[test]
+            fn spec_includes_pivots() {
+                let spec = spec();
+                let values = spec.values();
+                let min = <$ty as OrderedScalar>::min_pivot();
+                let mid = <$ty as OrderedScalar>::mid_pivot();
+                let max = <$ty as OrderedScalar>::max_pivot();
+                assert!(values.contains(&min), "spec must include min_pivot {min:?}");
+                assert!(values.contains(&mid), "spec must include mid_pivot {mid:?}");
+                assert!(values.contains(&max), "spec must include max_pivot {max:?}");
+            }
+        }
+    };
+
     // Storage-only (encryption-only) scalars (`bool`): the value is encrypted
     // with NO search index, so the payload is `{v,i,c}` with no term key. The
     // fixture declares zero indexes (`.storage_only()`), and the property test
@@ -258,6 +290,44 @@ macro_rules! scalar_fixture {
         }
     };
 
+    // Equality-only scalars (`uuid`, `bytea`): `Unique` alone, so payloads carry `hm`
+    // and no ordering term. The pivots are still asserted present — the
+    // `caps = [eq]` matrix arm fetches each one's ciphertext.
+    (eq, $name:literal, $ty:ty, $values:expr $(,)?) => {
+        $crate::scalar_fixture!(@common $name, $ty, $values, [Unique]);
+
+        #[cfg(test)]
+        mod tests {
+            use super::*;
+            use $crate::scalar_domains::OrderedScalar;
+
+            #[test]
+            fn spec_is_complete() {
+                assert!(spec().check_complete().is_ok());
+            }
+
+            #[test]
+            fn spec_declares_only_unique() {
+                assert_eq!(
+                    spec().indexes(),
+                    &[$crate::fixtures::IndexKind::Unique][..]
+                );
+            }
+
+            #[test]
+            fn spec_includes_pivots() {
+                let spec = spec();
+                let values = spec.values();
+                let min = <$ty as OrderedScalar>::min_pivot();
+                let mid = <$ty as OrderedScalar>::mid_pivot();
+                let max = <$ty as OrderedScalar>::max_pivot();
+                assert!(values.contains(&min), "spec must include min_pivot {min:?}");
+                assert!(values.contains(&mid), "spec must include mid_pivot {mid:?}");
+                assert!(values.contains(&max), "spec must include max_pivot {max:?}");
+            }
+        }
+    };
+
     // Shared expansion: the `spec()` builder + the gated generator test. The
     // trailing `[Unique, Ore, ...]` token list parametrizes the index set.
     (@common $name:literal, $ty:ty, $values:expr, [$($ix:ident),+ $(,)?]) => {
@@ -46,8 +46,13 @@ const FIELD: &str = "field";
 /// derived from the workspace keyset + the fixed STE_VEC_PREFIX + the `field`
 /// path, so it is stable for a given CipherStash workspace; if it drifts,
 /// regenerate the fixture and re-pin from the emitted `"s"`.
+#[cfg(not(feature = "fake-keys"))]
 pub const SELECTOR: &str = "fce8be759db230351b10a058b7ba50a7";
 
+/// The `fake-keys` selector for `$.field` (`fake_keys::ste_vec_selector`).
+#[cfg(feature = "fake-keys")]
+pub const SELECTOR: &str = "d461b78a309d55a9426cafab2b91c327";
+
 /// Build the plaintext documents: `{"field": <value>}` per integer fixture value,
 /// paired with the bare integer oracle value.
 fn documents() -> Vec<(i32, Value)> {
@@ -6,14 +6,12 @@
 //!   (~5 lines), with a `caps` capability marker selecting the shape:
 //!   `caps = [eq, ord]` for an ordered scalar (i32, i64, date, timestamp,
 //!   ...) where all four variants are present and the full
-//!   `=`/`<>`/`<`/`>`/`min`/`max` surface applies; `caps = [eq]` for a
-//!   hypothetical equality-only scalar (e.g. a future hash-only type) where
-//!   only storage + `_eq` materialise and the ord operators are blockers — no
-//!   current type uses this shape. The only other inputs that change per type
-//!   are the scalar
-//!   itself, the suite token (used to derive domain + test names), and the EQL
-//!   type name (the fixture `scripts(...)` ref); pivots are derived from the
-//!   `ScalarType` impl.
+//!   `=`/`<>`/`<`/`>`/`min`/`max` surface applies; `caps = [eq]` for an
+//!   equality-only scalar (`uuid`, `bytea`) where only storage + `_eq`
+//!   materialise and the ord operators are blockers. The only other inputs
+//!   that change per type are the scalar itself, the suite token (used to
+//!   derive domain + test names), and the EQL type name (the fixture
+//!   `scripts(...)` ref); pivots are derived from the `ScalarType` impl.
 //!
 //! - **`scalar_domain_matrix!`** — the lower-level macro the wrapper
 //!   expands to. Use directly only for types with a non-standard surface
@@ -149,12 +147,13 @@ fn collect_index_scan_nodes(value: &serde_json::Value, found: &mut Vec<(String,
 /// - `caps = [eq, ord]` — the ordered-numeric shape (all four variants;
 ///   `=`/`<>`/`<`/`<=`/`>`/`>=`; ORDER BY / ORDER BY USING; ORE injectivity;
 ///   the ordered functional index). Consumers:
-///   `smallint`/`integer`/`bigint`/`date`/`timestamp`/`numeric`.
+///   `smallint`/`integer`/`bigint`/`date`/`timestamp`/`timestamp_naive`/`time`/
+///   `interval`/`numeric`.
 /// - `caps = [eq]` — equality-only (storage + `_eq` only; `=`/`<>` meaningful,
 ///   the four ord operators are deliberate blockers). The empty `ord_domains`
-///   make the order-by / ORE arms emit zero tests. No current consumer —
-///   `timestamp` was promoted to the ordered shape once the N-block ORE
-///   comparator could order its native 12-block width.
+///   make the order-by / ORE arms emit zero tests. Consumers: `uuid`, `bytea`.
+///   (`timestamp` once used this shape; it was promoted to the ordered shape
+///   once the N-block ORE comparator could order its native 12-block width.)
 ///
 /// Both arms take the identical `(suite, scalar, eql_type)` signature, so the
 /// invocation shape is the same regardless of capability — only the `caps`
@@ -33,9 +33,11 @@
 //! - [`ScalarType::PLAINTEXT_SQL_TYPE`] — the **actual Postgres storage type**
 //!   of the cleartext value in the oracle columns. Defaults to `PG_TYPE`.
 //!
-//! For every scalar except `timestamp` the two coincide — `integer`, `date`,
+//! For most scalars the two coincide — `integer`, `date`,
 //! `numeric`, … are each a valid Postgres type equal to their domain token.
-//! `timestamp` is the sole divergence: its domain token is `timestamp` (chosen
+//! `timestamp_naive` diverges trivially (its token is not a Postgres type; the
+//! plaintext is `timestamp without time zone`). `timestamp` is the subtle
+//! divergence: its domain token is `timestamp` (chosen
 //! to match the cipherstash cast / `ColumnType::Timestamp` / `Plaintext::Timestamp`
 //! naming), but the encrypted value is a UTC **instant** — `chrono::DateTime<Utc>`,
 //! the only sub-day temporal `Plaintext` variant cipherstash offers — whose
@@ -72,6 +74,7 @@
 
 use anyhow::{bail, Context, Result};
 use eql_domains::{Term, CATALOG};
+use sqlx::postgres::types::PgInterval;
 use sqlx::PgPool;
 use std::fmt::{Debug, Display};
 
@@ -100,7 +103,8 @@ pub trait ScalarType:
     /// materialise a plaintext column or a typed `NULL` sentinel. Defaults to
     /// `PG_TYPE`, which is a valid plaintext type for every catalog scalar
     /// except `timestamp` (whose domain token is `timestamp` but whose plaintext
-    /// is `timestamp with time zone`, a UTC instant) — the temporal impls
+    /// is `timestamp with time zone`, a UTC instant) and `timestamp_naive`
+    /// (plaintext `timestamp without time zone`) — the temporal impls
     /// override it, deriving the value from `EqlPlaintext` (the same
     /// `ScalarKind`-keyed source the fixture generator uses) so it cannot drift.
     /// Kept on `ScalarType`, not read from `EqlPlaintext` directly, because some
@@ -506,6 +510,22 @@ temporal_values! {
     sql_lit   = |v| format!("'{}'", v.to_rfc3339()),
 }
 
+// `timestamp_naive` reuses the same `temporal_values!` path with a zone-less
+// `NaiveDateTime`. Its fixtures carry no offset, so there is nothing to
+// normalise: the parsed fields are exactly the encrypted wall-clock reading,
+// and the epoch `NaiveDateTime::default()` is both origin and mid pivot.
+temporal_values! {
+    cell      = TIMESTAMP_NAIVE_VALUES_CELL,
+    accessor  = timestamp_naive_values,
+    rust_type = chrono::NaiveDateTime,
+    spec      = eql_domains::TIMESTAMP_NAIVE_FIXTURES,
+    variant   = NaiveTimestamp,
+    pg_type   = "timestamp_naive",
+    parse     = |s| chrono::NaiveDateTime::parse_from_str(s, "%Y-%m-%dT%H:%M:%S")
+        .expect("catalog timestamp_naive fixture must be YYYY-MM-DDTHH:MM:SS"),
+    sql_lit   = |v| format!("'{}'", v.format("%Y-%m-%dT%H:%M:%S")),
+}
+
 /// Focused guards for the timestamp value wiring that the `temporal_values!`
 /// auto-generated tests can't cover, because every catalog fixture is already
 /// `…Z` (UTC). Both tests intentionally live in the harness, not in
@@ -1195,6 +1215,641 @@ mod float_value_guards {
     }
 }
 
+// `uuid` is hand-written (like `text`/`numeric`) and is the first
+// **equality-only** scalar: a storage `eql_v3.uuid` domain plus `uuid_eq`
+// (`hm`), no `_ord`. Values parse the catalog's canonical `Fixture::Uuid`
+// strings through the shared `lazy_values!` materialiser.
+lazy_values! {
+    cell      = UUID_VALUES_CELL,
+    accessor  = uuid_values,
+    rust_type = uuid::Uuid,
+    spec      = eql_domains::UUID_FIXTURES,
+    variant   = Uuid,
+    pg_type   = "uuid",
+    parse     = |f| match f {
+        eql_domains::Fixture::Uuid(s) => uuid::Uuid::parse_str(s)
+            .unwrap_or_else(|e| panic!("invalid uuid catalog fixture {s:?}: {e}")),
+        other => panic!("non-uuid fixture in uuid catalog row: {other:?}"),
+    },
+}
+
+impl ScalarType for uuid::Uuid {
+    const PG_TYPE: &'static str = "uuid";
+
+    fn fixture_values() -> &'static [Self] {
+        uuid_values()
+    }
+
+    /// `Display` is the canonical hyphenated form, which Postgres accepts as a
+    /// quoted `uuid` literal.
+    fn to_sql_literal(value: &Self) -> String {
+        format!("'{value}'")
+    }
+
+    fn arbitrary_value() -> proptest::strategy::BoxedStrategy<Self> {
+        use proptest::strategy::Strategy;
+        proptest::sample::select(uuid_values().to_vec()).boxed()
+    }
+}
+
+impl OrderedScalar for uuid::Uuid {
+    /// The `caps = [eq]` matrix arm still sweeps the three pivots as equality
+    /// anchors; the byte order behind them is never asserted against SQL.
+    /// `Uuid::default()` is the nil uuid — already the `min` pivot — so pick a
+    /// distinct interior fixture instead.
+    fn mid_pivot() -> Self {
+        uuid::Uuid::parse_str("886313e1-3b8a-5372-9b90-0c9aee199e5d").expect("literal uuid")
+    }
+}
+
+// `uuid::Uuid` is deliberately NOT `SignedScalar` / `MatchScalar`: it has no
+// `_ord` domain (so no sign boundary) and no bloom-match capability.
+
+#[cfg(test)]
+mod uuid_value_tests {
+    use super::*;
+
+    /// The harness value list round-trips the catalog strings in order — the
+    /// oracle cannot drift from the catalog the fixture generator encrypts.
+    #[test]
+    fn uuid_values_match_catalog() {
+        let got: Vec<String> = uuid_values().iter().map(|u| u.to_string()).collect();
+        let want: Vec<&str> = eql_domains::UUID_FIXTURES
+            .values
+            .iter()
+            .map(|f| match f {
+                eql_domains::Fixture::Uuid(s) => *s,
+                other => panic!("non-uuid fixture: {other:?}"),
+            })
+            .collect();
+        assert_eq!(got, want);
+    }
+
+    /// The eq matrix fetches each pivot's ciphertext via
+    /// `fetch_fixture_payload`, so all three must be fixture rows, and `mid`
+    /// must differ from both boundaries to be a useful equality anchor.
+    #[test]
+    fn uuid_pivots_are_distinct_fixtures() {
+        let values = uuid_values();
+        let min = <uuid::Uuid as OrderedScalar>::min_pivot();
+        let mid = <uuid::Uuid as OrderedScalar>::mid_pivot();
+        let max = <uuid::Uuid as OrderedScalar>::max_pivot();
+        for p in [min, mid, max] {
+            assert!(values.contains(&p), "pivot {p} must be a fixture");
+        }
+        assert!(min != mid && mid != max, "mid_pivot must be interior");
+    }
+
+    #[test]
+    fn uuid_to_sql_literal_is_quoted_canonical_form() {
+        assert_eq!(
+            <uuid::Uuid as ScalarType>::to_sql_literal(&uuid::Uuid::nil()),
+            "'00000000-0000-0000-0000-000000000000'"
+        );
+    }
+}
+
+/// Harness newtype over `Vec<u8>` for the `bytea` scalar. `Vec<u8>` already
+/// has the `Ord`/`Default` and sqlx `bytea` wiring `ScalarType` needs, but no
+/// `Display`; the newtype adds Postgres' hex output form (`\xdeadbeef`), which
+/// its `bytea` input accepts back. The ordering is plain lexicographic byte
+/// order — only used to pick matrix anchors, never asserted against SQL.
+///
+/// As with `F4`/`F8`, the transparent `sqlx::Type` derive supplies `Decode`
+/// and `Encode` against Postgres `bytea`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, sqlx::Type)]
+#[sqlx(transparent)]
+pub struct Bytes(pub Vec<u8>);
+
+impl Bytes {
+    /// Decode lowercase-or-uppercase hex, two digits per byte, no `\x` prefix —
+    /// the catalog's `Fixture::Bytes` form.
+    pub fn from_hex(s: &str) -> Result<Self> {
+        if !s.bytes().all(|b| b.is_ascii_hexdigit()) {
+            bail!("bytea hex {s:?} contains a non-hex character");
+        }
+        if s.len() % 2 != 0 {
+            bail!("bytea hex {s:?} has an odd number of digits");
+        }
+        (0..s.len())
+            .step_by(2)
+            .map(|i| {
+                u8::from_str_radix(&s[i..i + 2], 16)
+                    .with_context(|| format!("bytea hex {s:?}: bad byte at offset {i}"))
+            })
+            .collect::<Result<Vec<u8>>>()
+            .map(Bytes)
+    }
+
+    /// Lowercase hex, two digits per byte — the text the client encrypts, so
+    /// equal bytes always yield equal `hm` terms.
+    pub fn to_hex(&self) -> String {
+        self.0.iter().map(|b| format!("{b:02x}")).collect()
+    }
+}
+
+impl std::fmt::Display for Bytes {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "\\x{}", self.to_hex())
+    }
+}
+
+// `bytea` is the second equality-only scalar, wired like `uuid`: the catalog's
+// hex `Fixture::Bytes` strings decode through `lazy_values!`.
+lazy_values! {
+    cell      = BYTEA_VALUES_CELL,
+    accessor  = bytea_values,
+    rust_type = Bytes,
+    spec      = eql_domains::BYTEA_FIXTURES,
+    variant   = Bytes,
+    pg_type   = "bytea",
+    parse     = |f| match f {
+        eql_domains::Fixture::Bytes(s) => Bytes::from_hex(s)
+            .unwrap_or_else(|e| panic!("invalid bytea catalog fixture {s:?}: {e:#}")),
+        other => panic!("non-bytes fixture in bytea catalog row: {other:?}"),
+    },
+}
+
+impl ScalarType for Bytes {
+    const PG_TYPE: &'static str = "bytea";
+
+    fn fixture_values() -> &'static [Self] {
+        bytea_values()
+    }
+
+    /// The hex-format `bytea` literal (`'\xdeadbeef'`; `'\x'` is empty), read
+    /// verbatim under `standard_conforming_strings`.
+    fn to_sql_literal(value: &Self) -> String {
+        format!("'{value}'")
+    }
+
+    fn arbitrary_value() -> proptest::strategy::BoxedStrategy<Self> {
+        use proptest::strategy::Strategy;
+        proptest::sample::select(bytea_values().to_vec()).boxed()
+    }
+}
+
+impl OrderedScalar for Bytes {
+    /// As for `uuid`, the pivots are equality anchors only. `Bytes::default()`
+    /// is the empty string — already the `min` pivot — so anchor the middle on
+    /// the SHA-256 of the empty input instead.
+    fn mid_pivot() -> Self {
+        Bytes::from_hex("e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855")
+            .expect("literal digest")
+    }
+}
+
+// `Bytes` is NOT `SignedScalar` / `MatchScalar` either: no `_ord` domain, no
+// bloom-match capability.
+
+#[cfg(test)]
+mod bytea_value_tests {
+    use super::*;
+
+    /// Decoding then re-encoding each catalog string is the identity, so the
+    /// oracle column holds exactly the bytes the generator encrypted.
+    #[test]
+    fn bytea_values_round_trip_catalog_hex() {
+        let got: Vec<String> = bytea_values().iter().map(Bytes::to_hex).collect();
+        let want: Vec<&str> = eql_domains::BYTEA_FIXTURES
+            .values
+            .iter()
+            .map(|f| match f {
+                eql_domains::Fixture::Bytes(s) => *s,
+                other => panic!("non-bytes fixture: {other:?}"),
+            })
+            .collect();
+        assert_eq!(got, want);
+    }
+
+    #[test]
+    fn bytea_pivots_are_distinct_fixtures() {
+        let values = bytea_values();
+        let min = <Bytes as OrderedScalar>::min_pivot();
+        let mid = <Bytes as OrderedScalar>::mid_pivot();
+        let max = <Bytes as OrderedScalar>::max_pivot();
+        for p in [&min, &mid, &max] {
+            assert!(values.contains(p), "pivot {p} must be a fixture");
+        }
+        assert_eq!(min, Bytes::default(), "the empty string sorts first");
+        assert!(min != mid && mid != max, "mid_pivot must be interior");
+    }
+
+    #[test]
+    fn from_hex_rejects_malformed_input() {
+        for bad in ["0", "abc", "zz", "+f", "0x00", "\\x00"] {
+            assert!(Bytes::from_hex(bad).is_err(), "{bad:?} must not decode");
+        }
+        assert_eq!(Bytes::from_hex("00FF").unwrap(), Bytes(vec![0x00, 0xff]));
+    }
+
+    #[test]
+    fn bytea_to_sql_literal_is_hex_format() {
+        assert_eq!(
+            <Bytes as ScalarType>::to_sql_literal(&Bytes::default()),
+            "'\\x'"
+        );
+        assert_eq!(
+            <Bytes as ScalarType>::to_sql_literal(&Bytes(vec![0xde, 0xad])),
+            "'\\xdead'"
+        );
+    }
+}
+
+// `time` is chrono-backed like the other temporal scalars but is not wired
+// through `temporal_values!`: that macro makes `Default` the signed origin and
+// mid pivot, and `NaiveTime::default()` is midnight — the domain's minimum, not
+// an interior value. So `time` is ordered but NOT `SignedScalar`, and its mid
+// pivot is noon.
+lazy_values! {
+    cell      = TIME_VALUES_CELL,
+    accessor  = time_values,
+    rust_type = chrono::NaiveTime,
+    spec      = eql_domains::TIME_FIXTURES,
+    variant   = Time,
+    pg_type   = "time",
+    parse     = |f| match f {
+        eql_domains::Fixture::Time(s) => s
+            .parse()
+            .unwrap_or_else(|e| panic!("invalid time catalog fixture {s:?}: {e}")),
+        other => panic!("non-time fixture in time catalog row: {other:?}"),
+    },
+}
+
+impl ScalarType for chrono::NaiveTime {
+    const PG_TYPE: &'static str = "time";
+    const PLAINTEXT_SQL_TYPE: &'static str =
+        <chrono::NaiveTime as crate::fixtures::EqlPlaintext>::PLAINTEXT_SQL_TYPE.as_str();
+
+    fn fixture_values() -> &'static [Self] {
+        time_values()
+    }
+
+    /// Always six fractional digits, so sub-second fixtures survive the round
+    /// trip through a SQL literal.
+    fn to_sql_literal(value: &Self) -> String {
+        format!("'{}'", value.format("%H:%M:%S%.6f"))
+    }
+
+    fn arbitrary_value() -> proptest::strategy::BoxedStrategy<Self> {
+        use proptest::strategy::Strategy;
+        proptest::sample::select(time_values().to_vec()).boxed()
+    }
+}
+
+impl OrderedScalar for chrono::NaiveTime {
+    /// Noon: `Default` (midnight) is already `min_pivot`.
+    fn mid_pivot() -> Self {
+        chrono::NaiveTime::from_hms_opt(12, 0, 0).expect("literal time")
+    }
+}
+
+#[cfg(test)]
+mod time_value_tests {
+    use super::*;
+
+    #[test]
+    fn time_pivots_are_distinct_fixtures() {
+        let values = time_values();
+        let min = <chrono::NaiveTime as OrderedScalar>::min_pivot();
+        let mid = <chrono::NaiveTime as OrderedScalar>::mid_pivot();
+        let max = <chrono::NaiveTime as OrderedScalar>::max_pivot();
+        for p in [min, mid, max] {
+            assert!(values.contains(&p), "pivot {p} must be a fixture");
+        }
+        assert_eq!(min, chrono::NaiveTime::default(), "midnight is the minimum");
+        assert!(min < mid && mid < max, "mid_pivot must be interior");
+    }
+
+    #[test]
+    fn time_to_sql_literal_keeps_microseconds() {
+        let t = chrono::NaiveTime::from_hms_micro_opt(23, 59, 59, 999_999).unwrap();
+        assert_eq!(
+            <chrono::NaiveTime as ScalarType>::to_sql_literal(&t),
+            "'23:59:59.999999'"
+        );
+    }
+}
+
+/// Harness newtype over sqlx's [`PgInterval`](sqlx::postgres::types::PgInterval)
+/// for the `interval` scalar. `PgInterval` compares its three fields
+/// structurally, which is not Postgres' order, so `Eq`/`Ord` are by
+/// [`span`](Self::span) — the canonical key Postgres `interval_cmp` uses and
+/// the value the fixture generator encrypts. Two intervals with equal spans
+/// (`'1 mon'`, `'30 days'`) are therefore equal here, in SQL, and in the
+/// ciphertext. `Default` is the zero interval (the origin / mid pivot).
+///
+/// As with `F4`/`F8`, the transparent `sqlx::Type` derive supplies `Decode`
+/// and `Encode` against Postgres `interval`.
+#[derive(Debug, Clone, sqlx::Type)]
+#[sqlx(transparent)]
+pub struct Interval(pub sqlx::postgres::types::PgInterval);
+
+impl Interval {
+    const MICROS_PER_DAY: i128 = 86_400_000_000;
+
+    /// The canonical span in microseconds: a month counts as 30 days and a
+    /// day as 24 hours, exactly as Postgres orders intervals. `i128` because
+    /// the three fields together can exceed `i64`; the encrypted range is
+    /// checked where the value is lifted into a `Plaintext`.
+    pub fn span(&self) -> i128 {
+        let PgInterval {
+            months,
+            days,
+            microseconds,
+        } = self.0;
+        (i128::from(months) * 30 + i128::from(days)) * Self::MICROS_PER_DAY
+            + i128::from(microseconds)
+    }
+
+    /// Parse an ISO-8601 duration (`P1Y2M3W4DT5H6M7.000008S`) as Postgres
+    /// does: `Y`/`M`/`W`/`D` fill months and days, the time part fills
+    /// microseconds, each component carries its own sign, and only seconds may
+    /// be fractional (to at most microsecond precision). The catalog fixtures
+    /// use this form because it is unambiguous and zero-dep to store.
+    pub fn parse_iso8601(s: &str) -> Result<Self> {
+        let rest = s
+            .strip_prefix('P')
+            .with_context(|| format!("interval {s:?} must start with 'P'"))?;
+        let (date, time) = match rest.split_once('T') {
+            Some((date, time)) if time.is_empty() => bail!("interval {s:?} has an empty time part"),
+            Some((date, time)) => (date, Some(time)),
+            None => (rest, None),
+        };
+        if date.is_empty() && time.is_none() {
+            bail!("interval {s:?} has no components");
+        }
+        let mut interval = PgInterval {
+            months: 0,
+            days: 0,
+            microseconds: 0,
+        };
+        for (number, unit) in iso8601_components(date, s)? {
+            let n: i32 = number
+                .parse()
+                .with_context(|| format!("interval {s:?}: bad {unit} count {number:?}"))?;
+            let (field, scale) = match unit {
+                'Y' => (&mut interval.months, 12),
+                'M' => (&mut interval.months, 1),
+                'W' => (&mut interval.days, 7),
+                'D' => (&mut interval.days, 1),
+                other => bail!("interval {s:?}: unknown date designator {other:?}"),
+            };
+            *field = n
+                .checked_mul(scale)
+                .and_then(|v| field.checked_add(v))
+                .with_context(|| format!("interval {s:?} overflows its {unit} field"))?;
+        }
+        for (number, unit) in iso8601_components(time.unwrap_or(""), s)? {
+            let micros = match unit {
+                'H' => whole_micros(number, 3_600_000_000, s)?,
+                'M' => whole_micros(number, 60_000_000, s)?,
+                'S' => second_micros(number, s)?,
+                other => bail!("interval {s:?}: unknown time designator {other:?}"),
+            };
+            interval.microseconds = interval
+                .microseconds
+                .checked_add(micros)
+                .with_context(|| format!("interval {s:?} overflows its microseconds"))?;
+        }
+        Ok(Interval(interval))
+    }
+}
+
+/// Split one ISO-8601 duration part into `(number, designator)` pairs.
+fn iso8601_components<'a>(part: &'a str, whole: &str) -> Result<Vec<(&'a str, char)>> {
+    let mut out = Vec::new();
+    let mut start = 0;
+    for (i, c) in part.char_indices() {
+        if c.is_ascii_uppercase() {
+            let number = &part[start..i];
+            if number.is_empty() || number == "-" || number == "+" {
+                bail!("interval {whole:?}: designator {c:?} has no number");
+            }
+            out.push((number, c));
+            start = i + c.len_utf8();
+        }
+    }
+    if start != part.len() {
+        bail!(
+            "interval {whole:?}: trailing {:?} has no designator",
+            &part[start..]
+        );
+    }
+    Ok(out)
+}
+
+fn whole_micros(number: &str, scale: i64, whole: &str) -> Result<i64> {
+    let n: i64 = number
+        .parse()
+        .with_context(|| format!("interval {whole:?}: bad count {number:?}"))?;
+    n.checked_mul(scale)
+        .with_context(|| format!("interval {whole:?} overflows its microseconds"))
+}
+
+/// Seconds with an optional fraction of at most six digits, as microseconds.
+fn second_micros(number: &str, whole: &str) -> Result<i64> {
+    let (negative, digits) = match number.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, number.strip_prefix('+').unwrap_or(number)),
+    };
+    let (int, frac) = digits.split_once('.').unwrap_or((digits, ""));
+    if frac.len() > 6 || !frac.bytes().all(|b| b.is_ascii_digit()) {
+        bail!("interval {whole:?}: seconds {number:?} exceed microsecond precision");
+    }
+    let int: i64 = int
+        .parse()
+        .with_context(|| format!("interval {whole:?}: bad seconds {number:?}"))?;
+    let frac: i64 = format!("{frac:0<6}").parse().expect("six ascii digits");
+    let magnitude = int
+        .checked_mul(1_000_000)
+        .and_then(|v| v.checked_add(frac))
+        .with_context(|| format!("interval {whole:?} overflows its microseconds"))?;
+    Ok(if negative { -magnitude } else { magnitude })
+}
+
+impl PartialEq for Interval {
+    fn eq(&self, other: &Self) -> bool {
+        self.span() == other.span()
+    }
+}
+impl Eq for Interval {}
+impl Ord for Interval {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.span().cmp(&other.span())
+    }
+}
+impl PartialOrd for Interval {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Default for Interval {
+    fn default() -> Self {
+        Interval(PgInterval {
+            months: 0,
+            days: 0,
+            microseconds: 0,
+        })
+    }
+}
+/// Postgres' own `postgres`-style output (`1 mons 2 days -01:00:00.000001`),
+/// which its interval input accepts back with every field intact.
+impl std::fmt::Display for Interval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let PgInterval {
+            months,
+            days,
+            microseconds,
+        } = self.0;
+        let sign = if microseconds < 0 { "-" } else { "" };
+        let us = microseconds.unsigned_abs();
+        write!(
+            f,
+            "{months} mons {days} days {sign}{:02}:{:02}:{:02}.{:06}",
+            us / 3_600_000_000,
+            us / 60_000_000 % 60,
+            us / 1_000_000 % 60,
+            us % 1_000_000,
+        )
+    }
+}
+
+// `interval` value wiring goes through the shared `lazy_values!` materialiser,
+// parsing the catalog's ISO-8601 `Fixture::Interval` strings into the newtype.
+lazy_values! {
+    cell      = INTERVAL_VALUES_CELL,
+    accessor  = interval_values,
+    rust_type = Interval,
+    spec      = eql_domains::INTERVAL_FIXTURES,
+    variant   = Interval,
+    pg_type   = "interval",
+    parse     = |f| match f {
+        eql_domains::Fixture::Interval(s) => Interval::parse_iso8601(s)
+            .unwrap_or_else(|e| panic!("invalid interval catalog fixture {s:?}: {e:#}")),
+        other => panic!("non-interval fixture in interval catalog row: {other:?}"),
+    },
+}
+
+impl ScalarType for Interval {
+    const PG_TYPE: &'static str = "interval";
+    const PLAINTEXT_SQL_TYPE: &'static str =
+        <Interval as crate::fixtures::EqlPlaintext>::PLAINTEXT_SQL_TYPE.as_str();
+
+    fn fixture_values() -> &'static [Self] {
+        interval_values()
+    }
+
+    fn to_sql_literal(value: &Self) -> String {
+        format!("'{value}'")
+    }
+
+    fn arbitrary_value() -> proptest::strategy::BoxedStrategy<Self> {
+        use proptest::strategy::Strategy;
+        proptest::sample::select(interval_values().to_vec()).boxed()
+    }
+}
+
+impl OrderedScalar for Interval {
+    // Boundary pivots derive from `fixture_values()` (= ±1000 years);
+    // `mid_pivot` inherits the zero interval, a real fixture and the origin.
+}
+
+impl SignedScalar for Interval {
+    /// Intervals are signed about the zero span; fixtures straddle it.
+    fn origin() -> Self {
+        Interval::default()
+    }
+}
+
+/// Guards for the interval wiring: the catalog keys `Fixture::Interval` by its
+/// literal string, but the fixture table (and Postgres `=`) key on the span,
+/// so aliasing fixtures would insert duplicate `plaintext` rows.
+#[cfg(test)]
+mod interval_value_guards {
+    use super::*;
+
+    fn iv(s: &str) -> Interval {
+        Interval::parse_iso8601(s).unwrap()
+    }
+
+    #[test]
+    fn fixtures_are_distinct_by_span() {
+        use std::collections::HashSet;
+        let vals = interval_values();
+        let unique: HashSet<i128> = vals.iter().map(Interval::span).collect();
+        assert_eq!(
+            unique.len(),
+            vals.len(),
+            "two interval fixtures alias to the same span"
+        );
+    }
+
+    #[test]
+    fn fixtures_are_listed_in_span_order() {
+        let vals = interval_values();
+        assert!(vals.windows(2).all(|w| w[0] < w[1]));
+        assert_eq!(<Interval as OrderedScalar>::min_pivot(), iv("P-1000Y"));
+        assert_eq!(<Interval as OrderedScalar>::max_pivot(), iv("P1000Y"));
+        assert!(vals.contains(&<Interval as SignedScalar>::origin()));
+    }
+
+    /// The equalities and orderings Postgres `interval_cmp` gives, which the
+    /// encrypted span must reproduce.
+    #[test]
+    fn span_follows_postgres_interval_semantics() {
+        assert_eq!(iv("P1M"), iv("P30D"));
+        assert_eq!(iv("P1D"), iv("PT24H"));
+        assert_eq!(iv("P1W"), iv("P7D"));
+        assert_eq!(iv("P1Y"), iv("P12M"));
+        assert!(iv("P29DT23H59M59S") < iv("P1M"));
+        assert!(iv("P-1M") < iv("P-1D"));
+        assert_eq!(iv("P1M-30D").span(), 0);
+    }
+
+    #[test]
+    fn parse_keeps_fields_and_fractional_seconds() {
+        let PgInterval {
+            months,
+            days,
+            microseconds,
+        } = iv("P1Y2M3W4DT5H6M7.000008S").0;
+        assert_eq!((months, days), (14, 25));
+        assert_eq!(microseconds, ((5 * 60 + 6) * 60 + 7) * 1_000_000 + 8);
+        assert_eq!(iv("PT-0.000001S").0.microseconds, -1);
+    }
+
+    #[test]
+    fn parse_rejects_malformed_durations() {
+        for bad in [
+            "",
+            "P",
+            "1D",
+            "PT",
+            "P1",
+            "P1.5D",
+            "PT1.0000001S",
+            "P1X",
+            "PD",
+        ] {
+            assert!(
+                Interval::parse_iso8601(bad).is_err(),
+                "{bad:?} must not parse"
+            );
+        }
+    }
+
+    #[test]
+    fn display_is_postgres_style_and_keeps_fields() {
+        assert_eq!(iv("P1M1D").to_string(), "1 mons 1 days 00:00:00.000000");
+        assert_eq!(iv("PT-1H").to_string(), "0 mons 0 days -01:00:00.000000");
+        assert_eq!(
+            <Interval as ScalarType>::to_sql_literal(&iv("PT23H59M59.5S")),
+            "'0 mons 0 days 23:59:59.500000'"
+        );
+    }
+}
+
 /// Per-domain capability + payload shape, resolved from `CATALOG`. Each
 /// variant maps to a domain suffix (`Eq` => `_eq`, `Search` => `_search`,
 /// …); its terms, required payload keys, supported operators, and
@@ -1928,7 +2583,10 @@ mod oracle_inventory_tests {
                 "numeric",
                 "text",
                 "real",
-                "double"
+                "double",
+                "timestamp_naive",
+                "time",
+                "interval"
             ],
         );
         // bool is storage-only: no ordered domain, so it is excluded.
@@ -1964,7 +2622,10 @@ mod oracle_inventory_tests {
                 "numeric",
                 "text",
                 "real",
-                "double"
+                "double",
+                "timestamp_naive",
+                "time",
+                "interval"
             ],
             "a new ordered scalar must be wired into BOTH oracle suites \
              (fixture_oracle.rs and e2e_oracle.rs)"
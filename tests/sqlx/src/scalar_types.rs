@@ -61,6 +61,11 @@ macro_rules! scalar_types {
             boolean => bool,
             real => eql_tests::scalar_domains::F4,
             double => eql_tests::scalar_domains::F8,
+            uuid => uuid::Uuid,
+            timestamp_naive => chrono::NaiveDateTime,
+            time => chrono::NaiveTime,
+            interval => eql_tests::scalar_domains::Interval,
+            bytea => eql_tests::scalar_domains::Bytes,
         }
     };
 }
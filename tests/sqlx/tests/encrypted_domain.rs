@@ -64,12 +64,39 @@ mod real_ord_ope;
 #[path = "encrypted_domain/ope/double_ord_ope.rs"]
 mod double_ord_ope;
 
+#[path = "encrypted_domain/ope/timestamp_naive_ord_ope.rs"]
+mod timestamp_naive_ord_ope;
+
+#[path = "encrypted_domain/ope/time_ord_ope.rs"]
+mod time_ord_ope;
+
+#[path = "encrypted_domain/ope/interval_ord_ope.rs"]
+mod interval_ord_ope;
+
 // Signed-only sign-boundary suite (`int`, `date`). Like the text suites it
 // lives outside `scalars::` so the matrix-inventory snapshot (which pins the
 // uniform per-type set) does not see the signed-only delta.
 #[path = "encrypted_domain/signed.rs"]
 mod signed;
 
+// Per-type semantic suites: behaviour one scalar type defines beyond the
+// uniform matrix (which only compares each pivot against its own fixture).
+// Each sits outside `scalars::`, like `signed`, so the matrix-inventory
+// snapshot does not read its module name as a scalar type.
+//
+// `timestamp_naive`: ordering and equality under a DST-observing session zone.
+#[path = "encrypted_domain/timestamp_naive_wall_clock.rs"]
+mod timestamp_naive_wall_clock;
+
+// `interval`: the span ordering Postgres uses, through the plaintext oracle
+// and every ordered domain.
+#[path = "encrypted_domain/interval_canonical_order.rs"]
+mod interval_canonical_order;
+
+// `bytea`: encrypted `=` / `eq_term` joins agree with the plaintext join.
+#[path = "encrypted_domain/bytea_digest_join.rs"]
+mod bytea_digest_join;
+
 // Float edge-case behavioural suite (NaN / ±0 / ±Inf). Creds/e2e-gated: it
 // encrypts the special values FRESH at test time, so NaN never enters the shared
 // double fixture table (where it would corrupt the all-pairs oracle). Deliberately
@@ -98,3 +125,9 @@ mod jsonb_entry;
 // does not mis-read them as scalar types. See `encrypted_domain/property/mod.rs`.
 #[path = "encrypted_domain/property/mod.rs"]
 mod property;
+
+// Array containment suite: literal `hm`-array payloads through `@>` / `<@` /
+// `&&`, the blockers, the CHECK and a GIN index over `eq_terms`. Outside
+// `scalars::` for the same reason as `signed`.
+#[path = "encrypted_domain/array_containment.rs"]
+mod array_containment;
@@ -0,0 +1,142 @@
+//! Literal-payload smoke tests for the array domains
+//! (`public.eql_v3_{integer,text}_array_contains`): `@>` / `<@` / `&&` compare
+//! the per-element `hm` sets, `=` and the ordering operators raise (blockers —
+//! the storage domain only carries a set of element terms), the domain CHECK
+//! requires `hm` to be a JSON array, and a GIN index over `eq_terms` engages
+//! for containment. Uses hand-written payloads carrying `hm` arrays — the
+//! fixture proxy encrypts scalars only, so there are no array fixtures to load.
+//!
+//! Outside `scalars::` for the same reason as `signed`.
+use sqlx::PgPool;
+
+const DOMAINS: &[&str] = &[
+    "public.eql_v3_integer_array_contains",
+    "public.eql_v3_text_array_contains",
+];
+
+/// A stored array payload whose element terms are `hm`, e.g. `["aa","bb"]`.
+fn stored(hm: &str) -> String {
+    format!(r#"{{"v":"3","i":{{}},"c":"x","hm":{hm}}}"#)
+}
+
+#[sqlx::test]
+async fn containment_compares_element_term_sets(pool: PgPool) -> anyhow::Result<()> {
+    // (lhs, rhs, @>, <@, &&)
+    let cases = [
+        (r#"["aa","bb","cc"]"#, r#"["bb"]"#, true, false, true),
+        (r#"["aa","bb"]"#, r#"["bb","aa"]"#, true, true, true),
+        (r#"["aa"]"#, r#"["bb"]"#, false, false, false),
+        (r#"["aa","bb"]"#, r#"["bb","dd"]"#, false, false, true),
+        // An empty needle is contained by everything and overlaps nothing.
+        (r#"["aa"]"#, "[]", true, false, false),
+    ];
+    for domain in DOMAINS {
+        for (lhs, rhs, contains, contained_by, overlaps) in cases {
+            let got: (bool, bool, bool) = sqlx::query_as(&format!(
+                "SELECT ($1::jsonb::{domain}) @> ($2::jsonb::{domain}), \
+                        ($1::jsonb::{domain}) <@ ($2::jsonb::{domain}), \
+                        ($1::jsonb::{domain}) && ($2::jsonb::{domain})"
+            ))
+            .bind(stored(lhs))
+            .bind(stored(rhs))
+            .fetch_one(&pool)
+            .await?;
+            assert_eq!(
+                got,
+                (contains, contained_by, overlaps),
+                "{domain}: {lhs} vs {rhs} as (@>, <@, &&)"
+            );
+        }
+    }
+    Ok(())
+}
+
+#[sqlx::test]
+async fn equality_and_ordering_are_blocked(pool: PgPool) -> anyhow::Result<()> {
+    let payload = stored(r#"["aa"]"#);
+    for domain in DOMAINS {
+        for op in ["=", "<>", "<", ">"] {
+            let sql = format!("SELECT ($1::jsonb::{domain}) {op} ($2::jsonb::{domain})");
+            eql_tests::assert_raises(
+                &pool,
+                &sql,
+                &[Some(&payload), Some(&payload)],
+                "not supported",
+            )
+            .await?;
+        }
+    }
+    Ok(())
+}
+
+#[sqlx::test]
+async fn payload_check_requires_an_hm_array(pool: PgPool) -> anyhow::Result<()> {
+    // A scalar `hm` (the shape an equality-only scalar stores) and a missing
+    // `hm` must both be rejected at the cast.
+    for domain in DOMAINS {
+        for bad in [
+            r#"{"v":"3","i":{},"c":"x","hm":"aa"}"#,
+            r#"{"v":"3","i":{},"c":"x"}"#,
+        ] {
+            eql_tests::assert_raises(
+                &pool,
+                &format!("SELECT $1::jsonb::{domain}"),
+                &[Some(bad)],
+                "violates check constraint",
+            )
+            .await?;
+        }
+    }
+    Ok(())
+}
+
+#[sqlx::test]
+async fn gin_index_on_eq_terms_engages_for_containment(pool: PgPool) -> anyhow::Result<()> {
+    // (operator, needle terms, ids hit) over rows [aa,bb], [bb,cc], [dd].
+    let cases = [
+        ("@>", r#"["bb"]"#, vec![1, 2]),
+        ("&&", r#"["bb"]"#, vec![1, 2]),
+        ("<@", r#"["bb","cc","dd"]"#, vec![2, 3]),
+    ];
+    for family in ["integer_array", "text_array"] {
+        let mut tx = pool.begin().await?;
+        let table = format!("{family}_tags");
+        let index = format!("{table}_eq_terms");
+        sqlx::query(&format!(
+            "CREATE TABLE {table} (id serial PRIMARY KEY, v public.eql_v3_{family}_contains)"
+        ))
+        .execute(&mut *tx)
+        .await?;
+        sqlx::query(&format!(
+            "CREATE INDEX {index} ON {table} USING gin (eql_v3.eq_terms(v))"
+        ))
+        .execute(&mut *tx)
+        .await?;
+        for hm in [r#"["aa","bb"]"#, r#"["bb","cc"]"#, r#"["dd"]"#] {
+            sqlx::query(&format!("INSERT INTO {table} (v) VALUES ($1::jsonb)"))
+                .bind(stored(hm))
+                .execute(&mut *tx)
+                .await?;
+        }
+        // Forces `enable_seqscan = off` so this is an index-validity proof, not
+        // a cost-model assertion about a three-row table.
+        sqlx::query("SET LOCAL enable_seqscan = off")
+            .execute(&mut *tx)
+            .await?;
+
+        for (op, needle, expected) in &cases {
+            let needle = format!(
+                r#"'{{"v":"3","i":{{}},"hm":{needle}}}'::jsonb::eql_v3.query_{family}_contains"#
+            );
+            let query = format!("SELECT id FROM {table} WHERE v {op} {needle}");
+            let context = format!("{family} {op}");
+            eql_tests::matrix::assert_index_scan_uses(&mut *tx, &query, &index, &context).await?;
+            let hits: Vec<i32> = sqlx::query_scalar(&format!("{query} ORDER BY id"))
+                .fetch_all(&mut *tx)
+                .await?;
+            assert_eq!(&hits, expected, "{context} {needle}");
+        }
+        tx.rollback().await?;
+    }
+    Ok(())
+}
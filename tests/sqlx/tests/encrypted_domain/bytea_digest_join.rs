@@ -0,0 +1,57 @@
+//! Join semantics for `bytea`: two relations joined through the encrypted
+//! domain.
+//!
+//! The point of an encrypted `bytea_eq` is that a column of digests can be
+//! joined on `=` like its plaintext would be. These tests join the fixture
+//! table to itself through the encrypted domain (both the operator and the
+//! bare `eq_term`, the form a functional index serves) and assert the result
+//! is exactly the plaintext `bytea` join — the empty string, length-only
+//! neighbours (`\x00` / `\x0000`) and the final-byte digest pair included.
+use eql_tests::scalar_domains::{Bytes, ScalarType};
+use sqlx::PgPool;
+
+async fn join_pairs(pool: &PgPool, on: &str) -> anyhow::Result<Vec<(i64, i64)>> {
+    let table = Bytes::fixture_table_name();
+    let pairs: Vec<(i64, i64)> = sqlx::query_as(&format!(
+        "SELECT a.id, b.id FROM {table} a JOIN {table} b ON {on} \
+         ORDER BY 1, 2"
+    ))
+    .fetch_all(pool)
+    .await?;
+    Ok(pairs)
+}
+
+#[sqlx::test(fixtures(path = "../../fixtures", scripts("eql_v3_bytea")))]
+async fn encrypted_join_matches_plaintext_join(pool: PgPool) -> anyhow::Result<()> {
+    let expected = join_pairs(&pool, "a.plaintext = b.plaintext").await?;
+    // Fixtures are distinct, so the plaintext join is the identity.
+    assert_eq!(expected.len(), Bytes::fixture_values().len());
+    assert!(expected.iter().all(|(a, b)| a == b));
+
+    for on in [
+        "(a.payload)::public.eql_v3_bytea_eq = (b.payload)::public.eql_v3_bytea_eq",
+        "eql_v3.eq_term((a.payload)::public.eql_v3_bytea_eq) \
+           = eql_v3.eq_term((b.payload)::public.eql_v3_bytea_eq)",
+    ] {
+        assert_eq!(
+            join_pairs(&pool, on).await?,
+            expected,
+            "join on `{on}` must pair exactly the equal byte strings"
+        );
+    }
+    Ok(())
+}
+
+#[sqlx::test(fixtures(path = "../../fixtures", scripts("eql_v3_bytea")))]
+async fn anti_join_on_neq_excludes_only_self(pool: PgPool) -> anyhow::Result<()> {
+    let n = Bytes::fixture_values().len() as i64;
+    let table = Bytes::fixture_table_name();
+    let count: i64 = sqlx::query_scalar(&format!(
+        "SELECT count(*) FROM {table} a JOIN {table} b \
+           ON (a.payload)::public.eql_v3_bytea_eq <> (b.payload)::public.eql_v3_bytea_eq"
+    ))
+    .fetch_one(&pool)
+    .await?;
+    assert_eq!(count, n * (n - 1), "`<>` must pair every distinct fixture");
+    Ok(())
+}
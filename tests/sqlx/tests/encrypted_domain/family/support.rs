@@ -165,13 +165,15 @@ async fn no_cross_variant_operator_is_declared(pool: PgPool) -> Result<()> {
     // The check is structural (`pg_operator`) rather than dynamic
     // ("invoke and see it raise") so a future PG version with stricter
     // operator resolution doesn't mask the regression.
-    // Derive the excluded (non-scalar) domain names from the catalog rather than
-    // hardcoding `'json', 'jsonb_entry', 'query_json'` — a future rename or a
-    // second non-scalar family stays covered automatically (the names come from
-    // the same `DomainFamily::domain_name` the SQL surface is generated through).
+    // Derive the excluded (hand-written SteVec) domain names from the catalog
+    // rather than hardcoding `'json', 'jsonb_entry', 'query_json'` — a future
+    // rename or a second such family stays covered automatically (the names
+    // come from the same `DomainFamily::domain_name` the SQL surface is
+    // generated through). The array families are generated, so they stay in
+    // scope: their storage and `_contains` domains must not mix either.
     let excluded: Vec<String> = eql_domains::CATALOG
         .iter()
-        .filter(|f| !f.is_scalar())
+        .filter(|f| !f.is_generated())
         .flat_map(|f| f.domains.iter().map(move |d| f.domain_name(d)))
         .collect();
     assert!(
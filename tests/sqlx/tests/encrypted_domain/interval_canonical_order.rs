@@ -0,0 +1,103 @@
+//! Canonical-order semantics for `interval`.
+//!
+//! Postgres does not order intervals field by field: `interval_cmp` compares
+//! the span with a month counted as 30 days and a day as 24 hours, so
+//! `P29DT23H59M59S < P1M` and `'1 mon' = '30 days'`. The encrypted domains
+//! carry that same span, so these tests pin that the plaintext oracle, the
+//! harness' `Interval` order, and every ordered domain agree — including on
+//! the pairs whose fields alone would order the other way.
+use eql_tests::scalar_domains::{Interval, ScalarType};
+use sqlx::PgPool;
+
+/// Each ordered domain with the extractor `ORDER BY` sorts on: the `op` term
+/// for `_ord` / `_ord_ope`, the `ob` term for `_ord_ore`.
+const ORDERED_DOMAINS: &[(&str, &str)] = &[
+    ("eql_v3_interval_ord", "ord_term"),
+    ("eql_v3_interval_ord_ore", "ord_term_ore"),
+    ("eql_v3_interval_ord_ope", "ord_term"),
+];
+
+fn iv(s: &str) -> Interval {
+    Interval::parse_iso8601(s).expect("literal interval")
+}
+
+#[sqlx::test(fixtures(path = "../../fixtures", scripts("eql_v3_interval")))]
+async fn plaintext_oracle_orders_by_canonical_span(pool: PgPool) -> anyhow::Result<()> {
+    let table = Interval::fixture_table_name();
+
+    let got: Vec<Interval> =
+        sqlx::query_scalar(&format!("SELECT plaintext FROM {table} ORDER BY plaintext"))
+            .fetch_all(&pool)
+            .await?;
+    let mut expected = Interval::fixture_values().to_vec();
+    expected.sort();
+    assert_eq!(
+        got, expected,
+        "Postgres interval order must be the documented canonical span order"
+    );
+
+    // The aliasing the span implies, on the plaintext side.
+    let aliases: bool = sqlx::query_scalar(
+        "SELECT '1 mon'::interval = '30 days'::interval \
+            AND '1 day'::interval = '24 hours'::interval",
+    )
+    .fetch_one(&pool)
+    .await?;
+    assert!(
+        aliases,
+        "Postgres must equate month = 30 days and day = 24 hours"
+    );
+    Ok(())
+}
+
+#[sqlx::test(fixtures(path = "../../fixtures", scripts("eql_v3_interval")))]
+async fn order_by_encrypted_matches_plaintext_interval_order(pool: PgPool) -> anyhow::Result<()> {
+    let table = Interval::fixture_table_name();
+    let expected: Vec<Interval> =
+        sqlx::query_scalar(&format!("SELECT plaintext FROM {table} ORDER BY plaintext"))
+            .fetch_all(&pool)
+            .await?;
+
+    for (domain, extractor) in ORDERED_DOMAINS {
+        let got: Vec<Interval> = sqlx::query_scalar(&format!(
+            "SELECT plaintext FROM {table} \
+             ORDER BY eql_v3.{extractor}((payload)::public.{domain})"
+        ))
+        .fetch_all(&pool)
+        .await?;
+        assert_eq!(
+            got, expected,
+            "{domain}: ORDER BY must follow Postgres interval order"
+        );
+    }
+    Ok(())
+}
+
+#[sqlx::test(fixtures(path = "../../fixtures", scripts("eql_v3_interval")))]
+async fn cross_field_pairs_compare_by_span(pool: PgPool) -> anyhow::Result<()> {
+    let table = Interval::fixture_table_name();
+
+    // Each pair's larger field sits in the *smaller* value: field-wise order
+    // would invert it, span order does not.
+    let pairs = [
+        ("P29DT23H59M59S", "P1M"),
+        ("PT23H59M59S", "P1D"),
+        ("P-1M", "P-1D"),
+        ("P1M1D", "P1Y"),
+    ];
+
+    for (domain, _) in ORDERED_DOMAINS {
+        for (lo, hi) in pairs {
+            let lt: bool = sqlx::query_scalar(&format!(
+                "SELECT (SELECT (payload)::public.{domain} FROM {table} WHERE plaintext = $1) \
+                      < (SELECT (payload)::public.{domain} FROM {table} WHERE plaintext = $2)"
+            ))
+            .bind(iv(lo))
+            .bind(iv(hi))
+            .fetch_one(&pool)
+            .await?;
+            assert!(lt, "{domain}: {lo} must order before {hi}");
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,13 @@
+//! `public.eql_v3_interval_ord_ope` smoke suite — the shared `_ord_ope`
+//! literal-payload tests (see `ope/support.rs`). The canonical-span semantics
+//! that make `interval` ordering non-lexical live in `interval_canonical_order`.
+
+crate::ope_ord_smoke!("eql_v3_interval_ord_ope");
+
+// Real-ciphertext coverage: the generated fixture's client-emitted
+// `op` terms must order and compare like the plaintext oracle.
+crate::ope_ord_fixture_smoke!(
+    "eql_v3_interval_ord_ope",
+    eql_tests::scalar_domains::Interval,
+    "eql_v3_interval"
+);
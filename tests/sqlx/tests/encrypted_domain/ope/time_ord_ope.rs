@@ -0,0 +1,10 @@
+//! `public.eql_v3_time_ord_ope` smoke suite — the shared `_ord_ope`
+//! literal-payload tests (see `ope/support.rs`). A time of day encrypts as
+//! microseconds since midnight, so the surface is the integer one modulo the
+//! domain name.
+
+crate::ope_ord_smoke!("eql_v3_time_ord_ope");
+
+// Real-ciphertext coverage: the generated fixture's client-emitted
+// `op` terms must order and compare like the plaintext oracle.
+crate::ope_ord_fixture_smoke!("eql_v3_time_ord_ope", chrono::NaiveTime, "eql_v3_time");
@@ -0,0 +1,13 @@
+//! `public.eql_v3_timestamp_naive_ord_ope` smoke suite — the shared `_ord_ope`
+//! literal-payload tests (see `ope/support.rs`). The wall-clock semantics that
+//! set this family apart from `timestamp` live in `timestamp_naive_wall_clock`.
+
+crate::ope_ord_smoke!("eql_v3_timestamp_naive_ord_ope");
+
+// Real-ciphertext coverage: the generated fixture's client-emitted
+// `op` terms must order and compare like the plaintext oracle.
+crate::ope_ord_fixture_smoke!(
+    "eql_v3_timestamp_naive_ord_ope",
+    chrono::NaiveDateTime,
+    "eql_v3_timestamp_naive"
+);
@@ -0,0 +1,120 @@
+//! fixture suite: `BloomFilter::matches` (eql-bindings) agrees with the SQL
+//! `@@` operator (`eql_v3.matches`) on the text `_match` domain.
+//!
+//! Unlike `match_smoke`, this needs no plaintext oracle: the client-side port
+//! must give the database's answer on *every* pair, false positives included.
+//! proptest samples the generated `eql_v3_text` rows (with repeats) and adds
+//! one derived needle per case — a random subset of one row's `bf` plus a few
+//! arbitrary `i16` positions, written back into that row's payload. The
+//! derived needle reaches what the fixture filters cannot: an empty `bf` (the
+//! empty-needle guard; every fixture value is at least trigram-length) and
+//! negative, upper-half positions. Every ordered pair of the sample is then
+//! decided by one SQL round trip and compared with the Rust verdict.
+
+use super::fixture_oracle::{drive_proptest, load_rows};
+use anyhow::{Context, Result};
+use eql_bindings::v3::terms::BloomFilter;
+use proptest::prelude::*;
+use proptest::test_runner::Config;
+use serde_json::Value;
+use sqlx::PgPool;
+use std::sync::Arc;
+
+/// `public.eql_v3_text_match` — the bloom-filter (`bf`) domain (`@@` fuzzy match).
+const TEXT_MATCH_DOMAIN: &str = "public.eql_v3_text_match";
+
+/// Same budget as the fixture oracles: read-only SQL over committed fixtures.
+const BLOOM_PARITY_CASES: u32 = 32;
+
+/// The payload's `bf`, as the bindings deserialize it.
+fn bloom_of(payload: &Value) -> Result<BloomFilter> {
+    let bf = payload
+        .get("bf")
+        .context("payload has no `bf` match term")?;
+    Ok(serde_json::from_value(bf.clone())?)
+}
+
+/// A copy of `payload` whose `bf` keeps the positions `mask` (cycled) selects,
+/// followed by `extra`. An all-`false` mask and no `extra` is an empty needle.
+fn derive_needle(payload: &Value, mask: &[bool], extra: &[i16]) -> Result<Value> {
+    let mut bits: Vec<i16> = bloom_of(payload)?
+        .0
+        .into_iter()
+        .enumerate()
+        .filter(|(i, _)| mask[i % mask.len()])
+        .map(|(_, bit)| bit)
+        .collect();
+    bits.extend_from_slice(extra);
+    let mut needle = payload.clone();
+    needle["bf"] = serde_json::json!(bits);
+    Ok(needle)
+}
+
+/// Assert `haystack.matches(needle)` equals `haystack @@ needle` for every
+/// ordered pair (self-pairs included) of `payloads`.
+async fn assert_matches_parity(pool: &PgPool, payloads: &[Value]) -> Result<()> {
+    let texts: Vec<String> = payloads.iter().map(Value::to_string).collect();
+    let sql = format!(
+        "SELECT a.i, b.i, a.p::jsonb::{TEXT_MATCH_DOMAIN} @@ b.p::jsonb::{TEXT_MATCH_DOMAIN} \
+         FROM unnest($1::text[]) WITH ORDINALITY a(p, i) \
+         CROSS JOIN unnest($1::text[]) WITH ORDINALITY b(p, i)"
+    );
+    let verdicts: Vec<(i64, i64, bool)> = sqlx::query_as(&sql)
+        .bind(&texts)
+        .fetch_all(pool)
+        .await
+        .context("evaluating `@@` over the sample")?;
+    anyhow::ensure!(
+        verdicts.len() == payloads.len() * payloads.len(),
+        "expected {} pairs, SQL returned {}",
+        payloads.len() * payloads.len(),
+        verdicts.len()
+    );
+
+    let blooms = payloads.iter().map(bloom_of).collect::<Result<Vec<_>>>()?;
+    for (a, b, sql) in verdicts {
+        // WITH ORDINALITY is 1-based.
+        let (haystack, needle) = (&blooms[a as usize - 1], &blooms[b as usize - 1]);
+        anyhow::ensure!(
+            haystack.matches(needle) == sql,
+            "BloomFilter::matches disagrees with `@@` (SQL said {sql}): \
+             haystack bf {:?}, needle bf {:?}",
+            haystack.0,
+            needle.0
+        );
+    }
+    Ok(())
+}
+
+#[sqlx::test]
+async fn text_match_agrees_with_sql(pool: PgPool) -> Result<()> {
+    let rows = load_rows::<String>(&pool).await?;
+    let payloads: Arc<Vec<Value>> = Arc::new(
+        rows.iter()
+            .map(|row| serde_json::from_str(&row.payload_json))
+            .collect::<serde_json::Result<_>>()?,
+    );
+    let n = payloads.len();
+
+    let config = Config {
+        cases: BLOOM_PARITY_CASES,
+        failure_persistence: None,
+        ..Config::default()
+    };
+    let strategy = (
+        prop::collection::vec(0..n, 1..9),
+        0..n,
+        prop::collection::vec(any::<bool>(), 1..6),
+        prop::collection::vec(any::<i16>(), 0..3),
+    );
+    drive_proptest(config, strategy, move |(idxs, base, mask, extra)| {
+        let pool = pool.clone();
+        let payloads = payloads.clone();
+        async move {
+            let mut sample: Vec<Value> = idxs.iter().map(|&i| payloads[i].clone()).collect();
+            sample.push(derive_needle(&payloads[base], &mask, &extra)?);
+            assert_matches_parity(&pool, &sample).await
+        }
+    })
+    .await
+}
@@ -200,3 +200,9 @@ cross_ciphertext_test!(cross_ciphertext_date, chrono::NaiveDate);
 cross_ciphertext_test!(cross_ciphertext_timestamp, chrono::DateTime<chrono::Utc>);
 cross_ciphertext_test!(cross_ciphertext_numeric, rust_decimal::Decimal);
 cross_ciphertext_test!(cross_ciphertext_text, String);
+cross_ciphertext_test!(cross_ciphertext_timestamp_naive, chrono::NaiveDateTime);
+cross_ciphertext_test!(cross_ciphertext_time, chrono::NaiveTime);
+cross_ciphertext_test!(
+    cross_ciphertext_interval,
+    eql_tests::scalar_domains::Interval
+);
@@ -178,6 +178,42 @@ e2e_oracle_suite!(
             .with_timezone(&chrono::Utc),
     ]
 );
+e2e_oracle_suite!(
+    timestamp_naive,
+    chrono::NaiveDateTime,
+    "proptest_e2e_timestamp_naive",
+    seeds = [
+        chrono::NaiveDate::from_ymd_opt(1900, 1, 1)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap(),
+        chrono::NaiveDateTime::default(),
+        chrono::NaiveDate::from_ymd_opt(2099, 12, 31)
+            .unwrap()
+            .and_hms_opt(23, 59, 59)
+            .unwrap(),
+    ]
+);
+e2e_oracle_suite!(
+    time,
+    chrono::NaiveTime,
+    "proptest_e2e_time",
+    seeds = [
+        chrono::NaiveTime::default(),
+        chrono::NaiveTime::from_hms_opt(12, 0, 0).unwrap(),
+        chrono::NaiveTime::from_hms_micro_opt(23, 59, 59, 999_999).unwrap(),
+    ]
+);
+e2e_oracle_suite!(
+    interval,
+    eql_tests::scalar_domains::Interval,
+    "proptest_e2e_interval",
+    seeds = [
+        eql_tests::scalar_domains::Interval::parse_iso8601("P-1000Y").unwrap(),
+        eql_tests::scalar_domains::Interval::default(),
+        eql_tests::scalar_domains::Interval::parse_iso8601("P1000Y").unwrap(),
+    ]
+);
 e2e_oracle_suite!(
     numeric,
     rust_decimal::Decimal,
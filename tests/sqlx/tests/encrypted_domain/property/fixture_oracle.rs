@@ -78,6 +78,26 @@ pub(crate) fn embedded_fixture_sql<T: ScalarType>() -> &'static str {
             env!("CARGO_MANIFEST_DIR"),
             "/fixtures/eql_v3_double.sql"
         )),
+        "uuid" => include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/fixtures/eql_v3_uuid.sql"
+        )),
+        "timestamp_naive" => include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/fixtures/eql_v3_timestamp_naive.sql"
+        )),
+        "time" => include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/fixtures/eql_v3_time.sql"
+        )),
+        "interval" => include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/fixtures/eql_v3_interval.sql"
+        )),
+        "bytea" => include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/fixtures/eql_v3_bytea.sql"
+        )),
         other => panic!(
             "no embedded fixture for catalog token '{other}'; \
              add an include_str! arm in fixture_oracle.rs"
@@ -124,6 +144,18 @@ pub(crate) fn embedded_doubles_sql<T: ScalarType>() -> &'static str {
             env!("CARGO_MANIFEST_DIR"),
             "/fixtures/eql_v3_text_doubles.sql"
         )),
+        "timestamp_naive" => include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/fixtures/eql_v3_timestamp_naive_doubles.sql"
+        )),
+        "time" => include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/fixtures/eql_v3_time_doubles.sql"
+        )),
+        "interval" => include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/fixtures/eql_v3_interval_doubles.sql"
+        )),
         other => panic!(
             "no embedded doubles fixture for catalog token '{other}'; \
              add an include_str! arm in embedded_doubles_sql"
@@ -211,7 +243,7 @@ fn pick<T: Clone>(all: &[Row<T>], idxs: &[usize]) -> Vec<Row<T>> {
 /// runtimes, and it works under any runtime flavour. Shrinking is preserved:
 /// proptest re-invokes the closure with shrunk inputs, which flow through the
 /// same channel.
-async fn drive_proptest<V, S, F, Fut>(config: Config, strategy: S, body: F) -> Result<()>
+pub(crate) async fn drive_proptest<V, S, F, Fut>(config: Config, strategy: S, body: F) -> Result<()>
 where
     V: std::fmt::Debug + Send + 'static,
     S: Strategy<Value = V> + Send + 'static,
@@ -335,6 +367,11 @@ fixture_oracle_suite!(numeric, rust_decimal::Decimal, ordered);
 fixture_oracle_suite!(text, String, ordered);
 fixture_oracle_suite!(real, eql_tests::scalar_domains::F4, ordered);
 fixture_oracle_suite!(double, eql_tests::scalar_domains::F8, ordered);
+fixture_oracle_suite!(uuid, uuid::Uuid, eq_only);
+fixture_oracle_suite!(timestamp_naive, chrono::NaiveDateTime, ordered);
+fixture_oracle_suite!(time, chrono::NaiveTime, ordered);
+fixture_oracle_suite!(interval, eql_tests::scalar_domains::Interval, ordered);
+fixture_oracle_suite!(bytea, eql_tests::scalar_domains::Bytes, eq_only);
 
 // --- function-double oracles -------------------------------------
 //
@@ -433,13 +470,21 @@ macro_rules! fixture_fn_oracle_suite {
 
 fixture_fn_oracle_suite!(smallint_fn, i16, ordered);
 fixture_fn_oracle_suite!(bigint_fn, i64, ordered);
-// date, timestamp, and numeric are all ordered scalars on the `eql_v3` base,
+// date, timestamp, timestamp_naive, time, interval, and numeric are all ordered
+// scalars on the `eql_v3` base,
 // so each gets eq/neq functions + eq_term identity plus the four ord functions
 // on both ordered twins. The generated fixtures already encrypt the whole
 // catalog, so this is full function-level coverage at zero marginal ZeroKMS cost.
 fixture_fn_oracle_suite!(date_fn, chrono::NaiveDate, ordered);
 fixture_fn_oracle_suite!(timestamp_fn, chrono::DateTime<chrono::Utc>, ordered);
 fixture_fn_oracle_suite!(numeric_fn, rust_decimal::Decimal, ordered);
+fixture_fn_oracle_suite!(timestamp_naive_fn, chrono::NaiveDateTime, ordered);
+fixture_fn_oracle_suite!(time_fn, chrono::NaiveTime, ordered);
+fixture_fn_oracle_suite!(interval_fn, eql_tests::scalar_domains::Interval, ordered);
+// uuid and bytea are equality-only: eq/neq functions + eq_term identity on
+// their `_eq` domain.
+fixture_fn_oracle_suite!(uuid_fn, uuid::Uuid, eq_only);
+fixture_fn_oracle_suite!(bytea_fn, eql_tests::scalar_domains::Bytes, eq_only);
 
 // text is bespoke rather than `fixture_fn_oracle_suite!`: its ordered domains
 // carry `hm` plus an ordering term (`Ope` for `text_ord`, `Ore` for
@@ -27,6 +27,9 @@ mod edge_cases;
 mod fixture_oracle;
 // fixture suite: example-based bloom match smoke over the text `_match` fixtures.
 mod match_smoke;
+// fixture suite: `BloomFilter::matches` (eql-bindings) vs `@@` on every sampled
+// pair of text `_match` payloads, plus derived needles.
+mod bloom_match_parity;
 // fixture suite: cross-ciphertext equality over the per-type doubles fixtures
 // (each plaintext encrypted twice) — proves two independent encryptions of one
 // value compare equal through both the hm (`_eq`) and ORE (`_ord`/`_ord_ore`)
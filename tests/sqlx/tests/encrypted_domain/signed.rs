@@ -1,9 +1,10 @@
 //! Sign-boundary coverage for **signed** scalars (`smallint`/`integer`/`bigint`, `date`,
-//! `timestamp`, `real`/`double`) — the `SignedScalar` delta on top of the
+//! `timestamp`/`timestamp_naive`, `real`/`double`, `interval`) — the `SignedScalar` delta on top of the
 //! uniform ordered matrix.
 //!
 //! ORE encrypts signed values as an offset from a numeric origin (`0` for
-//! integers and floats, the epoch for `date`/`timestamp`). This suite asserts the ORE block ordering is
+//! integers and floats, the epoch for the dated temporal scalars, the zero span
+//! for `interval`; `time` has none — midnight is its minimum). This suite asserts the ORE block ordering is
 //! **monotonic across that origin**: a fixture below the origin orders before
 //! the origin, which orders before a fixture above it — through the encrypted
 //! `_ord` domain, with no decryption.
@@ -68,6 +69,16 @@ async fn timestamp_sign_boundary(pool: PgPool) -> anyhow::Result<()> {
     sign_boundary_is_monotonic::<chrono::DateTime<chrono::Utc>>(&pool).await
 }
 
+#[sqlx::test(fixtures(path = "../../fixtures", scripts("eql_v3_timestamp_naive")))]
+async fn timestamp_naive_sign_boundary(pool: PgPool) -> anyhow::Result<()> {
+    sign_boundary_is_monotonic::<chrono::NaiveDateTime>(&pool).await
+}
+
+#[sqlx::test(fixtures(path = "../../fixtures", scripts("eql_v3_interval")))]
+async fn interval_sign_boundary(pool: PgPool) -> anyhow::Result<()> {
+    sign_boundary_is_monotonic::<eql_tests::scalar_domains::Interval>(&pool).await
+}
+
 #[sqlx::test(fixtures(path = "../../fixtures", scripts("eql_v3_real")))]
 async fn real_sign_boundary(pool: PgPool) -> anyhow::Result<()> {
     sign_boundary_is_monotonic::<eql_tests::scalar_domains::F4>(&pool).await
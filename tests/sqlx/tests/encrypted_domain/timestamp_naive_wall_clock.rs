@@ -0,0 +1,115 @@
+//! Wall-clock semantics for `timestamp_naive` outside the default (UTC)
+//! session.
+//!
+//! A naive reading has no instant: `2021-03-14T02:30:00` does not exist in
+//! `America/New_York` and `2021-11-07T01:30:00` happens there twice. These tests
+//! switch the session to that zone and assert the encrypted domains still order
+//! and compare by the written fields alone — the DST gap and the ambiguous hour
+//! are not shifted, collapsed, or rejected. The plaintext oracle column is
+//! `timestamp without time zone`, so it is zone-independent too.
+use eql_tests::scalar_domains::ScalarType;
+use sqlx::PgPool;
+
+/// A DST-observing zone whose 2021 transitions the catalog fixtures straddle.
+const DST_ZONE: &str = "America/New_York";
+
+/// Each ordered domain with the extractor `ORDER BY` sorts on: the `op` term
+/// for `_ord` / `_ord_ope`, the `ob` term for `_ord_ore`.
+const ORDERED_DOMAINS: &[(&str, &str)] = &[
+    ("eql_v3_timestamp_naive_ord", "ord_term"),
+    ("eql_v3_timestamp_naive_ord_ore", "ord_term_ore"),
+    ("eql_v3_timestamp_naive_ord_ope", "ord_term"),
+];
+
+fn reading(s: &str) -> chrono::NaiveDateTime {
+    chrono::NaiveDateTime::parse_from_str(s, "%Y-%m-%dT%H:%M:%S").expect("literal reading")
+}
+
+/// Acquire one connection with the session zone switched, so every query in
+/// the test runs under it (a pool may hand out a fresh connection per query).
+async fn dst_session(pool: &PgPool) -> anyhow::Result<sqlx::pool::PoolConnection<sqlx::Postgres>> {
+    let mut conn = pool.acquire().await?;
+    sqlx::query(&format!("SET TIME ZONE '{DST_ZONE}'"))
+        .execute(&mut *conn)
+        .await?;
+    Ok(conn)
+}
+
+#[sqlx::test(fixtures(path = "../../fixtures", scripts("eql_v3_timestamp_naive")))]
+async fn order_by_follows_wall_clock_under_dst_zone(pool: PgPool) -> anyhow::Result<()> {
+    let table = chrono::NaiveDateTime::fixture_table_name();
+    let mut conn = dst_session(&pool).await?;
+
+    let mut expected = chrono::NaiveDateTime::fixture_values().to_vec();
+    expected.sort();
+
+    for (domain, extractor) in ORDERED_DOMAINS {
+        let got: Vec<chrono::NaiveDateTime> = sqlx::query_scalar(&format!(
+            "SELECT plaintext FROM {table} \
+             ORDER BY eql_v3.{extractor}((payload)::public.{domain})"
+        ))
+        .fetch_all(&mut *conn)
+        .await?;
+        assert_eq!(
+            got, expected,
+            "{domain}: ORDER BY under {DST_ZONE} must be wall-clock order"
+        );
+    }
+    Ok(())
+}
+
+#[sqlx::test(fixtures(path = "../../fixtures", scripts("eql_v3_timestamp_naive")))]
+async fn dst_gap_and_ambiguous_readings_compare_by_fields(pool: PgPool) -> anyhow::Result<()> {
+    let table = chrono::NaiveDateTime::fixture_table_name();
+    let mut conn = dst_session(&pool).await?;
+
+    // Spring-forward: 01:59:59 < 02:30:00 (nonexistent) < 03:00:00.
+    // Fall-back: 01:30:00 (ambiguous) < 02:00:00.
+    let chains: [&[&str]; 2] = [
+        &[
+            "2021-03-14T01:59:59",
+            "2021-03-14T02:30:00",
+            "2021-03-14T03:00:00",
+        ],
+        &["2021-11-07T01:30:00", "2021-11-07T02:00:00"],
+    ];
+
+    for (domain, _) in ORDERED_DOMAINS {
+        for chain in chains {
+            for pair in chain.windows(2) {
+                let (lo, hi) = (reading(pair[0]), reading(pair[1]));
+                let (lt, eq): (bool, bool) = sqlx::query_as(&format!(
+                    "SELECT \
+                       (SELECT (payload)::public.{domain} FROM {table} WHERE plaintext = $1) \
+                         < (SELECT (payload)::public.{domain} FROM {table} WHERE plaintext = $2), \
+                       (SELECT (payload)::public.{domain} FROM {table} WHERE plaintext = $1) \
+                         = (SELECT (payload)::public.{domain} FROM {table} WHERE plaintext = $1)"
+                ))
+                .bind(lo)
+                .bind(hi)
+                .fetch_one(&mut *conn)
+                .await?;
+                assert!(lt, "{domain}: {lo} must order before {hi} under {DST_ZONE}");
+                assert!(eq, "{domain}: {lo} must equal itself under {DST_ZONE}");
+            }
+        }
+    }
+    Ok(())
+}
+
+#[sqlx::test(fixtures(path = "../../fixtures", scripts("eql_v3_timestamp_naive")))]
+async fn plaintext_oracle_is_not_shifted_by_session_zone(pool: PgPool) -> anyhow::Result<()> {
+    let table = chrono::NaiveDateTime::fixture_table_name();
+    let mut conn = dst_session(&pool).await?;
+
+    let got: Vec<chrono::NaiveDateTime> =
+        sqlx::query_scalar(&format!("SELECT plaintext FROM {table} ORDER BY id"))
+            .fetch_all(&mut *conn)
+            .await?;
+    assert_eq!(
+        got,
+        chrono::NaiveDateTime::fixture_values(),
+        "the `timestamp without time zone` oracle must read back verbatim under {DST_ZONE}"
+    );
+    Ok(())
+}
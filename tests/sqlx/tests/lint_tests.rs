@@ -94,11 +94,13 @@ async fn lint_categories_are_well_known(pool: PgPool) -> Result<()> {
         "inlinability_volatility",
         "inlinability_set_clause",
         "inlinability_secdef",
+        "inlinability_body",
         "inlinability_transitive",
         "blocker_language",
         "blocker_strict",
         "domain_over_domain",
         "domain_opclass",
+        "index_record_ops",
         "schema_placement",
     ];
     for row in rows {
@@ -191,6 +193,109 @@ async fn lint_flags_strict_blocker(pool: PgPool) -> Result<()> {
     Ok(())
 }
 
+/// Operator-implementation bodies for the `inlinability_body` fixtures, with
+/// whether the lint must flag each. Every body is `LANGUAGE sql IMMUTABLE`
+/// with no `SET` clause, so only the body rule can fire. The unflagged rows
+/// pin the parser: a `;` or quote inside a comment or string literal, and a
+/// trailing semicolon, must not read as a second statement; nor may a `--`
+/// inside a literal hide the statement after it.
+const BODY_FIXTURES: &[(&str, &str, bool)] = &[
+    (
+        "two_selects",
+        "SELECT true; SELECT a::jsonb = b::jsonb",
+        true,
+    ),
+    (
+        "cte",
+        "WITH t AS (SELECT a::jsonb = b::jsonb AS v) SELECT v FROM t",
+        true,
+    ),
+    ("values", "VALUES (a::jsonb = b::jsonb)", true),
+    (
+        "leading_comment_then_two",
+        "-- compare\nSELECT 1; SELECT a::jsonb = b::jsonb",
+        true,
+    ),
+    ("plain", "SELECT a::jsonb = b::jsonb", false),
+    ("trailing_semicolon", "SELECT a::jsonb = b::jsonb;", false),
+    ("parenthesised", "(SELECT a::jsonb = b::jsonb)", false),
+    (
+        "comment_marker_in_literal_then_two",
+        "SELECT a::jsonb ->> '--' = b::jsonb ->> 'x'; SELECT true",
+        true,
+    ),
+    (
+        "semicolons_in_comments",
+        "-- it's; a comment\n/* one; two ' */ SELECT a::jsonb = b::jsonb",
+        false,
+    ),
+    (
+        "semicolon_in_literal",
+        "SELECT a::jsonb ->> 'x;y' = b::jsonb ->> 'it''s;'",
+        false,
+    ),
+    // Escape strings are not recognised (see the lint's `@note`): the `\'`
+    // ends the literal early and the `;` after it reads as a second
+    // statement. A known false positive, pinned so a fix is deliberate.
+    (
+        "escape_string_literal_is_not_recognised",
+        "SELECT a::jsonb ->> E'it\\'s;' = b::jsonb ->> 'x'",
+        true,
+    ),
+];
+
+/// Generates one operator per [`BODY_FIXTURES`] row on
+/// `public.eql_v3_integer_eq` (a distinct operator name each, `=` repeated)
+/// and asserts `inlinability_body` fires for exactly the flagged rows.
+#[sqlx::test]
+async fn lint_flags_non_single_select_bodies(pool: PgPool) -> Result<()> {
+    for (i, (label, body, _)) in BODY_FIXTURES.iter().enumerate() {
+        sqlx::raw_sql(&format!(
+            "CREATE FUNCTION eql_v3.test_body_{label}(a public.eql_v3_integer_eq, b public.eql_v3_integer_eq) \
+             RETURNS boolean LANGUAGE sql IMMUTABLE AS $fixture$ {body} $fixture$; \
+             CREATE OPERATOR {op} (FUNCTION = eql_v3.test_body_{label}, \
+               LEFTARG = public.eql_v3_integer_eq, RIGHTARG = public.eql_v3_integer_eq);",
+            op = "=".repeat(i + 3),
+        ))
+        .execute(&pool)
+        .await?;
+    }
+
+    let rows = fetch_lints(&pool).await?;
+    for (label, body, flagged) in BODY_FIXTURES {
+        let hits: Vec<&LintRow> = rows
+            .iter()
+            .filter(|r| {
+                r.category == "inlinability_body"
+                    && r.object_name.contains(&format!("test_body_{label}("))
+            })
+            .collect();
+        assert_eq!(
+            !hits.is_empty(),
+            *flagged,
+            "{label}: body {body:?} must{} be flagged; got {hits:#?}",
+            if *flagged { "" } else { " not" }
+        );
+        if let Some(hit) = hits.first() {
+            assert_eq!(
+                hit.severity, "error",
+                "inlinability_body must be severity=error"
+            );
+        }
+    }
+
+    // Only the fixtures: the generated surface is all single SELECT bodies.
+    let stray: Vec<&LintRow> = rows
+        .iter()
+        .filter(|r| r.category == "inlinability_body" && !r.object_name.contains("test_body_"))
+        .collect();
+    assert!(
+        stray.is_empty(),
+        "unexpected inlinability_body rows: {stray:#?}"
+    );
+    Ok(())
+}
+
 /// Generated encrypted-domain blockers intentionally use non-inlinable
 /// plpgsql functions. They should be checked by the blocker-specific lint
 /// rules, not reported as normal operator inlinability failures.
@@ -279,6 +384,66 @@ async fn lint_domain_opclass_surface_is_clean(pool: PgPool) -> Result<()> {
     Ok(())
 }
 
+/// Reproduces the skipped ORE opclass: on a non-superuser install the DO block
+/// in `src/v3/sem/ore_block_256/operator_class.sql` leaves
+/// `eql_v3_internal.ore_block_256` without a default btree opclass, and a btree
+/// on `eql_v3.ord_term_ore(col)` still builds — bound to `record_ops`. Dropping
+/// the family leaves the same state on this superuser install (the
+/// `ore_fallback.sql` poison never ran, so the ORE column is still usable).
+/// The index must be reported as `error` under `index_record_ops`, and the same
+/// index over the real opclass must not be.
+#[sqlx::test]
+async fn lint_flags_ore_index_bound_to_record_ops(pool: PgPool) -> Result<()> {
+    sqlx::query("CREATE TABLE lint_ore (v public.eql_v3_integer_ord_ore)")
+        .execute(&pool)
+        .await?;
+    sqlx::query("CREATE INDEX lint_ore_good ON lint_ore USING btree (eql_v3.ord_term_ore(v))")
+        .execute(&pool)
+        .await?;
+    let clean = fetch_lints(&pool).await?;
+    assert!(
+        !clean.iter().any(|r| r.category == "index_record_ops"),
+        "an index over the ORE opclass must not be flagged, got: {:#?}",
+        clean
+    );
+
+    // The family CASCADE takes the good index with it.
+    sqlx::query(
+        "DROP OPERATOR FAMILY eql_v3_internal.ore_block_256_operator_family USING btree CASCADE",
+    )
+    .execute(&pool)
+    .await?;
+    sqlx::query("CREATE INDEX lint_ore_fallback ON lint_ore USING btree (eql_v3.ord_term_ore(v))")
+        .execute(&pool)
+        .await?;
+    let opclass: String = sqlx::query_scalar(
+        "SELECT oc.opcname::text FROM pg_index i
+           JOIN pg_opclass oc ON oc.oid = i.indclass[0]
+          WHERE i.indexrelid = 'lint_ore_fallback'::regclass",
+    )
+    .fetch_one(&pool)
+    .await?;
+    assert_eq!(
+        opclass, "record_ops",
+        "precondition: the fallback reproduced"
+    );
+
+    let rows = fetch_lints(&pool).await?;
+    let violations: Vec<&LintRow> = rows
+        .iter()
+        .filter(|r| r.category == "index_record_ops")
+        .collect();
+    assert_eq!(
+        violations.len(),
+        1,
+        "expected exactly the fallback index, got: {:#?}",
+        violations
+    );
+    assert_eq!(violations[0].object_name, "index public.lint_ore_fallback");
+    assert_eq!(violations[0].severity, "error");
+    Ok(())
+}
+
 /// The public `eql_v3` schema must hold only jsonb-backed encrypted-domain
 /// types; a naked composite/enum type there is an internal index-term type in
 /// the wrong schema (Table-Builder-picker clutter the split exists to prevent).
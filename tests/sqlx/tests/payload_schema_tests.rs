@@ -539,6 +539,19 @@ fn assert_converts_to_valid_v3(v2: &Value, domain: &str) -> Value {
     out
 }
 
+// The v3 schemas pattern-check ciphertext and term encodings, so the v3
+// fixtures are well-formed on the wire: `c`/`h` are RFC 1924 base85 of a
+// MessagePack value, `hm` is 32 bytes, `op` leads with its CLLW domain tag
+// (0x00 scalar, 0x02 SteVec string entry), and each `ob` block term is
+// `49 * N + 16` bytes.
+const V3_CIPHERTEXT: &str = "#2|S>z49Rj@Pv0_@bk+}c1_0zAW;>X4~g=|+REcZyD<";
+const V3_KEY_HEADER: &str = "lf(=XMg4Dt-<sFOv(R>^Yh`(JWpphu%>e)";
+const V3_ENTRY_CIPHERTEXT: &str = "tPYP;r!{Pdkv|~z_bsp@(oG7M+SEK27s08HcoF";
+const V3_HMAC: &str = "9e0327371065d095864f15ada0b846c1c0ebc5348adc799adf849bad05d4a10a";
+const V3_OPE_SCALAR: &str = "00c0441eaaeeb4b48efa0b1f0abd80e9";
+const V3_OPE_STRING: &str = "0298a35aba5ea0bd8799c1350d439e71";
+const V3_ORE: &str = "897aa75fde3134a4aa72e05628ac6fe68a733d1161a15d8eae2bb042d7958aedb1d594d6d112d34f6602f4de7110e993ae7422923d7d171165dc1906f63d57997a";
+
 /// The fully-populated v2.3 scalar payload used by the conversion tests
 /// (`op` included: cipherstash-client emits it for OPE-ordered columns ahead
 /// of the v3 envelope).
@@ -546,12 +559,12 @@ fn v2_ct_full() -> Value {
     json!({
         "v": 2,
         "k": "ct",
-        "c": CIPHERTEXT,
+        "c": V3_CIPHERTEXT,
         "i": ident(),
-        "hm": HEX,
+        "hm": V3_HMAC,
         "bf": [12, 47, 91, 40000],
-        "ob": [HEX, HEX_LONG],
-        "op": HEX
+        "ob": [V3_ORE, V3_ORE],
+        "op": V3_OPE_SCALAR
     })
 }
 
@@ -585,10 +598,10 @@ fn native_v3_ste_vec_document_validates_against_published_v3_schema() {
         "v": 3,
         "k": "sv",
         "i": ident(),
-        "h": "mp_base85_key_header",
+        "h": V3_KEY_HEADER,
         "sv": [
-            { "s": SELECTOR, "c": CIPHERTEXT },
-            { "s": SELECTOR, "a": true, "c": CIPHERTEXT, "op": HEX_LONG }
+            { "s": SELECTOR, "c": V3_ENTRY_CIPHERTEXT },
+            { "s": SELECTOR, "a": true, "c": V3_ENTRY_CIPHERTEXT, "op": V3_OPE_STRING }
         ]
     });
     assert_valid(
@@ -598,6 +611,43 @@ fn native_v3_ste_vec_document_validates_against_published_v3_schema() {
     );
 }
 
+#[test]
+fn published_v3_schemas_reject_malformed_encodings() {
+    // Each case passes the binding struct's shape check but carries an
+    // encoding the schema's `pattern`s refuse.
+    let text_search = load_v3_schema("eql_v3_text_search_ore");
+    let valid = json!({
+        "v": 3, "i": ident(), "c": V3_CIPHERTEXT,
+        "hm": V3_HMAC, "ob": [V3_ORE], "bf": [12, 47]
+    });
+    assert_valid(&text_search, &valid, "well-formed text_search_ore");
+    let truncated = &V3_CIPHERTEXT[..41];
+    let cases = [
+        ("c", json!(truncated), "ciphertext one character short"),
+        ("c", json!("not base85"), "ciphertext outside the alphabet"),
+        ("hm", json!(HEX), "16-byte hm"),
+        ("ob", json!([HEX_LONG]), "ob term not 49 * N + 16 bytes"),
+    ];
+    for (key, value, label) in cases {
+        let mut payload = valid.clone();
+        payload[key] = value;
+        assert_invalid(&text_search, &payload, label);
+    }
+    assert_invalid(
+        &load_v3_schema("eql_v3_text_ord_ope"),
+        &json!({ "v": 3, "i": ident(), "c": V3_CIPHERTEXT, "op": HEX }),
+        "op without a CLLW domain tag",
+    );
+    assert_invalid(
+        &load_v3_schema("eql_v3_json_search"),
+        &json!({
+            "v": 3, "k": "sv", "i": ident(), "h": V3_KEY_HEADER,
+            "sv": [{ "s": "9493d6", "c": V3_ENTRY_CIPHERTEXT }]
+        }),
+        "selector shorter than the entry nonce",
+    );
+}
+
 #[test]
 fn from_v2_ste_vec_query_fails_closed() {
     use eql_bindings::from_v2::{from_v2_query, TargetDomain};
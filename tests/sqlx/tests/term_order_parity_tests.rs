@@ -0,0 +1,99 @@
+//! Parity between the client-side term orders in `eql-bindings` and the SQL
+//! comparators they port:
+//!
+//!   * `OreBlock256::cmp` vs `eql_v3_internal.compare_ore_block_256_terms`
+//!     over `eql_v3_internal.ore_block_256(payload)`;
+//!   * `OpeCllw::cmp` vs native comparison of `eql_v3_internal.ope_cllw(payload)`.
+//!
+//! Every pair of rows (both orders, and each row against itself) of a
+//! generated fixture is compared on both sides. The fixtures cover the three
+//! ORE widths — 8 blocks (`integer`), 12 (`timestamp`), 14 (`numeric`) — and
+//! multi-term `ob` arrays plus variable-length `op` terms (`text`). A
+//! mismatch names the two fixture ids, so the disagreeing pair can be
+//! replayed in `psql`.
+
+use anyhow::Result;
+use eql_bindings::v3::terms::{OpeCllw, OreBlock256};
+use sqlx::PgPool;
+
+/// `-1` / `0` / `1` for an [`std::cmp::Ordering`], matching the SQL result.
+fn sign(ordering: std::cmp::Ordering) -> i32 {
+    ordering as i32
+}
+
+/// Assert the Rust `Ord` (and `Eq`) of both order terms agrees with SQL for
+/// every pair of rows in `fixtures.{table}` that carry the term.
+async fn assert_parity(pool: &PgPool, table: &str) -> Result<()> {
+    let ore_pairs: Vec<(i64, i64, String, String, i32)> = sqlx::query_as(&format!(
+        "SELECT a.id, b.id, (a.payload->'ob')::text, (b.payload->'ob')::text, \
+             eql_v3_internal.compare_ore_block_256_terms( \
+                 eql_v3_internal.ore_block_256(a.payload), \
+                 eql_v3_internal.ore_block_256(b.payload)) \
+         FROM fixtures.{table} a CROSS JOIN fixtures.{table} b \
+         WHERE a.payload ? 'ob' AND b.payload ? 'ob'"
+    ))
+    .fetch_all(pool)
+    .await?;
+    anyhow::ensure!(
+        !ore_pairs.is_empty(),
+        "fixtures.{table} has no `ob`-carrying rows — nothing was cross-checked"
+    );
+    for (a_id, b_id, a, b, sql) in ore_pairs {
+        let a: OreBlock256 = serde_json::from_str(&a)?;
+        let b: OreBlock256 = serde_json::from_str(&b)?;
+        assert_eq!(
+            sign(a.cmp(&b)),
+            sql,
+            "OreBlock256::cmp disagrees with compare_ore_block_256_terms \
+             on fixtures.{table} ids {a_id} vs {b_id}"
+        );
+        assert_eq!(
+            a == b,
+            sql == 0,
+            "OreBlock256 equality disagrees with SQL on fixtures.{table} ids {a_id} vs {b_id}"
+        );
+    }
+
+    let ope_pairs: Vec<(i64, i64, String, String, i32)> = sqlx::query_as(&format!(
+        "SELECT a.id, b.id, a.payload->>'op', b.payload->>'op', \
+             CASE WHEN eql_v3_internal.ope_cllw(a.payload) < eql_v3_internal.ope_cllw(b.payload) THEN -1 \
+                  WHEN eql_v3_internal.ope_cllw(a.payload) = eql_v3_internal.ope_cllw(b.payload) THEN 0 \
+                  ELSE 1 END \
+         FROM fixtures.{table} a CROSS JOIN fixtures.{table} b \
+         WHERE a.payload ? 'op' AND b.payload ? 'op'"
+    ))
+    .fetch_all(pool)
+    .await?;
+    anyhow::ensure!(
+        !ope_pairs.is_empty(),
+        "fixtures.{table} has no `op`-carrying rows — nothing was cross-checked"
+    );
+    for (a_id, b_id, a, b, sql) in ope_pairs {
+        assert_eq!(
+            sign(OpeCllw(a).cmp(&OpeCllw(b))),
+            sql,
+            "OpeCllw::cmp disagrees with bytea order on fixtures.{table} ids {a_id} vs {b_id}"
+        );
+    }
+    Ok(())
+}
+
+#[sqlx::test(fixtures(path = "../fixtures", scripts("eql_v3_integer")))]
+async fn integer_terms_order_like_sql(pool: PgPool) -> Result<()> {
+    assert_parity(&pool, "eql_v3_integer").await
+}
+
+#[sqlx::test(fixtures(path = "../fixtures", scripts("eql_v3_timestamp")))]
+async fn timestamp_terms_order_like_sql(pool: PgPool) -> Result<()> {
+    assert_parity(&pool, "eql_v3_timestamp").await
+}
+
+#[sqlx::test(fixtures(path = "../fixtures", scripts("eql_v3_numeric")))]
+async fn numeric_terms_order_like_sql(pool: PgPool) -> Result<()> {
+    assert_parity(&pool, "eql_v3_numeric").await
+}
+
+#[sqlx::test(fixtures(path = "../fixtures", scripts("eql_v3_text")))]
+async fn text_terms_order_like_sql(pool: PgPool) -> Result<()> {
+    assert_parity(&pool, "eql_v3_text").await
+}
@@ -582,7 +582,8 @@ async fn json_entry_text_ord_cross_type_matches_plaintext_ordering(pool: PgPool)
 ///
 /// Two operand groups carry the `op` term this seam serves yet must not bind it:
 ///
-/// - **`query_date_ord` / `query_timestamp_ord` (+ `_ope` twins)** — JSON has no
+/// - **`query_date_ord` / `query_timestamp_ord` / `query_timestamp_naive_ord` /
+///   `query_time_ord` / `query_interval_ord` (+ `_ope` twins)** — JSON has no
 ///   date/timestamp type (coderdan, PR #410): those values are marshaled into
 ///   ISO-8601 STRINGS, so a "date leaf" IS a text leaf and the TEXT surface owns
 ///   it (ordering via `query_text_ord`; equality via `@>` containment).
@@ -592,6 +593,8 @@ async fn json_entry_text_ord_cross_type_matches_plaintext_ordering(pool: PgPool)
 ///   comparison. It is also the collated-equality back door: a date leaf and a
 ///   text leaf are byte-identically encoded, so an unblocked `= query_date_ord`
 ///   would be exactly the text `=` that #5 blocks, reached by a different cast.
+///   `time` and `interval` have no JSON type either; their encrypted integer
+///   (microseconds, canonical span) is not what a JSON leaf of them holds.
 /// - **`query_text_search`** — SteVec has no match/bloom capability, so `search`
 ///   offers nothing over `_ord` while its CHECK demands a `bf` the seam never
 ///   reads (coderdan, PR #410).
@@ -609,7 +612,7 @@ async fn json_entry_unserved_operand_operators_raise(pool: PgPool) -> Result<()>
     // mint one), so what is under test is OPERATOR RESOLUTION — the blocker
     // raises before reading any term. The ste_vec text-leaf operand `{v,i,op}`
     // (SteVec entries carry no `hm`) satisfies the `{v,i,op}` CHECK of
-    // `query_{date,timestamp}_ord{,_ope}`.
+    // `query_{date,timestamp,timestamp_naive,time,interval}_ord{,_ope}`.
     let temporal_operand: String = sqlx::query_scalar(&format!(
         "SELECT jsonb_build_object('v', '3', 'i', (payload::jsonb -> 'i'), \
                 'op', (payload -> '{SEL_HELLO_OP}'::text)::jsonb -> 'op')::text \
@@ -634,6 +637,18 @@ async fn json_entry_unserved_operand_operators_raise(pool: PgPool) -> Result<()>
         ("eql_v3.query_date_ord_ope", temporal_operand.as_str()),
         ("eql_v3.query_timestamp_ord", temporal_operand.as_str()),
         ("eql_v3.query_timestamp_ord_ope", temporal_operand.as_str()),
+        (
+            "eql_v3.query_timestamp_naive_ord",
+            temporal_operand.as_str(),
+        ),
+        (
+            "eql_v3.query_timestamp_naive_ord_ope",
+            temporal_operand.as_str(),
+        ),
+        ("eql_v3.query_time_ord", temporal_operand.as_str()),
+        ("eql_v3.query_time_ord_ope", temporal_operand.as_str()),
+        ("eql_v3.query_interval_ord", temporal_operand.as_str()),
+        ("eql_v3.query_interval_ord_ope", temporal_operand.as_str()),
         ("eql_v3.query_text_search", search_operand.as_str()),
     ];
 
@@ -9,6 +9,7 @@
 //! validated at INSERT — not a hand-written literal.
 
 use eql_bindings::v3::json::{SteVecDocument, SteVecEntry, SteVecQuery};
+use eql_bindings::v3::terms::Selector;
 use sqlx::PgPool;
 
 #[sqlx::test(fixtures(path = "../fixtures", scripts("v3_ste_vec")))]
@@ -91,3 +92,112 @@ async fn real_ste_vec_query_parses_into_bindings(pool: PgPool) -> anyhow::Result
     assert!(query.sv.iter().all(|entry| entry.op.is_none()));
     Ok(())
 }
+
+/// Every fixture document, parsed — the haystacks for the accessor parity
+/// tests below.
+async fn fixture_documents(pool: &PgPool) -> anyhow::Result<Vec<SteVecDocument>> {
+    let docs: Vec<serde_json::Value> =
+        sqlx::query_scalar("SELECT payload::jsonb FROM fixtures.v3_ste_vec ORDER BY id")
+            .fetch_all(pool)
+            .await?;
+    docs.into_iter()
+        .map(|doc| Ok(serde_json::from_value(doc)?))
+        .collect()
+}
+
+#[sqlx::test(fixtures(path = "../fixtures", scripts("v3_ste_vec")))]
+async fn document_accessors_match_the_sql_extractors(pool: PgPool) -> anyhow::Result<()> {
+    // For every selector of every real document (plus one no document
+    // carries), the client-side accessors return exactly what SQL does:
+    // `extract_entry` is `->`, grafted `entries_matching` is
+    // `eql_v3.jsonb_path_query` row for row, and `to_query` is
+    // `eql_v3.to_ste_vec_query`.
+    let missing = Selector("00000000000000000000000000000000".into());
+    for doc in fixture_documents(&pool).await? {
+        let wire = serde_json::to_value(&doc)?;
+        for selector in doc.sv.iter().map(|entry| &entry.s).chain([&missing]) {
+            let arrow: Option<serde_json::Value> = sqlx::query_scalar(
+                "SELECT ($1::jsonb::public.eql_v3_json_search -> $2::text)::jsonb",
+            )
+            .bind(&wire)
+            .bind(&selector.0)
+            .fetch_one(&pool)
+            .await?;
+            let extracted = doc
+                .extract_entry(selector)
+                .map(serde_json::to_value)
+                .transpose()?;
+            assert_eq!(extracted, arrow, "`->` parity for selector {}", selector.0);
+
+            let rows: Vec<serde_json::Value> = sqlx::query_scalar(
+                "SELECT e::jsonb FROM eql_v3.jsonb_path_query($1::jsonb, $2::text) AS e",
+            )
+            .bind(&wire)
+            .bind(&selector.0)
+            .fetch_all(&pool)
+            .await?;
+            let matching = doc
+                .entries_matching(selector)
+                .map(|entry| serde_json::to_value(doc.graft_metadata(entry)))
+                .collect::<Result<Vec<_>, _>>()?;
+            assert_eq!(
+                matching, rows,
+                "jsonb_path_query parity for selector {}",
+                selector.0
+            );
+        }
+
+        let needle: serde_json::Value = sqlx::query_scalar(
+            "SELECT eql_v3.to_ste_vec_query($1::jsonb::public.eql_v3_json_search)::jsonb",
+        )
+        .bind(&wire)
+        .fetch_one(&pool)
+        .await?;
+        assert_eq!(serde_json::to_value(doc.to_query())?, needle);
+    }
+    Ok(())
+}
+
+#[sqlx::test]
+async fn array_entries_match_the_sql_array_flag(pool: PgPool) -> anyhow::Result<()> {
+    // The fixture documents carry no arrays, so this document flags its
+    // entries by hand: `a` true, false and absent. `array_entries` must pick
+    // exactly the entries `eql_v3_internal.is_ste_vec_array` accepts, and
+    // `eql_v3.jsonb_array_elements` must expand each of them (with the
+    // metadata `graft_metadata` adds).
+    let doc: SteVecDocument = serde_json::from_value(serde_json::json!({
+        "v": 3, "k": "sv", "i": { "t": "users", "c": "profile" }, "h": "kh",
+        "sv": [
+            { "s": "bca213de9ccce676fa849ff9c4807963", "c": "root" },
+            { "s": "a7cea93975ed8c01f861ccb6bd082784", "c": "hello", "a": false },
+            { "s": "f510853730e1c3dbd31b86963f029dd5", "c": "first", "a": true },
+            { "s": "f510853730e1c3dbd31b86963f029dd5", "c": "second", "a": true }
+        ]
+    }))?;
+    let wire = serde_json::to_value(&doc)?;
+
+    let flagged: Vec<serde_json::Value> = sqlx::query_scalar(
+        "SELECT eql_v3.meta_data($1::jsonb) || elem \
+         FROM jsonb_array_elements($1::jsonb -> 'sv') WITH ORDINALITY AS t(elem, n) \
+         WHERE eql_v3_internal.is_ste_vec_array(elem) ORDER BY n",
+    )
+    .bind(&wire)
+    .fetch_all(&pool)
+    .await?;
+    let array_entries = doc
+        .array_entries()
+        .map(|entry| serde_json::to_value(doc.graft_metadata(entry)))
+        .collect::<Result<Vec<_>, _>>()?;
+    assert_eq!(array_entries.len(), 2);
+    assert_eq!(array_entries, flagged);
+
+    for entry in doc.array_entries() {
+        let expanded: Vec<serde_json::Value> =
+            sqlx::query_scalar("SELECT e::jsonb FROM eql_v3.jsonb_array_elements($1::jsonb) AS e")
+                .bind(serde_json::to_value(doc.graft_metadata(entry))?)
+                .fetch_all(&pool)
+                .await?;
+        assert_eq!(expanded, [serde_json::to_value(doc.graft_metadata(entry))?]);
+    }
+    Ok(())
+}
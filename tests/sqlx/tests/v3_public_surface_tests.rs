@@ -90,8 +90,8 @@ async fn public_surface(pool: &PgPool) -> Result<Vec<String>> {
     Ok(entries)
 }
 
-/// User-column domain names, as they appear in SQL: scalar-family domains plus
-/// the hand-written JSON/JSONB column domains. These MUST live in `public`
+/// User-column domain names, as they appear in SQL: scalar- and array-family
+/// domains plus the hand-written JSON/JSONB column domains. These MUST live in `public`
 /// (never `eql_v3` or `eql_v3_internal`) so application tables using them
 /// survive EQL schema uninstall. The query-operand domains are deliberately
 /// NOT here — they are never column types and live in `eql_v3`;
@@ -101,7 +101,7 @@ fn user_domain_names() -> Vec<String> {
     // prefix — resolved through the same DomainFamily::domain_name
     // the SQL surface is generated through.
     let mut names = Vec::new();
-    for family in eql_domains::scalar_families() {
+    for family in eql_domains::CATALOG.iter().filter(|f| f.is_generated()) {
         for domain in family.domains {
             names.push(family.domain_name(domain));
         }
@@ -116,12 +116,14 @@ fn user_domain_names() -> Vec<String> {
 }
 
 /// Query-operand domain names: the `query_<name>` twin of every term-bearing
-/// scalar domain plus the jsonb containment needle. These MUST live in
+/// scalar or array domain plus the jsonb containment needle. These MUST live in
 /// `eql_v3` (never `public`) — a query operand is not a column type, so it
 /// stays out of the column-type namespace and is uninstalled with the EQL
 /// surface.
 fn query_domain_names() -> Vec<String> {
-    let mut names: Vec<String> = eql_domains::scalar_families()
+    let mut names: Vec<String> = eql_domains::CATALOG
+        .iter()
+        .filter(|f| f.is_generated())
         .flat_map(|f| {
             f.domains
                 .iter()
@@ -0,0 +1,121 @@
+//! The `eql-bindings` `sqlx` feature against a live install: payload structs
+//! bind as `public.eql_v3_*` columns and `eql_v3.query_*` operands, rows
+//! decode straight into them, and the decode is the strict serde parse — an
+//! unknown key or `v: 2` is an error, not a silently widened value. The
+//! offline `Type` / `Encode` checks are in
+//! `crates/eql-bindings/tests/sqlx_codec.rs`.
+
+use anyhow::Result;
+use eql_bindings::v3::integer::{IntegerEq, IntegerEqQuery};
+use eql_bindings::v3::text::TextEq;
+use serde_json::{json, Value};
+use sqlx::PgPool;
+
+fn integer_eq() -> IntegerEq {
+    serde_json::from_value(integer_eq_wire()).unwrap()
+}
+
+fn integer_eq_wire() -> Value {
+    json!({
+        "v": 3,
+        "i": { "t": "codec", "c": "e" },
+        "c": "mp_base85_ciphertext",
+        "hm": "deadbeef"
+    })
+}
+
+#[sqlx::test]
+async fn payloads_round_trip_through_domain_columns_and_operands(pool: PgPool) -> Result<()> {
+    sqlx::query("CREATE TABLE codec (e public.eql_v3_integer_eq)")
+        .execute(&pool)
+        .await?;
+    sqlx::query("INSERT INTO codec (e) VALUES ($1)")
+        .bind(integer_eq())
+        .execute(&pool)
+        .await?;
+
+    let stored: IntegerEq = sqlx::query_scalar("SELECT e FROM codec")
+        .fetch_one(&pool)
+        .await?;
+    assert_eq!(stored, integer_eq());
+
+    let operand: IntegerEqQuery = integer_eq().to_query();
+    let echoed: IntegerEqQuery = sqlx::query_scalar("SELECT $1")
+        .bind(&operand)
+        .fetch_one(&pool)
+        .await?;
+    assert_eq!(echoed, operand);
+
+    let matched: bool = sqlx::query_scalar("SELECT e = $1 FROM codec")
+        .bind(&operand)
+        .fetch_one(&pool)
+        .await?;
+    assert!(
+        matched,
+        "the bound query operand must reach eql_v3.query_integer_eq"
+    );
+    Ok(())
+}
+
+#[sqlx::test]
+async fn a_payload_bound_to_another_domain_is_refused(pool: PgPool) -> Result<()> {
+    sqlx::query("CREATE TABLE codec_ord (o public.eql_v3_integer_ord)")
+        .execute(&pool)
+        .await?;
+    let err = sqlx::query("INSERT INTO codec_ord (o) VALUES ($1)")
+        .bind(integer_eq())
+        .execute(&pool)
+        .await
+        .expect_err("an IntegerEq parameter is not an eql_v3_integer_ord");
+    assert!(err.to_string().contains("eql_v3_integer_eq"), "{err}");
+    Ok(())
+}
+
+#[sqlx::test]
+async fn decoding_is_the_strict_parse(pool: PgPool) -> Result<()> {
+    let decode = |wire: Value| {
+        let pool = pool.clone();
+        async move {
+            sqlx::query_scalar::<_, IntegerEq>("SELECT $1::jsonb")
+                .bind(wire)
+                .fetch_one(&pool)
+                .await
+        }
+    };
+    assert_eq!(decode(integer_eq_wire()).await?, integer_eq());
+
+    let mut unknown = integer_eq_wire();
+    unknown["zz"] = json!(1);
+    let err = decode(unknown).await.expect_err("unknown key decoded");
+    assert!(err.to_string().contains("zz"), "{err}");
+
+    let mut v2 = integer_eq_wire();
+    v2["v"] = json!(2);
+    decode(v2).await.expect_err("a v: 2 payload decoded as v3");
+    Ok(())
+}
+
+#[sqlx::test]
+async fn a_domain_column_is_told_apart_only_by_its_shape(pool: PgPool) -> Result<()> {
+    // Postgres reports a domain column as `jsonb`, so the column's domain is
+    // not checked on read: another domain's row is rejected when its keys
+    // differ, and decodes when the shapes coincide.
+    let ord = json!({
+        "v": 3,
+        "i": { "t": "codec", "c": "o" },
+        "c": "mp_base85_ciphertext",
+        "op": "00ab"
+    });
+    sqlx::query_scalar::<_, TextEq>("SELECT $1::jsonb::public.eql_v3_integer_ord")
+        .bind(ord)
+        .fetch_one(&pool)
+        .await
+        .expect_err("an eql_v3_integer_ord row decoded as TextEq");
+
+    let text: TextEq = sqlx::query_scalar("SELECT $1::jsonb::public.eql_v3_integer_eq")
+        .bind(integer_eq_wire())
+        .fetch_one(&pool)
+        .await?;
+    assert_eq!(serde_json::to_value(text)?, integer_eq_wire());
+    Ok(())
+}
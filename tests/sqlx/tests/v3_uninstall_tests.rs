@@ -354,3 +354,63 @@ async fn uninstaller_preserves_application_tables_with_public_domain_columns(
 
     Ok(())
 }
+
+#[sqlx::test]
+async fn extractor_indexes_are_snapshotted_and_replayed(pool: PgPool) -> Result<()> {
+    sqlx::query(
+        "CREATE TABLE public.eql_v3_uninstall_indexed (
+           id integer PRIMARY KEY,
+           email public.eql_v3_text_eq
+         )",
+    )
+    .execute(&pool)
+    .await?;
+    sqlx::query(
+        "CREATE INDEX eql_v3_uninstall_indexed_email
+           ON public.eql_v3_uninstall_indexed USING hash (eql_v3.eq_term(email))",
+    )
+    .execute(&pool)
+    .await?;
+
+    // The uninstaller records the index before its CASCADE drops it.
+    run_shipped_uninstaller(&pool).await?;
+    let recorded: Vec<String> =
+        sqlx::query_scalar("SELECT index_name::text FROM public.eql_v3_index_snapshot ORDER BY 1")
+            .fetch_all(&pool)
+            .await?;
+    assert_eq!(
+        recorded,
+        vec!["eql_v3_uninstall_indexed_email"],
+        "only the extractor-backed index is recorded (not the primary key)"
+    );
+
+    run_shipped_installer(&pool).await?;
+    let statements: Vec<String> = sqlx::query_scalar("SELECT eql_v3.replay_indexes()")
+        .fetch_all(&pool)
+        .await?;
+    assert_eq!(statements.len(), 1, "{statements:?}");
+    assert!(
+        statements[0].starts_with(
+            "CREATE INDEX CONCURRENTLY IF NOT EXISTS eql_v3_uninstall_indexed_email ON public.eql_v3_uninstall_indexed"
+        ),
+        "{}",
+        statements[0]
+    );
+
+    // Outside a transaction, as psql's \gexec would run it.
+    sqlx::raw_sql(&statements[0]).execute(&pool).await?;
+    let restored: bool = sqlx::query_scalar(
+        "SELECT indisvalid FROM pg_catalog.pg_index
+          WHERE indexrelid = 'public.eql_v3_uninstall_indexed_email'::regclass",
+    )
+    .fetch_one(&pool)
+    .await?;
+    assert!(restored, "the replayed index must exist and be valid");
+
+    let remaining: i64 = sqlx::query_scalar("SELECT count(*) FROM eql_v3.replay_indexes()")
+        .fetch_one(&pool)
+        .await?;
+    assert_eq!(remaining, 0, "a replayed index is not offered again");
+
+    Ok(())
+}
@@ -0,0 +1,216 @@
+//! In-place upgrade gate: `eql-codegen upgrade` must replace the `eql_v3`
+//! surface under a live functional index without touching it.
+//!
+//! The `#[sqlx::test]` migration installs the release; each test builds btree
+//! and GIN indexes on the `eql_v3` extractors, renders the upgrade script
+//! from the same source tree (`render_upgrade_from_root`, upgrading from the
+//! installed `eql_v3.version()`) and applies it. `CREATE OR REPLACE` keeps a
+//! function's OID, so the extractors and the indexes built on them must come
+//! through with the same OIDs, valid, and still chosen by the planner.
+
+use anyhow::Result;
+use sqlx::{PgConnection, PgPool};
+
+/// One index per extractor shape: two btree (`eq_term`, `ord_term_ore`) and a
+/// GIN over the `eq_terms` array.
+const SETUP: &str = r#"
+CREATE TABLE upgraded (
+  id int,
+  e public.eql_v3_text_eq,
+  o public.eql_v3_integer_ord_ore,
+  a public.eql_v3_text_array_contains
+);
+CREATE INDEX upgraded_e ON upgraded (eql_v3.eq_term(e));
+CREATE INDEX upgraded_o ON upgraded (eql_v3.ord_term_ore(o));
+CREATE INDEX upgraded_a ON upgraded USING gin (eql_v3.eq_terms(a));
+"#;
+
+/// Each index with a query whose plan must use it. The operand comes from an
+/// InitPlan so the planner cannot fold it away.
+const INDEXED_QUERIES: [(&str, &str); 3] = [
+    (
+        "upgraded_e",
+        "SELECT id FROM upgraded \
+         WHERE eql_v3.eq_term(e) = (SELECT eql_v3.eq_term(e) FROM upgraded LIMIT 1)",
+    ),
+    (
+        "upgraded_o",
+        "SELECT id FROM upgraded \
+         WHERE eql_v3.ord_term_ore(o) < (SELECT eql_v3.ord_term_ore(o) FROM upgraded LIMIT 1)",
+    ),
+    (
+        "upgraded_a",
+        "SELECT id FROM upgraded \
+         WHERE eql_v3.eq_terms(a) @> (SELECT eql_v3.eq_terms(a) FROM upgraded LIMIT 1)",
+    ),
+];
+
+/// `(signature, oid)` of every `eql_v3` overload of the extractors the indexes use.
+async fn extractor_oids(conn: &mut PgConnection) -> Result<Vec<(String, i64)>> {
+    Ok(sqlx::query_as(
+        r#"
+        SELECT p.oid::regprocedure::text, p.oid::int8
+        FROM pg_catalog.pg_proc p
+        WHERE p.pronamespace = 'eql_v3'::regnamespace
+          AND p.proname IN ('eq_term', 'ord_term_ore', 'eq_terms')
+        ORDER BY 1
+        "#,
+    )
+    .fetch_all(conn)
+    .await?)
+}
+
+/// `(index, indexrelid, indisvalid, indisready)` for the test table.
+async fn indexes(conn: &mut PgConnection) -> Result<Vec<(String, i64, bool, bool)>> {
+    Ok(sqlx::query_as(
+        r#"
+        SELECT indexrelid::regclass::text, indexrelid::int8, indisvalid, indisready
+        FROM pg_catalog.pg_index
+        WHERE indrelid = 'upgraded'::regclass
+        ORDER BY 1
+        "#,
+    )
+    .fetch_all(conn)
+    .await?)
+}
+
+async fn upgrade_script(conn: &mut PgConnection) -> Result<String> {
+    let installed: String = sqlx::query_scalar("SELECT eql_v3.version()")
+        .fetch_one(conn)
+        .await?;
+    Ok(eql_codegen::upgrade::render_upgrade_from_root(
+        &eql_codegen::repo_root(),
+        &installed,
+        None,
+    )?)
+}
+
+#[sqlx::test]
+async fn upgrade_keeps_extractor_oids_and_their_indexes(pool: PgPool) -> Result<()> {
+    let mut conn = pool.acquire().await?;
+    sqlx::raw_sql(SETUP).execute(&mut *conn).await?;
+    let oids = extractor_oids(&mut conn).await?;
+    let before = indexes(&mut conn).await?;
+    assert_eq!(before.len(), 3);
+
+    let script = upgrade_script(&mut conn).await?;
+    sqlx::raw_sql(&script).execute(&mut *conn).await?;
+
+    assert_eq!(
+        extractor_oids(&mut conn).await?,
+        oids,
+        "the upgrade must replace the extractors in place, keeping their OIDs"
+    );
+    let after = indexes(&mut conn).await?;
+    assert_eq!(
+        after, before,
+        "the upgrade must not rebuild or drop an index"
+    );
+    for (index, _, valid, ready) in &after {
+        assert!(valid && ready, "{index} is not valid after the upgrade");
+    }
+
+    sqlx::query("SET enable_seqscan = off")
+        .execute(&mut *conn)
+        .await?;
+    for (index, query) in INDEXED_QUERIES {
+        let plan: Vec<String> = sqlx::query_scalar(&format!("EXPLAIN (COSTS OFF) {query}"))
+            .fetch_all(&mut *conn)
+            .await?;
+        assert!(
+            plan.iter().any(|line| line.contains(index)),
+            "{index} is not used after the upgrade:\n{}",
+            plan.join("\n")
+        );
+    }
+    Ok(())
+}
+
+#[sqlx::test]
+async fn upgrade_recreates_a_function_whose_parameter_was_renamed(pool: PgPool) -> Result<()> {
+    // An installed overload whose parameter names differ from the release's:
+    // `CREATE OR REPLACE` refuses it, so the upgrade drops and re-creates it,
+    // along with the operator it backs.
+    let mut conn = pool.acquire().await?;
+    sqlx::raw_sql(
+        r#"
+        DROP OPERATOR public.-> (public.eql_v3_json_search, text);
+        DROP FUNCTION eql_v3."->"(public.eql_v3_json_search, text);
+        CREATE FUNCTION eql_v3."->"(doc public.eql_v3_json_search, sel text)
+          RETURNS public.eql_v3_json_entry LANGUAGE sql IMMUTABLE
+          AS 'SELECT NULL::public.eql_v3_json_entry';
+        CREATE OPERATOR public.-> (
+          FUNCTION = eql_v3."->",
+          LEFTARG = public.eql_v3_json_search, RIGHTARG = text
+        );
+        "#,
+    )
+    .execute(&mut *conn)
+    .await?;
+
+    let script = upgrade_script(&mut conn).await?;
+    sqlx::raw_sql(&script).execute(&mut *conn).await?;
+
+    let names: Vec<String> = sqlx::query_scalar(
+        r#"
+        SELECT pg_catalog.unnest(proargnames)
+        FROM pg_catalog.pg_proc
+        WHERE oid = 'eql_v3."->"(public.eql_v3_json_search, text)'::regprocedure
+        "#,
+    )
+    .fetch_all(&mut *conn)
+    .await?;
+    assert_ne!(names, ["doc", "sel"], "the old definition survived");
+    let operator: Option<String> = sqlx::query_scalar(
+        "SELECT pg_catalog.to_regoperator('public.->(public.eql_v3_json_search, text)')::text",
+    )
+    .fetch_one(&mut *conn)
+    .await?;
+    assert!(operator.is_some(), "the operator was not re-created");
+    Ok(())
+}
+
+#[sqlx::test]
+async fn upgrade_aborts_before_dropping_a_retired_extractor_under_an_index(
+    pool: PgPool,
+) -> Result<()> {
+    // A function the release no longer defines, backing an index: the stale
+    // sweep must name the index and roll the whole upgrade back.
+    let mut conn = pool.acquire().await?;
+    sqlx::raw_sql(SETUP).execute(&mut *conn).await?;
+    sqlx::raw_sql(
+        r#"
+        CREATE FUNCTION eql_v3.retired_term(public.eql_v3_text_eq) RETURNS text
+          LANGUAGE sql IMMUTABLE AS 'SELECT NULL::text';
+        CREATE INDEX upgraded_retired ON upgraded (eql_v3.retired_term(e));
+        "#,
+    )
+    .execute(&mut *conn)
+    .await?;
+    let oids = extractor_oids(&mut conn).await?;
+
+    let script = upgrade_script(&mut conn).await?;
+    let err = sqlx::raw_sql(&script)
+        .execute(&mut *conn)
+        .await
+        .expect_err("the upgrade must refuse to drop an indexed function");
+    // The script's own BEGIN leaves the connection in the failed transaction.
+    sqlx::query("ROLLBACK").execute(&mut *conn).await?;
+
+    let message = err.to_string();
+    assert!(
+        message.contains("eql_v3.retired_term") && message.contains("upgraded_retired"),
+        "the error must name the function and its dependent index: {message}"
+    );
+    assert!(
+        sqlx::query_scalar::<_, Option<String>>(
+            "SELECT pg_catalog.to_regprocedure('eql_v3.retired_term(public.eql_v3_text_eq)')::text"
+        )
+        .fetch_one(&mut *conn)
+        .await?
+        .is_some(),
+        "nothing may be dropped when the upgrade aborts"
+    );
+    assert_eq!(extractor_oids(&mut conn).await?, oids);
+    Ok(())
+}